@@ -1,13 +1,15 @@
 #![forbid(unsafe_code)]
 
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
 use chatty_client_core::{ClientConfigV1, DEFAULT_SERVER_ENDPOINT_QUIC, SessionControl};
+use chatty_protocol::Codec;
 use tracing::{info, warn};
 
 fn usage_and_exit() -> ! {
 	eprintln!(
-		"Usage: chatty_client [--connect quic://host:port] [--addr ip:port] [--sni name] [--topic topic]...\n\
+		"Usage: chatty_client [--connect quic://host:port] [--addr ip:port] [--sni name] [--ca path] [--pin-sha256 hex] [--insecure-skip-verify] [--codec protobuf|cbor] [--topic topic]...\n\
 \n\
 Options:\n\
 	--connect   Server endpoint (alias: --endpoint) (default: baked build endpoint)\n\
@@ -17,11 +19,20 @@ Options:\n\
 	            Default: derived from --connect (or baked endpoint)\n\
 	--sni       TLS server name/SNI (overrides the host from --connect)\n\
 	            Default: derived from --connect host\n\
+	--ca        PEM bundle with extra trusted CA certificates\n\
+	--pin-sha256\n\
+	            Expected SHA-256 fingerprint (hex) of the server certificate\n\
+	            When set, only this certificate is accepted (works for self-signed servers)\n\
+	--insecure-skip-verify\n\
+	            Skip server certificate verification (debug builds only)\n\
+	--codec     Payload codec after the handshake: protobuf or cbor (default: protobuf)\n\
 	--topic     Topic to subscribe to (repeatable; default: room:twitch/demo)\n\
 	--help      Show this help\n\
 \n\
 Notes:\n\
 	Events are delivered over a second bidirectional QUIC stream.\n\
+	Server certificates are verified against the platform and webpki roots\n\
+	(only --insecure-skip-verify in a debug build disables verification).\n\
 \n\
 Examples:\n\
 	chatty_client --connect quic://127.0.0.1:18203 --topic room:twitch/demo\n\
@@ -35,7 +46,17 @@ fn init_tracing() {
 	tracing_subscriber::fmt().with_env_filter(filter).with_target(false).init();
 }
 
-fn parse_args() -> (SocketAddr, String, Vec<String>) {
+struct CliArgs {
+	addr: SocketAddr,
+	sni: String,
+	topics: Vec<String>,
+	ca_path: Option<PathBuf>,
+	pin_sha256: Option<String>,
+	insecure_skip_verify: bool,
+	codec: Codec,
+}
+
+fn parse_args() -> CliArgs {
 	let mut endpoint: String = DEFAULT_SERVER_ENDPOINT_QUIC.to_string();
 
 	let mut addr_override: Option<SocketAddr> = None;
@@ -43,6 +64,11 @@ fn parse_args() -> (SocketAddr, String, Vec<String>) {
 
 	let mut topics: Vec<String> = Vec::new();
 
+	let mut ca_path: Option<PathBuf> = None;
+	let mut pin_sha256: Option<String> = None;
+	let mut insecure_skip_verify = false;
+	let mut codec = Codec::Protobuf;
+
 	let mut it = std::env::args().skip(1);
 	while let Some(arg) = it.next() {
 		match arg.as_str() {
@@ -71,6 +97,34 @@ fn parse_args() -> (SocketAddr, String, Vec<String>) {
 				}
 				sni_override = Some(v);
 			}
+			"--ca" => {
+				let v = it.next().unwrap_or_else(|| usage_and_exit());
+				if v.trim().is_empty() {
+					eprintln!("--ca must be non-empty");
+					usage_and_exit();
+				}
+				ca_path = Some(PathBuf::from(v));
+			}
+			"--pin-sha256" => {
+				let v = it.next().unwrap_or_else(|| usage_and_exit());
+				if v.trim().is_empty() {
+					eprintln!("--pin-sha256 must be non-empty");
+					usage_and_exit();
+				}
+				pin_sha256 = Some(v);
+			}
+			"--insecure-skip-verify" => insecure_skip_verify = true,
+			"--codec" => {
+				let v = it.next().unwrap_or_else(|| usage_and_exit());
+				codec = match v.trim().to_ascii_lowercase().as_str() {
+					"protobuf" | "proto" => Codec::Protobuf,
+					"cbor" => Codec::Cbor,
+					_ => {
+						eprintln!("Invalid --codec value: {v} (expected protobuf or cbor)");
+						usage_and_exit();
+					}
+				};
+			}
 			"--topic" => {
 				let t = it.next().unwrap_or_else(|| usage_and_exit());
 				if t.trim().is_empty() {
@@ -103,13 +157,29 @@ fn parse_args() -> (SocketAddr, String, Vec<String>) {
 
 	let sni: String = sni_override.unwrap_or(host);
 
-	(addr, sni, topics)
+	CliArgs {
+		addr,
+		sni,
+		topics,
+		ca_path,
+		pin_sha256,
+		insecure_skip_verify,
+		codec,
+	}
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
 	init_tracing();
-	let (addr, sni, topics) = parse_args();
+	let CliArgs {
+		addr,
+		sni,
+		topics,
+		ca_path,
+		pin_sha256,
+		insecure_skip_verify,
+		codec,
+	} = parse_args();
 
 	let cfg = ClientConfigV1 {
 		server_host: sni.clone(),
@@ -161,6 +231,14 @@ async fn main() -> anyhow::Result<()> {
 			let v = v.trim().to_string();
 			(!v.is_empty()).then_some(v)
 		}),
+		youtube_user_oauth_token: std::env::var("CHATTY_CLIENT_YOUTUBE_USER_OAUTH_TOKEN").ok().and_then(|v| {
+			let v = v.trim().to_string();
+			(!v.is_empty()).then_some(v)
+		}),
+		tls_ca_path: ca_path,
+		tls_pin_sha256: pin_sha256,
+		tls_insecure_skip_verify: insecure_skip_verify,
+		codec,
 		..ClientConfigV1::default()
 	};
 
@@ -1,19 +1,23 @@
 #![forbid(unsafe_code)]
 
 use std::net::{SocketAddr, ToSocketAddrs};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::Context as _;
 use bytes::BytesMut;
-use chatty_protocol::framing::{DEFAULT_MAX_FRAME_SIZE, FramingError, encode_frame_into, try_decode_frame_from_buffer};
-use chatty_protocol::pb;
+use chatty_protocol::framing::{
+	DEFAULT_MAX_FRAME_SIZE, FramingError, encode_frame_into_with_codec, try_decode_frame_from_buffer_with_codec,
+};
+use chatty_protocol::{Codec, pb};
 use chatty_util::endpoint::QuicEndpoint;
 use quinn::{ClientConfig, Endpoint, TransportConfig, VarInt};
 use tokio::io::AsyncWriteExt as _;
 use tracing::{debug, info, warn};
 
 mod server_endpoint;
+mod tls;
 
 /// Default server endpoint for the standalone client (build-time injection).
 pub const DEFAULT_SERVER_ENDPOINT_QUIC: &str = server_endpoint::DEFAULT_SERVER_ENDPOINT;
@@ -80,11 +84,29 @@ pub struct ClientConfigV1 {
 	/// Optional Kick refresh token (for long-lived sessions).
 	pub kick_refresh_token: Option<String>,
 
+	/// Optional YouTube user OAuth token.
+	pub youtube_user_oauth_token: Option<String>,
+
+	/// Resume token from a previous Welcome; restores that session if the server still holds it.
+	pub resume_token: Option<String>,
+
 	/// Maximum inbound/outbound frame size.
 	pub max_frame_bytes: usize,
 
 	/// Timeout for connect + handshake.
 	pub connect_timeout: Duration,
+
+	/// Optional PEM bundle with extra trusted CA certificates.
+	pub tls_ca_path: Option<PathBuf>,
+
+	/// Optional pinned SHA-256 fingerprint (hex) of the server certificate.
+	pub tls_pin_sha256: Option<String>,
+
+	/// Skip server certificate verification (explicit opt-in; debug builds only, ignored in release builds).
+	pub tls_insecure_skip_verify: bool,
+
+	/// Preferred payload codec, offered via ALPN (protobuf is always offered as a fallback).
+	pub codec: Codec,
 }
 
 impl std::fmt::Debug for ClientConfigV1 {
@@ -105,8 +127,14 @@ impl std::fmt::Debug for ClientConfigV1 {
 			.field("kick_user_id", &self.kick_user_id)
 			.field("kick_username", &self.kick_username)
 			.field("kick_refresh_token", &"<redacted>")
+			.field("youtube_user_oauth_token", &"<redacted>")
+			.field("resume_token", &"<redacted>")
 			.field("max_frame_bytes", &self.max_frame_bytes)
 			.field("connect_timeout", &self.connect_timeout)
+			.field("tls_ca_path", &self.tls_ca_path)
+			.field("tls_pin_sha256", &self.tls_pin_sha256)
+			.field("tls_insecure_skip_verify", &self.tls_insecure_skip_verify)
+			.field("codec", &self.codec)
 			.finish()
 	}
 }
@@ -160,8 +188,14 @@ impl Default for ClientConfigV1 {
 			kick_user_id: None,
 			kick_username: None,
 			kick_refresh_token: None,
+			youtube_user_oauth_token: None,
+			resume_token: None,
 			max_frame_bytes: DEFAULT_MAX_FRAME_SIZE,
 			connect_timeout: Duration::from_secs(15),
+			tls_ca_path: None,
+			tls_pin_sha256: None,
+			tls_insecure_skip_verify: false,
+			codec: Codec::Protobuf,
 		}
 	}
 }
@@ -189,6 +223,12 @@ pub enum ClientCoreError {
 	#[error("io error: {0}")]
 	Io(String),
 
+	/// The server announced it is shutting down; reconnect after the hinted delay without backoff.
+	#[error("server is shutting down (reconnect after {reconnect_after:?})")]
+	ServerShuttingDown {
+		reconnect_after: Duration,
+	},
+
 	/// Other error.
 	#[error("error: {0}")]
 	Other(String),
@@ -206,7 +246,10 @@ pub struct SessionControl {
 	control_send: quinn::SendStream,
 	control_recv: quinn::RecvStream,
 	max_frame_bytes: usize,
+	codec: Codec,
 	events_opened: bool,
+	resume_token: Option<String>,
+	resumed_topics: Vec<String>,
 	write_buf: BytesMut,
 	read_buf: BytesMut,
 }
@@ -217,6 +260,7 @@ pub struct SessionEvents {
 	// Keep the send half alive so the peer doesn't see an immediate FIN.
 	_events_send_keepalive: quinn::SendStream,
 	max_frame_bytes: usize,
+	codec: Codec,
 }
 
 impl SessionControl {
@@ -224,7 +268,7 @@ impl SessionControl {
 	pub async fn connect(cfg: ClientConfigV1) -> Result<(Self, pb::Welcome), ClientCoreError> {
 		let endpoint = make_client_endpoint().map_err(|e| ClientCoreError::Endpoint(format!("{e:#}")))?;
 
-		let quinn_cfg = make_insecure_client_config().map_err(|e| ClientCoreError::Endpoint(format!("{e:#}")))?;
+		let quinn_cfg = make_client_config(&cfg).map_err(|e| ClientCoreError::Endpoint(format!("{e:#}")))?;
 
 		let connect_timeout = cfg.connect_timeout;
 
@@ -285,6 +329,9 @@ impl SessionControl {
 
 		info!(remote = %conn.remote_address(), "connected");
 
+		// The server picked the codec from our ALPN offer; every frame, Hello included, uses it.
+		let codec = negotiated_codec(&conn, cfg.codec)?;
+
 		debug!("opening control stream (open_bi)");
 		let (mut control_send, mut control_recv) = tokio::time::timeout(connect_timeout, conn.open_bi())
 			.await
@@ -305,8 +352,10 @@ impl SessionControl {
 			kick_user_id: cfg.kick_user_id.unwrap_or_default(),
 			kick_username: cfg.kick_username.unwrap_or_default(),
 			kick_refresh_token: cfg.kick_refresh_token.unwrap_or_default(),
-			supported_codecs: vec![pb::Codec::Protobuf as i32],
-			preferred_codec: pb::Codec::Protobuf as i32,
+			youtube_user_oauth_token: cfg.youtube_user_oauth_token.unwrap_or_default(),
+			resume_token: cfg.resume_token.unwrap_or_default(),
+			supported_codecs: offered_codecs(cfg.codec).iter().map(|c| c.as_pb() as i32).collect(),
+			preferred_codec: cfg.codec.as_pb() as i32,
 		};
 		let mut write_buf = BytesMut::with_capacity(8 * 1024);
 		let env = pb::Envelope {
@@ -314,7 +363,7 @@ impl SessionControl {
 			request_id: String::new(),
 			msg: Some(pb::envelope::Msg::Hello(hello)),
 		};
-		write_envelope(&mut control_send, &env, cfg.max_frame_bytes, &mut write_buf)
+		write_envelope(&mut control_send, &env, codec, cfg.max_frame_bytes, &mut write_buf)
 			.await
 			.map_err(|e| ClientCoreError::Io(format!("send Hello failed: {e}")))?;
 		debug!("sent Hello envelope to server (without sensitive fields)");
@@ -322,7 +371,7 @@ impl SessionControl {
 		let mut read_buf = BytesMut::with_capacity(8 * 1024);
 		let welcome_env = tokio::time::timeout(
 			connect_timeout,
-			read_one_envelope(&mut control_recv, cfg.max_frame_bytes, &mut read_buf),
+			read_one_envelope(&mut control_recv, codec, cfg.max_frame_bytes, &mut read_buf),
 		)
 		.await
 		.map_err(|_| ClientCoreError::Protocol(format!("timeout waiting for Welcome after {connect_timeout:?}")))??;
@@ -334,9 +383,9 @@ impl SessionControl {
 			}
 		};
 
-		if welcome.selected_codec != 0 && welcome.selected_codec != (pb::Codec::Protobuf as i32) {
+		if Codec::try_from(welcome.selected_codec).ok() != Some(codec) {
 			return Err(ClientCoreError::Protocol(format!(
-				"unsupported negotiated codec: {}",
+				"Welcome codec {} does not match the ALPN-selected codec {codec:?}",
 				welcome.selected_codec
 			)));
 		}
@@ -345,15 +394,28 @@ impl SessionControl {
 			server_name = %welcome.server_name,
 			server_instance_id = %welcome.server_instance_id,
 			max_frame_bytes = welcome.max_frame_bytes,
+			codec = ?codec,
+			resumed = welcome.resumed,
 			"received Welcome"
 		);
 
+		// Topics that failed to resume are left for the caller to subscribe again.
+		let resumed_topics = welcome
+			.resumed_subscriptions
+			.iter()
+			.filter(|r| r.status != pb::subscription_result::Status::InternalError as i32)
+			.map(|r| r.topic.clone())
+			.collect();
+
 		let control = Self {
 			conn,
 			control_send,
 			control_recv,
 			max_frame_bytes: (welcome.max_frame_bytes as usize).min(cfg.max_frame_bytes),
+			codec,
 			events_opened: false,
+			resume_token: (!welcome.resume_token.is_empty()).then(|| welcome.resume_token.clone()),
+			resumed_topics,
 			write_buf: BytesMut::with_capacity(8 * 1024),
 			read_buf: BytesMut::with_capacity(8 * 1024),
 		};
@@ -361,6 +423,17 @@ impl SessionControl {
 		Ok((control, welcome))
 	}
 
+	/// Token to present as `ClientConfigV1::resume_token` when reconnecting, if the server resumes sessions.
+	pub fn resume_token(&self) -> Option<&str> {
+		self.resume_token.as_deref()
+	}
+
+	/// Topics restored by a resumed session. They are already subscribed; open the events stream to
+	/// receive the events missed since the disconnect.
+	pub fn resumed_topics(&self) -> &[String] {
+		&self.resumed_topics
+	}
+
 	/// Subscribe to topics with optional resume cursors.
 	pub async fn subscribe_with_cursors(
 		&mut self,
@@ -379,9 +452,16 @@ impl SessionControl {
 			msg: Some(pb::envelope::Msg::Subscribe(pb::Subscribe { subs })),
 		};
 
-		write_envelope(&mut self.control_send, &env, self.max_frame_bytes, &mut self.write_buf).await?;
+		write_envelope(
+			&mut self.control_send,
+			&env,
+			self.codec,
+			self.max_frame_bytes,
+			&mut self.write_buf,
+		)
+		.await?;
 
-		let resp = read_one_envelope(&mut self.control_recv, self.max_frame_bytes, &mut self.read_buf).await?;
+		let resp = read_one_envelope(&mut self.control_recv, self.codec, self.max_frame_bytes, &mut self.read_buf).await?;
 		match resp.msg {
 			Some(pb::envelope::Msg::Subscribed(s)) => {
 				debug!("subscribe acknowledged");
@@ -410,9 +490,16 @@ impl SessionControl {
 			msg: Some(pb::envelope::Msg::Unsubscribe(pb::Unsubscribe { topics: topics_vec })),
 		};
 
-		write_envelope(&mut self.control_send, &env, self.max_frame_bytes, &mut self.write_buf).await?;
+		write_envelope(
+			&mut self.control_send,
+			&env,
+			self.codec,
+			self.max_frame_bytes,
+			&mut self.write_buf,
+		)
+		.await?;
 
-		let resp = read_one_envelope(&mut self.control_recv, self.max_frame_bytes, &mut self.read_buf).await?;
+		let resp = read_one_envelope(&mut self.control_recv, self.codec, self.max_frame_bytes, &mut self.read_buf).await?;
 		match resp.msg {
 			Some(pb::envelope::Msg::Unsubscribed(u)) => {
 				debug!("unsubscribe acknowledged");
@@ -430,15 +517,68 @@ impl SessionControl {
 			msg: Some(pb::envelope::Msg::Command(command)),
 		};
 
-		write_envelope(&mut self.control_send, &env, self.max_frame_bytes, &mut self.write_buf).await?;
+		write_envelope(
+			&mut self.control_send,
+			&env,
+			self.codec,
+			self.max_frame_bytes,
+			&mut self.write_buf,
+		)
+		.await?;
 
-		let resp = read_one_envelope(&mut self.control_recv, self.max_frame_bytes, &mut self.read_buf).await?;
+		let resp = read_one_envelope(&mut self.control_recv, self.codec, self.max_frame_bytes, &mut self.read_buf).await?;
 		match resp.msg {
 			Some(pb::envelope::Msg::CommandResult(r)) => Ok(r),
 			other => Err(ClientCoreError::Protocol(format!("expected CommandResult, got {other:?}"))),
 		}
 	}
 
+	/// Fetch a page of older events for `topic` (`before_cursor == 0` starts from the newest).
+	pub async fn fetch_history(
+		&mut self,
+		topic: impl Into<String>,
+		before_cursor: u64,
+		limit: u32,
+	) -> Result<pb::FetchHistoryResult, ClientCoreError> {
+		let result = self
+			.send_command(pb::Command {
+				command: Some(pb::command::Command::FetchHistory(pb::FetchHistoryCommand {
+					topic: topic.into(),
+					before_cursor,
+					limit,
+				})),
+			})
+			.await?;
+
+		if result.status != pb::command_result::Status::Ok as i32 {
+			return Err(ClientCoreError::Other(format!(
+				"fetch history failed (status={}): {}",
+				result.status, result.detail
+			)));
+		}
+		Ok(result.history.unwrap_or_default())
+	}
+
+	/// Search the server's chat log for `topic`, newest entries first.
+	pub async fn search_chat_log(
+		&mut self,
+		search: pb::SearchChatLogCommand,
+	) -> Result<pb::SearchChatLogResult, ClientCoreError> {
+		let result = self
+			.send_command(pb::Command {
+				command: Some(pb::command::Command::SearchChatLog(search)),
+			})
+			.await?;
+
+		if result.status != pb::command_result::Status::Ok as i32 {
+			return Err(ClientCoreError::Other(format!(
+				"search chat log failed (status={}): {}",
+				result.status, result.detail
+			)));
+		}
+		Ok(result.search.unwrap_or_default())
+	}
+
 	/// Send a keepalive ping and await the pong response.
 	pub async fn ping(&mut self, client_time_unix_ms: i64) -> Result<pb::Pong, ClientCoreError> {
 		let env = pb::Envelope {
@@ -447,9 +587,16 @@ impl SessionControl {
 			msg: Some(pb::envelope::Msg::Ping(pb::Ping { client_time_unix_ms })),
 		};
 
-		write_envelope(&mut self.control_send, &env, self.max_frame_bytes, &mut self.write_buf).await?;
+		write_envelope(
+			&mut self.control_send,
+			&env,
+			self.codec,
+			self.max_frame_bytes,
+			&mut self.write_buf,
+		)
+		.await?;
 
-		let resp = read_one_envelope(&mut self.control_recv, self.max_frame_bytes, &mut self.read_buf).await?;
+		let resp = read_one_envelope(&mut self.control_recv, self.codec, self.max_frame_bytes, &mut self.read_buf).await?;
 		match resp.msg {
 			Some(pb::envelope::Msg::Pong(p)) => Ok(p),
 			other => Err(ClientCoreError::Protocol(format!("expected Pong, got {other:?}"))),
@@ -486,6 +633,7 @@ impl SessionControl {
 			events_recv: recv,
 			_events_send_keepalive: send,
 			max_frame_bytes: self.max_frame_bytes,
+			codec: self.codec,
 		})
 	}
 
@@ -495,7 +643,8 @@ impl SessionControl {
 }
 
 impl SessionEvents {
-	/// Run the events loop until EOF or error.
+	/// Run the events loop until EOF or error. A shutdown notice from the server ends the loop with
+	/// [`ClientCoreError::ServerShuttingDown`].
 	pub async fn run_events_loop<F>(&mut self, mut on_event: F) -> Result<(), ClientCoreError>
 	where
 		F: FnMut(pb::EventEnvelope),
@@ -516,7 +665,7 @@ impl SessionEvents {
 			buf.extend_from_slice(&tmp[..n]);
 
 			loop {
-				match try_decode_frame_from_buffer::<pb::Envelope>(&mut buf, self.max_frame_bytes) {
+				match try_decode_frame_from_buffer_with_codec::<pb::Envelope>(self.codec, &mut buf, self.max_frame_bytes) {
 					Ok(Some(env)) => {
 						if let Some(msg) = env.msg {
 							match msg {
@@ -529,6 +678,17 @@ impl SessionEvents {
 									);
 									on_event(ev)
 								}
+								pb::envelope::Msg::Notice(notice)
+									if notice.code == chatty_protocol::notice::SERVER_SHUTTING_DOWN =>
+								{
+									info!(message = %notice.message, reconnect_after_ms = notice.reconnect_after_ms, "server is shutting down");
+									return Err(ClientCoreError::ServerShuttingDown {
+										reconnect_after: Duration::from_millis(notice.reconnect_after_ms),
+									});
+								}
+								pb::envelope::Msg::Notice(notice) => {
+									info!(code = %notice.code, message = %notice.message, "server notice");
+								}
 								other => warn!("unexpected message on events stream: {:?}", other),
 							}
 						}
@@ -544,16 +704,41 @@ impl SessionEvents {
 async fn write_envelope(
 	send: &mut quinn::SendStream,
 	env: &pb::Envelope,
+	codec: Codec,
 	max_frame_bytes: usize,
 	buf: &mut BytesMut,
 ) -> Result<(), ClientCoreError> {
 	buf.clear();
-	encode_frame_into(buf, env, max_frame_bytes).map_err(ClientCoreError::Framing)?;
+	encode_frame_into_with_codec(codec, buf, env, max_frame_bytes).map_err(ClientCoreError::Framing)?;
 	send.write_all(buf).await.map_err(|e| ClientCoreError::Io(e.to_string()))?;
 	send.flush().await.map_err(|e| ClientCoreError::Io(e.to_string()))?;
 	Ok(())
 }
 
+/// Codec selected by the server from our ALPN offer.
+fn negotiated_codec(conn: &quinn::Connection, preferred: Codec) -> Result<Codec, ClientCoreError> {
+	let protocol = conn
+		.handshake_data()
+		.and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+		.and_then(|data| data.protocol)
+		.ok_or_else(|| ClientCoreError::Protocol("server did not select an ALPN protocol".to_string()))?;
+	match Codec::from_alpn(&protocol) {
+		Some(codec) if offered_codecs(preferred).contains(&codec) => Ok(codec),
+		_ => Err(ClientCoreError::Protocol(format!(
+			"server selected unsupported ALPN protocol {}",
+			String::from_utf8_lossy(&protocol)
+		))),
+	}
+}
+
+/// Codecs offered via ALPN and `Hello` for the configured preference, most preferred first.
+fn offered_codecs(preferred: Codec) -> Vec<Codec> {
+	match preferred {
+		Codec::Protobuf => vec![Codec::Protobuf],
+		Codec::Cbor => vec![Codec::Cbor, Codec::Protobuf],
+	}
+}
+
 fn event_kind(ev: &pb::EventEnvelope) -> &'static str {
 	match ev.event.as_ref() {
 		Some(pb::event_envelope::Event::ChatMessage(_)) => "chat_message",
@@ -561,12 +746,20 @@ fn event_kind(ev: &pb::EventEnvelope) -> &'static str {
 		Some(pb::event_envelope::Event::Permissions(_)) => "permissions",
 		Some(pb::event_envelope::Event::AssetBundle(_)) => "asset_bundle",
 		Some(pb::event_envelope::Event::RoomState(_)) => "room_state",
+		Some(pb::event_envelope::Event::Moderation(_)) => "moderation",
+		Some(pb::event_envelope::Event::UserNotice(_)) => "user_notice",
+		Some(pb::event_envelope::Event::AdapterStatus(_)) => "adapter_status",
+		Some(pb::event_envelope::Event::StreamStatus(_)) => "stream_status",
+		Some(pb::event_envelope::Event::Poll(_)) => "poll",
+		Some(pb::event_envelope::Event::Redemption(_)) => "redemption",
+		Some(pb::event_envelope::Event::Progress(_)) => "progress",
 		None => "empty",
 	}
 }
 
 async fn read_one_envelope(
 	recv: &mut quinn::RecvStream,
+	codec: Codec,
 	max_frame_bytes: usize,
 	buf: &mut BytesMut,
 ) -> Result<pb::Envelope, ClientCoreError> {
@@ -575,7 +768,7 @@ async fn read_one_envelope(
 
 	loop {
 		// Try decoding first in case buffer already has a full frame.
-		match try_decode_frame_from_buffer::<pb::Envelope>(buf, max_frame_bytes) {
+		match try_decode_frame_from_buffer_with_codec::<pb::Envelope>(codec, buf, max_frame_bytes) {
 			Ok(Some(env)) => return Ok(env),
 			Ok(None) => {}
 			Err(e) => return Err(ClientCoreError::Framing(e)),
@@ -601,84 +794,27 @@ fn make_client_endpoint() -> anyhow::Result<Endpoint> {
 	Ok(endpoint)
 }
 
-/// Dev-only TLS config that skips server cert validation.
-#[cfg(debug_assertions)]
-fn make_insecure_client_config() -> anyhow::Result<ClientConfig> {
-	let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
-
-	#[derive(Debug)]
-	struct NoVerifier;
-
-	impl rustls::client::danger::ServerCertVerifier for NoVerifier {
-		fn verify_server_cert(
-			&self,
-			_end_entity: &rustls::pki_types::CertificateDer<'_>,
-			_intermediates: &[rustls::pki_types::CertificateDer<'_>],
-			_server_name: &rustls::pki_types::ServerName<'_>,
-			_ocsp_response: &[u8],
-			_now: rustls::pki_types::UnixTime,
-		) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
-			use sha2::{Digest, Sha256};
-			let der = _end_entity.as_ref();
-			let digest = Sha256::digest(der);
-			let hex = digest.iter().map(|b| format!("{:02x}", b)).collect::<String>();
-			tracing::info!(cert_sha256 = %hex, "server certificate fingerprint (sha256)");
-			Ok(rustls::client::danger::ServerCertVerified::assertion())
-		}
-
-		fn verify_tls12_signature(
-			&self,
-			_message: &[u8],
-			_cert: &rustls::pki_types::CertificateDer<'_>,
-			_dss: &rustls::DigitallySignedStruct,
-		) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
-			Err(rustls::Error::General("TLS1.2 not supported".into()))
-		}
+/// QUIC client config with server certificate verification per `cfg`.
+fn make_client_config(cfg: &ClientConfigV1) -> anyhow::Result<ClientConfig> {
+	let mut tls = tls::make_rustls_client_config(&tls::TlsOptions {
+		ca_path: cfg.tls_ca_path.as_deref(),
+		pin_sha256: cfg.tls_pin_sha256.as_deref(),
+		insecure_skip_verify: cfg.tls_insecure_skip_verify,
+	})?;
 
-		fn verify_tls13_signature(
-			&self,
-			_message: &[u8],
-			_cert: &rustls::pki_types::CertificateDer<'_>,
-			_dss: &rustls::DigitallySignedStruct,
-		) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
-			Ok(rustls::client::danger::HandshakeSignatureValid::assertion())
-		}
-
-		fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
-			vec![
-				rustls::SignatureScheme::ECDSA_NISTP256_SHA256,
-				rustls::SignatureScheme::ECDSA_NISTP384_SHA384,
-				rustls::SignatureScheme::RSA_PSS_SHA256,
-				rustls::SignatureScheme::RSA_PSS_SHA384,
-				rustls::SignatureScheme::RSA_PSS_SHA512,
-				rustls::SignatureScheme::ED25519,
-			]
-		}
-	}
-
-	let mut tls = rustls::ClientConfig::builder()
-		.with_root_certificates(rustls::RootCertStore::empty())
-		.with_no_client_auth();
-
-	tls.dangerous().set_certificate_verifier(Arc::new(NoVerifier));
-	tls.alpn_protocols = vec![b"chatty-v1".to_vec()];
+	tls.alpn_protocols = offered_codecs(cfg.codec).iter().map(|c| c.alpn().to_vec()).collect();
 
 	let quic_tls = quinn::crypto::rustls::QuicClientConfig::try_from(tls)?;
 
-	let mut cfg = ClientConfig::new(Arc::new(quic_tls));
+	let mut client_cfg = ClientConfig::new(Arc::new(quic_tls));
 
 	// Allow multiple streams (control + events at minimum).
 	let mut transport = TransportConfig::default();
 	transport.max_concurrent_bidi_streams(VarInt::from_u32(64));
 	transport.max_concurrent_uni_streams(VarInt::from_u32(64));
-	cfg.transport_config(Arc::new(transport));
-
-	Ok(cfg)
-}
+	client_cfg.transport_config(Arc::new(transport));
 
-#[cfg(not(debug_assertions))]
-fn make_insecure_client_config() -> anyhow::Result<ClientConfig> {
-	Err(anyhow::anyhow!("insecure TLS config is only available in debug builds"))
+	Ok(client_cfg)
 }
 
 #[cfg(test)]
@@ -690,5 +826,6 @@ mod tests {
 		let cfg = ClientConfigV1::default();
 		assert_eq!(cfg.server_host, "localhost");
 		assert!(cfg.max_frame_bytes > 0);
+		assert!(!cfg.tls_insecure_skip_verify);
 	}
 }
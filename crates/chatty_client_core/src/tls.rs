@@ -0,0 +1,259 @@
+#![forbid(unsafe_code)]
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+
+/// Server certificate verification options.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TlsOptions<'a> {
+	/// PEM bundle with extra trusted CA certificates.
+	pub ca_path: Option<&'a Path>,
+
+	/// Expected SHA-256 fingerprint of the server leaf certificate.
+	pub pin_sha256: Option<&'a str>,
+
+	/// Skip verification entirely (honored only in debug builds).
+	pub insecure_skip_verify: bool,
+}
+
+/// Build the rustls client config for the given verification options.
+///
+/// A pinned fingerprint takes precedence and is sufficient on its own (so self-signed servers can be pinned).
+/// Otherwise the chain is verified against the platform roots, the bundled webpki roots and the optional CA bundle.
+pub(crate) fn make_rustls_client_config(opts: &TlsOptions<'_>) -> anyhow::Result<rustls::ClientConfig> {
+	let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+	let provider = rustls::crypto::CryptoProvider::get_default()
+		.cloned()
+		.context("no rustls crypto provider installed")?;
+
+	let verifier: Arc<dyn ServerCertVerifier> = if let Some(pin) = opts.pin_sha256 {
+		Arc::new(PinnedCertVerifier {
+			fingerprint: parse_pin_sha256(pin)?,
+			provider: Arc::clone(&provider),
+		})
+	} else if opts.insecure_skip_verify && cfg!(debug_assertions) && opts.ca_path.is_none() {
+		warn!("server certificate verification disabled (debug build)");
+		Arc::new(NoVerifier {
+			provider: Arc::clone(&provider),
+		})
+	} else {
+		let roots = load_root_store(opts.ca_path)?;
+		WebPkiServerVerifier::builder_with_provider(Arc::new(roots), Arc::clone(&provider))
+			.build()
+			.context("build webpki server verifier")?
+	};
+
+	let tls = rustls::ClientConfig::builder_with_provider(provider)
+		.with_safe_default_protocol_versions()
+		.context("select TLS protocol versions")?
+		.dangerous()
+		.with_custom_certificate_verifier(verifier)
+		.with_no_client_auth();
+
+	Ok(tls)
+}
+
+/// Parse a hex SHA-256 fingerprint (colons and whitespace are ignored).
+pub(crate) fn parse_pin_sha256(pin: &str) -> anyhow::Result<[u8; 32]> {
+	let hex: String = pin
+		.chars()
+		.filter(|c| !c.is_whitespace() && *c != ':')
+		.collect::<String>()
+		.to_ascii_lowercase();
+	let hex = hex.strip_prefix("sha256/").unwrap_or(&hex);
+
+	if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+		anyhow::bail!("invalid sha256 pin: not hex");
+	}
+	if hex.len() != 64 {
+		anyhow::bail!("invalid sha256 pin: expected 64 hex characters, got {}", hex.len());
+	}
+
+	let mut out = [0u8; 32];
+	for (i, byte) in out.iter_mut().enumerate() {
+		// All-ASCII was checked above, so byte offsets are char boundaries.
+		*byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).context("invalid sha256 pin: not hex")?;
+	}
+	Ok(out)
+}
+
+fn cert_sha256_hex(der: &[u8]) -> String {
+	Sha256::digest(der).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn load_root_store(ca_path: Option<&Path>) -> anyhow::Result<RootCertStore> {
+	let mut roots = RootCertStore::empty();
+
+	let native = rustls_native_certs::load_native_certs();
+	for err in &native.errors {
+		debug!(error = %err, "failed to load some platform root certificates");
+	}
+	let (added, ignored) = roots.add_parsable_certificates(native.certs);
+	debug!(added, ignored, "loaded platform root certificates");
+
+	roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+	if let Some(path) = ca_path {
+		let pem = std::fs::read(path).with_context(|| format!("read CA bundle {}", path.display()))?;
+		let certs: Vec<CertificateDer<'static>> = rustls_pemfile::certs(&mut pem.as_slice())
+			.collect::<Result<_, _>>()
+			.with_context(|| format!("parse CA bundle {}", path.display()))?;
+		if certs.is_empty() {
+			anyhow::bail!("CA bundle {} contains no certificates", path.display());
+		}
+		for cert in certs {
+			roots
+				.add(cert)
+				.with_context(|| format!("add CA certificate from {}", path.display()))?;
+		}
+	}
+
+	Ok(roots)
+}
+
+/// Accepts exactly the server certificate with the pinned SHA-256 fingerprint.
+#[derive(Debug)]
+struct PinnedCertVerifier {
+	fingerprint: [u8; 32],
+	provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+	fn verify_server_cert(
+		&self,
+		end_entity: &CertificateDer<'_>,
+		_intermediates: &[CertificateDer<'_>],
+		_server_name: &ServerName<'_>,
+		_ocsp_response: &[u8],
+		_now: UnixTime,
+	) -> Result<ServerCertVerified, rustls::Error> {
+		let digest = Sha256::digest(end_entity.as_ref());
+		if digest.as_slice() != self.fingerprint {
+			warn!(
+				cert_sha256 = %cert_sha256_hex(end_entity.as_ref()),
+				"server certificate does not match pinned fingerprint"
+			);
+			return Err(rustls::Error::General(
+				"server certificate does not match pinned sha256".into(),
+			));
+		}
+		Ok(ServerCertVerified::assertion())
+	}
+
+	fn verify_tls12_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+	}
+
+	fn verify_tls13_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+	}
+
+	fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+		self.provider.signature_verification_algorithms.supported_schemes()
+	}
+}
+
+/// Dev-only verifier that accepts any certificate and logs its fingerprint (for pinning).
+#[derive(Debug)]
+struct NoVerifier {
+	provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for NoVerifier {
+	fn verify_server_cert(
+		&self,
+		end_entity: &CertificateDer<'_>,
+		_intermediates: &[CertificateDer<'_>],
+		_server_name: &ServerName<'_>,
+		_ocsp_response: &[u8],
+		_now: UnixTime,
+	) -> Result<ServerCertVerified, rustls::Error> {
+		tracing::info!(cert_sha256 = %cert_sha256_hex(end_entity.as_ref()), "server certificate fingerprint (sha256)");
+		Ok(ServerCertVerified::assertion())
+	}
+
+	fn verify_tls12_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+	}
+
+	fn verify_tls13_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+	}
+
+	fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+		self.provider.signature_verification_algorithms.supported_schemes()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_pin_accepts_colon_separated_hex() {
+		let hex = "AB:".repeat(31) + "AB";
+		let pin = parse_pin_sha256(&hex).expect("valid pin");
+		assert_eq!(pin, [0xab; 32]);
+	}
+
+	#[test]
+	fn parse_pin_rejects_wrong_length() {
+		assert!(parse_pin_sha256("abcd").is_err());
+		assert!(parse_pin_sha256(&"zz".repeat(32)).is_err());
+	}
+
+	#[test]
+	fn parse_pin_rejects_non_ascii_and_signs_without_panicking() {
+		// 62 ASCII digits plus one 2-byte char: 64 bytes, but slicing at byte 62 would split the char.
+		assert!(parse_pin_sha256(&("a".repeat(62) + "é")).is_err());
+		assert!(parse_pin_sha256(&"é".repeat(32)).is_err());
+		assert!(parse_pin_sha256(&"+f".repeat(32)).is_err());
+	}
+
+	#[test]
+	fn pinned_verifier_checks_leaf_fingerprint() {
+		let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+		let provider = CryptoProvider::get_default().cloned().expect("provider");
+
+		let cert = CertificateDer::from(b"not-really-a-certificate".to_vec());
+		let verifier = PinnedCertVerifier {
+			fingerprint: Sha256::digest(cert.as_ref()).into(),
+			provider,
+		};
+		let name = ServerName::try_from("localhost").expect("server name");
+
+		assert!(verifier.verify_server_cert(&cert, &[], &name, &[], UnixTime::now()).is_ok());
+
+		let other = CertificateDer::from(b"some-other-certificate".to_vec());
+		assert!(verifier.verify_server_cert(&other, &[], &name, &[], UnixTime::now()).is_err());
+	}
+}
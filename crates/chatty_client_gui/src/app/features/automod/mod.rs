@@ -0,0 +1,5 @@
+#![forbid(unsafe_code)]
+
+pub mod queue;
+
+pub use queue::{AutoModQueue, HeldMessage};
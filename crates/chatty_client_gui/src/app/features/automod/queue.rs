@@ -0,0 +1,110 @@
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use chatty_domain::RoomKey;
+use smol_str::SmolStr;
+
+/// Held messages kept at most; the oldest are dropped first since AutoMod expires them anyway.
+const MAX_HELD_MESSAGES: usize = 200;
+
+/// A chat message AutoMod is holding until a moderator approves or denies it.
+#[derive(Debug, Clone)]
+pub struct HeldMessage {
+	pub room: RoomKey,
+	pub message_id: SmolStr,
+	pub user_login: Option<SmolStr>,
+	pub user_display: Option<SmolStr>,
+	pub text: String,
+	pub reason: Option<String>,
+	pub held_at: SystemTime,
+}
+
+/// Messages awaiting AutoMod review across all joined rooms, oldest first.
+#[derive(Debug, Default)]
+pub struct AutoModQueue {
+	held: Vec<HeldMessage>,
+}
+
+impl AutoModQueue {
+	pub fn hold(&mut self, message: HeldMessage) {
+		self.held
+			.retain(|m| !(m.room == message.room && m.message_id == message.message_id));
+		self.held.push(message);
+		if self.held.len() > MAX_HELD_MESSAGES {
+			let excess = self.held.len() - MAX_HELD_MESSAGES;
+			self.held.drain(..excess);
+		}
+	}
+
+	/// Drop a message once it was approved, denied or expired, whoever resolved it.
+	pub fn resolve(&mut self, room: &RoomKey, message_id: &str) -> Option<HeldMessage> {
+		let idx = self.held.iter().position(|m| &m.room == room && m.message_id == message_id)?;
+		Some(self.held.remove(idx))
+	}
+
+	pub fn len(&self) -> usize {
+		self.held.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.held.is_empty()
+	}
+
+	/// Rooms with pending messages, in the order their oldest message was held.
+	pub fn rooms(&self) -> Vec<&RoomKey> {
+		let mut rooms: Vec<&RoomKey> = Vec::new();
+		for m in &self.held {
+			if !rooms.contains(&&m.room) {
+				rooms.push(&m.room);
+			}
+		}
+		rooms
+	}
+
+	pub fn for_room<'a>(&'a self, room: &'a RoomKey) -> impl Iterator<Item = &'a HeldMessage> + 'a {
+		self.held.iter().filter(move |m| &m.room == room)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chatty_domain::{Platform, RoomId};
+
+	use super::*;
+
+	fn room(name: &str) -> RoomKey {
+		RoomKey::new(Platform::Twitch, RoomId::new(name.to_string()).expect("room id"))
+	}
+
+	fn held(room_name: &str, id: &str) -> HeldMessage {
+		HeldMessage {
+			room: room(room_name),
+			message_id: SmolStr::new(id),
+			user_login: Some(SmolStr::new("spammer")),
+			user_display: None,
+			text: format!("message {id}"),
+			reason: None,
+			held_at: SystemTime::UNIX_EPOCH,
+		}
+	}
+
+	#[test]
+	fn holds_are_grouped_per_room_and_removed_when_resolved() {
+		let mut queue = AutoModQueue::default();
+		queue.hold(held("alpha", "1"));
+		queue.hold(held("beta", "2"));
+		queue.hold(held("alpha", "3"));
+		queue.hold(held("alpha", "1"));
+		assert_eq!(queue.len(), 3, "a repeated hold replaces the earlier entry");
+		assert_eq!(queue.rooms(), vec![&room("beta"), &room("alpha")]);
+
+		assert!(queue.resolve(&room("beta"), "1").is_none(), "ids are scoped to their room");
+		assert!(queue.resolve(&room("alpha"), "1").is_some());
+		let alpha = room("alpha");
+		assert_eq!(
+			queue.for_room(&alpha).map(|m| m.message_id.as_str()).collect::<Vec<_>>(),
+			vec!["3"]
+		);
+	}
+}
@@ -14,6 +14,10 @@ pub enum ChatPaneMessage {
 	SendPressed,
 	PlatformSelected(Platform),
 	CancelReply,
+	/// The chat log was scrolled to the top; page in older messages from the server.
+	LoadOlderMessages,
+	HistoryRequested(RoomKey, Result<(), String>),
+	OpenRoomSettings,
 }
 
 #[derive(Debug, Clone)]
@@ -65,6 +69,15 @@ impl ChatPane {
 				app.save_ui_layout();
 				Task::none()
 			}
+			ChatPaneMessage::LoadOlderMessages => app.request_older_history(pane),
+			ChatPaneMessage::HistoryRequested(room, res) => {
+				if let Err(e) = res {
+					app.state.finish_history_fetch(&room, None);
+					return app.report_error(e);
+				}
+				Task::none()
+			}
+			ChatPaneMessage::OpenRoomSettings => app.update_open_room_settings(pane),
 		}
 	}
 }
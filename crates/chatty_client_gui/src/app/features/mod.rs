@@ -1,10 +1,12 @@
 #![forbid(unsafe_code)]
 
+pub mod automod;
 pub mod chat;
 pub mod layout;
 pub mod overlays;
 pub mod settings;
 pub mod tabs;
 pub mod toaster;
+pub mod unban_requests;
 pub mod users;
 pub mod window;
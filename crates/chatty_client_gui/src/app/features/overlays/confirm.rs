@@ -16,6 +16,13 @@ pub enum ConfirmModalKind {
 	DeleteMessage,
 	TimeoutUser(String),
 	BanUser(String),
+	ClearChat,
+	ClearUserMessages(String),
+	/// Pay out to `winning_outcome_id`, or cancel and refund when `None`.
+	ResolvePrediction {
+		prediction_id: String,
+		winning_outcome_id: Option<String>,
+	},
 }
 
 #[derive(Debug, Clone)]
@@ -58,6 +65,40 @@ impl ConfirmModal {
 		}
 	}
 
+	pub fn new_clear_chat(room: chatty_domain::RoomKey) -> Self {
+		Self {
+			room,
+			kind: ConfirmModalKind::ClearChat,
+			server_message_id: None,
+			platform_message_id: None,
+		}
+	}
+
+	pub fn new_clear_user_messages(room: chatty_domain::RoomKey, user_id: String) -> Self {
+		Self {
+			room,
+			kind: ConfirmModalKind::ClearUserMessages(user_id),
+			server_message_id: None,
+			platform_message_id: None,
+		}
+	}
+
+	pub fn new_resolve_prediction(
+		room: chatty_domain::RoomKey,
+		prediction_id: String,
+		winning_outcome_id: Option<String>,
+	) -> Self {
+		Self {
+			room,
+			kind: ConfirmModalKind::ResolvePrediction {
+				prediction_id,
+				winning_outcome_id,
+			},
+			server_message_id: None,
+			platform_message_id: None,
+		}
+	}
+
 	pub fn update(&mut self, app: &mut Chatty, message: ConfirmModalMessage) -> Task<Message> {
 		match message {
 			ConfirmModalMessage::Confirm => {
@@ -11,6 +11,11 @@ pub enum MessageActionMenuMessage {
 	Delete,
 	Timeout,
 	Ban,
+	Untimeout,
+	Unban,
+	ClearUserMessages,
+	ClearChat,
+	SearchUser,
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +71,29 @@ impl MessageActionMenu {
 					Task::none()
 				}
 			}
+			MessageActionMenuMessage::Untimeout => {
+				if let Some(uid) = &self.author_id {
+					app.execute_untimeout_user(self.room.clone(), uid.clone())
+				} else {
+					Task::none()
+				}
+			}
+			MessageActionMenuMessage::Unban => {
+				if let Some(uid) = &self.author_id {
+					app.execute_unban_user(self.room.clone(), uid.clone())
+				} else {
+					Task::none()
+				}
+			}
+			MessageActionMenuMessage::ClearUserMessages => {
+				if let Some(uid) = &self.author_id {
+					app.update_clear_user_messages(self.room.clone(), uid.clone())
+				} else {
+					Task::none()
+				}
+			}
+			MessageActionMenuMessage::ClearChat => app.update_clear_chat(self.room.clone()),
+			MessageActionMenuMessage::SearchUser => app.update_open_search_modal(self.room.clone(), self.author_id.clone()),
 		}
 	}
 }
@@ -9,11 +9,15 @@ pub mod confirm;
 pub mod join;
 pub mod layout;
 pub mod message_action;
+pub mod room_settings;
+pub mod search;
 
 pub use confirm::{ConfirmModal, ConfirmModalKind, ConfirmModalMessage};
 pub use join::{JoinModal, JoinModalMessage};
 pub use layout::{LayoutModal, LayoutModalKind, LayoutModalMessage};
 pub use message_action::{MessageActionMenu, MessageActionMenuMessage};
+pub use room_settings::{RoomSettingsDraft, RoomSettingsModal, RoomSettingsModalMessage};
+pub use search::{ChatSearchModal, ChatSearchModalMessage, SearchRange};
 
 #[derive(Debug, Clone)]
 pub enum OverlayMessage {
@@ -21,6 +25,8 @@ pub enum OverlayMessage {
 	Layout(LayoutModalMessage),
 	MessageAction(MessageActionMenuMessage),
 	Confirm(ConfirmModalMessage),
+	ChatSearch(ChatSearchModalMessage),
+	RoomSettings(RoomSettingsModalMessage),
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +35,8 @@ pub enum ActiveOverlay {
 	Layout(LayoutModal),
 	MessageAction(MessageActionMenu),
 	Confirm(ConfirmModal),
+	ChatSearch(ChatSearchModal),
+	RoomSettings(RoomSettingsModal),
 }
 
 impl ActiveOverlay {
@@ -38,6 +46,8 @@ impl ActiveOverlay {
 			(ActiveOverlay::Layout(modal), OverlayMessage::Layout(msg)) => modal.update(app, msg),
 			(ActiveOverlay::MessageAction(modal), OverlayMessage::MessageAction(msg)) => modal.update(app, msg),
 			(ActiveOverlay::Confirm(modal), OverlayMessage::Confirm(msg)) => modal.update(app, msg),
+			(ActiveOverlay::ChatSearch(modal), OverlayMessage::ChatSearch(msg)) => modal.update(app, msg),
+			(ActiveOverlay::RoomSettings(modal), OverlayMessage::RoomSettings(msg)) => modal.update(app, msg),
 			_ => Task::none(),
 		}
 	}
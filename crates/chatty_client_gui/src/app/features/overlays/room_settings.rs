@@ -0,0 +1,172 @@
+#![forbid(unsafe_code)]
+
+use chatty_domain::RoomKey;
+use chatty_protocol::pb;
+use iced::Task;
+
+use crate::app::message::Message;
+use crate::app::model::Chatty;
+use crate::app::room::RoomStateUi;
+
+#[derive(Debug, Clone)]
+pub enum RoomSettingsModalMessage {
+	RoomSelected(RoomKey),
+	EmoteOnlyToggled(bool),
+	SubscribersOnlyToggled(bool),
+	UniqueChatToggled(bool),
+	SlowModeToggled(bool),
+	SlowModeWaitChanged(String),
+	FollowersOnlyToggled(bool),
+	FollowersDurationChanged(String),
+	Apply,
+	Cancel,
+}
+
+/// Editable copy of a room's chat modes; durations are kept as typed so partial input survives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoomSettingsDraft {
+	pub emote_only: bool,
+	pub subscribers_only: bool,
+	pub unique_chat: bool,
+	pub slow_mode: bool,
+	pub slow_mode_wait: String,
+	pub followers_only: bool,
+	pub followers_duration: String,
+}
+
+impl RoomSettingsDraft {
+	pub fn from_state(state: &RoomStateUi) -> Self {
+		Self {
+			emote_only: state.emote_only.unwrap_or(false),
+			subscribers_only: state.subscribers_only.unwrap_or(false),
+			unique_chat: state.unique_chat.unwrap_or(false),
+			slow_mode: state.slow_mode.unwrap_or(false),
+			slow_mode_wait: state.slow_mode_wait_time_seconds.map(|s| s.to_string()).unwrap_or_default(),
+			followers_only: state.followers_only.unwrap_or(false),
+			followers_duration: state
+				.followers_only_duration_minutes
+				.map(|m| m.to_string())
+				.unwrap_or_default(),
+		}
+	}
+
+	/// Fields that differ from `base`, or `None` when nothing changed.
+	pub fn changes_from(&self, base: &RoomSettingsDraft) -> Option<pb::RoomChatSettings> {
+		fn flag(new: bool, old: bool) -> Option<bool> {
+			(new != old).then_some(new)
+		}
+		fn number(new: &str, old: &str, enabled: bool) -> Option<u64> {
+			let parsed = new.trim().parse().ok();
+			(enabled && parsed != old.trim().parse().ok()).then_some(parsed).flatten()
+		}
+
+		let settings = pb::RoomChatSettings {
+			emote_only: flag(self.emote_only, base.emote_only),
+			subscribers_only: flag(self.subscribers_only, base.subscribers_only),
+			unique_chat: flag(self.unique_chat, base.unique_chat),
+			slow_mode: flag(self.slow_mode, base.slow_mode),
+			slow_mode_wait_time_seconds: number(&self.slow_mode_wait, &base.slow_mode_wait, self.slow_mode),
+			followers_only: flag(self.followers_only, base.followers_only),
+			followers_only_duration_minutes: number(&self.followers_duration, &base.followers_duration, self.followers_only),
+		};
+		(settings != pb::RoomChatSettings::default()).then_some(settings)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct RoomSettingsModal {
+	/// Rooms in the pane the user can moderate.
+	pub rooms: Vec<RoomKey>,
+	pub room: RoomKey,
+	pub draft: RoomSettingsDraft,
+	/// Last known settings of `room`, used to send only what the user changed.
+	pub base: RoomSettingsDraft,
+	pub cursor_pos: Option<(f32, f32)>,
+}
+
+impl RoomSettingsModal {
+	pub fn new(app: &Chatty, rooms: Vec<RoomKey>, room: RoomKey, cursor_pos: Option<(f32, f32)>) -> Self {
+		let base = Self::current_settings(app, &room);
+		Self {
+			rooms,
+			room,
+			draft: base.clone(),
+			base,
+			cursor_pos,
+		}
+	}
+
+	fn current_settings(app: &Chatty, room: &RoomKey) -> RoomSettingsDraft {
+		app.state
+			.room_states
+			.get(room)
+			.map(RoomSettingsDraft::from_state)
+			.unwrap_or_default()
+	}
+
+	pub fn update(&mut self, app: &mut Chatty, message: RoomSettingsModalMessage) -> Task<Message> {
+		match message {
+			RoomSettingsModalMessage::RoomSelected(room) => {
+				self.base = Self::current_settings(app, &room);
+				self.draft = self.base.clone();
+				self.room = room;
+			}
+			RoomSettingsModalMessage::EmoteOnlyToggled(v) => self.draft.emote_only = v,
+			RoomSettingsModalMessage::SubscribersOnlyToggled(v) => self.draft.subscribers_only = v,
+			RoomSettingsModalMessage::UniqueChatToggled(v) => self.draft.unique_chat = v,
+			RoomSettingsModalMessage::SlowModeToggled(v) => self.draft.slow_mode = v,
+			RoomSettingsModalMessage::SlowModeWaitChanged(v) => self.draft.slow_mode_wait = v,
+			RoomSettingsModalMessage::FollowersOnlyToggled(v) => self.draft.followers_only = v,
+			RoomSettingsModalMessage::FollowersDurationChanged(v) => self.draft.followers_duration = v,
+			RoomSettingsModalMessage::Apply => {
+				return match self.draft.changes_from(&self.base) {
+					Some(settings) => app.execute_update_room_settings(self.room.clone(), settings),
+					None => app.update_modal_dismissed(),
+				};
+			}
+			RoomSettingsModalMessage::Cancel => return app.update_modal_dismissed(),
+		}
+		Task::none()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn changes_only_include_edited_fields() {
+		let base = RoomSettingsDraft {
+			slow_mode: true,
+			slow_mode_wait: "30".to_string(),
+			..Default::default()
+		};
+		assert_eq!(base.changes_from(&base), None);
+
+		let mut draft = base.clone();
+		draft.slow_mode_wait = "60".to_string();
+		draft.followers_only = true;
+		draft.followers_duration = "10".to_string();
+		assert_eq!(
+			draft.changes_from(&base),
+			Some(pb::RoomChatSettings {
+				slow_mode_wait_time_seconds: Some(60),
+				followers_only: Some(true),
+				followers_only_duration_minutes: Some(10),
+				..Default::default()
+			})
+		);
+
+		let mut off = base.clone();
+		off.slow_mode = false;
+		off.slow_mode_wait = "90".to_string();
+		assert_eq!(
+			off.changes_from(&base),
+			Some(pb::RoomChatSettings {
+				slow_mode: Some(false),
+				..Default::default()
+			}),
+			"durations are not sent for a mode being turned off"
+		);
+	}
+}
@@ -0,0 +1,204 @@
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chatty_domain::{RoomKey, RoomTopic};
+use chatty_protocol::pb;
+use iced::Task;
+use rust_i18n::t;
+
+use crate::app::features::overlays::OverlayMessage;
+use crate::app::message::Message;
+use crate::app::model::Chatty;
+use crate::net::ChatLogEntryUi;
+
+const SEARCH_PAGE_SIZE: u32 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchRange {
+	LastHour,
+	LastDay,
+	LastWeek,
+	LastMonth,
+	AllTime,
+}
+
+impl SearchRange {
+	pub const ALL: [SearchRange; 5] = [
+		SearchRange::LastHour,
+		SearchRange::LastDay,
+		SearchRange::LastWeek,
+		SearchRange::LastMonth,
+		SearchRange::AllTime,
+	];
+
+	fn window(self) -> Option<Duration> {
+		let hours = match self {
+			SearchRange::LastHour => 1,
+			SearchRange::LastDay => 24,
+			SearchRange::LastWeek => 24 * 7,
+			SearchRange::LastMonth => 24 * 30,
+			SearchRange::AllTime => return None,
+		};
+		Some(Duration::from_secs(hours * 3600))
+	}
+
+	/// Lower bound of the range in unix ms (0 when open).
+	pub fn since_unix_ms(self, now: SystemTime) -> i64 {
+		self.window()
+			.and_then(|w| now.checked_sub(w))
+			.and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+			.map(|d| d.as_millis() as i64)
+			.unwrap_or(0)
+	}
+}
+
+impl fmt::Display for SearchRange {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let label = match self {
+			SearchRange::LastHour => t!("search.range_hour"),
+			SearchRange::LastDay => t!("search.range_day"),
+			SearchRange::LastWeek => t!("search.range_week"),
+			SearchRange::LastMonth => t!("search.range_month"),
+			SearchRange::AllTime => t!("search.range_all"),
+		};
+		f.write_str(&label)
+	}
+}
+
+#[derive(Debug, Clone)]
+pub enum ChatSearchModalMessage {
+	UserChanged(String),
+	TextChanged(String),
+	RangeSelected(SearchRange),
+	Submit,
+	LoadMore,
+	Requested(Result<(), String>),
+	Cancel,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatSearchModal {
+	pub room: RoomKey,
+	pub user: String,
+	pub text: String,
+	pub range: SearchRange,
+	pub results: Vec<ChatLogEntryUi>,
+	pub next_before_id: u64,
+	pub loading: bool,
+	pub searched: bool,
+	pub error: Option<String>,
+	/// Lower time bound of the search in flight, kept so "load more" pages the same range.
+	since_unix_ms: i64,
+}
+
+impl ChatSearchModal {
+	pub fn new(room: RoomKey, user: Option<String>) -> Self {
+		Self {
+			room,
+			user: user.unwrap_or_default(),
+			text: String::new(),
+			range: SearchRange::LastWeek,
+			results: Vec::new(),
+			next_before_id: 0,
+			loading: false,
+			searched: false,
+			error: None,
+			since_unix_ms: 0,
+		}
+	}
+
+	pub fn topic(&self) -> String {
+		RoomTopic::format(&self.room)
+	}
+
+	pub fn update(&mut self, app: &mut Chatty, message: ChatSearchModalMessage) -> Task<Message> {
+		match message {
+			ChatSearchModalMessage::UserChanged(v) => {
+				self.user = v;
+				Task::none()
+			}
+			ChatSearchModalMessage::TextChanged(v) => {
+				self.text = v;
+				Task::none()
+			}
+			ChatSearchModalMessage::RangeSelected(range) => {
+				self.range = range;
+				Task::none()
+			}
+			ChatSearchModalMessage::Submit => {
+				self.since_unix_ms = self.range.since_unix_ms(SystemTime::now());
+				self.results.clear();
+				self.next_before_id = 0;
+				self.request(app, 0)
+			}
+			ChatSearchModalMessage::LoadMore => {
+				if self.loading || self.next_before_id == 0 {
+					return Task::none();
+				}
+				self.request(app, self.next_before_id)
+			}
+			ChatSearchModalMessage::Requested(res) => {
+				if let Err(e) = res {
+					self.loading = false;
+					self.error = Some(e);
+				}
+				Task::none()
+			}
+			ChatSearchModalMessage::Cancel => app.update_modal_dismissed(),
+		}
+	}
+
+	fn request(&mut self, app: &Chatty, before_id: u64) -> Task<Message> {
+		self.loading = true;
+		self.error = None;
+		let search = pb::SearchChatLogCommand {
+			topic: self.topic(),
+			user: self.user.trim().to_string(),
+			text: self.text.trim().to_string(),
+			since_unix_ms: self.since_unix_ms,
+			until_unix_ms: 0,
+			before_id,
+			limit: SEARCH_PAGE_SIZE,
+		};
+		let net = app.net_effects.clone();
+		Task::perform(async move { net.search_chat_log(search).await }, |res| {
+			Message::OverlayMessage(OverlayMessage::ChatSearch(ChatSearchModalMessage::Requested(res)))
+		})
+	}
+
+	/// Apply a page of results delivered by the network task.
+	pub fn apply_results(
+		&mut self,
+		entries: Vec<ChatLogEntryUi>,
+		next_before_id: u64,
+		append: bool,
+		error: Option<String>,
+	) {
+		self.loading = false;
+		self.searched = true;
+		if let Some(e) = error {
+			self.error = Some(e);
+			return;
+		}
+		if !append {
+			self.results.clear();
+		}
+		self.results.extend(entries);
+		self.next_before_id = next_before_id;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn range_lower_bound_is_relative_to_now() {
+		let now = UNIX_EPOCH + Duration::from_secs(10 * 24 * 3600);
+		assert_eq!(SearchRange::AllTime.since_unix_ms(now), 0);
+		assert_eq!(SearchRange::LastDay.since_unix_ms(now), 9 * 24 * 3600 * 1000);
+		assert_eq!(SearchRange::LastMonth.since_unix_ms(now), 0, "ranges before the epoch stay open");
+	}
+}
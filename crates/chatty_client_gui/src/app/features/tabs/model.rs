@@ -46,6 +46,18 @@ impl ChatLog {
 		}
 		removed
 	}
+
+	/// Insert older items (oldest first) ahead of the log, keeping only the newest that still fit.
+	/// Returns how many items were inserted.
+	pub fn prepend(&mut self, items: Vec<ChatItem>) -> usize {
+		let room = self.max_items.saturating_sub(self.items.len());
+		let skip = items.len().saturating_sub(room);
+		let inserted = items.len() - skip;
+		for item in items.into_iter().skip(skip).rev() {
+			self.items.push_front(item);
+		}
+		inserted
+	}
 }
 
 #[derive(Debug, Clone)]
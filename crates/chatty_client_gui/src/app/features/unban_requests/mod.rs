@@ -0,0 +1,5 @@
+#![forbid(unsafe_code)]
+
+pub mod view;
+
+pub use view::{UnbanRequestsView, UnbanRequestsViewMessage};
@@ -0,0 +1,235 @@
+#![forbid(unsafe_code)]
+
+use chatty_domain::{RoomKey, RoomTopic};
+use chatty_protocol::pb;
+use iced::Task;
+use smol_str::SmolStr;
+
+use crate::app::message::Message;
+use crate::app::model::Chatty;
+use crate::app::types::Page;
+use crate::net::{ChatLogEntryUi, UnbanRequestUi};
+
+/// Chat log entries fetched for the selected requester.
+const HISTORY_LIMIT: u32 = 50;
+
+#[derive(Debug, Clone)]
+pub enum UnbanRequestsViewMessage {
+	Open,
+	RoomSelected(RoomKey),
+	Refresh,
+	RequestSelected(SmolStr),
+	ResolutionChanged(String),
+	Resolve(bool),
+	Requested(Result<(), String>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnbanRequestsView {
+	/// Rooms the user can moderate, refreshed each time the page is opened.
+	pub rooms: Vec<RoomKey>,
+	pub room: Option<RoomKey>,
+	pub requests: Vec<UnbanRequestUi>,
+	pub selected: Option<SmolStr>,
+	/// Previous messages and moderation actions of the selected requester, newest first.
+	pub history: Vec<ChatLogEntryUi>,
+	pub history_loading: bool,
+	pub resolution_text: String,
+	pub loading: bool,
+	pub error: Option<String>,
+}
+
+impl UnbanRequestsView {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn selected_request(&self) -> Option<&UnbanRequestUi> {
+		let id = self.selected.as_ref()?;
+		self.requests.iter().find(|r| &r.request_id == id)
+	}
+
+	pub fn update(&mut self, app: &mut Chatty, message: UnbanRequestsViewMessage) -> Task<Message> {
+		match message {
+			UnbanRequestsViewMessage::Open => {
+				let mut rooms: Vec<RoomKey> = app
+					.state
+					.room_permissions
+					.iter()
+					.filter(|(_, p)| p.is_moderator || p.is_broadcaster)
+					.map(|(room, _)| room.clone())
+					.collect();
+				rooms.sort_by_key(|r| r.to_string());
+				self.rooms = rooms;
+				if self.room.as_ref().is_none_or(|r| !self.rooms.contains(r)) {
+					self.room = self.rooms.first().cloned();
+					self.clear_selection();
+				}
+				let nav = app.update_navigate(Page::UnbanRequests);
+				Task::batch([nav, self.request_list(app)])
+			}
+			UnbanRequestsViewMessage::RoomSelected(room) => {
+				self.room = Some(room);
+				self.requests.clear();
+				self.clear_selection();
+				self.request_list(app)
+			}
+			UnbanRequestsViewMessage::Refresh => self.request_list(app),
+			UnbanRequestsViewMessage::RequestSelected(id) => {
+				self.selected = Some(id);
+				self.history.clear();
+				self.resolution_text.clear();
+				self.request_history(app)
+			}
+			UnbanRequestsViewMessage::ResolutionChanged(v) => {
+				self.resolution_text = v;
+				Task::none()
+			}
+			UnbanRequestsViewMessage::Resolve(approve) => {
+				let (Some(room), Some(request)) = (self.room.clone(), self.selected_request()) else {
+					return Task::none();
+				};
+				let request_id = request.request_id.to_string();
+				app.execute_resolve_unban_request(room, request_id, approve, self.resolution_text.trim().to_string())
+			}
+			UnbanRequestsViewMessage::Requested(res) => {
+				if let Err(e) = res {
+					self.loading = false;
+					self.history_loading = false;
+					self.error = Some(e);
+				}
+				Task::none()
+			}
+		}
+	}
+
+	fn clear_selection(&mut self) {
+		self.selected = None;
+		self.history.clear();
+		self.history_loading = false;
+		self.resolution_text.clear();
+	}
+
+	fn topic(&self) -> Option<String> {
+		self.room.as_ref().map(RoomTopic::format)
+	}
+
+	fn request_list(&mut self, app: &Chatty) -> Task<Message> {
+		let Some(topic) = self.topic() else {
+			return Task::none();
+		};
+		self.loading = true;
+		self.error = None;
+		let net = app.net_effects.clone();
+		Task::perform(async move { net.list_unban_requests(topic).await }, |res| {
+			Message::UnbanRequestsViewMessage(UnbanRequestsViewMessage::Requested(res))
+		})
+	}
+
+	fn request_history(&mut self, app: &Chatty) -> Task<Message> {
+		let (Some(topic), Some(request)) = (self.topic(), self.selected_request()) else {
+			return Task::none();
+		};
+		let search = pb::SearchChatLogCommand {
+			topic,
+			user: request.user_id.to_string(),
+			limit: HISTORY_LIMIT,
+			..Default::default()
+		};
+		self.history_loading = true;
+		let net = app.net_effects.clone();
+		Task::perform(async move { net.search_chat_log(search).await }, |res| {
+			Message::UnbanRequestsViewMessage(UnbanRequestsViewMessage::Requested(res))
+		})
+	}
+
+	/// Whether a chat log search for `topic` should be routed to the requester history.
+	pub fn awaits_history(&self, topic: &str) -> bool {
+		self.history_loading && self.topic().as_deref() == Some(topic)
+	}
+
+	pub fn apply_history(&mut self, entries: Vec<ChatLogEntryUi>, error: Option<String>) {
+		self.history_loading = false;
+		match error {
+			Some(e) => self.error = Some(e),
+			None => self.history = entries,
+		}
+	}
+
+	/// Apply a listing delivered by the network task; listings for another room are dropped.
+	pub fn apply_requests(&mut self, topic: &str, requests: Vec<UnbanRequestUi>, error: Option<String>) {
+		if self.topic().as_deref() != Some(topic) {
+			return;
+		}
+		self.loading = false;
+		if let Some(e) = error {
+			self.error = Some(e);
+			return;
+		}
+		self.requests = requests;
+		if self.selected_request().is_none() {
+			self.clear_selection();
+		}
+	}
+
+	/// Add a request announced live while its room is shown.
+	pub fn push(&mut self, room: &RoomKey, request: UnbanRequestUi) {
+		if self.room.as_ref() == Some(room) && !self.requests.iter().any(|r| r.request_id == request.request_id) {
+			self.requests.push(request);
+		}
+	}
+
+	/// Drop a resolved request, matched by id or, when the id is unknown, by the requester.
+	pub fn remove(&mut self, room: &RoomKey, request_id: Option<&str>, user_id: Option<&str>) {
+		if self.room.as_ref() != Some(room) {
+			return;
+		}
+		self.requests.retain(|r| match request_id {
+			Some(id) => r.request_id != id,
+			None => user_id.is_none_or(|u| r.user_id != u),
+		});
+		if self.selected_request().is_none() {
+			self.clear_selection();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chatty_domain::{Platform, RoomId};
+
+	use super::*;
+
+	fn request(id: &str, user_id: &str) -> UnbanRequestUi {
+		UnbanRequestUi {
+			request_id: SmolStr::new(id),
+			user_id: SmolStr::new(user_id),
+			user_login: SmolStr::new(format!("user{user_id}")),
+			user_display: None,
+			text: "sorry".to_string(),
+			created_at: None,
+		}
+	}
+
+	#[test]
+	fn resolved_requests_are_removed_by_id_or_requester() {
+		let room = RoomKey::new(Platform::Twitch, RoomId::new("chan".to_string()).expect("room id"));
+		let other = RoomKey::new(Platform::Twitch, RoomId::new("other".to_string()).expect("room id"));
+		let mut view = UnbanRequestsView {
+			room: Some(room.clone()),
+			..Default::default()
+		};
+		view.push(&room, request("a", "1"));
+		view.push(&room, request("a", "1"));
+		view.push(&room, request("b", "2"));
+		view.push(&other, request("c", "3"));
+		assert_eq!(view.requests.len(), 2, "duplicates and other rooms are ignored");
+
+		view.selected = Some(SmolStr::new("a"));
+		view.remove(&room, Some("a"), None);
+		assert!(view.selected.is_none(), "the selection is cleared with its request");
+
+		view.remove(&room, None, Some("2"));
+		assert!(view.requests.is_empty());
+	}
+}
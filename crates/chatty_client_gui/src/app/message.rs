@@ -8,6 +8,7 @@ use smol_str::SmolStr;
 use crate::app::features::chat::ChatPaneMessage;
 use crate::app::features::settings::SettingsMessage;
 use crate::app::features::tabs::TabId;
+use crate::app::features::unban_requests::UnbanRequestsViewMessage;
 use crate::app::features::users::UsersViewMessage;
 use crate::app::types::{JoinTarget, Page};
 
@@ -37,6 +38,19 @@ pub enum ChatMessage {
 	DeleteMessage(chatty_domain::RoomKey, Option<String>, Option<String>),
 	TimeoutUser(chatty_domain::RoomKey, String),
 	BanUser(chatty_domain::RoomKey, String),
+	UnbanUser(chatty_domain::RoomKey, String),
+	UntimeoutUser(chatty_domain::RoomKey, String),
+	ClearChat(chatty_domain::RoomKey),
+	ClearUserMessages(chatty_domain::RoomKey, String),
+	/// Approve (`true`) or deny a message held by AutoMod.
+	AutoModResolve(chatty_domain::RoomKey, String, bool),
+	EndPoll(chatty_domain::RoomKey, String),
+	/// Pay out a prediction to the given outcome, or cancel it when `None`.
+	ResolvePrediction(chatty_domain::RoomKey, String, Option<String>),
+	/// Hide a finished poll or prediction.
+	DismissPoll(chatty_domain::RoomKey, String),
+	/// Fulfill (`true`) or cancel and refund a channel points redemption: reward id, redemption id.
+	UpdateRedemption(chatty_domain::RoomKey, String, String, bool),
 	Sent(Result<(), String>),
 	MessageTextEdit(SmolStr, text_editor::Action),
 }
@@ -66,6 +80,7 @@ pub enum Message {
 	ToasterMessage(crate::app::features::toaster::ToasterMessage),
 	OverlayMessage(crate::app::features::overlays::OverlayMessage),
 	UsersViewMessage(UsersViewMessage),
+	UnbanRequestsViewMessage(UnbanRequestsViewMessage),
 	CursorMoved(f32, f32),
 	UserScrolled,
 	AnimationTick(Instant),
@@ -75,6 +90,7 @@ pub enum Message {
 
 	ModalDismissed,
 	OpenJoinModal(JoinTarget),
+	OpenChatSearch,
 	Net(Box<NetMessage>),
 	Chat(ChatMessage),
 
@@ -1,9 +1,12 @@
 #![forbid(unsafe_code)]
 
+use std::collections::VecDeque;
 use std::str::FromStr;
+use std::time::{Duration, SystemTime};
 
 use chatty_domain::{Platform, RoomId, RoomKey, RoomTopic};
 
+use crate::net::{PollStatusUi, PollUi, ProgressUi, RedemptionStatusUi, RedemptionUi};
 use crate::settings;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -16,6 +19,7 @@ pub struct RoomPermissions {
 	pub can_ban: bool,
 	pub is_moderator: bool,
 	pub is_broadcaster: bool,
+	pub can_manage_redemptions: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -29,6 +33,172 @@ pub struct RoomStateUi {
 	pub followers_only_duration_minutes: Option<u64>,
 }
 
+/// Last known stream status of a room, merged from stream status deltas.
+#[derive(Debug, Clone, Default)]
+pub struct RoomStreamStatus {
+	pub live: bool,
+	pub started_at: Option<SystemTime>,
+	pub title: Option<String>,
+	pub category: Option<String>,
+	pub viewer_count: Option<u64>,
+}
+
+impl RoomStreamStatus {
+	/// Unset fields keep their previous value; going offline drops the broadcast's start time and viewers.
+	pub fn apply(
+		&mut self,
+		live: Option<bool>,
+		started_at: Option<SystemTime>,
+		title: Option<String>,
+		category: Option<String>,
+		viewer_count: Option<u64>,
+	) {
+		if let Some(live) = live {
+			self.live = live;
+			if !live {
+				self.started_at = None;
+				self.viewer_count = None;
+			}
+		}
+		if self.live {
+			if started_at.is_some() {
+				self.started_at = started_at;
+			}
+			if viewer_count.is_some() {
+				self.viewer_count = viewer_count;
+			}
+		}
+		if title.is_some() {
+			self.title = title;
+		}
+		if category.is_some() {
+			self.category = category;
+		}
+	}
+
+	pub fn uptime(&self, now: SystemTime) -> Option<Duration> {
+		if !self.live {
+			return None;
+		}
+		self.started_at.and_then(|started| now.duration_since(started).ok())
+	}
+
+	/// `h:mm:ss` (or `m:ss` in the first hour) since the broadcast started.
+	pub fn uptime_label(&self, now: SystemTime) -> Option<String> {
+		let secs = self.uptime(now)?.as_secs();
+		let (h, m, s) = (secs / 3600, (secs / 60) % 60, secs % 60);
+		Some(if h > 0 {
+			format!("{h}:{m:02}:{s:02}")
+		} else {
+			format!("{m}:{s:02}")
+		})
+	}
+}
+
+/// Poll and prediction shown above a room's chat; Twitch runs at most one of each at a time.
+#[derive(Debug, Clone, Default)]
+pub struct RoomPolls {
+	pub poll: Option<PollUi>,
+	pub prediction: Option<PollUi>,
+}
+
+impl RoomPolls {
+	/// Each update replaces the snapshot of its kind; a canceled one is dropped.
+	pub fn apply(&mut self, poll: PollUi) {
+		let slot = if poll.is_prediction {
+			&mut self.prediction
+		} else {
+			&mut self.poll
+		};
+		if poll.status == PollStatusUi::Canceled {
+			if slot.as_ref().is_some_and(|p| p.id == poll.id) {
+				*slot = None;
+			}
+			return;
+		}
+		*slot = Some(poll);
+	}
+
+	/// Hide a finished poll or prediction whose results the user has seen.
+	pub fn dismiss(&mut self, id: &str) {
+		for slot in [&mut self.poll, &mut self.prediction] {
+			if slot.as_ref().is_some_and(|p| p.id == id) {
+				*slot = None;
+			}
+		}
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &PollUi> {
+		self.poll.iter().chain(self.prediction.iter())
+	}
+}
+
+/// Unfulfilled channel points redemptions for a room, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct RoomRedemptions {
+	pub queue: VecDeque<RedemptionUi>,
+}
+
+impl RoomRedemptions {
+	/// Bounds memory for rewards nobody works through; the oldest entries go first.
+	const MAX_QUEUED: usize = 200;
+
+	/// New redemptions join the queue; fulfilled or canceled ones leave it.
+	pub fn apply(&mut self, redemption: RedemptionUi) {
+		let existing = self.queue.iter().position(|r| r.id == redemption.id);
+		match (redemption.status, existing) {
+			(RedemptionStatusUi::Unfulfilled, Some(idx)) => self.queue[idx] = redemption,
+			(RedemptionStatusUi::Unfulfilled, None) => {
+				self.queue.push_back(redemption);
+				while self.queue.len() > Self::MAX_QUEUED {
+					self.queue.pop_front();
+				}
+			}
+			(_, Some(idx)) => {
+				self.queue.remove(idx);
+			}
+			(_, None) => {}
+		}
+	}
+}
+
+/// Hype train, goal and charity campaign progress shown in a room's pane header.
+#[derive(Debug, Clone, Default)]
+pub struct RoomProgress {
+	pub items: Vec<ProgressUi>,
+}
+
+impl RoomProgress {
+	/// Each update replaces the snapshot with the same id; an ended one is dropped.
+	pub fn apply(&mut self, progress: ProgressUi) {
+		let existing = self.items.iter().position(|p| p.id == progress.id);
+		match (progress.ended, existing) {
+			(true, Some(idx)) => {
+				self.items.remove(idx);
+			}
+			(true, None) => {}
+			(false, Some(idx)) => self.items[idx] = progress,
+			(false, None) => self.items.push(progress),
+		}
+	}
+
+	/// Hype trains past `expires_at` are skipped in case their end event was missed.
+	pub fn active(&self, now: SystemTime) -> impl Iterator<Item = &ProgressUi> {
+		self.items
+			.iter()
+			.filter(move |p| p.expires_at.is_none_or(|expires_at| expires_at > now))
+	}
+}
+
+/// Scrollback paging state for a room's chat history.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoomHistoryState {
+	/// Cursor for the next older page; 0 starts from the newest retained event.
+	pub before_cursor: u64,
+	pub loading: bool,
+	pub exhausted: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct JoinRequest {
 	pub raw: String,
@@ -114,6 +284,145 @@ mod tests {
 		assert_eq!(rooms, vec![room]);
 	}
 
+	#[test]
+	fn stream_status_merges_deltas_and_clears_on_offline() {
+		let started = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+		let mut status = RoomStreamStatus::default();
+
+		status.apply(None, None, Some("speedruns".to_string()), Some("Celeste".to_string()), None);
+		assert!(!status.live);
+		assert_eq!(status.uptime_label(started), None);
+
+		status.apply(Some(true), Some(started), None, None, None);
+		status.apply(None, None, None, None, Some(42));
+		assert_eq!(status.title.as_deref(), Some("speedruns"));
+		assert_eq!(status.viewer_count, Some(42));
+		assert_eq!(
+			status.uptime_label(started + Duration::from_secs(65)).as_deref(),
+			Some("1:05")
+		);
+		assert_eq!(
+			status.uptime_label(started + Duration::from_secs(3_725)).as_deref(),
+			Some("1:02:05")
+		);
+
+		status.apply(Some(false), None, None, None, None);
+		assert!(status.started_at.is_none());
+		assert!(status.viewer_count.is_none());
+		assert_eq!(
+			status.category.as_deref(),
+			Some("Celeste"),
+			"channel info outlives the broadcast"
+		);
+	}
+
+	#[test]
+	fn polls_replace_by_kind_and_drop_when_canceled() {
+		let mk = |id: &str, is_prediction: bool, status: PollStatusUi| PollUi {
+			id: id.to_string(),
+			is_prediction,
+			status,
+			title: "title".to_string(),
+			choices: Vec::new(),
+			winning_choice_id: None,
+			ends_at: None,
+		};
+		let mut polls = RoomPolls::default();
+
+		polls.apply(mk("poll-1", false, PollStatusUi::Active));
+		polls.apply(mk("pred-1", true, PollStatusUi::Locked));
+		assert_eq!(polls.iter().count(), 2, "a poll and a prediction can run together");
+
+		polls.apply(mk("poll-1", false, PollStatusUi::Ended));
+		assert_eq!(polls.poll.as_ref().map(|p| p.status), Some(PollStatusUi::Ended));
+
+		polls.apply(mk("poll-2", false, PollStatusUi::Active));
+		assert_eq!(polls.poll.as_ref().map(|p| p.id.as_str()), Some("poll-2"));
+
+		polls.apply(mk("pred-old", true, PollStatusUi::Canceled));
+		assert!(
+			polls.prediction.is_some(),
+			"canceling another prediction keeps the current one"
+		);
+		polls.apply(mk("pred-1", true, PollStatusUi::Canceled));
+		assert!(polls.prediction.is_none());
+
+		polls.dismiss("poll-2");
+		assert_eq!(polls.iter().count(), 0);
+	}
+
+	#[test]
+	fn redemptions_queue_until_fulfilled_or_canceled() {
+		let mk = |id: &str, status: RedemptionStatusUi| RedemptionUi {
+			id: id.to_string(),
+			reward_id: "reward-1".to_string(),
+			reward_title: "Hydrate".to_string(),
+			cost: 100,
+			user_login: "viewer".into(),
+			user_display: None,
+			user_input: None,
+			status,
+		};
+		let mut redemptions = RoomRedemptions::default();
+
+		redemptions.apply(mk("red-1", RedemptionStatusUi::Unfulfilled));
+		redemptions.apply(mk("red-2", RedemptionStatusUi::Unfulfilled));
+		redemptions.apply(mk("red-1", RedemptionStatusUi::Unfulfilled));
+		assert_eq!(
+			redemptions.queue.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+			vec!["red-1", "red-2"],
+			"a repeated add keeps the queue position"
+		);
+
+		redemptions.apply(mk("red-1", RedemptionStatusUi::Fulfilled));
+		redemptions.apply(mk("red-3", RedemptionStatusUi::Canceled));
+		assert_eq!(
+			redemptions.queue.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+			vec!["red-2"]
+		);
+
+		for i in 0..RoomRedemptions::MAX_QUEUED {
+			redemptions.apply(mk(&format!("bulk-{i}"), RedemptionStatusUi::Unfulfilled));
+		}
+		assert_eq!(redemptions.queue.len(), RoomRedemptions::MAX_QUEUED);
+		assert_eq!(redemptions.queue.front().map(|r| r.id.as_str()), Some("bulk-0"));
+	}
+
+	#[test]
+	fn progress_replaces_by_id_and_hides_ended_or_expired() {
+		use crate::net::ProgressKindUi;
+
+		let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+		let mk = |id: &str, kind: ProgressKindUi, current: u64, ended: bool| ProgressUi {
+			id: id.to_string(),
+			kind,
+			level: None,
+			title: None,
+			current,
+			target: 100,
+			currency: None,
+			expires_at: None,
+			ended,
+		};
+		let mut progress = RoomProgress::default();
+
+		progress.apply(mk("goal-1", ProgressKindUi::Goal, 10, false));
+		progress.apply(mk("train-1", ProgressKindUi::HypeTrain, 5, false));
+		progress.apply(mk("goal-1", ProgressKindUi::Goal, 40, false));
+		assert_eq!(progress.items.len(), 2);
+		assert_eq!(progress.items[0].current, 40);
+
+		progress.items[1].expires_at = Some(now - Duration::from_secs(1));
+		assert_eq!(
+			progress.active(now).map(|p| p.id.as_str()).collect::<Vec<_>>(),
+			vec!["goal-1"],
+			"an expired hype train is hidden even without an end event"
+		);
+
+		progress.apply(mk("goal-1", ProgressKindUi::Goal, 100, true));
+		assert!(progress.items.iter().all(|p| p.id != "goal-1"));
+	}
+
 	#[test]
 	fn parse_rooms_default_platform() {
 		let req = JoinRequest {
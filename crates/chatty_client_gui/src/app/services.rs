@@ -19,6 +19,9 @@ pub trait NetEffects: Send + Sync {
 	fn subscribe_room_key(&self, room: RoomKey) -> NetFuture<Result<(), String>>;
 	fn unsubscribe_room_key(&self, room: RoomKey) -> NetFuture<Result<(), String>>;
 	fn send_command(&self, command: pb::Command) -> NetFuture<Result<(), String>>;
+	fn fetch_history(&self, topic: String, before_cursor: u64, limit: u32) -> NetFuture<Result<(), String>>;
+	fn search_chat_log(&self, search: pb::SearchChatLogCommand) -> NetFuture<Result<(), String>>;
+	fn list_unban_requests(&self, topic: String) -> NetFuture<Result<(), String>>;
 }
 
 pub trait LayoutStore: Send + Sync {
@@ -66,6 +69,21 @@ impl NetEffects for RealNetEffects {
 		let net = self.net.clone();
 		Box::pin(async move { net.send_command(command).await })
 	}
+
+	fn fetch_history(&self, topic: String, before_cursor: u64, limit: u32) -> NetFuture<Result<(), String>> {
+		let net = self.net.clone();
+		Box::pin(async move { net.fetch_history(topic, before_cursor, limit).await })
+	}
+
+	fn search_chat_log(&self, search: pb::SearchChatLogCommand) -> NetFuture<Result<(), String>> {
+		let net = self.net.clone();
+		Box::pin(async move { net.search_chat_log(search).await })
+	}
+
+	fn list_unban_requests(&self, topic: String) -> NetFuture<Result<(), String>> {
+		let net = self.net.clone();
+		Box::pin(async move { net.list_unban_requests(topic).await })
+	}
 }
 
 #[derive(Clone, Default)]
@@ -2,25 +2,33 @@
 #![allow(dead_code)]
 
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use chatty_client_core::ClientConfigV1;
 use chatty_domain::{Platform, RoomKey};
 use iced::keyboard;
 use iced::widget::pane_grid;
+use smol_str::SmolStr;
 use tracing::{debug, info};
 
 use crate::app::assets::AssetCatalog;
+use crate::app::features::automod::AutoModQueue;
 use crate::app::features::chat::ChatPane;
 use crate::app::features::tabs::{ChatItem, ChatLog, TabId, TabModel, TabTarget};
 use crate::app::features::toaster::{UiNotification, UiNotificationKind};
 use crate::app::features::window::{WindowId, WindowModel};
-use crate::app::room::{JoinRequest, RoomPermissions, RoomStateUi};
+use crate::app::room::{
+	JoinRequest, RoomHistoryState, RoomPermissions, RoomPolls, RoomProgress, RoomRedemptions, RoomStateUi, RoomStreamStatus,
+};
 use crate::app::types::{JoinTarget, Page, SettingsCategory};
-use crate::app::view_models::ChatMessageUi;
+use crate::app::view_models::{ChatMessageUi, SystemNoticeUi};
+use crate::net::AdapterStatusUi;
 use crate::settings;
 use crate::settings::GuiSettings;
 
+/// Upstream status changes kept for the diagnostics page.
+const ADAPTER_STATUS_HISTORY_LIMIT: usize = 50;
+
 #[derive(Debug, Clone)]
 pub enum ConnectionStatus {
 	Disconnected {
@@ -60,6 +68,7 @@ pub struct UiState {
 	pub server_auth_token: String,
 	pub max_log_items_raw: String,
 	pub users_view: crate::app::features::users::UsersView,
+	pub unban_requests_view: crate::app::features::unban_requests::UnbanRequestsView,
 	pub active_overlay: Option<crate::app::features::overlays::ActiveOverlay>,
 	pub overlay_dismissed: bool,
 	pub main_window_id: Option<iced::window::Id>,
@@ -93,6 +102,7 @@ impl Default for UiState {
 			server_auth_token: String::new(),
 			max_log_items_raw: String::new(),
 			users_view: crate::app::features::users::UsersView::new(),
+			unban_requests_view: crate::app::features::unban_requests::UnbanRequestsView::new(),
 			active_overlay: None,
 			overlay_dismissed: false,
 			main_window_id: None,
@@ -120,6 +130,16 @@ pub struct AppState {
 	pub settings: GuiSettings,
 	pub room_permissions: HashMap<RoomKey, RoomPermissions>,
 	pub room_states: HashMap<RoomKey, RoomStateUi>,
+	pub stream_statuses: HashMap<RoomKey, RoomStreamStatus>,
+	pub polls: HashMap<RoomKey, RoomPolls>,
+	pub redemptions: HashMap<RoomKey, RoomRedemptions>,
+	pub progress: HashMap<RoomKey, RoomProgress>,
+	pub room_history: HashMap<RoomKey, RoomHistoryState>,
+	/// Latest upstream platform status the server reported for each room.
+	pub adapter_status: HashMap<RoomKey, AdapterStatusUi>,
+	/// Recent upstream status changes, oldest first.
+	pub adapter_status_history: VecDeque<AdapterStatusUi>,
+	pub automod_queue: AutoModQueue,
 	pub asset_catalog: AssetCatalog,
 	pub selected_tab_id: Option<TabId>,
 	pub tab_order: Vec<TabId>,
@@ -154,6 +174,14 @@ impl AppState {
 			notifications: Vec::new(),
 			room_permissions: HashMap::new(),
 			room_states: HashMap::new(),
+			stream_statuses: HashMap::new(),
+			polls: HashMap::new(),
+			redemptions: HashMap::new(),
+			progress: HashMap::new(),
+			room_history: HashMap::new(),
+			adapter_status: HashMap::new(),
+			adapter_status_history: VecDeque::new(),
+			automod_queue: AutoModQueue::default(),
 			asset_catalog: AssetCatalog::new(),
 			selected_tab_id: None,
 			tab_order: Vec::new(),
@@ -293,6 +321,96 @@ impl AppState {
 		self.push_chat_item_for_room(&room, ChatItem::ChatMessage(Box::new(msg)))
 	}
 
+	/// Prepend a page of older items (oldest first) to every tab showing `room`.
+	/// Messages already in a tab's log (same server or platform id) are skipped.
+	pub fn prepend_history(&mut self, room: &RoomKey, items: Vec<ChatItem>) -> Vec<TabId> {
+		let matching_tabs: Vec<TabId> = self
+			.tabs
+			.iter()
+			.filter_map(|(id, tab)| if tab.target.0.contains(room) { Some(*id) } else { None })
+			.collect();
+
+		for tid in &matching_tabs {
+			let Some(tab) = self.tabs.get_mut(tid) else {
+				continue;
+			};
+
+			let fresh: Vec<ChatItem> = items
+				.iter()
+				.filter(|item| {
+					let ChatItem::ChatMessage(m) = item else {
+						return true;
+					};
+					!tab.log.items.iter().any(|existing| match existing {
+						ChatItem::ChatMessage(e) => {
+							e.room == m.room
+								&& ((m.server_message_id.is_some() && e.server_message_id == m.server_message_id)
+									|| (m.platform_message_id.is_some() && e.platform_message_id == m.platform_message_id))
+						}
+						ChatItem::SystemNotice(_) => false,
+					})
+				})
+				.cloned()
+				.collect();
+
+			let inserted = tab.log.prepend(fresh);
+			for item in tab.log.items.iter().take(inserted) {
+				if let ChatItem::ChatMessage(m) = item
+					&& !m.is_deleted
+				{
+					*tab.user_counts.entry(m.user_login.clone()).or_insert(0) += 1;
+				}
+			}
+		}
+
+		matching_tabs
+	}
+
+	/// Mark a history fetch as in flight; returns the cursor to request, or `None` if one is
+	/// already running or the room has no older history.
+	pub fn begin_history_fetch(&mut self, room: &RoomKey) -> Option<u64> {
+		let entry = self.room_history.entry(room.clone()).or_default();
+		if entry.loading || entry.exhausted {
+			return None;
+		}
+		entry.loading = true;
+		Some(entry.before_cursor)
+	}
+
+	/// Record the outcome of a history fetch (`None` on failure, so it can be retried).
+	pub fn finish_history_fetch(&mut self, room: &RoomKey, next_before_cursor: Option<u64>) {
+		let entry = self.room_history.entry(room.clone()).or_default();
+		entry.loading = false;
+		match next_before_cursor {
+			Some(0) => entry.exhausted = true,
+			Some(cursor) => entry.before_cursor = cursor,
+			None => {}
+		}
+	}
+
+	/// Store the upstream status for `room`. The server repeats a platform's status for every
+	/// subscribed room, so the history only keeps the first copy.
+	pub fn record_adapter_status(&mut self, room: RoomKey, status: AdapterStatusUi) {
+		if !self.adapter_status_history.contains(&status) {
+			if self.adapter_status_history.len() >= ADAPTER_STATUS_HISTORY_LIMIT {
+				self.adapter_status_history.pop_front();
+			}
+			self.adapter_status_history.push_back(status.clone());
+		}
+		self.adapter_status.insert(room, status);
+	}
+
+	/// Degraded upstream statuses affecting any of `rooms`, one per platform.
+	pub fn degraded_adapters<'a>(&'a self, rooms: &[RoomKey]) -> Vec<&'a AdapterStatusUi> {
+		let mut out: Vec<&AdapterStatusUi> = Vec::new();
+		for status in rooms.iter().filter_map(|room| self.adapter_status.get(room)) {
+			if !status.connected && !out.iter().any(|s| s.platform == status.platform) {
+				out.push(status);
+			}
+		}
+		out
+	}
+
 	pub fn remove_message(&mut self, room: &RoomKey, server_message_id: Option<&str>, platform_message_id: Option<&str>) {
 		for (_tid, tab) in self.tabs.iter_mut() {
 			let mut new_items = std::collections::VecDeque::with_capacity(tab.log.items.len());
@@ -340,43 +458,57 @@ impl AppState {
 		server_message_id: Option<&str>,
 		platform_message_id: Option<&str>,
 	) {
+		self.mark_messages_deleted_where(room, |m| {
+			let sid_match = matches!((server_message_id, m.server_message_id.as_deref()), (Some(a), Some(b)) if a == b);
+			let pid_match = matches!((platform_message_id, m.platform_message_id.as_deref()), (Some(a), Some(b)) if a == b);
+			sid_match || pid_match
+		});
+	}
+
+	/// Mark every message from a user in `room` as deleted (timeouts, bans, clears).
+	pub fn mark_user_messages_deleted(&mut self, room: &RoomKey, user_id: Option<&str>, user_login: Option<&str>) {
+		self.mark_messages_deleted_where(room, |m| {
+			let id_match = matches!((user_id, m.author_id.as_deref()), (Some(a), Some(b)) if a == b);
+			let login_match = user_login.is_some_and(|login| m.user_login.eq_ignore_ascii_case(login));
+			id_match || login_match
+		});
+	}
+
+	/// Mark every message in `room` as deleted (chat clear).
+	pub fn mark_room_messages_deleted(&mut self, room: &RoomKey) {
+		self.mark_messages_deleted_where(room, |_| true);
+	}
+
+	fn mark_messages_deleted_where(&mut self, room: &RoomKey, pred: impl Fn(&ChatMessageUi) -> bool) {
 		for (_tid, tab) in self.tabs.iter_mut() {
 			for item in tab.log.items.iter_mut() {
 				let ChatItem::ChatMessage(m) = item else {
 					continue;
 				};
 
-				if &m.room != room {
+				if &m.room != room || m.is_deleted || !pred(m) {
 					continue;
 				}
 
-				let mut should_mark = false;
-				if let Some(sid) = server_message_id
-					&& let Some(ref msg_sid) = m.server_message_id
-					&& msg_sid == sid
-				{
-					should_mark = true;
-				}
-				if let Some(pid) = platform_message_id
-					&& let Some(ref msg_pid) = m.platform_message_id
-					&& msg_pid == pid
-				{
-					should_mark = true;
-				}
-
-				if should_mark && !m.is_deleted {
-					m.is_deleted = true;
-					if let Some(count) = tab.user_counts.get_mut(&m.user_login) {
-						*count = count.saturating_sub(1);
-						if *count == 0 {
-							tab.user_counts.remove(&m.user_login);
-						}
+				m.is_deleted = true;
+				if let Some(count) = tab.user_counts.get_mut(&m.user_login) {
+					*count = count.saturating_sub(1);
+					if *count == 0 {
+						tab.user_counts.remove(&m.user_login);
 					}
 				}
 			}
 		}
 	}
 
+	pub fn push_system_notice(&mut self, room: &RoomKey, text: impl Into<SmolStr>) -> Vec<TabId> {
+		let notice = SystemNoticeUi {
+			time: SystemTime::now(),
+			text: text.into(),
+		};
+		self.push_chat_item_for_room(room, ChatItem::SystemNotice(notice))
+	}
+
 	pub fn set_connection_status(&mut self, st: ConnectionStatus) {
 		info!(?st, "connection status changed");
 		self.connection = st;
@@ -398,10 +530,11 @@ impl AppState {
 mod tests {
 	use std::time::SystemTime;
 
-	use super::*;
 	use smallvec::SmallVec;
 	use smol_str::SmolStr;
 
+	use super::*;
+
 	fn make_message(room: RoomKey, user_login: &str, server_id: &str) -> ChatMessageUi {
 		ChatMessageUi {
 			time: SystemTime::UNIX_EPOCH,
@@ -459,6 +592,35 @@ mod tests {
 		assert_eq!(tab.log.items.len(), 0);
 	}
 
+	#[test]
+	fn mark_user_messages_deleted_only_affects_matching_user() {
+		let mut state = AppState::new();
+		let room = RoomKey::new(Platform::Twitch, chatty_domain::RoomId::new("r3").expect("room id"));
+		let tab_id = state.create_tab_for_rooms("room", vec![room.clone()]);
+
+		state.push_message(make_message(room.clone(), "alice", "srv-1"));
+		state.push_message(make_message(room.clone(), "bob", "srv-2"));
+		state.push_message(make_message(room.clone(), "Alice", "srv-3"));
+
+		state.mark_user_messages_deleted(&room, None, Some("alice"));
+		state.push_system_notice(&room, "alice was timed out");
+
+		let tab = state.tabs.get(&tab_id).expect("tab");
+		let deleted: Vec<bool> = tab
+			.log
+			.items
+			.iter()
+			.filter_map(|item| match item {
+				ChatItem::ChatMessage(m) => Some(m.is_deleted),
+				ChatItem::SystemNotice(_) => None,
+			})
+			.collect();
+		assert_eq!(deleted, vec![true, false, true]);
+		assert_eq!(tab.log.items.len(), 4);
+		assert_eq!(tab.user_counts.get("alice"), None);
+		assert_eq!(tab.user_counts.get("bob").copied(), Some(1));
+	}
+
 	#[test]
 	fn pop_tab_updates_order_and_selection() {
 		let mut state = AppState::new();
@@ -474,6 +636,52 @@ mod tests {
 		assert_eq!(state.selected_tab_id, Some(id_b));
 	}
 
+	#[test]
+	fn prepend_history_skips_known_messages_and_respects_capacity() {
+		let mut state = AppState::new();
+		let room = RoomKey::new(Platform::Twitch, chatty_domain::RoomId::new("history").expect("room id"));
+		let tab_id = state.create_tab_for_rooms("room", vec![room.clone()]);
+		if let Some(tab) = state.tabs.get_mut(&tab_id) {
+			tab.log.max_items = 3;
+		}
+
+		state.push_message(make_message(room.clone(), "carol", "srv-3"));
+		let page = ["srv-0", "srv-1", "srv-2", "srv-3"]
+			.iter()
+			.map(|id| ChatItem::ChatMessage(Box::new(make_message(room.clone(), "alice", id))))
+			.collect();
+		state.prepend_history(&room, page);
+
+		let tab = state.tabs.get(&tab_id).expect("tab");
+		let ids: Vec<&str> = tab
+			.log
+			.items
+			.iter()
+			.filter_map(|item| match item {
+				ChatItem::ChatMessage(m) => m.server_message_id.as_deref(),
+				ChatItem::SystemNotice(_) => None,
+			})
+			.collect();
+		assert_eq!(ids, vec!["srv-1", "srv-2", "srv-3"]);
+		assert_eq!(tab.user_counts.get("alice").copied(), Some(2));
+		assert_eq!(tab.user_counts.get("carol").copied(), Some(1));
+	}
+
+	#[test]
+	fn history_fetch_state_tracks_cursor_and_exhaustion() {
+		let mut state = AppState::new();
+		let room = RoomKey::new(Platform::Twitch, chatty_domain::RoomId::new("paging").expect("room id"));
+
+		assert_eq!(state.begin_history_fetch(&room), Some(0));
+		assert_eq!(state.begin_history_fetch(&room), None, "only one fetch in flight");
+		state.finish_history_fetch(&room, Some(40));
+		assert_eq!(state.begin_history_fetch(&room), Some(40));
+		state.finish_history_fetch(&room, None);
+		assert_eq!(state.begin_history_fetch(&room), Some(40), "failed fetches can be retried");
+		state.finish_history_fetch(&room, Some(0));
+		assert_eq!(state.begin_history_fetch(&room), None);
+	}
+
 	#[test]
 	fn push_message_eviction_keeps_counts_consistent() {
 		let mut state = AppState::new();
@@ -492,4 +700,37 @@ mod tests {
 		assert_eq!(tab.log.items.len(), 1);
 		assert_eq!(tab.user_counts.get("alice").copied(), Some(1));
 	}
+
+	#[test]
+	fn adapter_status_history_skips_repeats_across_rooms() {
+		let mut state = AppState::new();
+		let room_a = RoomKey::new(Platform::Twitch, chatty_domain::RoomId::new("a").expect("room id"));
+		let room_b = RoomKey::new(Platform::Twitch, chatty_domain::RoomId::new("b").expect("room id"));
+		let down = AdapterStatusUi {
+			platform: Platform::Twitch,
+			connected: false,
+			detail: "eventsub ws ended".to_string(),
+			last_error: None,
+			time: SystemTime::UNIX_EPOCH,
+		};
+
+		state.record_adapter_status(room_a.clone(), down.clone());
+		state.record_adapter_status(room_b.clone(), down.clone());
+		assert_eq!(state.adapter_status_history.len(), 1);
+		assert_eq!(state.degraded_adapters(&[room_a.clone(), room_b.clone()]).len(), 1);
+
+		let up = AdapterStatusUi {
+			connected: true,
+			detail: "eventsub connected".to_string(),
+			..down
+		};
+		state.record_adapter_status(room_a.clone(), up);
+		assert_eq!(state.adapter_status_history.len(), 2);
+		assert!(state.degraded_adapters(&[room_a.clone()]).is_empty());
+		assert_eq!(
+			state.degraded_adapters(&[room_a, room_b]).len(),
+			1,
+			"room b has not heard the recovery yet"
+		);
+	}
 }
@@ -107,6 +107,8 @@ pub enum Page {
 	Main,
 	Settings,
 	Users,
+	AutoMod,
+	UnbanRequests,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -142,7 +144,11 @@ impl SettingsCategory {
 pub struct PlatformChoice(pub Platform);
 
 impl PlatformChoice {
-	pub const ALL: [PlatformChoice; 2] = [PlatformChoice(Platform::Twitch), PlatformChoice(Platform::Kick)];
+	pub const ALL: [PlatformChoice; 3] = [
+		PlatformChoice(Platform::Twitch),
+		PlatformChoice(Platform::Kick),
+		PlatformChoice(Platform::YouTube),
+	];
 }
 
 impl std::fmt::Display for PlatformChoice {
@@ -150,6 +156,7 @@ impl std::fmt::Display for PlatformChoice {
 		let label = match self.0 {
 			Platform::Twitch => t!("platform.twitch"),
 			Platform::Kick => t!("platform.kick"),
+			Platform::YouTube => t!("platform.youtube"),
 			_ => t!("platform.unknown"),
 		};
 		write!(f, "{}", label)
@@ -183,4 +190,42 @@ pub enum PendingCommand {
 		room: RoomKey,
 		user_id: String,
 	},
+	Unban {
+		room: RoomKey,
+		user_id: String,
+	},
+	Untimeout {
+		room: RoomKey,
+		user_id: String,
+	},
+	ClearChat {
+		room: RoomKey,
+	},
+	ClearUserMessages {
+		room: RoomKey,
+		user_id: String,
+	},
+	UpdateRoomSettings {
+		room: RoomKey,
+	},
+	AutoModResolve {
+		room: RoomKey,
+		message_id: String,
+	},
+	ResolveUnbanRequest {
+		room: RoomKey,
+		request_id: String,
+	},
+	EndPoll {
+		room: RoomKey,
+		poll_id: String,
+	},
+	ResolvePrediction {
+		room: RoomKey,
+		prediction_id: String,
+	},
+	UpdateRedemption {
+		room: RoomKey,
+		redemption_id: String,
+	},
 }
@@ -14,6 +14,24 @@ impl Chatty {
 			ChatMessage::DeleteMessage(room, s_id, p_id) => self.update_delete_message(room, s_id, p_id),
 			ChatMessage::TimeoutUser(room, user_id) => self.update_timeout_user(room, user_id),
 			ChatMessage::BanUser(room, user_id) => self.update_ban_user(room, user_id),
+			ChatMessage::UnbanUser(room, user_id) => self.execute_unban_user(room, user_id),
+			ChatMessage::UntimeoutUser(room, user_id) => self.execute_untimeout_user(room, user_id),
+			ChatMessage::ClearChat(room) => self.update_clear_chat(room),
+			ChatMessage::ClearUserMessages(room, user_id) => self.update_clear_user_messages(room, user_id),
+			ChatMessage::AutoModResolve(room, message_id, allow) => self.execute_automod_resolve(room, message_id, allow),
+			ChatMessage::EndPoll(room, poll_id) => self.execute_end_poll(room, poll_id),
+			ChatMessage::ResolvePrediction(room, prediction_id, winning_outcome_id) => {
+				self.update_resolve_prediction(room, prediction_id, winning_outcome_id)
+			}
+			ChatMessage::UpdateRedemption(room, reward_id, redemption_id, fulfill) => {
+				self.execute_update_redemption(room, reward_id, redemption_id, fulfill)
+			}
+			ChatMessage::DismissPoll(room, poll_id) => {
+				if let Some(polls) = self.state.polls.get_mut(&room) {
+					polls.dismiss(&poll_id);
+				}
+				Task::none()
+			}
 			ChatMessage::Sent(res) => self.update_sent(res),
 			ChatMessage::MessageTextEdit(key, action) => self.update_message_text_edit(key, action),
 		}
@@ -182,6 +200,232 @@ impl Chatty {
 		)
 	}
 
+	// Reversals need no confirmation; they are sent straight away.
+	pub fn execute_unban_user(&mut self, room: chatty_domain::RoomKey, user_id: String) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::UnbanUser(
+				chatty_protocol::pb::UnbanUserCommand {
+					topic,
+					user_id: user_id.clone(),
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::Unban { room, user_id })
+	}
+
+	pub fn execute_untimeout_user(&mut self, room: chatty_domain::RoomKey, user_id: String) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::UntimeoutUser(
+				chatty_protocol::pb::UntimeoutUserCommand {
+					topic,
+					user_id: user_id.clone(),
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::Untimeout { room, user_id })
+	}
+
+	pub fn update_clear_chat(&mut self, room: chatty_domain::RoomKey) -> Task<Message> {
+		self.state.ui.active_overlay = Some(crate::app::features::overlays::ActiveOverlay::Confirm(
+			crate::app::features::overlays::ConfirmModal::new_clear_chat(room),
+		));
+		Task::none()
+	}
+
+	pub fn execute_clear_chat(&mut self, room: chatty_domain::RoomKey) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::ClearChat(
+				chatty_protocol::pb::ClearChatCommand { topic },
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::ClearChat { room })
+	}
+
+	pub fn update_clear_user_messages(&mut self, room: chatty_domain::RoomKey, user_id: String) -> Task<Message> {
+		self.state.ui.active_overlay = Some(crate::app::features::overlays::ActiveOverlay::Confirm(
+			crate::app::features::overlays::ConfirmModal::new_clear_user_messages(room, user_id),
+		));
+		Task::none()
+	}
+
+	pub fn execute_clear_user_messages(&mut self, room: chatty_domain::RoomKey, user_id: String) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::ClearUserMessages(
+				chatty_protocol::pb::ClearUserMessagesCommand {
+					topic,
+					user_id: user_id.clone(),
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::ClearUserMessages { room, user_id })
+	}
+
+	pub fn update_open_room_settings(&mut self, pane: iced::widget::pane_grid::Pane) -> Task<Message> {
+		let rooms: Vec<_> = self
+			.pane_rooms(pane)
+			.into_iter()
+			.filter(|rk| {
+				self.state
+					.room_permissions
+					.get(rk)
+					.is_some_and(|p| p.is_moderator || p.is_broadcaster)
+			})
+			.collect();
+		let Some(room) = rooms.first().cloned() else {
+			return Task::none();
+		};
+		let modal = crate::app::features::overlays::RoomSettingsModal::new(self, rooms, room, self.state.ui.last_cursor_pos);
+		self.state.ui.active_overlay = Some(crate::app::features::overlays::ActiveOverlay::RoomSettings(modal));
+		Task::none()
+	}
+
+	pub fn execute_update_room_settings(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		settings: chatty_protocol::pb::RoomChatSettings,
+	) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::UpdateRoomSettings(
+				chatty_protocol::pb::UpdateRoomSettingsCommand {
+					topic,
+					settings: Some(settings),
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::UpdateRoomSettings { room })
+	}
+
+	pub fn execute_automod_resolve(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		message_id: String,
+		allow: bool,
+	) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::AutomodResolve(
+				chatty_protocol::pb::AutoModResolveCommand {
+					topic,
+					message_id: message_id.clone(),
+					allow,
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::AutoModResolve { room, message_id })
+	}
+
+	pub fn execute_resolve_unban_request(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		request_id: String,
+		approve: bool,
+		resolution_text: String,
+	) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::ResolveUnbanRequest(
+				chatty_protocol::pb::ResolveUnbanRequestCommand {
+					topic,
+					request_id: request_id.clone(),
+					approve,
+					resolution_text,
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::ResolveUnbanRequest { room, request_id })
+	}
+
+	// Ending a poll early keeps its results visible, so it needs no confirmation.
+	pub fn execute_end_poll(&mut self, room: chatty_domain::RoomKey, poll_id: String) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::EndPoll(
+				chatty_protocol::pb::EndPollCommand {
+					topic,
+					poll_id: poll_id.clone(),
+					archive: false,
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::EndPoll { room, poll_id })
+	}
+
+	pub fn update_resolve_prediction(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		prediction_id: String,
+		winning_outcome_id: Option<String>,
+	) -> Task<Message> {
+		self.state.ui.active_overlay = Some(crate::app::features::overlays::ActiveOverlay::Confirm(
+			crate::app::features::overlays::ConfirmModal::new_resolve_prediction(room, prediction_id, winning_outcome_id),
+		));
+		Task::none()
+	}
+
+	pub fn execute_resolve_prediction(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		prediction_id: String,
+		winning_outcome_id: Option<String>,
+	) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let cmd = chatty_protocol::pb::Command {
+			command: Some(chatty_protocol::pb::command::Command::ResolvePrediction(
+				chatty_protocol::pb::ResolvePredictionCommand {
+					topic,
+					prediction_id: prediction_id.clone(),
+					winning_outcome_id: winning_outcome_id.unwrap_or_default(),
+				},
+			)),
+		};
+		self.send_moderation_command(cmd, PendingCommand::ResolvePrediction { room, prediction_id })
+	}
+
+	// No confirmation: the queue is meant to be worked through quickly, and an entry only leaves it once the
+	// platform reports the new status.
+	pub fn execute_update_redemption(
+		&mut self,
+		room: chatty_domain::RoomKey,
+		reward_id: String,
+		redemption_id: String,
+		fulfill: bool,
+	) -> Task<Message> {
+		let topic = chatty_domain::RoomTopic::format(&room);
+		let command = if fulfill {
+			chatty_protocol::pb::command::Command::FulfillRedemption(chatty_protocol::pb::FulfillRedemptionCommand {
+				topic,
+				reward_id,
+				redemption_id: redemption_id.clone(),
+			})
+		} else {
+			chatty_protocol::pb::command::Command::CancelRedemption(chatty_protocol::pb::CancelRedemptionCommand {
+				topic,
+				reward_id,
+				redemption_id: redemption_id.clone(),
+			})
+		};
+		let cmd = chatty_protocol::pb::Command { command: Some(command) };
+		self.send_moderation_command(cmd, PendingCommand::UpdateRedemption { room, redemption_id })
+	}
+
+	fn send_moderation_command(&mut self, cmd: chatty_protocol::pb::Command, pending: PendingCommand) -> Task<Message> {
+		let net = self.net_effects.clone();
+		self.state.ui.active_overlay = None;
+		self.pending_commands.push(pending);
+		Task::perform(
+			async move {
+				let res: Result<(), String> = net.send_command(cmd).await.map_err(|e| e.to_string());
+				Message::Chat(crate::app::message::ChatMessage::Sent(res))
+			},
+			|m| m,
+		)
+	}
+
 	pub fn update_confirm_modal_confirmed(&mut self, modal: crate::app::features::overlays::ConfirmModal) -> Task<Message> {
 		let room = modal.room;
 		let kind = modal.kind;
@@ -196,6 +440,14 @@ impl Chatty {
 				self.execute_timeout_user(room, user_id)
 			}
 			crate::app::features::overlays::ConfirmModalKind::BanUser(user_id) => self.execute_ban_user(room, user_id),
+			crate::app::features::overlays::ConfirmModalKind::ClearChat => self.execute_clear_chat(room),
+			crate::app::features::overlays::ConfirmModalKind::ClearUserMessages(user_id) => {
+				self.execute_clear_user_messages(room, user_id)
+			}
+			crate::app::features::overlays::ConfirmModalKind::ResolvePrediction {
+				prediction_id,
+				winning_outcome_id,
+			} => self.execute_resolve_prediction(room, prediction_id, winning_outcome_id),
 		}
 	}
 
@@ -94,6 +94,12 @@ impl Chatty {
 				self.state.ui.users_view = view;
 				task
 			}
+			Message::UnbanRequestsViewMessage(msg) => {
+				let mut view = std::mem::take(&mut self.state.ui.unban_requests_view);
+				let task = view.update(self, msg);
+				self.state.ui.unban_requests_view = view;
+				task
+			}
 			Message::Net(msg) => self.update_net_message(*msg),
 			Message::Window(msg) => self.update_window_message(msg),
 			Message::Chat(msg) => self.update_chat_message(msg),
@@ -103,6 +109,7 @@ impl Chatty {
 
 			Message::ModalDismissed => self.update_modal_dismissed(),
 			Message::OpenJoinModal(target) => self.update_open_join_modal(target),
+			Message::OpenChatSearch => self.update_open_chat_search(),
 			Message::PaneSubscribed(pane, res) => self.update_pane_subscribed(pane, res),
 			Message::TabUnsubscribed(room, res) => self.update_tab_unsubscribed(room, res),
 			Message::ClipboardRead(target, txt) => self.update_clipboard_read(target, txt),
@@ -4,17 +4,20 @@ use chatty_domain::{RoomKey, RoomTopic};
 use iced::Task;
 use iced::widget::pane_grid;
 use rust_i18n::t;
+use smol_str::SmolStr;
 use tracing::info;
 
+use crate::app::features::automod::HeldMessage;
+use crate::app::features::overlays::ActiveOverlay;
+use crate::app::features::tabs::ChatItem;
 use crate::app::message::{Message, NetMessage};
 use crate::app::message_format::{build_message_key, tokenize_message_parts};
 use crate::app::model::Chatty;
 use crate::app::net::recv_next;
 use crate::app::state::ConnectionStatus;
-use crate::app::view_models::{AssetBundleUi, ChatMessageUi};
-use crate::net::UiEvent;
+use crate::app::view_models::{AssetBundleUi, ChatMessageUi, SystemNoticeUi};
+use crate::net::{ModerationActionUi, UiEvent, UnbanRequestUi};
 use crate::settings;
-use smol_str::SmolStr;
 
 impl Chatty {
 	pub fn update_net_message(&mut self, message: NetMessage) -> Task<Message> {
@@ -150,8 +153,18 @@ impl Chatty {
 			UiEvent::ChatMessage { .. } => "chat_message",
 			UiEvent::RoomPermissions { .. } => "room_permissions",
 			UiEvent::RoomState { .. } => "room_state",
+			UiEvent::StreamStatus { .. } => "stream_status",
+			UiEvent::Poll { .. } => "poll",
+			UiEvent::Redemption { .. } => "redemption",
+			UiEvent::Progress { .. } => "progress",
 			UiEvent::AssetBundle { .. } => "asset_bundle",
+			UiEvent::Moderation { .. } => "moderation",
+			UiEvent::UserNotice { .. } => "user_notice",
 			UiEvent::CommandResult { .. } => "command_result",
+			UiEvent::ChatHistory { .. } => "chat_history",
+			UiEvent::ChatLogSearch { .. } => "chat_log_search",
+			UiEvent::UnbanRequests { .. } => "unban_requests",
+			UiEvent::AdapterStatus { .. } => "adapter_status",
 		};
 		tracing::debug!(event_kind, "NetPolled event received in UI");
 
@@ -172,9 +185,27 @@ impl Chatty {
 			| UiEvent::Disconnected { .. } => self.handle_connection_event(ev),
 			UiEvent::ErrorWithServer { .. } => self.handle_error_event(ev),
 			UiEvent::ChatMessage { .. } => self.handle_chat_event(ev),
-			UiEvent::RoomPermissions { .. } | UiEvent::RoomState { .. } => self.handle_room_event(ev),
+			UiEvent::RoomPermissions { .. }
+			| UiEvent::RoomState { .. }
+			| UiEvent::StreamStatus { .. }
+			| UiEvent::Poll { .. }
+			| UiEvent::Redemption { .. }
+			| UiEvent::Progress { .. } => self.handle_room_event(ev),
 			UiEvent::AssetBundle { .. } => self.handle_asset_event(ev),
+			UiEvent::Moderation { .. } | UiEvent::UserNotice { .. } => self.handle_notice_event(ev),
 			UiEvent::CommandResult { .. } => self.handle_command_result_event(ev),
+			UiEvent::ChatHistory { .. } => self.handle_history_event(ev),
+			UiEvent::ChatLogSearch { .. } => self.handle_chat_log_search_event(ev),
+			UiEvent::UnbanRequests { topic, requests, error } => {
+				self.state.ui.unban_requests_view.apply_requests(&topic, requests, error);
+				None
+			}
+			UiEvent::AdapterStatus { topic, status } => {
+				if let Ok(room) = RoomTopic::parse(&topic) {
+					self.state.record_adapter_status(room, status);
+				}
+				None
+			}
 		};
 
 		let ev_task = ev_task_opt.unwrap_or_else(Task::none);
@@ -217,6 +248,9 @@ impl Chatty {
 					format!("{} ({})", server_name, server_instance_id)
 				};
 				self.state.set_connection_status(ConnectionStatus::Connected { server });
+				self.state.room_history.clear();
+				// The server resends any ongoing outage when rooms are resubscribed.
+				self.state.adapter_status.clear();
 
 				let mut rooms = Vec::new();
 				let mut seen = std::collections::HashSet::new();
@@ -275,53 +309,301 @@ impl Chatty {
 	}
 
 	fn handle_chat_event(&mut self, ev: UiEvent) -> Option<Task<Message>> {
-		match ev {
-			UiEvent::ChatMessage {
+		let UiEvent::ChatMessage { topic, .. } = &ev else {
+			unreachable!("handle_chat_event called with non-chat event");
+		};
+		match RoomTopic::parse(topic) {
+			Ok(room) => chat_message_ui(room, SystemTime::now(), ev).map(|msg| self.update_chat_message_prepared(msg)),
+			Err(_) => Some(self.report_warning(format!("{}: {topic}", t!("unparseable_topic")))),
+		}
+	}
+
+	fn handle_chat_log_search_event(&mut self, ev: UiEvent) -> Option<Task<Message>> {
+		let UiEvent::ChatLogSearch {
+			topic,
+			entries,
+			next_before_id,
+			append,
+			error,
+		} = ev
+		else {
+			unreachable!("handle_chat_log_search_event called with non-search event");
+		};
+
+		// Results for a closed or replaced search modal are dropped.
+		if let Some(ActiveOverlay::ChatSearch(modal)) = self.state.ui.active_overlay.as_mut()
+			&& modal.topic() == topic
+		{
+			modal.apply_results(entries, next_before_id, append, error);
+		} else if self.state.ui.unban_requests_view.awaits_history(&topic) {
+			self.state.ui.unban_requests_view.apply_history(entries, error);
+		}
+		None
+	}
+
+	fn handle_history_event(&mut self, ev: UiEvent) -> Option<Task<Message>> {
+		let UiEvent::ChatHistory {
+			topic,
+			events,
+			next_before_cursor,
+			error,
+		} = ev
+		else {
+			unreachable!("handle_history_event called with non-history event");
+		};
+		let Ok(room) = RoomTopic::parse(&topic) else {
+			return Some(self.report_warning(format!("{}: {topic}", t!("unparseable_topic"))));
+		};
+
+		if let Some(e) = error {
+			self.state.finish_history_fetch(&room, None);
+			return Some(self.report_warning(format!("{}: {e}", t!("history_fetch_failed"))));
+		}
+
+		let mut items: Vec<ChatItem> = Vec::with_capacity(events.len());
+		for (time, ev) in events {
+			match ev {
+				UiEvent::ChatMessage { .. } => {
+					if let Some(msg) = chat_message_ui(room.clone(), time, ev) {
+						items.push(ChatItem::ChatMessage(Box::new(msg)));
+					}
+				}
+				UiEvent::UserNotice {
+					kind,
+					text,
+					user_login,
+					user_display,
+					..
+				} => items.push(ChatItem::SystemNotice(SystemNoticeUi {
+					time,
+					text: SmolStr::new(user_notice_text(kind, text, user_login, user_display)),
+				})),
+				UiEvent::Moderation {
+					target_id,
+					target_login,
+					action,
+					..
+				} => {
+					// Apply moderation to the earlier messages of the page, as the live log would have.
+					for item in items.iter_mut() {
+						let ChatItem::ChatMessage(m) = item else {
+							continue;
+						};
+						let user_match = || {
+							matches!((target_id.as_deref(), m.author_id.as_deref()), (Some(a), Some(b)) if a == b)
+								|| target_login
+									.as_deref()
+									.is_some_and(|login| m.user_login.eq_ignore_ascii_case(login))
+						};
+						let deleted = match &action {
+							ModerationActionUi::DeleteMessage { message_id } => {
+								m.platform_message_id.as_deref() == Some(message_id.as_str())
+							}
+							ModerationActionUi::Timeout { .. }
+							| ModerationActionUi::Ban { .. }
+							| ModerationActionUi::ClearUserMessages => user_match(),
+							ModerationActionUi::ClearChat => true,
+							_ => false,
+						};
+						if deleted {
+							m.is_deleted = true;
+						}
+					}
+				}
+				_ => {}
+			}
+		}
+
+		self.state.prepend_history(&room, items);
+		self.state.finish_history_fetch(&room, Some(next_before_cursor));
+		None
+	}
+
+	fn handle_notice_event(&mut self, ev: UiEvent) -> Option<Task<Message>> {
+		let (room, text) = match ev {
+			UiEvent::Moderation {
 				topic,
-				author_login,
-				author_display,
-				author_id,
-				text,
-				server_message_id,
-				platform_message_id,
-				badge_ids,
-				emotes,
-				reply,
+				kind,
+				target_id,
+				target_login,
+				target_display,
+				notes,
+				action,
 			} => {
-				if let Ok(room) = RoomTopic::parse(&topic) {
-					let token_parts = tokenize_message_parts(text.as_str());
-					let time = SystemTime::now();
-					let display_name = author_display.clone().unwrap_or_else(|| author_login.clone());
-					let key = build_message_key(&room, server_message_id.as_deref(), platform_message_id.as_deref(), time);
-					let msg = ChatMessageUi {
-						time,
-						platform: room.platform,
-						room: room.clone(),
-						key: SmolStr::new(key),
-						server_message_id,
-						author_id,
-						user_login: author_login,
-						user_display: author_display,
-						display_name,
+				let Ok(room) = RoomTopic::parse(&topic) else {
+					return Some(self.report_warning(format!("{}: {topic}", t!("unparseable_topic"))));
+				};
+
+				let target_name = target_display
+					.clone()
+					.or_else(|| target_login.clone())
+					.map(|s| s.to_string())
+					.unwrap_or_else(|| t!("moderation.someone").to_string());
+
+				let text = match action {
+					ModerationActionUi::DeleteMessage { message_id } => {
+						self.state.mark_message_deleted(&room, None, Some(message_id.as_str()));
+						None
+					}
+					ModerationActionUi::Timeout {
+						duration_seconds,
+						reason,
+					} => {
+						self.state
+							.mark_user_messages_deleted(&room, target_id.as_deref(), target_login.as_deref());
+						let user = target_name.as_str();
+						let text = match (duration_seconds, reason) {
+							(Some(seconds), Some(reason)) => {
+								t!(
+									"moderation.timed_out_for_reason",
+									user = user,
+									seconds = seconds,
+									reason = reason
+								)
+							}
+							(Some(seconds), None) => t!("moderation.timed_out_for", user = user, seconds = seconds),
+							(None, Some(reason)) => t!("moderation.timed_out_reason", user = user, reason = reason),
+							(None, None) => t!("moderation.timed_out", user = user),
+						};
+						Some(text.to_string())
+					}
+					ModerationActionUi::Ban { reason } => {
+						self.state
+							.mark_user_messages_deleted(&room, target_id.as_deref(), target_login.as_deref());
+						let text = match reason {
+							Some(reason) => t!("moderation.banned_reason", user = target_name, reason = reason),
+							None => t!("moderation.banned", user = target_name),
+						};
+						Some(text.to_string())
+					}
+					ModerationActionUi::ClearUserMessages => {
+						self.state
+							.mark_user_messages_deleted(&room, target_id.as_deref(), target_login.as_deref());
+						Some(t!("moderation.messages_cleared", user = target_name).to_string())
+					}
+					ModerationActionUi::Untimeout => Some(t!("moderation.untimed_out", user = target_name).to_string()),
+					ModerationActionUi::Unban => Some(t!("moderation.unbanned", user = target_name).to_string()),
+					ModerationActionUi::ClearChat => {
+						self.state.mark_room_messages_deleted(&room);
+						Some(t!("moderation.chat_cleared").to_string())
+					}
+					ModerationActionUi::AutoModHold {
+						message_id,
 						text,
-						token_parts,
-						badge_ids,
-						emotes,
-						platform_message_id,
-						reply: *reply,
-						is_deleted: false,
-					};
-					Some(self.update_chat_message_prepared(msg))
-				} else {
-					Some(self.report_warning(format!("{}: {topic}", t!("unparseable_topic"))))
-				}
+						reason,
+					} => {
+						self.state.automod_queue.hold(HeldMessage {
+							room: room.clone(),
+							message_id,
+							user_login: target_login,
+							user_display: target_display,
+							text,
+							reason,
+							held_at: SystemTime::now(),
+						});
+						Some(t!("automod.held_notice", user = target_name).to_string())
+					}
+					ModerationActionUi::AutoModUpdate { message_id } => {
+						self.state.automod_queue.resolve(&room, &message_id);
+						None
+					}
+					ModerationActionUi::UnbanRequestCreate { request_id, text } => {
+						if let (Some(user_id), Some(user_login)) = (target_id, target_login) {
+							self.state.ui.unban_requests_view.push(
+								&room,
+								UnbanRequestUi {
+									request_id,
+									user_id,
+									user_login,
+									user_display: target_display,
+									text: text.unwrap_or_default(),
+									created_at: Some(SystemTime::now()),
+								},
+							);
+						}
+						Some(t!("moderation.unban_requested", user = target_name).to_string())
+					}
+					ModerationActionUi::UnbanRequestResolve { request_id, resolution } => {
+						self.state
+							.ui
+							.unban_requests_view
+							.remove(&room, request_id.as_deref(), target_id.as_deref());
+						// Twitch reports each resolution twice; only the dedicated event (with an id) gets a notice.
+						request_id.map(|_| {
+							let resolution = resolution.unwrap_or_else(|| t!("moderation.resolved").to_string());
+							t!(
+								"moderation.unban_request_resolved",
+								user = target_name,
+								resolution = resolution
+							)
+							.to_string()
+						})
+					}
+					ModerationActionUi::Other => Some(match notes {
+						Some(notes) => format!("[{kind}] {notes}"),
+						None => format!("[{kind}]"),
+					}),
+				};
+
+				(room, text)
+			}
+			UiEvent::UserNotice {
+				topic,
+				kind,
+				text,
+				user_login,
+				user_display,
+			} => {
+				let Ok(room) = RoomTopic::parse(&topic) else {
+					return Some(self.report_warning(format!("{}: {topic}", t!("unparseable_topic"))));
+				};
+
+				(room, Some(user_notice_text(kind, text, user_login, user_display)))
 			}
-			_ => unreachable!("handle_chat_event called with non-chat event"),
+			_ => return None,
+		};
+
+		if let Some(text) = text {
+			self.state.push_system_notice(&room, text);
 		}
+		None
 	}
 
 	fn handle_room_event(&mut self, ev: UiEvent) -> Option<Task<Message>> {
 		match ev {
+			UiEvent::StreamStatus {
+				topic,
+				live,
+				started_at,
+				title,
+				category,
+				viewer_count,
+			} => {
+				if let Ok(room) = RoomTopic::parse(&topic) {
+					self.state.stream_statuses.entry(room).or_default().apply(
+						live,
+						started_at,
+						title,
+						category,
+						viewer_count,
+					);
+				}
+			}
+			UiEvent::Poll { topic, poll } => {
+				if let Ok(room) = RoomTopic::parse(&topic) {
+					self.state.polls.entry(room).or_default().apply(poll);
+				}
+			}
+			UiEvent::Redemption { topic, redemption } => {
+				if let Ok(room) = RoomTopic::parse(&topic) {
+					self.state.redemptions.entry(room).or_default().apply(redemption);
+				}
+			}
+			UiEvent::Progress { topic, progress } => {
+				if let Ok(room) = RoomTopic::parse(&topic) {
+					self.state.progress.entry(room).or_default().apply(progress);
+				}
+			}
 			UiEvent::RoomPermissions { .. } | UiEvent::RoomState { .. } => {
 				if let UiEvent::RoomPermissions {
 					topic,
@@ -332,6 +614,7 @@ impl Chatty {
 					can_ban,
 					is_moderator,
 					is_broadcaster,
+					can_manage_redemptions,
 				} = ev
 				{
 					if let Ok(room) = RoomTopic::parse(&topic) {
@@ -345,6 +628,7 @@ impl Chatty {
 								can_ban,
 								is_moderator,
 								is_broadcaster,
+								can_manage_redemptions,
 							},
 						);
 					}
@@ -451,12 +735,9 @@ impl Chatty {
 						.mark_message_deleted(&room, server_message_id.as_deref(), platform_message_id.as_deref());
 				}
 
-				self.pending_commands.retain(|pc| {
-					!matches!(
-						pc,
-						crate::app::types::PendingCommand::Timeout { .. } | crate::app::types::PendingCommand::Ban { .. }
-					)
-				});
+				// Only deletes need reconciling with the log; any other moderation command is done.
+				self.pending_commands
+					.retain(|pc| matches!(pc, crate::app::types::PendingCommand::Delete { .. }));
 
 				self.rebuild_pending_delete_keys();
 			}
@@ -464,3 +745,56 @@ impl Chatty {
 		None
 	}
 }
+
+fn user_notice_text(
+	kind: String,
+	text: Option<String>,
+	user_login: Option<SmolStr>,
+	user_display: Option<SmolStr>,
+) -> String {
+	let body = text.unwrap_or(kind);
+	match user_display.or(user_login) {
+		Some(name) => format!("{name}: {body}"),
+		None => body,
+	}
+}
+
+fn chat_message_ui(room: RoomKey, time: SystemTime, ev: UiEvent) -> Option<ChatMessageUi> {
+	let UiEvent::ChatMessage {
+		author_login,
+		author_display,
+		author_id,
+		text,
+		server_message_id,
+		platform_message_id,
+		badge_ids,
+		emotes,
+		reply,
+		..
+	} = ev
+	else {
+		return None;
+	};
+
+	let token_parts = tokenize_message_parts(text.as_str());
+	let display_name = author_display.clone().unwrap_or_else(|| author_login.clone());
+	let key = build_message_key(&room, server_message_id.as_deref(), platform_message_id.as_deref(), time);
+	Some(ChatMessageUi {
+		time,
+		platform: room.platform,
+		room,
+		key: SmolStr::new(key),
+		server_message_id,
+		author_id,
+		user_login: author_login,
+		user_display: author_display,
+		display_name,
+		text,
+		token_parts,
+		badge_ids,
+		emotes,
+		platform_message_id,
+		reply: *reply,
+		is_deleted: false,
+	})
+}
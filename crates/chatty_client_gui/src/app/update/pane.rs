@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use chatty_domain::RoomTopic;
+use chatty_domain::{RoomKey, RoomTopic};
 use chatty_protocol::pb;
 use iced::Task;
 use iced::widget::{pane_grid, scrollable};
@@ -14,6 +14,8 @@ use crate::app::room::JoinRequest;
 use crate::app::types::JoinTarget;
 use crate::settings::SplitLayoutKind;
 
+const HISTORY_PAGE_SIZE: u32 = 50;
+
 impl Chatty {
 	pub fn update_layout_message(&mut self, message: LayoutMessage) -> Task<Message> {
 		match message {
@@ -32,15 +34,36 @@ impl Chatty {
 		}
 	}
 
-	pub fn update_chat_log_scrolled(&mut self, _pane: pane_grid::Pane, viewport: scrollable::Viewport) -> Task<Message> {
+	pub fn update_chat_log_scrolled(&mut self, pane: pane_grid::Pane, viewport: scrollable::Viewport) -> Task<Message> {
 		let bounds = viewport.bounds();
 		let content = viewport.content_bounds();
 		let offset = viewport.absolute_offset();
 		let at_end = offset.y + bounds.height + 4.0 >= content.height;
 		self.state.ui.follow_end = at_end;
+		if offset.y <= 4.0 {
+			return self.update_pane_message(pane, ChatPaneMessage::LoadOlderMessages);
+		}
 		Task::none()
 	}
 
+	pub fn request_older_history(&mut self, pane: pane_grid::Pane) -> Task<Message> {
+		let rooms = self.selected_tab().map(|t| t.target.0.clone()).unwrap_or_default();
+		let mut tasks = Vec::new();
+		for room in rooms {
+			let Some(before_cursor) = self.state.begin_history_fetch(&room) else {
+				continue;
+			};
+
+			let topic = RoomTopic::format(&room);
+			let net = self.net_effects.clone();
+			tasks.push(Task::perform(
+				async move { (room, net.fetch_history(topic, before_cursor, HISTORY_PAGE_SIZE).await) },
+				move |(room, res)| Message::PaneMessage(pane, ChatPaneMessage::HistoryRequested(room, res)),
+			));
+		}
+		Task::batch(tasks)
+	}
+
 	pub fn update_pane_message(&mut self, pane: pane_grid::Pane, msg: ChatPaneMessage) -> Task<Message> {
 		if let Some(tab) = self.selected_tab_mut()
 			&& let Some(mut p) = tab.panes.get_mut(pane).cloned()
@@ -76,7 +99,11 @@ impl Chatty {
 			);
 			return self.report_error(msg);
 		} else {
-			// unsubscribed successfully; nothing else to do
+			// No more events arrive for the room, so anything tracking its live activity would go stale.
+			self.state.stream_statuses.remove(&room);
+			self.state.polls.remove(&room);
+			self.state.redemptions.remove(&room);
+			self.state.progress.remove(&room);
 		}
 
 		tracing::info!("TabUnsubscribed handled; resuming network event polling");
@@ -219,6 +246,24 @@ impl Chatty {
 		Task::none()
 	}
 
+	pub fn update_open_chat_search(&mut self) -> Task<Message> {
+		let room = self
+			.selected_tab()
+			.and_then(|t| t.focused_pane.or_else(|| t.panes.iter().next().map(|(id, _)| *id)))
+			.and_then(|pane| self.pane_room(pane));
+		let Some(room) = room else {
+			return self.toast(t!("no_active_room").to_string());
+		};
+		self.update_open_search_modal(room, None)
+	}
+
+	pub fn update_open_search_modal(&mut self, room: RoomKey, user: Option<String>) -> Task<Message> {
+		self.state.ui.active_overlay = Some(crate::app::features::overlays::ActiveOverlay::ChatSearch(
+			crate::app::features::overlays::ChatSearchModal::new(room, user),
+		));
+		Task::none()
+	}
+
 	pub fn update_join_modal_submit(&mut self, modal: crate::app::features::overlays::JoinModal) -> Task<Message> {
 		let raw_input = modal.input.trim().to_string();
 		if raw_input.is_empty() {
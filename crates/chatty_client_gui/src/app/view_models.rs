@@ -179,6 +179,13 @@ pub struct ChatPaneViewModel<'a> {
 	pub show_platform_selector: bool,
 	pub replying_to: Option<ReplyToInfo>,
 	pub room_states: Vec<RoomStateInfo>,
+	/// Whether the user moderates any room in the pane, enabling the room settings popover.
+	pub can_edit_room_settings: bool,
+	pub polls: Vec<PollInfo>,
+	/// Queued channel points redemptions across the pane's rooms, oldest first.
+	pub redemptions: Vec<RedemptionInfo>,
+	/// Hype trains, goals and charity campaigns shown in the title bar.
+	pub progress: Vec<ProgressInfo>,
 }
 
 #[derive(Debug, Clone)]
@@ -189,6 +196,145 @@ pub struct RoomStateInfo {
 	pub slow_mode_wait: Option<u64>,
 }
 
+#[derive(Debug, Clone)]
+pub struct PollInfo {
+	pub room: RoomKey,
+	pub id: String,
+	pub title: String,
+	/// Kind plus time left, "Locked" or "Ended".
+	pub status_label: String,
+	pub choices: Vec<PollChoiceInfo>,
+	pub ended: bool,
+	/// Broadcaster may end the poll early.
+	pub can_end: bool,
+	/// Broadcaster may pick a winning outcome or cancel the prediction.
+	pub can_resolve: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PollChoiceInfo {
+	pub id: String,
+	pub title: String,
+	/// Votes (and points for predictions) with the share in percent.
+	pub tally_label: String,
+	/// Share of votes for polls, of channel points for predictions.
+	pub share: f32,
+	pub is_winner: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RedemptionInfo {
+	pub room: RoomKey,
+	pub id: String,
+	pub reward_id: String,
+	pub reward_title: String,
+	/// Redeemer plus cost, e.g. "viewer · 500 points".
+	pub detail_label: String,
+	pub user_input: Option<String>,
+	/// From the room's permission snapshot; without it the queue is read-only.
+	pub can_manage: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProgressInfo {
+	/// Kind or title, with level, amounts and time left where known.
+	pub label: String,
+	pub share: f32,
+}
+
+fn build_progress_info(progress: &crate::net::ProgressUi, now: SystemTime) -> ProgressInfo {
+	use crate::net::ProgressKindUi;
+
+	let name = match (progress.kind, progress.title.as_deref()) {
+		(ProgressKindUi::HypeTrain, _) => t!("main.hype_train_label").to_string(),
+		(_, Some(title)) => title.to_string(),
+		(ProgressKindUi::Goal, None) => t!("main.goal_label").to_string(),
+		(ProgressKindUi::CharityCampaign, None) => t!("main.charity_label").to_string(),
+	};
+	let mut label = name;
+	if let Some(level) = progress.level {
+		label.push_str(&format!(" · {} {level}", t!("main.hype_train_level")));
+	}
+	label.push_str(&format!(" · {}/{}", progress.current, progress.target));
+	if let Some(currency) = &progress.currency {
+		label.push_str(&format!(" {currency}"));
+	}
+	if let Some(left) = progress.expires_at.and_then(|end| end.duration_since(now).ok()) {
+		let secs = left.as_secs();
+		label.push_str(&format!(" · {}:{:02}", secs / 60, secs % 60));
+	}
+
+	let share = if progress.target == 0 {
+		0.0
+	} else {
+		(progress.current as f32 / progress.target as f32).min(1.0)
+	};
+	ProgressInfo { label, share }
+}
+
+fn build_poll_info(room: &RoomKey, poll: &crate::net::PollUi, is_broadcaster: bool, now: SystemTime) -> PollInfo {
+	use crate::net::PollStatusUi;
+
+	let kind_label = if poll.is_prediction {
+		t!("main.prediction_label")
+	} else {
+		t!("main.poll_label")
+	};
+	let status_label = match poll.status {
+		PollStatusUi::Active => match poll.ends_at.and_then(|end| end.duration_since(now).ok()) {
+			Some(left) => {
+				let secs = left.as_secs();
+				format!("{kind_label} · {}:{:02} {}", secs / 60, secs % 60, t!("main.poll_time_left"))
+			}
+			None => kind_label.to_string(),
+		},
+		PollStatusUi::Locked => format!("{kind_label} · {}", t!("main.poll_locked")),
+		PollStatusUi::Ended | PollStatusUi::Canceled => format!("{kind_label} · {}", t!("main.poll_ended")),
+	};
+
+	// Predictions are decided by points wagered, polls by votes cast.
+	let weight = |c: &crate::net::PollChoiceUi| if poll.is_prediction { c.channel_points } else { c.votes };
+	let total: u64 = poll.choices.iter().map(weight).sum();
+	let choices = poll
+		.choices
+		.iter()
+		.map(|c| {
+			let share = if total == 0 { 0.0 } else { weight(c) as f32 / total as f32 };
+			let percent = (share * 100.0).round() as u32;
+			let tally_label = if poll.is_prediction {
+				format!(
+					"{} {} · {} {} · {percent}%",
+					c.votes,
+					t!("main.poll_votes"),
+					c.channel_points,
+					t!("main.poll_points")
+				)
+			} else {
+				format!("{} {} · {percent}%", c.votes, t!("main.poll_votes"))
+			};
+			PollChoiceInfo {
+				id: c.id.clone(),
+				title: c.title.clone(),
+				tally_label,
+				share,
+				is_winner: poll.winning_choice_id.as_deref() == Some(c.id.as_str()),
+			}
+		})
+		.collect();
+
+	let ended = matches!(poll.status, PollStatusUi::Ended | PollStatusUi::Canceled);
+	PollInfo {
+		room: room.clone(),
+		id: poll.id.clone(),
+		title: poll.title.clone(),
+		status_label,
+		choices,
+		ended,
+		can_end: is_broadcaster && !poll.is_prediction && poll.status == PollStatusUi::Active,
+		can_resolve: is_broadcaster && poll.is_prediction && !ended,
+	}
+}
+
 pub fn build_chat_pane_view_model<'a>(
 	app: &'a Chatty,
 	tab: &'a TabModel,
@@ -234,11 +380,20 @@ pub fn build_chat_pane_view_model<'a>(
 			let warning_text = match platform {
 				chatty_domain::Platform::Twitch => t!("main.warning_no_twitch_login"),
 				chatty_domain::Platform::Kick => t!("main.warning_no_kick_login"),
+				chatty_domain::Platform::YouTube => t!("main.warning_no_youtube_login"),
 				_ => t!("main.warning_no_login"),
 			};
 			warnings.push(warning_text.to_string());
 		}
 	}
+	for status in app.state.degraded_adapters(rooms) {
+		let warning_text = match status.platform {
+			chatty_domain::Platform::Twitch => t!("main.warning_twitch_degraded"),
+			chatty_domain::Platform::Kick => t!("main.warning_kick_degraded"),
+			chatty_domain::Platform::YouTube => t!("main.warning_youtube_degraded"),
+		};
+		warnings.push(format!("{warning_text}: {}", status.detail));
+	}
 
 	let mut emotes_map_by_room: HashMap<RoomKey, Arc<HashMap<String, AssetRefUi>>> = HashMap::new();
 	let mut badges_map_by_room: HashMap<RoomKey, Arc<HashMap<String, AssetRefUi>>> = HashMap::new();
@@ -385,6 +540,13 @@ pub fn build_chat_pane_view_model<'a>(
 		None
 	};
 
+	let can_edit_room_settings = rooms.iter().any(|rk| {
+		app.state
+			.room_permissions
+			.get(rk)
+			.is_some_and(|p| p.is_moderator || p.is_broadcaster)
+	});
+
 	let mut room_states = Vec::new();
 	for rk in rooms {
 		if let Some(rs) = app.state.room_states.get(rk) {
@@ -397,6 +559,43 @@ pub fn build_chat_pane_view_model<'a>(
 		}
 	}
 
+	let now = SystemTime::now();
+	let mut polls = Vec::new();
+	for rk in rooms {
+		let is_broadcaster = app.state.room_permissions.get(rk).is_some_and(|p| p.is_broadcaster);
+		if let Some(room_polls) = app.state.polls.get(rk) {
+			polls.extend(room_polls.iter().map(|p| build_poll_info(rk, p, is_broadcaster, now)));
+		}
+	}
+
+	let progress = rooms
+		.iter()
+		.filter_map(|rk| app.state.progress.get(rk))
+		.flat_map(|room_progress| room_progress.active(now))
+		.map(|p| build_progress_info(p, now))
+		.collect();
+
+	let mut redemptions = Vec::new();
+	for rk in rooms {
+		let can_manage = app.state.room_permissions.get(rk).is_some_and(|p| p.can_manage_redemptions);
+		if let Some(room_redemptions) = app.state.redemptions.get(rk) {
+			redemptions.extend(room_redemptions.queue.iter().map(|r| RedemptionInfo {
+				room: rk.clone(),
+				id: r.id.clone(),
+				reward_id: r.reward_id.clone(),
+				reward_title: r.reward_title.clone(),
+				detail_label: format!(
+					"{} · {} {}",
+					r.user_display.as_deref().unwrap_or(r.user_login.as_str()),
+					r.cost,
+					t!("main.poll_points")
+				),
+				user_input: r.user_input.clone(),
+				can_manage,
+			}));
+		}
+	}
+
 	ChatPaneViewModel {
 		pane,
 		title,
@@ -414,6 +613,10 @@ pub fn build_chat_pane_view_model<'a>(
 		show_platform_selector,
 		replying_to,
 		room_states,
+		can_edit_room_settings,
+		polls,
+		redemptions,
+		progress,
 	}
 }
 
@@ -32,6 +32,12 @@ pub trait SessionControlApi: Send {
 		command: pb::Command,
 	) -> Pin<Box<dyn Future<Output = Result<pb::CommandResult, ClientCoreError>> + Send + 'a>>;
 
+	/// Token for resuming this session after a reconnect.
+	fn resume_token(&self) -> Option<String>;
+
+	/// Topics the server restored from a previous session at connect time.
+	fn resumed_topics(&self) -> Vec<String>;
+
 	fn close(&self, code: u32, reason: &str);
 }
 
@@ -81,6 +87,14 @@ impl SessionControlApi for SessionControl {
 		Box::pin(async move { SessionControl::send_command(self, command).await })
 	}
 
+	fn resume_token(&self) -> Option<String> {
+		SessionControl::resume_token(self).map(str::to_string)
+	}
+
+	fn resumed_topics(&self) -> Vec<String> {
+		SessionControl::resumed_topics(self).to_vec()
+	}
+
 	fn close(&self, code: u32, reason: &str) {
 		SessionControl::close(self, code, reason);
 	}
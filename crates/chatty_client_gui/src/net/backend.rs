@@ -5,7 +5,10 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
 
 use chatty_client_core::{ClientConfigV1, ClientCoreError, SessionControl};
+use chatty_domain::RoomTopic;
 use chatty_protocol::pb;
+use smallvec::SmallVec;
+use smol_str::SmolStr;
 use tokio::sync::{mpsc, oneshot};
 use tokio::time::{Instant, MissedTickBehavior};
 use tracing::{debug, info, warn};
@@ -14,11 +17,12 @@ use super::api::{BoxedSessionControl, BoxedSessionEvents};
 use super::controller::NetCommand;
 use super::reconnect::{RECONNECT_RESET_AFTER, schedule_reconnect};
 use super::subscriptions::{reconcile_subscriptions_on_connect, topic_for_room, unsubscribe_topics};
-use super::types::UiEvent;
+use super::types::{
+	AdapterStatusUi, ChatLogEntryUi, ModerationActionUi, PollChoiceUi, PollStatusUi, PollUi, ProgressKindUi, ProgressUi,
+	RedemptionStatusUi, RedemptionUi, UiEvent, UnbanRequestUi,
+};
 use crate::app::view_models::{AssetImageUi, AssetRefUi, AssetScaleUi, ChatReplyUi};
 use crate::net::{dev_default_topics, should_dev_auto_connect};
-use smallvec::SmallVec;
-use smol_str::SmolStr;
 
 const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(3);
 const KEEPALIVE_TIMEOUT: Duration = Duration::from_secs(10);
@@ -37,6 +41,7 @@ pub fn map_core_err(e: ClientCoreError) -> String {
 		ClientCoreError::Protocol(s) => s,
 		ClientCoreError::Io(s) => s,
 		ClientCoreError::Other(s) => s,
+		e @ ClientCoreError::ServerShuttingDown { .. } => e.to_string(),
 	}
 }
 
@@ -70,6 +75,8 @@ pub async fn run_network_task_with_session_factory<F>(
 	let cursor_by_topic: Arc<Mutex<HashMap<String, u64>>> = Arc::new(Mutex::new(HashMap::new()));
 	let lagged_topics: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
 	let mut last_connect_cfg: Option<ClientConfigV1> = None;
+	// Presented on automatic reconnects only; an explicit connect may carry a different identity.
+	let mut resume_token: Option<String> = None;
 	let mut reconnect_attempt: u32 = 0;
 	let mut reconnect_deadline: Option<Instant> = None;
 	let mut keepalive_failures: u32 = 0;
@@ -180,6 +187,7 @@ pub async fn run_network_task_with_session_factory<F>(
 						if let Some(t) = events_task.take() { t.abort(); }
 						if let Some(s) = session.as_ref() { s.close(0, "reconnect"); }
 						session = connect_fn(cfg.clone(), ui_tx.clone()).await;
+						resume_token = session.as_ref().and_then(|s| s.resume_token());
 
 						if let Some(s) = session.as_mut() {
 							if cfg!(debug_assertions) {
@@ -234,19 +242,28 @@ pub async fn run_network_task_with_session_factory<F>(
 						if let Some(s) = session.as_ref() { s.close(0, &reason); }
 						session = None;
 						last_connect_cfg = None;
+						resume_token = None;
 						reconnect_attempt = 0;
 						reconnect_deadline = None;
 						lagged_topics.lock().unwrap().clear();
 						let _ = ui_tx.send(UiEvent::Disconnected { reason });
 					}
 
-					NetCommand::EventsLoopEnded { reason } => {
+					NetCommand::EventsLoopEnded { reason, reconnect_after } => {
 						if let Some(t) = events_task.take() { t.abort(); }
 						if let Some(s) = session.as_ref() { s.close(0, &reason); }
 						session = None;
 						keepalive_failures = 0;
 						lagged_topics.lock().unwrap().clear();
-						if let Some(_cfg) = last_connect_cfg.clone() {
+						if let (Some(_cfg), Some(delay)) = (last_connect_cfg.clone(), reconnect_after) {
+							// A planned server restart is not a failure; skip the backoff.
+							reconnect_attempt = 0;
+							reconnect_deadline = Some(Instant::now() + delay);
+							let _ = ui_tx.send(UiEvent::Reconnecting {
+								attempt: 0,
+								next_retry_in_ms: delay.as_millis() as u64,
+							});
+						} else if let Some(_cfg) = last_connect_cfg.clone() {
 							let attempt = bump_reconnect_attempt(&mut reconnect_attempt, last_successful_connect_time);
 							let (deadline, ms) = schedule_reconnect(attempt);
 							reconnect_deadline = Some(deadline);
@@ -385,6 +402,116 @@ pub async fn run_network_task_with_session_factory<F>(
 							ui_send_error(&ui_tx, "not connected".to_string(), last_connect_cfg.as_ref());
 						}
 					}
+
+					NetCommand::FetchHistory { topic, before_cursor, limit } => {
+						let failed = |topic: String, error: String| UiEvent::ChatHistory {
+							topic,
+							events: Vec::new(),
+							next_before_cursor: before_cursor,
+							error: Some(error),
+						};
+
+						let ev = if let Some(s) = session.as_mut() {
+							let command = pb::Command {
+								command: Some(pb::command::Command::FetchHistory(pb::FetchHistoryCommand {
+									topic: topic.clone(),
+									before_cursor,
+									limit,
+								})),
+							};
+							match s.send_command(command).await {
+								Ok(result) if result.status == pb::command_result::Status::Ok as i32 => {
+									let page = result.history.unwrap_or_default();
+									let events = page
+										.events
+										.into_iter()
+										.filter_map(|env| {
+											let time = event_time(env.server_time_unix_ms);
+											map_event_envelope_to_ui_event(env).map(|ev| (time, ev))
+										})
+										.collect();
+									UiEvent::ChatHistory {
+										topic,
+										events,
+										next_before_cursor: page.next_before_cursor,
+										error: None,
+									}
+								}
+								Ok(result) => failed(topic, result.detail),
+								Err(e) => failed(topic, map_core_err(e)),
+							}
+						} else {
+							failed(topic, "not connected".to_string())
+						};
+						let _ = ui_tx.send(ev);
+					}
+
+					NetCommand::SearchChatLog { search } => {
+						let topic = search.topic.clone();
+						let append = search.before_id > 0;
+						let failed = |topic: String, error: String| UiEvent::ChatLogSearch {
+							topic,
+							entries: Vec::new(),
+							next_before_id: 0,
+							append,
+							error: Some(error),
+						};
+
+						let ev = if let Some(s) = session.as_mut() {
+							let command = pb::Command {
+								command: Some(pb::command::Command::SearchChatLog(search)),
+							};
+							match s.send_command(command).await {
+								Ok(result) if result.status == pb::command_result::Status::Ok as i32 => {
+									let page = result.search.unwrap_or_default();
+									UiEvent::ChatLogSearch {
+										topic,
+										entries: page.entries.into_iter().map(map_chat_log_entry).collect(),
+										next_before_id: page.next_before_id,
+										append,
+										error: None,
+									}
+								}
+								Ok(result) => failed(topic, result.detail),
+								Err(e) => failed(topic, map_core_err(e)),
+							}
+						} else {
+							failed(topic, "not connected".to_string())
+						};
+						let _ = ui_tx.send(ev);
+					}
+
+					NetCommand::ListUnbanRequests { topic } => {
+						let failed = |topic: String, error: String| UiEvent::UnbanRequests {
+							topic,
+							requests: Vec::new(),
+							error: Some(error),
+						};
+
+						let ev = if let Some(s) = session.as_mut() {
+							let command = pb::Command {
+								command: Some(pb::command::Command::ListUnbanRequests(pb::ListUnbanRequestsCommand {
+									topic: topic.clone(),
+									status: String::new(),
+								})),
+							};
+							match s.send_command(command).await {
+								Ok(result) if result.status == pb::command_result::Status::Ok as i32 => {
+									let list = result.unban_requests.unwrap_or_default();
+									UiEvent::UnbanRequests {
+										topic,
+										requests: list.requests.into_iter().filter_map(map_unban_request).collect(),
+										error: None,
+									}
+								}
+								Ok(result) => failed(topic, result.detail),
+								Err(e) => failed(topic, map_core_err(e)),
+							}
+						} else {
+							failed(topic, "not connected".to_string())
+						};
+						let _ = ui_tx.send(ev);
+					}
 				}
 			}
 
@@ -399,6 +526,7 @@ pub async fn run_network_task_with_session_factory<F>(
 				if let Some(t) = events_task.take() { t.abort(); }
 				if let Some(s) = session.as_ref() { s.close(0, "dev auto-connect"); }
 				session = connect_fn(Box::default(), ui_tx.clone()).await;
+				resume_token = session.as_ref().and_then(|s| s.resume_token());
 
 				if let Some(s) = session.as_mut() {
 					if cfg!(debug_assertions) {
@@ -444,7 +572,12 @@ pub async fn run_network_task_with_session_factory<F>(
 					let _ = ui_tx.send(UiEvent::Connecting);
 					if let Some(t) = events_task.take() { t.abort(); }
 					if let Some(s) = session.as_ref() { s.close(0, "reconnect"); }
+					let cfg = ClientConfigV1 {
+						resume_token: resume_token.take(),
+						..cfg
+					};
 					session = connect_fn(Box::new(cfg), ui_tx.clone()).await;
+					resume_token = session.as_ref().and_then(|s| s.resume_token());
 					if let Some(s) = session.as_mut() {
 						if let Err(e) = reconcile_subscriptions_on_connect(
 							s,
@@ -557,6 +690,13 @@ fn spawn_events_loop(
 					Some(pb::event_envelope::Event::Permissions(_)) => "permissions",
 					Some(pb::event_envelope::Event::AssetBundle(_)) => "asset_bundle",
 					Some(pb::event_envelope::Event::RoomState(_)) => "room_state",
+					Some(pb::event_envelope::Event::Moderation(_)) => "moderation",
+					Some(pb::event_envelope::Event::UserNotice(_)) => "user_notice",
+					Some(pb::event_envelope::Event::AdapterStatus(_)) => "adapter_status",
+					Some(pb::event_envelope::Event::StreamStatus(_)) => "stream_status",
+					Some(pb::event_envelope::Event::Poll(_)) => "poll",
+					Some(pb::event_envelope::Event::Redemption(_)) => "redemption",
+					Some(pb::event_envelope::Event::Progress(_)) => "progress",
 					None => "empty",
 				};
 
@@ -582,12 +722,16 @@ fn spawn_events_loop(
 			}))
 			.await;
 
+		let reconnect_after = match &res {
+			Err(ClientCoreError::ServerShuttingDown { reconnect_after }) => Some(*reconnect_after),
+			_ => None,
+		};
 		let reason = match res {
 			Ok(()) => "events stream closed".to_string(),
 			Err(e) => map_core_err(e),
 		};
 		let _ = ui_tx.send(UiEvent::Disconnected { reason: reason.clone() });
-		let _ = cmd_tx.send(NetCommand::EventsLoopEnded { reason }).await;
+		let _ = cmd_tx.send(NetCommand::EventsLoopEnded { reason, reconnect_after }).await;
 	})
 }
 
@@ -651,6 +795,89 @@ fn map_asset_ref(emote: pb::AssetRef) -> AssetRefUi {
 	}
 }
 
+fn event_time(server_time_unix_ms: i64) -> SystemTime {
+	if server_time_unix_ms > 0 {
+		SystemTime::UNIX_EPOCH + Duration::from_millis(server_time_unix_ms as u64)
+	} else {
+		SystemTime::now()
+	}
+}
+
+fn map_chat_log_entry(entry: pb::ChatLogEntry) -> ChatLogEntryUi {
+	ChatLogEntryUi {
+		id: entry.id,
+		time: event_time(entry.time_unix_ms),
+		is_moderation: entry.kind == pb::chat_log_entry::Kind::Moderation as i32,
+		user_login: non_empty_smol(entry.user_login),
+		user_display: non_empty_smol(entry.user_display),
+		text: entry.text,
+		action: non_empty_smol(entry.action),
+		actor_login: non_empty_smol(entry.actor_login),
+	}
+}
+
+fn map_unban_request(req: pb::UnbanRequest) -> Option<UnbanRequestUi> {
+	let user = req.user?;
+	Some(UnbanRequestUi {
+		request_id: SmolStr::new(req.request_id),
+		user_id: SmolStr::new(user.id),
+		user_login: SmolStr::new(user.login),
+		user_display: non_empty_smol(user.display),
+		text: req.text,
+		created_at: req.created_at_unix_ms.map(event_time),
+	})
+}
+
+fn non_empty(s: String) -> Option<String> {
+	if s.is_empty() { None } else { Some(s) }
+}
+
+fn non_empty_smol(s: String) -> Option<SmolStr> {
+	if s.is_empty() { None } else { Some(SmolStr::new(s)) }
+}
+
+fn map_moderation_action(action: Option<pb::ModerationAction>, target_message_platform_id: &str) -> ModerationActionUi {
+	use pb::moderation_action::Action;
+
+	match action.and_then(|a| a.action) {
+		Some(Action::DeleteMessage(d)) => ModerationActionUi::DeleteMessage {
+			message_id: SmolStr::new(d.message_id),
+		},
+		Some(Action::Timeout(t)) => ModerationActionUi::Timeout {
+			duration_seconds: t.duration_seconds,
+			reason: non_empty(t.reason),
+		},
+		Some(Action::Untimeout(_)) => ModerationActionUi::Untimeout,
+		Some(Action::Ban(b)) => ModerationActionUi::Ban {
+			reason: non_empty(b.reason),
+		},
+		Some(Action::Unban(_)) => ModerationActionUi::Unban,
+		Some(Action::ClearChat(_)) => ModerationActionUi::ClearChat,
+		Some(Action::ClearUserMessages(_)) => ModerationActionUi::ClearUserMessages,
+		Some(Action::AutomodHold(h)) if !h.message_id.is_empty() => ModerationActionUi::AutoModHold {
+			message_id: SmolStr::new(h.message_id),
+			text: h.text,
+			reason: non_empty(h.reason),
+		},
+		Some(Action::AutomodUpdate(u)) if !u.message_id.is_empty() => ModerationActionUi::AutoModUpdate {
+			message_id: SmolStr::new(u.message_id),
+		},
+		Some(Action::UnbanRequestCreate(c)) if !c.request_id.is_empty() => ModerationActionUi::UnbanRequestCreate {
+			request_id: SmolStr::new(c.request_id),
+			text: non_empty(c.text),
+		},
+		Some(Action::UnbanRequestResolve(r)) => ModerationActionUi::UnbanRequestResolve {
+			request_id: non_empty_smol(r.request_id),
+			resolution: non_empty(r.resolution),
+		},
+		Some(_) => ModerationActionUi::Other,
+		None if !target_message_platform_id.is_empty() => ModerationActionUi::DeleteMessage {
+			message_id: SmolStr::new(target_message_platform_id),
+		},
+		None => ModerationActionUi::Other,
+	}
+}
+
 fn map_event_envelope_to_ui_event(ev: pb::EventEnvelope) -> Option<UiEvent> {
 	let topic = ev.topic;
 
@@ -753,6 +980,7 @@ fn map_event_envelope_to_ui_event(ev: pb::EventEnvelope) -> Option<UiEvent> {
 			can_ban: perms.can_ban,
 			is_moderator: perms.is_moderator,
 			is_broadcaster: perms.is_broadcaster,
+			can_manage_redemptions: perms.can_manage_redemptions,
 		}),
 		Some(pb::event_envelope::Event::RoomState(state)) => {
 			let settings = state.settings.unwrap_or_default();
@@ -767,6 +995,85 @@ fn map_event_envelope_to_ui_event(ev: pb::EventEnvelope) -> Option<UiEvent> {
 				followers_only_duration_minutes: settings.followers_only_duration_minutes,
 			})
 		}
+		Some(pb::event_envelope::Event::StreamStatus(status)) => Some(UiEvent::StreamStatus {
+			topic,
+			live: status.live,
+			started_at: status.started_at_unix_ms.filter(|ms| *ms > 0).map(event_time),
+			title: status.title,
+			category: status.category,
+			viewer_count: status.viewer_count,
+		}),
+		Some(pb::event_envelope::Event::Poll(poll)) => {
+			let status = match poll.status {
+				x if x == pb::poll_event::Status::Locked as i32 => PollStatusUi::Locked,
+				x if x == pb::poll_event::Status::Ended as i32 => PollStatusUi::Ended,
+				x if x == pb::poll_event::Status::Canceled as i32 => PollStatusUi::Canceled,
+				_ => PollStatusUi::Active,
+			};
+			Some(UiEvent::Poll {
+				topic,
+				poll: PollUi {
+					id: poll.poll_id,
+					is_prediction: poll.kind == pb::poll_event::Kind::Prediction as i32,
+					status,
+					title: poll.title,
+					choices: poll
+						.choices
+						.into_iter()
+						.map(|c| PollChoiceUi {
+							id: c.choice_id,
+							title: c.title,
+							votes: c.votes,
+							channel_points: c.channel_points,
+						})
+						.collect(),
+					winning_choice_id: Some(poll.winning_choice_id).filter(|id| !id.is_empty()),
+					ends_at: poll.ends_at_unix_ms.filter(|ms| *ms > 0).map(event_time),
+				},
+			})
+		}
+		Some(pb::event_envelope::Event::Redemption(redemption)) => {
+			let user = redemption.user.unwrap_or_default();
+			let status = match redemption.status {
+				x if x == pb::redemption_event::Status::Fulfilled as i32 => RedemptionStatusUi::Fulfilled,
+				x if x == pb::redemption_event::Status::Canceled as i32 => RedemptionStatusUi::Canceled,
+				_ => RedemptionStatusUi::Unfulfilled,
+			};
+			Some(UiEvent::Redemption {
+				topic,
+				redemption: RedemptionUi {
+					id: redemption.redemption_id,
+					reward_id: redemption.reward_id,
+					reward_title: redemption.reward_title,
+					cost: redemption.cost,
+					user_login: SmolStr::new(user.login),
+					user_display: non_empty_smol(user.display),
+					user_input: non_empty(redemption.user_input),
+					status,
+				},
+			})
+		}
+		Some(pb::event_envelope::Event::Progress(progress)) => {
+			let kind = match progress.kind {
+				x if x == pb::progress_event::Kind::Goal as i32 => ProgressKindUi::Goal,
+				x if x == pb::progress_event::Kind::CharityCampaign as i32 => ProgressKindUi::CharityCampaign,
+				_ => ProgressKindUi::HypeTrain,
+			};
+			Some(UiEvent::Progress {
+				topic,
+				progress: ProgressUi {
+					id: progress.progress_id,
+					kind,
+					level: Some(progress.level).filter(|level| *level > 0),
+					title: non_empty(progress.title),
+					current: progress.current,
+					target: progress.target,
+					currency: non_empty(progress.currency),
+					expires_at: progress.expires_at_unix_ms.filter(|ms| *ms > 0).map(event_time),
+					ended: progress.ended,
+				},
+			})
+		}
 		Some(pb::event_envelope::Event::AssetBundle(bundle)) => {
 			let cache_key = if bundle.cache_key.is_empty() {
 				format!("provider:{}:origin:{}", bundle.provider, topic)
@@ -787,6 +1094,59 @@ fn map_event_envelope_to_ui_event(ev: pb::EventEnvelope) -> Option<UiEvent> {
 				badges,
 			})
 		}
+		Some(pb::event_envelope::Event::Moderation(m)) => {
+			let action_user = match m.action.as_ref().and_then(|a| a.action.as_ref()) {
+				Some(pb::moderation_action::Action::ClearUserMessages(c)) => c.user.clone(),
+				Some(pb::moderation_action::Action::AutomodHold(h)) => h.user.clone(),
+				Some(pb::moderation_action::Action::UnbanRequestCreate(c)) => c.user.clone(),
+				Some(pb::moderation_action::Action::UnbanRequestResolve(r)) => r.user.clone(),
+				_ => None,
+			};
+			let target = m.target.or(action_user);
+			let action = map_moderation_action(m.action, &m.target_message_platform_id);
+			let (target_id, target_login, target_display) = match target {
+				Some(u) => (non_empty_smol(u.id), non_empty_smol(u.login), non_empty_smol(u.display)),
+				None => (None, None, None),
+			};
+
+			Some(UiEvent::Moderation {
+				topic,
+				kind: m.kind,
+				target_id,
+				target_login,
+				target_display,
+				notes: non_empty(m.notes),
+				action,
+			})
+		}
+		Some(pb::event_envelope::Event::UserNotice(n)) => {
+			let (user_login, user_display) = match n.user {
+				Some(u) => (non_empty_smol(u.login), non_empty_smol(u.display)),
+				None => (None, None),
+			};
+
+			Some(UiEvent::UserNotice {
+				topic,
+				kind: n.kind,
+				text: non_empty(n.text),
+				user_login,
+				user_display,
+			})
+		}
+		Some(pb::event_envelope::Event::AdapterStatus(st)) => {
+			// The topic names the platform already; the event field is informational.
+			let platform = RoomTopic::parse(&topic).ok()?.platform;
+			Some(UiEvent::AdapterStatus {
+				topic,
+				status: AdapterStatusUi {
+					platform,
+					connected: st.connected,
+					detail: st.detail,
+					last_error: non_empty(st.last_error),
+					time: event_time(st.time_unix_ms),
+				},
+			})
+		}
 		None => None,
 	}
 }
@@ -13,6 +13,8 @@ pub enum NetCommand {
 	},
 	EventsLoopEnded {
 		reason: String,
+		/// Set when the server announced a shutdown; reconnect after this delay instead of backing off.
+		reconnect_after: Option<std::time::Duration>,
 	},
 	RefreshTopic {
 		topic: String,
@@ -26,6 +28,17 @@ pub enum NetCommand {
 	SendCommand {
 		command: pb::Command,
 	},
+	FetchHistory {
+		topic: String,
+		before_cursor: u64,
+		limit: u32,
+	},
+	SearchChatLog {
+		search: pb::SearchChatLogCommand,
+	},
+	ListUnbanRequests {
+		topic: String,
+	},
 }
 
 #[derive(Clone)]
@@ -72,6 +85,31 @@ impl NetController {
 			.await
 			.map_err(|_| "network task is not running".to_string())
 	}
+
+	pub async fn fetch_history(&self, topic: String, before_cursor: u64, limit: u32) -> Result<(), String> {
+		self.cmd_tx
+			.send(NetCommand::FetchHistory {
+				topic,
+				before_cursor,
+				limit,
+			})
+			.await
+			.map_err(|_| "network task is not running".to_string())
+	}
+
+	pub async fn search_chat_log(&self, search: pb::SearchChatLogCommand) -> Result<(), String> {
+		self.cmd_tx
+			.send(NetCommand::SearchChatLog { search })
+			.await
+			.map_err(|_| "network task is not running".to_string())
+	}
+
+	pub async fn list_unban_requests(&self, topic: String) -> Result<(), String> {
+		self.cmd_tx
+			.send(NetCommand::ListUnbanRequests { topic })
+			.await
+			.map_err(|_| "network task is not running".to_string())
+	}
 }
 
 pub struct ShutdownHandle {
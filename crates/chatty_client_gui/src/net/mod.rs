@@ -9,10 +9,14 @@ pub mod types;
 
 pub use backend::map_core_err;
 pub use controller::{NetCommand, NetController, ShutdownHandle};
-pub use types::UiEvent;
+pub use types::{
+	AdapterStatusUi, ChatLogEntryUi, ModerationActionUi, PollChoiceUi, PollStatusUi, PollUi, ProgressKindUi, ProgressUi,
+	RedemptionStatusUi, RedemptionUi, UiEvent, UnbanRequestUi,
+};
 
 pub const CHATTY_UI_AUTO_CONNECT_ENV: &str = "CHATTY_UI_AUTO_CONNECT";
 pub const CHATTY_UI_AUTO_SUBSCRIBE_ENV: &str = "CHATTY_UI_AUTO_SUBSCRIBE";
+pub const CHATTY_UI_TLS_INSECURE_SKIP_VERIFY_ENV: &str = "CHATTY_UI_TLS_INSECURE_SKIP_VERIFY";
 
 pub fn start_networking() -> (NetController, mpsc::UnboundedReceiver<UiEvent>, ShutdownHandle) {
 	let (cmd_tx, cmd_rx) = mpsc::channel::<NetCommand>(128);
@@ -52,6 +56,16 @@ pub(crate) fn should_dev_auto_connect() -> bool {
 		.unwrap_or(true)
 }
 
+/// Skipping certificate verification is an explicit dev opt-in and never happens in release builds.
+pub(crate) fn dev_tls_insecure_skip_verify() -> bool {
+	if !cfg!(debug_assertions) {
+		return false;
+	}
+	std::env::var(CHATTY_UI_TLS_INSECURE_SKIP_VERIFY_ENV)
+		.map(|v| is_truthy_env(&v))
+		.unwrap_or(false)
+}
+
 pub(crate) fn dev_default_topics() -> Vec<String> {
 	if let Ok(val) = std::env::var(CHATTY_UI_AUTO_SUBSCRIBE_ENV) {
 		let topics: Vec<String> = val
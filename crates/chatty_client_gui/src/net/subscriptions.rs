@@ -25,9 +25,20 @@ pub async fn reconcile_subscriptions_on_connect(
 ) -> Result<(), String> {
 	ensure_events_loop_started(session, events_task, ui_tx, cmd_tx, cursor_by_topic, lagged_topics).await?;
 
+	// A resumed session already carries its subscriptions; only the difference is sent.
+	let resumed: HashSet<String> = session.resumed_topics().into_iter().collect();
+	let stale: Vec<String> = resumed
+		.iter()
+		.filter(|t| topics_refcounts.get(*t).is_none_or(|c| *c == 0))
+		.cloned()
+		.collect();
+	if !stale.is_empty() {
+		unsubscribe_topics(session, stale, ui_tx).await?;
+	}
+
 	let topics: Vec<String> = topics_refcounts
 		.iter()
-		.filter(|(_, c)| **c > 0)
+		.filter(|(t, c)| **c > 0 && !resumed.contains(*t))
 		.map(|(t, _)| t.clone())
 		.collect();
 
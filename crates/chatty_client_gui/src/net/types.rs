@@ -1,9 +1,162 @@
 use core::fmt;
+use std::time::SystemTime;
 
-use crate::app::view_models::{AssetRefUi, ChatReplyUi};
+use chatty_domain::Platform;
 use smallvec::SmallVec;
 use smol_str::SmolStr;
 
+use crate::app::view_models::{AssetRefUi, ChatReplyUi};
+
+/// Moderation action details relevant to the chat log.
+#[derive(Debug, Clone)]
+pub enum ModerationActionUi {
+	DeleteMessage {
+		message_id: SmolStr,
+	},
+	Timeout {
+		duration_seconds: Option<u64>,
+		reason: Option<String>,
+	},
+	Untimeout,
+	Ban {
+		reason: Option<String>,
+	},
+	Unban,
+	ClearChat,
+	ClearUserMessages,
+	AutoModHold {
+		message_id: SmolStr,
+		text: String,
+		reason: Option<String>,
+	},
+	/// A held message was approved, denied or expired.
+	AutoModUpdate {
+		message_id: SmolStr,
+	},
+	UnbanRequestCreate {
+		request_id: SmolStr,
+		text: Option<String>,
+	},
+	/// `request_id` is unset when the resolution was reported by `channel.moderate`.
+	UnbanRequestResolve {
+		request_id: Option<SmolStr>,
+		resolution: Option<String>,
+	},
+	Other,
+}
+
+/// Lifecycle state of a poll or prediction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollStatusUi {
+	Active,
+	/// Prediction closed to new predictions, waiting to be resolved.
+	Locked,
+	Ended,
+	/// Archived poll or canceled prediction; no longer shown.
+	Canceled,
+}
+
+/// Poll or prediction snapshot with current tallies.
+#[derive(Debug, Clone)]
+pub struct PollUi {
+	pub id: String,
+	pub is_prediction: bool,
+	pub status: PollStatusUi,
+	pub title: String,
+	pub choices: Vec<PollChoiceUi>,
+	pub winning_choice_id: Option<String>,
+	/// When voting closes (polls) or predictions lock (predictions).
+	pub ends_at: Option<SystemTime>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PollChoiceUi {
+	pub id: String,
+	pub title: String,
+	/// Poll votes, or users who predicted this outcome.
+	pub votes: u64,
+	pub channel_points: u64,
+}
+
+/// Fulfillment state of a channel points redemption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedemptionStatusUi {
+	Unfulfilled,
+	Fulfilled,
+	/// Rejected and refunded.
+	Canceled,
+}
+
+/// Channel points custom reward redemption.
+#[derive(Debug, Clone)]
+pub struct RedemptionUi {
+	pub id: String,
+	pub reward_id: String,
+	pub reward_title: String,
+	pub cost: u64,
+	pub user_login: SmolStr,
+	pub user_display: Option<SmolStr>,
+	pub user_input: Option<String>,
+	pub status: RedemptionStatusUi,
+}
+
+/// What a [`ProgressUi`] tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressKindUi {
+	HypeTrain,
+	Goal,
+	CharityCampaign,
+}
+
+/// Hype train, goal or charity campaign progress snapshot.
+#[derive(Debug, Clone)]
+pub struct ProgressUi {
+	pub id: String,
+	pub kind: ProgressKindUi,
+	pub level: Option<u32>,
+	/// Goal description or charity name.
+	pub title: Option<String>,
+	pub current: u64,
+	pub target: u64,
+	pub currency: Option<String>,
+	pub expires_at: Option<SystemTime>,
+	pub ended: bool,
+}
+
+/// A chat log search hit.
+#[derive(Debug, Clone)]
+pub struct ChatLogEntryUi {
+	pub id: u64,
+	pub time: SystemTime,
+	pub is_moderation: bool,
+	pub user_login: Option<SmolStr>,
+	pub user_display: Option<SmolStr>,
+	pub text: String,
+	pub action: Option<SmolStr>,
+	pub actor_login: Option<SmolStr>,
+}
+
+/// A pending (or resolved) unban request.
+#[derive(Debug, Clone)]
+pub struct UnbanRequestUi {
+	pub request_id: SmolStr,
+	pub user_id: SmolStr,
+	pub user_login: SmolStr,
+	pub user_display: Option<SmolStr>,
+	pub text: String,
+	pub created_at: Option<SystemTime>,
+}
+
+/// Upstream platform connection state reported by the server for a topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterStatusUi {
+	pub platform: Platform,
+	pub connected: bool,
+	pub detail: String,
+	pub last_error: Option<String>,
+	pub time: SystemTime,
+}
+
 /// UI-level events emitted by the networking layer.
 #[derive(Clone)]
 pub enum UiEvent {
@@ -44,6 +197,7 @@ pub enum UiEvent {
 		can_ban: bool,
 		is_moderator: bool,
 		is_broadcaster: bool,
+		can_manage_redemptions: bool,
 	},
 	RoomState {
 		topic: String,
@@ -55,6 +209,27 @@ pub enum UiEvent {
 		followers_only: Option<bool>,
 		followers_only_duration_minutes: Option<u64>,
 	},
+	/// Stream status delta; `None` fields are unknown or unchanged.
+	StreamStatus {
+		topic: String,
+		live: Option<bool>,
+		started_at: Option<SystemTime>,
+		title: Option<String>,
+		category: Option<String>,
+		viewer_count: Option<u64>,
+	},
+	Poll {
+		topic: String,
+		poll: PollUi,
+	},
+	Redemption {
+		topic: String,
+		redemption: RedemptionUi,
+	},
+	Progress {
+		topic: String,
+		progress: ProgressUi,
+	},
 	AssetBundle {
 		topic: String,
 		cache_key: String,
@@ -64,10 +239,53 @@ pub enum UiEvent {
 		emotes: Vec<AssetRefUi>,
 		badges: Vec<AssetRefUi>,
 	},
+	Moderation {
+		topic: String,
+		kind: String,
+		target_id: Option<SmolStr>,
+		target_login: Option<SmolStr>,
+		target_display: Option<SmolStr>,
+		notes: Option<String>,
+		action: ModerationActionUi,
+	},
+	UserNotice {
+		topic: String,
+		kind: String,
+		text: Option<String>,
+		user_login: Option<SmolStr>,
+		user_display: Option<SmolStr>,
+	},
 	CommandResult {
 		status: i32,
 		detail: String,
 	},
+	/// A page of older events for a topic, ordered oldest first with their server time.
+	ChatHistory {
+		topic: String,
+		events: Vec<(SystemTime, UiEvent)>,
+		next_before_cursor: u64,
+		error: Option<String>,
+	},
+	/// Results of a chat log search, newest first.
+	ChatLogSearch {
+		topic: String,
+		entries: Vec<ChatLogEntryUi>,
+		next_before_id: u64,
+		/// Whether this page continues an earlier search.
+		append: bool,
+		error: Option<String>,
+	},
+	/// A room's pending unban requests.
+	UnbanRequests {
+		topic: String,
+		requests: Vec<UnbanRequestUi>,
+		error: Option<String>,
+	},
+	/// The server's upstream connection for a topic's platform changed state.
+	AdapterStatus {
+		topic: String,
+		status: AdapterStatusUi,
+	},
 }
 
 impl fmt::Debug for UiEvent {
@@ -122,11 +340,20 @@ impl fmt::Debug for UiEvent {
 				can_ban,
 				is_moderator,
 				is_broadcaster,
+				can_manage_redemptions,
 			} => {
 				write!(
 					f,
-					"UiEvent::RoomPermissions {{ topic: {}, can_send: {}, can_reply: {}, can_delete: {}, can_timeout: {}, can_ban: {}, is_moderator: {}, is_broadcaster: {} }}",
-					topic, can_send, can_reply, can_delete, can_timeout, can_ban, is_moderator, is_broadcaster
+					"UiEvent::RoomPermissions {{ topic: {}, can_send: {}, can_reply: {}, can_delete: {}, can_timeout: {}, can_ban: {}, is_moderator: {}, is_broadcaster: {}, can_manage_redemptions: {} }}",
+					topic,
+					can_send,
+					can_reply,
+					can_delete,
+					can_timeout,
+					can_ban,
+					is_moderator,
+					is_broadcaster,
+					can_manage_redemptions
 				)
 			}
 			UiEvent::RoomState {
@@ -152,6 +379,29 @@ impl fmt::Debug for UiEvent {
 					followers_only_duration_minutes
 				)
 			}
+			UiEvent::StreamStatus {
+				topic,
+				live,
+				started_at,
+				title,
+				category,
+				viewer_count,
+			} => {
+				write!(
+					f,
+					"UiEvent::StreamStatus {{ topic: {}, live: {:?}, started_at: {:?}, title: {:?}, category: {:?}, viewer_count: {:?} }}",
+					topic, live, started_at, title, category, viewer_count
+				)
+			}
+			UiEvent::Poll { topic, poll } => {
+				write!(f, "UiEvent::Poll {{ topic: {}, poll: {:?} }}", topic, poll)
+			}
+			UiEvent::Progress { topic, progress } => {
+				write!(f, "UiEvent::Progress {{ topic: {}, progress: {:?} }}", topic, progress)
+			}
+			UiEvent::Redemption { topic, redemption } => {
+				write!(f, "UiEvent::Redemption {{ topic: {}, redemption: {:?} }}", topic, redemption)
+			}
 			UiEvent::AssetBundle { topic, cache_key, .. } => {
 				write!(
 					f,
@@ -159,9 +409,75 @@ impl fmt::Debug for UiEvent {
 					topic, cache_key
 				)
 			}
+			UiEvent::Moderation {
+				topic,
+				kind,
+				target_login,
+				action,
+				..
+			} => {
+				write!(
+					f,
+					"UiEvent::Moderation {{ topic: {}, kind: {}, target_login: {:?}, action: {:?}, ... }}",
+					topic, kind, target_login, action
+				)
+			}
+			UiEvent::UserNotice {
+				topic, kind, user_login, ..
+			} => {
+				write!(
+					f,
+					"UiEvent::UserNotice {{ topic: {}, kind: {}, user_login: {:?}, ... }}",
+					topic, kind, user_login
+				)
+			}
 			UiEvent::CommandResult { status, detail } => {
 				write!(f, "UiEvent::CommandResult {{ status: {}, detail: {} }}", status, detail)
 			}
+			UiEvent::ChatHistory {
+				topic,
+				events,
+				next_before_cursor,
+				error,
+			} => {
+				write!(
+					f,
+					"UiEvent::ChatHistory {{ topic: {}, events: {}, next_before_cursor: {}, error: {:?} }}",
+					topic,
+					events.len(),
+					next_before_cursor,
+					error
+				)
+			}
+			UiEvent::ChatLogSearch {
+				topic,
+				entries,
+				next_before_id,
+				append,
+				error,
+			} => {
+				write!(
+					f,
+					"UiEvent::ChatLogSearch {{ topic: {}, entries: {}, next_before_id: {}, append: {}, error: {:?} }}",
+					topic,
+					entries.len(),
+					next_before_id,
+					append,
+					error
+				)
+			}
+			UiEvent::UnbanRequests { topic, requests, error } => {
+				write!(
+					f,
+					"UiEvent::UnbanRequests {{ topic: {}, requests: {}, error: {:?} }}",
+					topic,
+					requests.len(),
+					error
+				)
+			}
+			UiEvent::AdapterStatus { topic, status } => {
+				write!(f, "UiEvent::AdapterStatus {{ topic: {}, status: {:?} }}", topic, status)
+			}
 		}
 	}
 }
@@ -179,9 +179,11 @@ pub fn build_client_config(settings: &GuiSettings) -> Result<ClientConfigV1, Str
 	if !token.is_empty() {
 		cfg.auth_token = Some(token.to_string());
 	}
+	cfg.tls_insecure_skip_verify = crate::net::dev_tls_insecure_skip_verify();
 
 	let mut twitch_identity = None;
 	let mut kick_identity = None;
+	let mut youtube_identity = None;
 	if let Some(active_id) = settings.active_identity.as_deref()
 		&& let Some(active) = settings
 			.identities
@@ -191,7 +193,7 @@ pub fn build_client_config(settings: &GuiSettings) -> Result<ClientConfigV1, Str
 		match active.platform {
 			Platform::Twitch => twitch_identity = Some(active),
 			Platform::Kick => kick_identity = Some(active),
-			_ => {}
+			Platform::YouTube => youtube_identity = Some(active),
 		}
 	}
 
@@ -211,7 +213,11 @@ pub fn build_client_config(settings: &GuiSettings) -> Result<ClientConfigV1, Str
 					kick_identity = Some(identity);
 				}
 			}
-			_ => {}
+			Platform::YouTube => {
+				if youtube_identity.is_none() {
+					youtube_identity = Some(identity);
+				}
+			}
 		}
 	}
 
@@ -264,6 +270,13 @@ pub fn build_client_config(settings: &GuiSettings) -> Result<ClientConfigV1, Str
 		}
 	}
 
+	if let Some(identity) = youtube_identity {
+		let token = identity.oauth_token.trim();
+		if !token.is_empty() {
+			cfg.youtube_user_oauth_token = Some(token.to_string());
+		}
+	}
+
 	Ok(cfg)
 }
 
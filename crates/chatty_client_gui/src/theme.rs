@@ -3,6 +3,9 @@
 use iced::Color;
 use serde::{Deserialize, Serialize};
 
+/// Live indicator color; the same in every theme, like the platforms' own badges.
+pub const LIVE_RED: Color = Color::from_rgb(0.9, 0.2, 0.2);
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ThemeKind {
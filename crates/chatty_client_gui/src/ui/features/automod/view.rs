@@ -0,0 +1,107 @@
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use iced::widget::{button, column, container, row, rule, scrollable, svg, text};
+use iced::{Alignment, Background, Border, Element, Length, Shadow};
+use rust_i18n::t;
+
+use crate::app::features::automod::{AutoModQueue, HeldMessage};
+use crate::app::message::{ChatMessage, Message};
+use crate::assets::svg_handle;
+use crate::theme;
+
+fn held_row<'a>(m: &'a HeldMessage, now: SystemTime, palette: theme::Palette) -> Element<'a, Message> {
+	let who = m
+		.user_display
+		.as_deref()
+		.or(m.user_login.as_deref())
+		.unwrap_or_default()
+		.to_string();
+	let waited = now.duration_since(m.held_at).map(|d| d.as_secs() / 60).unwrap_or(0);
+
+	let mut details = column![
+		row![
+			text(who).color(palette.chat_nick),
+			text(format!("{waited}m")).color(palette.text_dim),
+		]
+		.spacing(8),
+		text(m.text.as_str()).color(palette.text),
+	]
+	.spacing(4)
+	.width(Length::Fill);
+	if let Some(reason) = &m.reason {
+		details = details.push(text(format!("{} {reason}", t!("automod.reason"))).color(palette.text_dim));
+	}
+
+	let approve = button(text(t!("automod.approve"))).on_press(Message::Chat(ChatMessage::AutoModResolve(
+		m.room.clone(),
+		m.message_id.to_string(),
+		true,
+	)));
+	let deny = button(text(t!("automod.deny"))).on_press(Message::Chat(ChatMessage::AutoModResolve(
+		m.room.clone(),
+		m.message_id.to_string(),
+		false,
+	)));
+
+	container(row![details, approve, deny].spacing(8).align_y(Alignment::Center))
+		.padding(8)
+		.style(move |_theme| container::Style {
+			text_color: Some(palette.text),
+			background: Some(Background::Color(palette.panel_bg_2)),
+			border: Border {
+				color: palette.border,
+				width: 1.0,
+				radius: 8.0.into(),
+			},
+			shadow: Shadow::default(),
+			snap: false,
+		})
+		.into()
+}
+
+impl AutoModQueue {
+	pub fn view<'a>(&'a self, palette: theme::Palette) -> Element<'a, Message> {
+		let now = SystemTime::now();
+		let mut rows = column![].spacing(6);
+
+		if self.is_empty() {
+			rows = rows.push(text(t!("automod.empty")).color(palette.text_dim));
+		}
+		for room in self.rooms() {
+			rows = rows.push(text(room.to_string()).color(palette.text_dim));
+			for m in self.for_room(room) {
+				rows = rows.push(held_row(m, now, palette));
+			}
+		}
+
+		let header = row![
+			svg(svg_handle("shield.svg")).width(18).height(18),
+			text(format!("{} ({})", t!("automod.title"), self.len())).color(palette.text),
+		]
+		.spacing(10)
+		.align_y(Alignment::Center);
+
+		let body = column![header, rule::horizontal(1), scrollable(rows).height(Length::Fill)]
+			.spacing(10)
+			.padding(12);
+
+		container(body)
+			.width(Length::Fill)
+			.height(Length::Fill)
+			.style(move |_theme| container::Style {
+				text_color: Some(palette.text),
+				background: Some(Background::Color(palette.panel_bg)),
+				border: Border {
+					color: palette.border,
+					width: 1.0,
+					radius: 10.0.into(),
+				},
+				shadow: Shadow::default(),
+				snap: false,
+			})
+			.padding(12)
+			.into()
+	}
+}
@@ -1,11 +1,13 @@
-use iced::widget::{button, column, container, pane_grid, pick_list, row, rule, scrollable, svg, text, text_input};
+use iced::widget::{
+	button, column, container, pane_grid, pick_list, progress_bar, row, rule, scrollable, space, svg, text, text_input,
+};
 use iced::{Alignment, Background, Border, Color, Element, Length, Shadow};
 use rust_i18n::t;
 
 use super::message::ChatMessageView;
 use crate::app::features::chat::{ChatPane, ChatPaneMessage};
-use crate::app::message::Message;
-use crate::app::view_models::{ChatPaneLogItem, ChatPaneViewModel};
+use crate::app::message::{ChatMessage, Message};
+use crate::app::view_models::{ChatPaneLogItem, ChatPaneViewModel, PollInfo, ProgressInfo, RedemptionInfo};
 use crate::assets::svg_handle;
 use crate::theme::Palette;
 
@@ -20,7 +22,33 @@ impl ChatPane {
 		palette: Palette,
 	) -> pane_grid::Content<'a, Message> {
 		let title_color = if vm.is_focused { palette.text } else { palette.text_dim };
-		let title_bar = pane_grid::TitleBar::new(text(vm.title.clone()).color(title_color)).padding(6);
+		let mut title_row = row![text(vm.title.clone()).color(title_color)]
+			.spacing(12)
+			.align_y(Alignment::Center);
+		if !vm.progress.is_empty() {
+			title_row = title_row.push(space::horizontal());
+			for progress in &vm.progress {
+				title_row = title_row.push(view_progress(progress, palette));
+			}
+		}
+		let mut title_bar = pane_grid::TitleBar::new(title_row).padding(6);
+		if vm.can_edit_room_settings {
+			let settings_btn = button(
+				svg(svg_handle("settings.svg"))
+					.width(14)
+					.height(14)
+					.style(move |_, _| svg::Style {
+						color: Some(palette.text_dim),
+					}),
+			)
+			.on_press(Message::PaneMessage(vm.pane, ChatPaneMessage::OpenRoomSettings))
+			.padding(2)
+			.style(|_theme, _status| button::Style {
+				background: Some(Background::Color(Color::TRANSPARENT)),
+				..Default::default()
+			});
+			title_bar = title_bar.controls(pane_grid::Controls::new(settings_btn));
+		}
 
 		let body: Element<'a, Message> = if vm.is_subscribed {
 			self.view_subscribed_pane(vm, assets, palette)
@@ -218,14 +246,18 @@ impl ChatPane {
 			row![input_and_caret, send_btn].spacing(8).align_y(Alignment::Center)
 		};
 
+		let mut body = column![].spacing(8).padding(8);
+		for poll in vm.polls {
+			body = body.push(view_poll(poll, palette));
+		}
+		if !vm.redemptions.is_empty() {
+			body = body.push(view_redemption_queue(vm.redemptions, palette));
+		}
+		body = body.push(log).push(rule::horizontal(1));
 		if let Some(status) = status_bar {
-			column![log, rule::horizontal(1), status, composer]
-				.spacing(8)
-				.padding(8)
-				.into()
-		} else {
-			column![log, rule::horizontal(1), composer].spacing(8).padding(8).into()
+			body = body.push(status);
 		}
+		body.push(composer).into()
 	}
 
 	fn view_unsubscribed_pane<'a>(&'a self, vm: ChatPaneViewModel<'a>, palette: Palette) -> Element<'a, Message> {
@@ -257,3 +289,173 @@ impl ChatPane {
 		column![log, rule::horizontal(1), composer].spacing(8).padding(8).into()
 	}
 }
+
+fn view_poll<'a>(poll: PollInfo, palette: Palette) -> Element<'a, Message> {
+	let mut header = row![text(poll.status_label).size(12).color(palette.text_dim), space::horizontal(),]
+		.spacing(8)
+		.align_y(Alignment::Center);
+	if poll.can_end {
+		header = header.push(
+			button(text(t!("actions.end_poll")).size(12))
+				.on_press(Message::Chat(ChatMessage::EndPoll(poll.room.clone(), poll.id.clone())))
+				.padding([2, 6]),
+		);
+	}
+	if poll.can_resolve {
+		header = header.push(
+			button(text(t!("actions.cancel_prediction")).size(12))
+				.on_press(Message::Chat(ChatMessage::ResolvePrediction(
+					poll.room.clone(),
+					poll.id.clone(),
+					None,
+				)))
+				.padding([2, 6]),
+		);
+	}
+	if poll.ended {
+		header = header.push(
+			button(text(t!("actions.dismiss")).size(12))
+				.on_press(Message::Chat(ChatMessage::DismissPoll(poll.room.clone(), poll.id.clone())))
+				.padding([2, 6]),
+		);
+	}
+
+	let mut col = column![header, text(poll.title).color(palette.text)].spacing(4);
+	for choice in poll.choices {
+		let title_color = if choice.is_winner {
+			palette.accent_green
+		} else {
+			palette.text
+		};
+		let mut line = row![
+			text(choice.title).size(13).color(title_color),
+			space::horizontal(),
+			text(choice.tally_label).size(12).color(palette.text_dim),
+		]
+		.spacing(8)
+		.align_y(Alignment::Center);
+		if poll.can_resolve {
+			line = line.push(
+				button(text(t!("actions.resolve_prediction")).size(12))
+					.on_press(Message::Chat(ChatMessage::ResolvePrediction(
+						poll.room.clone(),
+						poll.id.clone(),
+						Some(choice.id),
+					)))
+					.padding([2, 6]),
+			);
+		}
+
+		let bar_color = if choice.is_winner {
+			palette.accent_green
+		} else {
+			palette.accent_blue
+		};
+		let bar = progress_bar(0.0..=1.0, choice.share)
+			.girth(4)
+			.style(move |_theme| progress_bar::Style {
+				background: Background::Color(palette.chat_bg),
+				bar: Background::Color(bar_color),
+				border: Border {
+					radius: 2.0.into(),
+					..Default::default()
+				},
+			});
+		col = col.push(column![line, bar].spacing(2));
+	}
+
+	container(col)
+		.padding([6, 8])
+		.width(Length::Fill)
+		.style(move |_theme| container::Style {
+			text_color: Some(palette.text),
+			background: Some(Background::Color(palette.panel_bg_2)),
+			border: Border {
+				color: palette.border,
+				width: 1.0,
+				radius: 4.0.into(),
+			},
+			shadow: Shadow::default(),
+			snap: false,
+		})
+		.into()
+}
+
+fn view_redemption_queue<'a>(redemptions: Vec<RedemptionInfo>, palette: Palette) -> Element<'a, Message> {
+	let header = text(format!("{} ({})", t!("main.redemption_queue"), redemptions.len()))
+		.size(12)
+		.color(palette.text_dim);
+
+	let mut list = column![].spacing(6);
+	for redemption in redemptions {
+		let mut line = row![
+			text(redemption.reward_title).size(13).color(palette.text),
+			space::horizontal(),
+			text(redemption.detail_label).size(12).color(palette.text_dim),
+		]
+		.spacing(8)
+		.align_y(Alignment::Center);
+		if redemption.can_manage {
+			line = line
+				.push(
+					button(text(t!("actions.fulfill_redemption")).size(12))
+						.on_press(Message::Chat(ChatMessage::UpdateRedemption(
+							redemption.room.clone(),
+							redemption.reward_id.clone(),
+							redemption.id.clone(),
+							true,
+						)))
+						.padding([2, 6]),
+				)
+				.push(
+					button(text(t!("actions.cancel_redemption")).size(12))
+						.on_press(Message::Chat(ChatMessage::UpdateRedemption(
+							redemption.room,
+							redemption.reward_id,
+							redemption.id,
+							false,
+						)))
+						.padding([2, 6]),
+				);
+		}
+
+		let mut entry = column![line].spacing(2);
+		if let Some(input) = redemption.user_input {
+			entry = entry.push(text(input).size(12).color(palette.text));
+		}
+		list = list.push(entry);
+	}
+
+	container(column![header, container(scrollable(list)).max_height(160)].spacing(4))
+		.padding([6, 8])
+		.width(Length::Fill)
+		.style(move |_theme| container::Style {
+			text_color: Some(palette.text),
+			background: Some(Background::Color(palette.panel_bg_2)),
+			border: Border {
+				color: palette.border,
+				width: 1.0,
+				radius: 4.0.into(),
+			},
+			shadow: Shadow::default(),
+			snap: false,
+		})
+		.into()
+}
+
+fn view_progress<'a>(progress: &ProgressInfo, palette: Palette) -> Element<'a, Message> {
+	let bar = progress_bar(0.0..=1.0, progress.share)
+		.length(140)
+		.girth(3)
+		.style(move |_theme| progress_bar::Style {
+			background: Background::Color(palette.panel_bg_2),
+			bar: Background::Color(palette.accent_blue),
+			border: Border {
+				radius: 2.0.into(),
+				..Default::default()
+			},
+		});
+	column![text(progress.label.clone()).size(11).color(palette.text_dim), bar]
+		.spacing(2)
+		.into()
+}
@@ -1,7 +1,9 @@
 #![forbid(unsafe_code)]
 
+pub mod automod;
 pub mod chat;
 pub mod overlays;
 pub mod settings;
 pub mod toaster;
+pub mod unban_requests;
 pub mod users;
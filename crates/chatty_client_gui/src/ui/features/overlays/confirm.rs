@@ -25,6 +25,32 @@ impl ConfirmModal {
 				t!("confirm.ban_desc").to_string(),
 				t!("actions.ban").to_string(),
 			),
+			ConfirmModalKind::ClearChat => (
+				t!("confirm.clear_chat_title").to_string(),
+				t!("confirm.clear_chat_desc").to_string(),
+				t!("actions.clear_chat").to_string(),
+			),
+			ConfirmModalKind::ClearUserMessages(_) => (
+				t!("confirm.clear_user_messages_title").to_string(),
+				t!("confirm.clear_user_messages_desc").to_string(),
+				t!("actions.clear_user_messages").to_string(),
+			),
+			ConfirmModalKind::ResolvePrediction {
+				winning_outcome_id: Some(_),
+				..
+			} => (
+				t!("confirm.resolve_prediction_title").to_string(),
+				t!("confirm.resolve_prediction_desc").to_string(),
+				t!("actions.resolve_prediction").to_string(),
+			),
+			ConfirmModalKind::ResolvePrediction {
+				winning_outcome_id: None,
+				..
+			} => (
+				t!("confirm.cancel_prediction_title").to_string(),
+				t!("confirm.cancel_prediction_desc").to_string(),
+				t!("actions.cancel_prediction").to_string(),
+			),
 		};
 
 		let confirm_btn = button(text(confirm_label))
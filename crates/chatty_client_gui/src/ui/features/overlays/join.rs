@@ -1,4 +1,6 @@
-use chatty_domain::Platform;
+use std::time::SystemTime;
+
+use chatty_domain::{Platform, RoomTopic};
 use iced::widget::{button, column, container, pick_list, row, rule, text, text_input};
 use iced::{Alignment, Background, Border, Element, Length, Shadow};
 use rust_i18n::t;
@@ -6,12 +8,41 @@ use rust_i18n::t;
 use super::overlay::wrap_overlay;
 use crate::app::features::overlays::{JoinModal, JoinModalMessage, OverlayMessage};
 use crate::app::message::Message;
+use crate::app::model::Chatty;
 use crate::theme;
 
 const PLATFORM_OPTIONS: [Platform; 2] = [Platform::Twitch, Platform::Kick];
 
+pub struct LiveRoomViewModel {
+	pub topic: String,
+	pub uptime: Option<String>,
+	pub title: Option<String>,
+}
+
+pub struct JoinModalViewModel {
+	/// Subscribed rooms that are currently live, by topic.
+	pub live_rooms: Vec<LiveRoomViewModel>,
+}
+
 impl JoinModal {
-	pub fn view<'a>(&'a self, palette: theme::Palette) -> Element<'a, Message> {
+	pub fn view_model(&self, app: &Chatty) -> JoinModalViewModel {
+		let now = SystemTime::now();
+		let mut live_rooms: Vec<LiveRoomViewModel> = app
+			.state
+			.stream_statuses
+			.iter()
+			.filter(|(_, status)| status.live)
+			.map(|(room, status)| LiveRoomViewModel {
+				topic: RoomTopic::format(room),
+				uptime: status.uptime_label(now),
+				title: status.title.clone().filter(|t| !t.is_empty()),
+			})
+			.collect();
+		live_rooms.sort_by(|a, b| a.topic.cmp(&b.topic));
+		JoinModalViewModel { live_rooms }
+	}
+
+	pub fn view<'a>(&'a self, vm: JoinModalViewModel, palette: theme::Palette) -> Element<'a, Message> {
 		let input = text_input(&t!("main.join_room_placeholder"), &self.input)
 			.on_input(|v| Message::OverlayMessage(OverlayMessage::Join(JoinModalMessage::InputChanged(v))));
 
@@ -24,7 +55,7 @@ impl JoinModal {
 		let cancel_btn = button(text(t!("cancel_label")))
 			.on_press(Message::OverlayMessage(OverlayMessage::Join(JoinModalMessage::Cancel)));
 
-		let body_col = column![
+		let mut body_col = column![
 			text(t!("main.join_room_title")).color(palette.text),
 			rule::horizontal(1),
 			row![text(t!("settings.default_platform")).color(palette.text_dim), platform_picker]
@@ -36,6 +67,23 @@ impl JoinModal {
 		.spacing(12)
 		.padding(12);
 
+		if !vm.live_rooms.is_empty() {
+			let mut live_col = column![text(t!("main.stream_live_now")).color(palette.text_dim)].spacing(4);
+			for room in vm.live_rooms {
+				let mut line = row![text("●").color(theme::LIVE_RED), text(room.topic).color(palette.text)]
+					.spacing(6)
+					.align_y(Alignment::Center);
+				if let Some(uptime) = room.uptime {
+					line = line.push(text(uptime).color(palette.text_dim));
+				}
+				if let Some(title) = room.title {
+					line = line.push(text(title).color(palette.text_muted));
+				}
+				live_col = live_col.push(line);
+			}
+			body_col = body_col.push(rule::horizontal(1)).push(live_col);
+		}
+
 		let content = container(body_col)
 			.width(Length::Shrink)
 			.height(Length::Shrink)
@@ -12,6 +12,10 @@ pub struct MessageActionViewModel {
 	pub can_delete: bool,
 	pub can_timeout: bool,
 	pub can_ban: bool,
+	pub can_reverse: bool,
+	pub can_clear_user: bool,
+	pub can_clear_chat: bool,
+	pub can_search: bool,
 	pub cursor_pos: Option<(f32, f32)>,
 }
 
@@ -22,6 +26,10 @@ impl MessageActionMenu {
 			can_delete: true,
 			can_timeout: true,
 			can_ban: true,
+			can_reverse: self.author_id.is_some(),
+			can_clear_user: self.author_id.is_some(),
+			can_clear_chat: true,
+			can_search: self.author_id.is_some(),
 			cursor_pos: self.cursor_pos,
 		}
 	}
@@ -49,6 +57,29 @@ impl MessageActionMenu {
 				OverlayMessage::MessageAction(MessageActionMenuMessage::Ban),
 			)));
 		}
+		if vm.can_reverse {
+			items = items.push(button(text(t!("actions.untimeout"))).on_press(Message::OverlayMessage(
+				OverlayMessage::MessageAction(MessageActionMenuMessage::Untimeout),
+			)));
+			items = items.push(button(text(t!("actions.unban"))).on_press(Message::OverlayMessage(
+				OverlayMessage::MessageAction(MessageActionMenuMessage::Unban),
+			)));
+		}
+		if vm.can_clear_user {
+			items = items.push(button(text(t!("actions.clear_user_messages"))).on_press(Message::OverlayMessage(
+				OverlayMessage::MessageAction(MessageActionMenuMessage::ClearUserMessages),
+			)));
+		}
+		if vm.can_clear_chat {
+			items = items.push(button(text(t!("actions.clear_chat"))).on_press(Message::OverlayMessage(
+				OverlayMessage::MessageAction(MessageActionMenuMessage::ClearChat),
+			)));
+		}
+		if vm.can_search {
+			items = items.push(button(text(t!("actions.search_messages"))).on_press(Message::OverlayMessage(
+				OverlayMessage::MessageAction(MessageActionMenuMessage::SearchUser),
+			)));
+		}
 
 		let container_el = container(items.padding(8)).style(move |_theme| container::Style {
 			text_color: Some(palette.text),
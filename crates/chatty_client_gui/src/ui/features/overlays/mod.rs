@@ -10,14 +10,18 @@ pub mod join;
 pub mod layout;
 pub mod message_action;
 pub mod overlay;
+pub mod room_settings;
+pub mod search;
 
 impl ActiveOverlay {
 	pub fn view<'a>(&'a self, app: &'a Chatty, palette: theme::Palette) -> Element<'a, Message> {
 		match self {
-			ActiveOverlay::Join(modal) => modal.view(palette),
+			ActiveOverlay::Join(modal) => modal.view(modal.view_model(app), palette),
 			ActiveOverlay::Layout(modal) => modal.view(palette),
 			ActiveOverlay::MessageAction(modal) => modal.view(modal.view_model(app), palette),
 			ActiveOverlay::Confirm(modal) => modal.view(palette),
+			ActiveOverlay::ChatSearch(modal) => modal.view(palette),
+			ActiveOverlay::RoomSettings(modal) => modal.view(palette),
 		}
 	}
 }
@@ -0,0 +1,112 @@
+use iced::widget::{button, checkbox, column, container, pick_list, row, rule, text, text_input};
+use iced::{Alignment, Background, Border, Element, Length, Shadow};
+use rust_i18n::t;
+
+use super::overlay::wrap_overlay_at;
+use crate::app::features::overlays::{OverlayMessage, RoomSettingsModal, RoomSettingsModalMessage};
+use crate::app::message::Message;
+use crate::theme;
+
+fn settings_msg(msg: RoomSettingsModalMessage) -> Message {
+	Message::OverlayMessage(OverlayMessage::RoomSettings(msg))
+}
+
+fn toggle_row<'a>(
+	label: String,
+	value: bool,
+	on_toggle: fn(bool) -> RoomSettingsModalMessage,
+	palette: theme::Palette,
+) -> Element<'a, Message> {
+	row![
+		text(label).color(palette.text_dim).width(Length::Fill),
+		checkbox(value).on_toggle(move |v| settings_msg(on_toggle(v))),
+	]
+	.spacing(12)
+	.align_y(Alignment::Center)
+	.into()
+}
+
+impl RoomSettingsModal {
+	pub fn view<'a>(&'a self, palette: theme::Palette) -> Element<'a, Message> {
+		let draft = &self.draft;
+		let mut body_col = column![text(t!("room_settings.title")).color(palette.text)].spacing(10);
+
+		if self.rooms.len() > 1 {
+			body_col = body_col.push(pick_list(self.rooms.as_slice(), Some(&self.room), |r| {
+				settings_msg(RoomSettingsModalMessage::RoomSelected(r))
+			}));
+		} else {
+			body_col = body_col.push(text(self.room.to_string()).color(palette.text_dim));
+		}
+
+		let mut slow_wait = text_input(&t!("room_settings.seconds"), &draft.slow_mode_wait).width(Length::Fixed(80.0));
+		if draft.slow_mode {
+			slow_wait = slow_wait.on_input(|v| settings_msg(RoomSettingsModalMessage::SlowModeWaitChanged(v)));
+		}
+		let mut followers_duration =
+			text_input(&t!("room_settings.minutes"), &draft.followers_duration).width(Length::Fixed(80.0));
+		if draft.followers_only {
+			followers_duration =
+				followers_duration.on_input(|v| settings_msg(RoomSettingsModalMessage::FollowersDurationChanged(v)));
+		}
+
+		body_col = body_col
+			.push(rule::horizontal(1))
+			.push(toggle_row(
+				t!("room_settings.emote_only").to_string(),
+				draft.emote_only,
+				RoomSettingsModalMessage::EmoteOnlyToggled,
+				palette,
+			))
+			.push(toggle_row(
+				t!("room_settings.subscribers_only").to_string(),
+				draft.subscribers_only,
+				RoomSettingsModalMessage::SubscribersOnlyToggled,
+				palette,
+			))
+			.push(toggle_row(
+				t!("room_settings.unique_chat").to_string(),
+				draft.unique_chat,
+				RoomSettingsModalMessage::UniqueChatToggled,
+				palette,
+			))
+			.push(toggle_row(
+				t!("room_settings.slow_mode").to_string(),
+				draft.slow_mode,
+				RoomSettingsModalMessage::SlowModeToggled,
+				palette,
+			))
+			.push(slow_wait)
+			.push(toggle_row(
+				t!("room_settings.followers_only").to_string(),
+				draft.followers_only,
+				RoomSettingsModalMessage::FollowersOnlyToggled,
+				palette,
+			))
+			.push(followers_duration)
+			.push(
+				row![
+					button(text(t!("room_settings.apply"))).on_press(settings_msg(RoomSettingsModalMessage::Apply)),
+					button(text(t!("cancel_label"))).on_press(settings_msg(RoomSettingsModalMessage::Cancel)),
+				]
+				.spacing(8),
+			);
+
+		let content = container(body_col.padding(12).width(Length::Fixed(260.0)))
+			.width(Length::Shrink)
+			.height(Length::Shrink)
+			.style(move |_theme| container::Style {
+				text_color: Some(palette.text),
+				background: Some(Background::Color(palette.panel_bg)),
+				border: Border {
+					color: palette.border,
+					width: 1.0,
+					radius: 8.0.into(),
+				},
+				shadow: Shadow::default(),
+				snap: false,
+			});
+
+		wrap_overlay_at(content.into(), palette, self.cursor_pos)
+	}
+}
@@ -0,0 +1,134 @@
+use std::time::SystemTime;
+
+use iced::widget::{button, column, container, pick_list, row, rule, scrollable, text, text_input};
+use iced::{Alignment, Background, Border, Element, Length, Shadow};
+use rust_i18n::t;
+
+use super::overlay::wrap_overlay;
+use crate::app::features::overlays::{ChatSearchModal, ChatSearchModalMessage, OverlayMessage, SearchRange};
+use crate::app::message::Message;
+use crate::net::ChatLogEntryUi;
+use crate::theme;
+
+fn search_msg(msg: ChatSearchModalMessage) -> Message {
+	Message::OverlayMessage(OverlayMessage::ChatSearch(msg))
+}
+
+pub(crate) fn age_label(now: SystemTime, time: SystemTime) -> String {
+	let secs = now.duration_since(time).map(|d| d.as_secs()).unwrap_or(0);
+	match secs {
+		0..60 => format!("{secs}s"),
+		60..3600 => format!("{}m", secs / 60),
+		3600..86_400 => format!("{}h", secs / 3600),
+		_ => format!("{}d", secs / 86_400),
+	}
+}
+
+pub(crate) fn entry_row<'a>(entry: &'a ChatLogEntryUi, now: SystemTime, palette: theme::Palette) -> Element<'a, Message> {
+	let who = entry
+		.user_display
+		.as_deref()
+		.or(entry.user_login.as_deref())
+		.unwrap_or_default()
+		.to_string();
+	let age = text(age_label(now, entry.time))
+		.color(palette.text_dim)
+		.width(Length::Fixed(40.0));
+
+	let body = if entry.is_moderation {
+		let action = entry.action.as_deref().unwrap_or_default();
+		let by = entry
+			.actor_login
+			.as_deref()
+			.map(|actor| format!(" ({} {actor})", t!("search.by")))
+			.unwrap_or_default();
+		let detail = if entry.text.is_empty() {
+			String::new()
+		} else {
+			format!(": {}", entry.text)
+		};
+		text(format!("[{action}] {who}{by}{detail}")).color(palette.text_dim)
+	} else {
+		text(format!("{who}: {}", entry.text)).color(palette.text)
+	};
+
+	row![age, body.width(Length::Fill)]
+		.spacing(8)
+		.align_y(Alignment::Start)
+		.into()
+}
+
+impl ChatSearchModal {
+	pub fn view<'a>(&'a self, palette: theme::Palette) -> Element<'a, Message> {
+		let user_input = text_input(&t!("search.user_placeholder"), &self.user)
+			.on_input(|v| search_msg(ChatSearchModalMessage::UserChanged(v)))
+			.on_submit(search_msg(ChatSearchModalMessage::Submit));
+		let text_input_el = text_input(&t!("search.text_placeholder"), &self.text)
+			.on_input(|v| search_msg(ChatSearchModalMessage::TextChanged(v)))
+			.on_submit(search_msg(ChatSearchModalMessage::Submit));
+		let range_picker = pick_list(&SearchRange::ALL[..], Some(self.range), |r| {
+			search_msg(ChatSearchModalMessage::RangeSelected(r))
+		});
+
+		let mut search_btn = button(text(t!("search.search_button")));
+		if !self.loading {
+			search_btn = search_btn.on_press(search_msg(ChatSearchModalMessage::Submit));
+		}
+		let close_btn = button(text(t!("cancel_label"))).on_press(search_msg(ChatSearchModalMessage::Cancel));
+
+		let now = SystemTime::now();
+		let mut results = column![].spacing(6);
+		for entry in &self.results {
+			results = results.push(entry_row(entry, now, palette));
+		}
+		if self.next_before_id != 0 {
+			let mut more_btn = button(text(t!("search.load_more")));
+			if !self.loading {
+				more_btn = more_btn.on_press(search_msg(ChatSearchModalMessage::LoadMore));
+			}
+			results = results.push(more_btn);
+		}
+
+		let status: Element<'a, Message> = if let Some(e) = &self.error {
+			text(format!("{}: {e}", t!("search.failed"))).color(palette.text_dim).into()
+		} else if self.loading {
+			text(t!("search.searching")).color(palette.text_dim).into()
+		} else if self.searched && self.results.is_empty() {
+			text(t!("search.no_results")).color(palette.text_dim).into()
+		} else {
+			column![].into()
+		};
+
+		let body_col = column![
+			text(format!("{} — {}", t!("search.title"), self.room.room_id.as_str())).color(palette.text),
+			rule::horizontal(1),
+			user_input,
+			text_input_el,
+			row![range_picker, search_btn, close_btn]
+				.spacing(8)
+				.align_y(Alignment::Center),
+			status,
+			scrollable(results).height(Length::Fixed(360.0)),
+		]
+		.spacing(12)
+		.padding(12)
+		.width(Length::Fixed(560.0));
+
+		let content = container(body_col)
+			.width(Length::Shrink)
+			.height(Length::Shrink)
+			.style(move |_theme| container::Style {
+				text_color: Some(palette.text),
+				background: Some(Background::Color(palette.panel_bg)),
+				border: Border {
+					color: palette.border,
+					width: 1.0,
+					radius: 10.0.into(),
+				},
+				shadow: Shadow::default(),
+				snap: false,
+			});
+
+		wrap_overlay(content.into(), palette)
+	}
+}
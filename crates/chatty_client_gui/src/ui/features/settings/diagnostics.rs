@@ -1,10 +1,32 @@
+use std::time::SystemTime;
+
 use iced::Element;
 use iced::widget::{column, row, rule, scrollable, text};
 use rust_i18n::t;
 
 use crate::app::message::Message;
 use crate::app::model::Chatty;
+use crate::net::AdapterStatusUi;
 use crate::theme;
+use crate::ui::features::overlays::search::age_label;
+
+fn adapter_status_label(status: &AdapterStatusUi, now: SystemTime) -> String {
+	let state = if status.connected {
+		t!("settings.adapter_connected")
+	} else {
+		t!("settings.adapter_disconnected")
+	};
+	let mut label = format!(
+		"{} {}: {state} ({})",
+		age_label(now, status.time),
+		status.platform,
+		status.detail
+	);
+	if let Some(e) = &status.last_error {
+		label.push_str(&format!(" - {e}"));
+	}
+	label
+}
 
 pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 	let status_text = match &app.state.connection {
@@ -48,6 +70,27 @@ pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 		notifs = notifs.push(text(format!("{:?}: {}", n.kind, n.message)).color(palette.text_dim));
 	}
 
+	let now = SystemTime::now();
+	let mut adapter_rooms: Vec<_> = app.state.adapter_status.iter().collect();
+	adapter_rooms.sort_by_key(|(room, _)| room.to_string());
+	let mut adapter_rows = column![].spacing(4);
+	if adapter_rooms.is_empty() {
+		adapter_rows = adapter_rows.push(text(t!("settings.no_adapter_status")).color(palette.text_dim));
+	}
+	for (room, status) in adapter_rooms {
+		let color = if status.connected {
+			palette.text_dim
+		} else {
+			palette.warning_text
+		};
+		adapter_rows = adapter_rows.push(text(format!("{room} → {}", adapter_status_label(status, now))).color(color));
+	}
+
+	let mut adapter_history = column![].spacing(4);
+	for status in app.state.adapter_status_history.iter().rev() {
+		adapter_history = adapter_history.push(text(adapter_status_label(status, now)).color(palette.text_dim));
+	}
+
 	let seventv_counts = app.state.asset_catalog.room_provider_asset_counts(3);
 	let mut seventv_rows = column![].spacing(4);
 	if seventv_counts.is_empty() {
@@ -84,6 +127,11 @@ pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 				text(last_error.unwrap_or_else(|| "(none)".to_string())).color(palette.text_dim)
 			],
 			rule::horizontal(1),
+			text(t!("settings.adapter_status")).color(palette.text_dim),
+			adapter_rows,
+			text(t!("settings.adapter_status_history")).color(palette.text_dim),
+			adapter_history,
+			rule::horizontal(1),
 			text(t!("settings.recent_notifications")).color(palette.text_dim),
 			notifs,
 			rule::horizontal(1),
@@ -0,0 +1,170 @@
+#![forbid(unsafe_code)]
+
+use std::time::SystemTime;
+
+use iced::widget::{button, column, container, pick_list, row, rule, scrollable, svg, text, text_input};
+use iced::{Alignment, Background, Border, Element, Length, Shadow};
+use rust_i18n::t;
+
+use crate::app::features::unban_requests::{UnbanRequestsView, UnbanRequestsViewMessage};
+use crate::app::message::Message;
+use crate::assets::svg_handle;
+use crate::net::UnbanRequestUi;
+use crate::theme;
+use crate::ui::features::overlays::search::{age_label, entry_row};
+
+fn unban_msg(msg: UnbanRequestsViewMessage) -> Message {
+	Message::UnbanRequestsViewMessage(msg)
+}
+
+fn card<'a>(content: impl Into<Element<'a, Message>>, palette: theme::Palette) -> container::Container<'a, Message> {
+	container(content).padding(8).style(move |_theme| container::Style {
+		text_color: Some(palette.text),
+		background: Some(Background::Color(palette.panel_bg_2)),
+		border: Border {
+			color: palette.border,
+			width: 1.0,
+			radius: 8.0.into(),
+		},
+		shadow: Shadow::default(),
+		snap: false,
+	})
+}
+
+fn requester(r: &UnbanRequestUi) -> &str {
+	r.user_display.as_deref().unwrap_or(r.user_login.as_str())
+}
+
+impl UnbanRequestsView {
+	fn request_list<'a>(&'a self, now: SystemTime, palette: theme::Palette) -> Element<'a, Message> {
+		let mut list = column![].spacing(6);
+		for r in &self.requests {
+			let age = r.created_at.map(|at| age_label(now, at)).unwrap_or_default();
+			let label = row![
+				text(requester(r)).color(palette.chat_nick).width(Length::Fill),
+				text(age).color(palette.text_dim),
+			]
+			.spacing(8);
+			let is_selected = self.selected.as_ref() == Some(&r.request_id);
+			let item = button(label)
+				.width(Length::Fill)
+				.padding(8)
+				.on_press(unban_msg(UnbanRequestsViewMessage::RequestSelected(r.request_id.clone())))
+				.style(move |_theme, _status| button::Style {
+					background: Some(Background::Color(palette.panel_bg_2)),
+					text_color: palette.text,
+					border: Border {
+						color: if is_selected { palette.accent_blue } else { palette.border },
+						width: 1.0,
+						radius: 8.0.into(),
+					},
+					..Default::default()
+				});
+			list = list.push(item);
+		}
+		if self.requests.is_empty() && !self.loading {
+			list = list.push(text(t!("unban_requests.empty")).color(palette.text_dim));
+		}
+		scrollable(list).height(Length::Fill).into()
+	}
+
+	fn details<'a>(&'a self, now: SystemTime, palette: theme::Palette) -> Element<'a, Message> {
+		let Some(r) = self.selected_request() else {
+			return text(t!("unban_requests.select_hint")).color(palette.text_dim).into();
+		};
+
+		let appeal = if r.text.is_empty() {
+			t!("unban_requests.no_text").to_string()
+		} else {
+			r.text.clone()
+		};
+
+		let mut history = column![].spacing(6);
+		if self.history_loading {
+			history = history.push(text(t!("search.searching")).color(palette.text_dim));
+		} else if self.history.is_empty() {
+			history = history.push(text(t!("unban_requests.no_history")).color(palette.text_dim));
+		}
+		for entry in &self.history {
+			history = history.push(entry_row(entry, now, palette));
+		}
+
+		let resolution = text_input(&t!("unban_requests.resolution_placeholder"), &self.resolution_text)
+			.on_input(|v| unban_msg(UnbanRequestsViewMessage::ResolutionChanged(v)));
+
+		column![
+			text(format!("{} ({})", requester(r), r.user_login)).color(palette.text),
+			card(text(appeal).color(palette.text), palette).width(Length::Fill),
+			text(t!("unban_requests.history")).color(palette.text_dim),
+			scrollable(history).height(Length::Fill),
+			rule::horizontal(1),
+			resolution,
+			row![
+				button(text(t!("unban_requests.approve"))).on_press(unban_msg(UnbanRequestsViewMessage::Resolve(true))),
+				button(text(t!("unban_requests.deny"))).on_press(unban_msg(UnbanRequestsViewMessage::Resolve(false))),
+			]
+			.spacing(8),
+		]
+		.spacing(10)
+		.into()
+	}
+
+	pub fn view<'a>(&'a self, palette: theme::Palette) -> Element<'a, Message> {
+		let now = SystemTime::now();
+
+		let mut header = row![
+			svg(svg_handle("gavel.svg")).width(18).height(18),
+			text(format!("{} ({})", t!("unban_requests.title"), self.requests.len()))
+				.color(palette.text)
+				.width(Length::Fill),
+		]
+		.spacing(10)
+		.align_y(Alignment::Center);
+		if self.rooms.len() > 1 {
+			header = header.push(pick_list(self.rooms.as_slice(), self.room.as_ref(), |r| {
+				unban_msg(UnbanRequestsViewMessage::RoomSelected(r))
+			}));
+		}
+		let mut refresh = button(svg(svg_handle("refresh.svg")).width(16).height(16));
+		if !self.loading {
+			refresh = refresh.on_press(unban_msg(UnbanRequestsViewMessage::Refresh));
+		}
+		header = header.push(refresh);
+
+		let content: Element<'a, Message> = if self.room.is_none() {
+			text(t!("unban_requests.no_rooms")).color(palette.text_dim).into()
+		} else {
+			row![
+				container(self.request_list(now, palette)).width(Length::FillPortion(1)),
+				rule::vertical(1),
+				container(self.details(now, palette)).width(Length::FillPortion(2)),
+			]
+			.spacing(10)
+			.height(Length::Fill)
+			.into()
+		};
+
+		let mut body = column![header, rule::horizontal(1)].spacing(10).padding(12);
+		if let Some(e) = &self.error {
+			body = body.push(text(format!("{}: {e}", t!("unban_requests.failed"))).color(palette.warning_text));
+		}
+		body = body.push(content);
+
+		container(body)
+			.width(Length::Fill)
+			.height(Length::Fill)
+			.style(move |_theme| container::Style {
+				text_color: Some(palette.text),
+				background: Some(Background::Color(palette.panel_bg)),
+				border: Border {
+					color: palette.border,
+					width: 1.0,
+					radius: 10.0.into(),
+				},
+				shadow: Shadow::default(),
+				snap: false,
+			})
+			.padding(12)
+			.into()
+	}
+}
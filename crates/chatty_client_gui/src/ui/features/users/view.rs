@@ -1,11 +1,14 @@
 #![forbid(unsafe_code)]
 
-use iced::widget::{column, container, row, rule, scrollable, svg, text, text_input};
+use std::collections::HashMap;
+
+use iced::widget::{button, column, container, row, rule, scrollable, svg, text, text_input};
 use iced::{Alignment, Background, Border, Element, Length, Shadow};
 use rust_i18n::t;
 
+use crate::app::features::tabs::ChatItem;
 use crate::app::features::users::{UsersView, UsersViewMessage};
-use crate::app::message::Message;
+use crate::app::message::{ChatMessage, Message};
 use crate::app::model::Chatty;
 use crate::assets::svg_handle;
 use crate::theme;
@@ -23,6 +26,16 @@ impl UsersView {
 			let mut users: Vec<(smol_str::SmolStr, usize)> = tab.user_counts.iter().map(|(k, v)| (k.clone(), *v)).collect();
 			users.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
+			// Moderation actions need the room and platform user id of each login's latest message.
+			let mut targets: HashMap<&str, (&chatty_domain::RoomKey, &str)> = HashMap::new();
+			for item in tab.log.items.iter() {
+				if let ChatItem::ChatMessage(m) = item
+					&& let Some(author_id) = m.author_id.as_deref()
+				{
+					targets.insert(m.user_login.as_str(), (&m.room, author_id));
+				}
+			}
+
 			let mut any = false;
 			for (user, count) in users {
 				if !filter.is_empty() && !user.as_str().to_ascii_lowercase().contains(&filter) {
@@ -35,15 +48,32 @@ impl UsersView {
 					t!("users.messages_other").to_string()
 				};
 
+				let mut user_row = row![
+					text(user.to_string()).color(palette.text).width(Length::Fill),
+					text(format!("{count} {count_label}")).color(palette.text_dim),
+				]
+				.align_y(Alignment::Center)
+				.spacing(12);
+				if let Some((room, user_id)) = targets.get(user.as_str()) {
+					let room = (*room).clone();
+					let user_id = user_id.to_string();
+					user_row = user_row
+						.push(button(text(t!("actions.untimeout"))).on_press(Message::Chat(ChatMessage::UntimeoutUser(
+							room.clone(),
+							user_id.clone(),
+						))))
+						.push(
+							button(text(t!("actions.unban")))
+								.on_press(Message::Chat(ChatMessage::UnbanUser(room.clone(), user_id.clone()))),
+						)
+						.push(
+							button(text(t!("actions.clear_user_messages")))
+								.on_press(Message::Chat(ChatMessage::ClearUserMessages(room, user_id))),
+						);
+				}
+
 				rows = rows.push(
-					container(
-						row![
-							text(user.to_string()).color(palette.text).width(Length::Fill),
-							text(format!("{count} {count_label}")).color(palette.text_dim),
-						]
-						.align_y(Alignment::Center)
-						.spacing(12),
-					)
+					container(user_row)
 					.padding(8)
 					.style(move |_theme| container::Style {
 						text_color: Some(palette.text),
@@ -1,5 +1,7 @@
 #![forbid(unsafe_code)]
 
+use std::time::SystemTime;
+
 use iced::widget::{button, column, container, row, svg, text};
 use iced::{Alignment, Background, Border, Element, Length};
 use rust_i18n::t;
@@ -17,6 +19,8 @@ struct TabBarItemViewModel {
 	title: String,
 	is_selected: bool,
 	pinned: bool,
+	/// `Some` while a room of the tab is live, with its uptime when known.
+	live: Option<Option<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -85,7 +89,14 @@ pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 			};
 
 			let title = item.title;
-			let mut c = row![text(title).color(tab_color)].align_y(Alignment::Center).spacing(8);
+			let mut c = row![].align_y(Alignment::Center).spacing(8);
+			if item.live.is_some() {
+				c = c.push(text("●").color(theme::LIVE_RED));
+			}
+			c = c.push(text(title).color(tab_color));
+			if let Some(Some(uptime)) = item.live {
+				c = c.push(text(uptime).color(palette.text_dim));
+			}
 
 			if !item.pinned {
 				let icon_style = move |_theme: &iced::Theme, _status| svg::Style {
@@ -140,6 +151,7 @@ pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 }
 
 fn build_main_view_model(app: &Chatty) -> MainViewModel {
+	let now = SystemTime::now();
 	let items = app
 		.state
 		.tab_order
@@ -150,6 +162,13 @@ fn build_main_view_model(app: &Chatty) -> MainViewModel {
 			title: tab.title.clone(),
 			is_selected: Some(*tid) == app.state.selected_tab_id,
 			pinned: tab.pinned,
+			live: tab
+				.target
+				.0
+				.iter()
+				.filter_map(|room| app.state.stream_statuses.get(room))
+				.find(|status| status.live)
+				.map(|status| status.uptime_label(now)),
 		})
 		.collect();
 
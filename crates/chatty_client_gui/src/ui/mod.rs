@@ -30,6 +30,8 @@ pub fn view(app: &Chatty, window: iced::window::Id) -> Element<'_, Message> {
 		Page::Main => main_view::view(app, palette),
 		Page::Settings => app.state.ui.settings_view.view(app, palette),
 		Page::Users => app.state.ui.users_view.view(app, palette),
+		Page::AutoMod => app.state.automod_queue.view(palette),
+		Page::UnbanRequests => app.state.ui.unban_requests_view.view(palette),
 	};
 
 	let root = column![
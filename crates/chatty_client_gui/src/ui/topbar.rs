@@ -4,6 +4,7 @@ use iced::widget::{button, container, row, rule, space, svg, text};
 use iced::{Alignment, Background, Border, Element, Length, Shadow};
 use rust_i18n::t;
 
+use crate::app::features::unban_requests::UnbanRequestsViewMessage;
 use crate::app::message::Message;
 use crate::app::model::Chatty;
 use crate::app::state::ConnectionStatus;
@@ -110,6 +111,29 @@ pub fn view(app: &Chatty, palette: theme::Palette) -> Element<'_, Message> {
 			"close.svg",
 			Message::Layout(crate::app::message::LayoutMessage::CloseFocused),
 		))
+		.push(icon_button("search.svg", Message::OpenChatSearch));
+	if !app.state.automod_queue.is_empty() {
+		right = right.push(
+			row![
+				icon_button("shield.svg", Message::Navigate(Page::AutoMod)),
+				text(app.state.automod_queue.len().to_string()).color(palette.warning_text),
+			]
+			.spacing(4)
+			.align_y(Alignment::Center),
+		);
+	}
+	if app
+		.state
+		.room_permissions
+		.values()
+		.any(|p| p.is_moderator || p.is_broadcaster)
+	{
+		right = right.push(icon_button(
+			"gavel.svg",
+			Message::UnbanRequestsViewMessage(UnbanRequestsViewMessage::Open),
+		));
+	}
+	right = right
 		.push(icon_button("users.svg", Message::Navigate(Page::Users)))
 		.push(icon_button("settings.svg", Message::Navigate(Page::Settings)))
 		.push(rule::vertical(1))
@@ -10,9 +10,9 @@ use serde::Deserialize;
 use tracing::{info, warn};
 
 use super::common::{CachedBundle, compute_bundle_etag, prune_map_cache, prune_optional_cache};
+use super::config::{AssetProviderConfig, AssetProviderSettings};
 use crate::{AssetBundle, AssetImage, AssetProvider, AssetRef, AssetScale, AssetScope};
 
-const BTTV_BASE_URL: &str = "https://api.betterttv.net/3";
 const BTTV_EMOTES_TTL: Duration = Duration::from_secs(300);
 const BTTV_BADGES_TTL: Duration = Duration::from_secs(600);
 const BTTV_GLOBAL_EMOTES_TTL: Duration = Duration::from_secs(600);
@@ -21,12 +21,14 @@ static BTTV_EMOTES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceL
 static BTTV_BADGES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceLock::new();
 static BTTV_GLOBAL_EMOTES_CACHE: OnceLock<Mutex<Option<CachedBundle>>> = OnceLock::new();
 
-pub async fn fetch_bttv_bundle(provider: &str, provider_id: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_bttv_emotes(provider, provider_id) {
+pub async fn fetch_bttv_bundle(cfg: &AssetProviderConfig, provider: &str, provider_id: &str) -> anyhow::Result<AssetBundle> {
+	cfg.bttv.ensure_enabled("bttv")?;
+
+	if let Some(bundle) = get_cached_bttv_emotes(&cfg.bttv, provider, provider_id) {
 		return Ok(bundle);
 	}
 
-	let url = format!("{BTTV_BASE_URL}/cached/users/{provider}/{provider_id}");
+	let url = cfg.bttv.url(&format!("/cached/users/{provider}/{provider_id}"));
 	let resp = reqwest::Client::new()
 		.get(url)
 		.send()
@@ -64,12 +66,14 @@ pub async fn fetch_bttv_bundle(provider: &str, provider_id: &str) -> anyhow::Res
 	Ok(bundle)
 }
 
-pub async fn fetch_bttv_global_emotes_bundle() -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_bttv_global_emotes() {
+pub async fn fetch_bttv_global_emotes_bundle(cfg: &AssetProviderConfig) -> anyhow::Result<AssetBundle> {
+	cfg.bttv.ensure_enabled("bttv")?;
+
+	if let Some(bundle) = get_cached_bttv_global_emotes(&cfg.bttv) {
 		return Ok(bundle);
 	}
 
-	let url = format!("{BTTV_BASE_URL}/cached/emotes/global");
+	let url = cfg.bttv.url("/cached/emotes/global");
 	let resp = reqwest::Client::new()
 		.get(url)
 		.send()
@@ -101,12 +105,14 @@ pub async fn fetch_bttv_global_emotes_bundle() -> anyhow::Result<AssetBundle> {
 	Ok(bundle)
 }
 
-pub async fn fetch_bttv_badges_bundle(provider: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_bttv_badges(provider) {
+pub async fn fetch_bttv_badges_bundle(cfg: &AssetProviderConfig, provider: &str) -> anyhow::Result<AssetBundle> {
+	cfg.bttv.ensure_enabled("bttv")?;
+
+	if let Some(bundle) = get_cached_bttv_badges(&cfg.bttv, provider) {
 		return Ok(bundle);
 	}
 
-	let url = format!("{BTTV_BASE_URL}/cached/badges/{provider}");
+	let url = cfg.bttv.url(&format!("/cached/badges/{provider}"));
 	let resp = reqwest::Client::new()
 		.get(url)
 		.send()
@@ -144,15 +150,15 @@ pub async fn fetch_bttv_badges_bundle(provider: &str) -> anyhow::Result<AssetBun
 	Ok(bundle)
 }
 
-pub(crate) fn prune_caches() {
+pub(crate) fn prune_caches(cfg: &AssetProviderConfig) {
 	if let Some(cache) = BTTV_EMOTES_CACHE.get() {
-		prune_map_cache(cache, BTTV_EMOTES_TTL);
+		prune_map_cache(cache, cfg.bttv.ttl(BTTV_EMOTES_TTL));
 	}
 	if let Some(cache) = BTTV_BADGES_CACHE.get() {
-		prune_map_cache(cache, BTTV_BADGES_TTL);
+		prune_map_cache(cache, cfg.bttv.ttl(BTTV_BADGES_TTL));
 	}
 	if let Some(cache) = BTTV_GLOBAL_EMOTES_CACHE.get() {
-		prune_optional_cache(cache, BTTV_GLOBAL_EMOTES_TTL);
+		prune_optional_cache(cache, cfg.bttv.ttl(BTTV_GLOBAL_EMOTES_TTL));
 	}
 }
 
@@ -210,12 +216,12 @@ fn bttv_badge_to_asset(badge: &BttvBadge) -> Option<AssetRef> {
 	})
 }
 
-fn get_cached_bttv_emotes(provider: &str, provider_id: &str) -> Option<AssetBundle> {
+fn get_cached_bttv_emotes(settings: &AssetProviderSettings, provider: &str, provider_id: &str) -> Option<AssetBundle> {
 	let cache = BTTV_EMOTES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let key = format!("{provider}:{provider_id}");
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(&key) {
-		if entry.fetched_at.elapsed() <= BTTV_EMOTES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(BTTV_EMOTES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(&key);
@@ -239,11 +245,11 @@ fn set_cached_bttv_emotes(provider: &str, provider_id: &str, bundle: AssetBundle
 	);
 }
 
-fn get_cached_bttv_global_emotes() -> Option<AssetBundle> {
+fn get_cached_bttv_global_emotes(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = BTTV_GLOBAL_EMOTES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= BTTV_GLOBAL_EMOTES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(BTTV_GLOBAL_EMOTES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
@@ -260,11 +266,11 @@ fn set_cached_bttv_global_emotes(bundle: AssetBundle) {
 	});
 }
 
-fn get_cached_bttv_badges(provider: &str) -> Option<AssetBundle> {
+fn get_cached_bttv_badges(settings: &AssetProviderSettings, provider: &str) -> Option<AssetBundle> {
 	let cache = BTTV_BADGES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(provider) {
-		if entry.fetched_at.elapsed() <= BTTV_BADGES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(BTTV_BADGES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(provider);
@@ -1,28 +1,30 @@
 #![forbid(unsafe_code)]
 
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
+use super::config::AssetProviderConfig;
 use super::{bttv, ffz, kick, seventv, twitch};
 
 static ASSET_CACHE_PRUNER: OnceLock<()> = OnceLock::new();
 
-pub fn ensure_asset_cache_pruner() {
+/// Starts the periodic cache pruner once per process, using the TTLs of the first caller's config.
+pub fn ensure_asset_cache_pruner(cfg: Arc<AssetProviderConfig>) {
 	ASSET_CACHE_PRUNER.get_or_init(|| {
-		tokio::spawn(async {
+		tokio::spawn(async move {
 			let mut interval = tokio::time::interval(Duration::from_secs(300));
 			loop {
 				interval.tick().await;
-				prune_asset_caches();
+				prune_asset_caches(&cfg);
 			}
 		});
 	});
 }
 
-fn prune_asset_caches() {
-	ffz::prune_caches();
-	seventv::prune_caches();
-	bttv::prune_caches();
-	twitch::prune_caches();
-	kick::prune_caches();
+fn prune_asset_caches(cfg: &AssetProviderConfig) {
+	ffz::prune_caches(cfg);
+	seventv::prune_caches(cfg);
+	bttv::prune_caches(cfg);
+	twitch::prune_caches(cfg);
+	kick::prune_caches(cfg);
 }
@@ -0,0 +1,74 @@
+#![forbid(unsafe_code)]
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+/// Fetch settings for a single emote/badge provider.
+#[derive(Debug, Clone)]
+pub struct AssetProviderSettings {
+	pub enabled: bool,
+	pub base_url: String,
+	/// Overrides every cache TTL of this provider when set.
+	pub cache_ttl: Option<Duration>,
+}
+
+impl AssetProviderSettings {
+	pub fn new(base_url: impl Into<String>) -> Self {
+		Self {
+			enabled: true,
+			base_url: base_url.into(),
+			cache_ttl: None,
+		}
+	}
+
+	pub(crate) fn url(&self, path: &str) -> String {
+		format!("{}{}", self.base_url.trim_end_matches('/'), path)
+	}
+
+	pub(crate) fn ttl(&self, default: Duration) -> Duration {
+		self.cache_ttl.unwrap_or(default)
+	}
+
+	pub(crate) fn ensure_enabled(&self, provider: &str) -> anyhow::Result<()> {
+		if self.enabled {
+			Ok(())
+		} else {
+			Err(anyhow!("{provider} asset provider disabled"))
+		}
+	}
+}
+
+/// Endpoints, enable flags and cache TTLs for the asset fetchers.
+#[derive(Debug, Clone)]
+pub struct AssetProviderConfig {
+	pub bttv: AssetProviderSettings,
+	pub ffz: AssetProviderSettings,
+	/// 7TV GraphQL endpoint.
+	pub seventv: AssetProviderSettings,
+	/// 7TV event API websocket; `cache_ttl` is unused.
+	pub seventv_event_api: AssetProviderSettings,
+	/// Twitch Helix base URL for native emotes/badges.
+	pub twitch: AssetProviderSettings,
+	/// Kick web base URL for native emotes.
+	pub kick: AssetProviderSettings,
+}
+
+impl Default for AssetProviderConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl AssetProviderConfig {
+	pub fn new() -> Self {
+		Self {
+			bttv: AssetProviderSettings::new("https://api.betterttv.net/3"),
+			ffz: AssetProviderSettings::new("https://api.frankerfacez.com"),
+			seventv: AssetProviderSettings::new("https://api.7tv.app/v4/gql"),
+			seventv_event_api: AssetProviderSettings::new("wss://events.7tv.io/v3"),
+			twitch: AssetProviderSettings::new("https://api.twitch.tv/helix"),
+			kick: AssetProviderSettings::new("https://kick.com"),
+		}
+	}
+}
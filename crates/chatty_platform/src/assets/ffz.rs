@@ -10,9 +10,9 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::common::{CachedBundle, compute_bundle_etag, guess_format, prune_map_cache, prune_optional_cache};
+use super::config::{AssetProviderConfig, AssetProviderSettings};
 use crate::{AssetBundle, AssetImage, AssetProvider, AssetRef, AssetScale, AssetScope};
 
-const FFZ_BASE_URL: &str = "https://api.frankerfacez.com";
 const FFZ_EMOTES_TTL: Duration = Duration::from_secs(300);
 const FFZ_BADGES_TTL: Duration = Duration::from_secs(600);
 const FFZ_GLOBAL_EMOTES_TTL: Duration = Duration::from_secs(600);
@@ -21,12 +21,14 @@ static FFZ_EMOTES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceLo
 static FFZ_BADGES_CACHE: OnceLock<Mutex<Option<CachedBundle>>> = OnceLock::new();
 static FFZ_GLOBAL_EMOTES_CACHE: OnceLock<Mutex<Option<CachedBundle>>> = OnceLock::new();
 
-pub async fn fetch_ffz_bundle(room_login: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_ffz_emotes(room_login) {
+pub async fn fetch_ffz_bundle(cfg: &AssetProviderConfig, room_login: &str) -> anyhow::Result<AssetBundle> {
+	cfg.ffz.ensure_enabled("ffz")?;
+
+	if let Some(bundle) = get_cached_ffz_emotes(&cfg.ffz, room_login) {
 		return Ok(bundle);
 	}
 
-	let url = format!("{FFZ_BASE_URL}/v1/room/{room_login}");
+	let url = cfg.ffz.url(&format!("/v1/room/{room_login}"));
 	let resp = reqwest::Client::new()
 		.get(url)
 		.send()
@@ -61,12 +63,14 @@ pub async fn fetch_ffz_bundle(room_login: &str) -> anyhow::Result<AssetBundle> {
 	Ok(bundle)
 }
 
-pub async fn fetch_ffz_badges_bundle() -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_ffz_badges() {
+pub async fn fetch_ffz_badges_bundle(cfg: &AssetProviderConfig) -> anyhow::Result<AssetBundle> {
+	cfg.ffz.ensure_enabled("ffz")?;
+
+	if let Some(bundle) = get_cached_ffz_badges(&cfg.ffz) {
 		return Ok(bundle);
 	}
 
-	let url = format!("{FFZ_BASE_URL}/v1/badges");
+	let url = cfg.ffz.url("/v1/badges");
 	let resp = reqwest::Client::new()
 		.get(url)
 		.send()
@@ -94,12 +98,14 @@ pub async fn fetch_ffz_badges_bundle() -> anyhow::Result<AssetBundle> {
 	Ok(bundle)
 }
 
-pub async fn fetch_ffz_global_emotes_bundle() -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_ffz_global_emotes() {
+pub async fn fetch_ffz_global_emotes_bundle(cfg: &AssetProviderConfig) -> anyhow::Result<AssetBundle> {
+	cfg.ffz.ensure_enabled("ffz")?;
+
+	if let Some(bundle) = get_cached_ffz_global_emotes(&cfg.ffz) {
 		return Ok(bundle);
 	}
 
-	let ids_url = format!("{FFZ_BASE_URL}/v1/set/global/ids");
+	let ids_url = cfg.ffz.url("/v1/set/global/ids");
 	let resp = reqwest::Client::new()
 		.get(ids_url)
 		.send()
@@ -114,7 +120,7 @@ pub async fn fetch_ffz_global_emotes_bundle() -> anyhow::Result<AssetBundle> {
 		.first()
 		.ok_or_else(|| anyhow!("ffz global emote set ids missing"))?;
 
-	let set_url = format!("{FFZ_BASE_URL}/v1/set/{global_id}");
+	let set_url = cfg.ffz.url(&format!("/v1/set/{global_id}"));
 	let resp = reqwest::Client::new()
 		.get(set_url)
 		.send()
@@ -142,17 +148,17 @@ pub async fn fetch_ffz_global_emotes_bundle() -> anyhow::Result<AssetBundle> {
 	Ok(bundle)
 }
 
-pub(crate) fn prune_caches() {
+pub(crate) fn prune_caches(cfg: &AssetProviderConfig) {
 	if let Some(cache) = FFZ_EMOTES_CACHE.get() {
-		prune_map_cache(cache, FFZ_EMOTES_TTL);
+		prune_map_cache(cache, cfg.ffz.ttl(FFZ_EMOTES_TTL));
 	}
 
 	if let Some(cache) = FFZ_BADGES_CACHE.get() {
-		prune_optional_cache(cache, FFZ_BADGES_TTL);
+		prune_optional_cache(cache, cfg.ffz.ttl(FFZ_BADGES_TTL));
 	}
 
 	if let Some(cache) = FFZ_GLOBAL_EMOTES_CACHE.get() {
-		prune_optional_cache(cache, FFZ_GLOBAL_EMOTES_TTL);
+		prune_optional_cache(cache, cfg.ffz.ttl(FFZ_GLOBAL_EMOTES_TTL));
 	}
 }
 
@@ -307,11 +313,11 @@ fn ffz_badge_to_asset(badge: FfzBadge) -> Option<AssetRef> {
 	})
 }
 
-fn get_cached_ffz_badges() -> Option<AssetBundle> {
+fn get_cached_ffz_badges(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = FFZ_BADGES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= FFZ_BADGES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(FFZ_BADGES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
@@ -328,11 +334,11 @@ fn set_cached_ffz_badges(bundle: AssetBundle) {
 	});
 }
 
-fn get_cached_ffz_global_emotes() -> Option<AssetBundle> {
+fn get_cached_ffz_global_emotes(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = FFZ_GLOBAL_EMOTES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= FFZ_GLOBAL_EMOTES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(FFZ_GLOBAL_EMOTES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
@@ -349,11 +355,11 @@ fn set_cached_ffz_global_emotes(bundle: AssetBundle) {
 	});
 }
 
-fn get_cached_ffz_emotes(room_login: &str) -> Option<AssetBundle> {
+fn get_cached_ffz_emotes(settings: &AssetProviderSettings, room_login: &str) -> Option<AssetBundle> {
 	let cache = FFZ_EMOTES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(room_login) {
-		if entry.fetched_at.elapsed() <= FFZ_EMOTES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(FFZ_EMOTES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(room_login);
@@ -6,6 +6,7 @@ use parking_lot::Mutex;
 use serde::Deserialize;
 
 use super::common::{CachedBundle, compute_bundle_etag, prune_map_cache};
+use super::config::{AssetProviderConfig, AssetProviderSettings};
 use crate::{AssetBundle, AssetImage, AssetProvider, AssetRef, AssetScale, AssetScope};
 
 const KICK_BADGES_TTL: Duration = Duration::from_secs(600);
@@ -15,8 +16,12 @@ const KICK_GLOBAL_EMOTES_CACHE_KEY: &str = "__global__";
 static KICK_BADGES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceLock::new();
 static KICK_EMOTES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceLock::new();
 
-pub async fn fetch_kick_badge_bundle(room_id: &str) -> Option<AssetBundle> {
-	if let Some(bundle) = get_cached_kick_badges(room_id) {
+pub async fn fetch_kick_badge_bundle(cfg: &AssetProviderConfig, room_id: &str) -> Option<AssetBundle> {
+	if !cfg.kick.enabled {
+		return None;
+	}
+
+	if let Some(bundle) = get_cached_kick_badges(&cfg.kick, room_id) {
 		return Some(bundle);
 	}
 
@@ -49,10 +54,14 @@ pub async fn fetch_kick_badge_bundle(room_id: &str) -> Option<AssetBundle> {
 	Some(bundle)
 }
 
-pub async fn fetch_kick_emote_bundles(room_slug: &str) -> Vec<AssetBundle> {
+pub async fn fetch_kick_emote_bundles(cfg: &AssetProviderConfig, room_slug: &str) -> Vec<AssetBundle> {
 	let mut bundles = Vec::new();
-	let cached_channel = get_cached_kick_emotes(room_slug);
-	let cached_global = get_cached_kick_emotes(KICK_GLOBAL_EMOTES_CACHE_KEY);
+	if !cfg.kick.enabled {
+		return bundles;
+	}
+
+	let cached_channel = get_cached_kick_emotes(&cfg.kick, room_slug);
+	let cached_global = get_cached_kick_emotes(&cfg.kick, KICK_GLOBAL_EMOTES_CACHE_KEY);
 	if let Some(bundle) = cached_channel {
 		bundles.push(bundle);
 	}
@@ -63,7 +72,7 @@ pub async fn fetch_kick_emote_bundles(room_slug: &str) -> Vec<AssetBundle> {
 		return bundles;
 	}
 
-	let url = format!("https://kick.com/emotes/{}", urlencoding::encode(room_slug));
+	let url = cfg.kick.url(&format!("/emotes/{}", urlencoding::encode(room_slug)));
 	let resp = match reqwest::Client::new()
 		.get(url)
 		.header("Accept", "application/json")
@@ -173,20 +182,20 @@ struct KickEmote {
 	name: String,
 }
 
-pub(crate) fn prune_caches() {
+pub(crate) fn prune_caches(cfg: &AssetProviderConfig) {
 	if let Some(cache) = KICK_BADGES_CACHE.get() {
-		prune_map_cache(cache, KICK_BADGES_TTL);
+		prune_map_cache(cache, cfg.kick.ttl(KICK_BADGES_TTL));
 	}
 	if let Some(cache) = KICK_EMOTES_CACHE.get() {
-		prune_map_cache(cache, KICK_EMOTES_TTL);
+		prune_map_cache(cache, cfg.kick.ttl(KICK_EMOTES_TTL));
 	}
 }
 
-fn get_cached_kick_badges(room_id: &str) -> Option<AssetBundle> {
+fn get_cached_kick_badges(settings: &AssetProviderSettings, room_id: &str) -> Option<AssetBundle> {
 	let cache = KICK_BADGES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(room_id) {
-		if entry.fetched_at.elapsed() <= KICK_BADGES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(KICK_BADGES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(room_id);
@@ -209,11 +218,11 @@ fn set_cached_kick_badges(room_id: &str, bundle: AssetBundle) {
 	);
 }
 
-fn get_cached_kick_emotes(room_id: &str) -> Option<AssetBundle> {
+fn get_cached_kick_emotes(settings: &AssetProviderSettings, room_id: &str) -> Option<AssetBundle> {
 	let cache = KICK_EMOTES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(room_id) {
-		if entry.fetched_at.elapsed() <= KICK_EMOTES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(KICK_EMOTES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(room_id);
@@ -3,13 +3,18 @@
 mod bttv;
 mod cache;
 mod common;
+mod config;
 mod ffz;
 pub mod kick;
 mod seventv;
 mod twitch;
 
+#[cfg(test)]
+mod tests;
+
 pub use bttv::{fetch_bttv_badges_bundle, fetch_bttv_bundle, fetch_bttv_global_emotes_bundle};
 pub use cache::ensure_asset_cache_pruner;
+pub use config::{AssetProviderConfig, AssetProviderSettings};
 pub use ffz::{fetch_ffz_badges_bundle, fetch_ffz_bundle, fetch_ffz_global_emotes_bundle};
 pub use kick::{fetch_kick_badge_bundle, fetch_kick_emote_bundles};
 pub use seventv::{
@@ -11,7 +11,8 @@ use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, info, warn};
 
-const SEVENTV_EVENT_API_URL: &str = "wss://events.7tv.io/v3";
+use crate::assets::config::AssetProviderSettings;
+
 const SEVENTV_EVENT_API_RECONNECT_DELAY: Duration = Duration::from_secs(1);
 
 static SEVENTV_EVENT_API: OnceLock<SevenTvEventApi> = OnceLock::new();
@@ -22,14 +23,18 @@ pub struct SevenTvEventApi {
 	command_tx: mpsc::UnboundedSender<Command>,
 }
 
-pub fn ensure_seventv_event_api() -> SevenTvEventApi {
-	SEVENTV_EVENT_API
-		.get_or_init(|| {
-			let (command_tx, command_rx) = mpsc::unbounded_channel();
-			tokio::spawn(run_event_api(command_rx));
-			SevenTvEventApi { command_tx }
-		})
-		.clone()
+/// Returns the shared 7TV event API connection, or `None` when the provider is disabled.
+/// The socket is shared process-wide, so the first enabled caller's URL is the one dialled.
+pub fn ensure_seventv_event_api(settings: &AssetProviderSettings) -> Option<SevenTvEventApi> {
+	if !settings.enabled {
+		return None;
+	}
+	let api = SEVENTV_EVENT_API.get_or_init(|| {
+		let (command_tx, command_rx) = mpsc::unbounded_channel();
+		tokio::spawn(run_event_api(settings.base_url.clone(), command_rx));
+		SevenTvEventApi { command_tx }
+	});
+	Some(api.clone())
 }
 
 impl SevenTvEventApi {
@@ -131,12 +136,12 @@ impl ManagerState {
 	}
 }
 
-async fn run_event_api(mut command_rx: mpsc::UnboundedReceiver<Command>) {
+async fn run_event_api(url: String, mut command_rx: mpsc::UnboundedReceiver<Command>) {
 	let mut state = ManagerState::default();
 
 	loop {
-		info!(url = %SEVENTV_EVENT_API_URL, "connecting to 7tv event api");
-		let (mut ws, _) = match tokio_tungstenite::connect_async(SEVENTV_EVENT_API_URL).await {
+		info!(url = %url, "connecting to 7tv event api");
+		let (mut ws, _) = match tokio_tungstenite::connect_async(url.as_str()).await {
 			Ok(result) => result,
 			Err(err) => {
 				warn!(error = %err, "7tv event api connect failed");
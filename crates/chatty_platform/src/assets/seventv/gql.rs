@@ -7,8 +7,7 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
 use super::types::{SevenTvPlatform, SevenTvUserEmoteSets};
-
-const SEVENTV_GQL_URL: &str = "https://api.7tv.app/v4/gql";
+use crate::assets::config::AssetProviderSettings;
 
 static SEVENTV_HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
 
@@ -18,11 +17,15 @@ fn http_client() -> &'static Client {
 
 pub struct SevenTvGqlClient {
 	http: &'static Client,
+	url: String,
 }
 
 impl SevenTvGqlClient {
-	pub fn new() -> Self {
-		Self { http: http_client() }
+	pub fn new(settings: &AssetProviderSettings) -> Self {
+		Self {
+			http: http_client(),
+			url: settings.base_url.clone(),
+		}
 	}
 
 	pub async fn user_emote_sets(
@@ -116,7 +119,7 @@ impl SevenTvGqlClient {
 	{
 		let resp = self
 			.http
-			.post(SEVENTV_GQL_URL)
+			.post(&self.url)
 			.json(&req)
 			.send()
 			.await
@@ -9,6 +9,7 @@ use parking_lot::Mutex;
 use tracing::info;
 
 use super::common::{CachedBundle, compute_bundle_etag, prune_map_cache, prune_optional_cache};
+use super::config::{AssetProviderConfig, AssetProviderSettings};
 use crate::{AssetBundle, AssetImage, AssetProvider, AssetRef, AssetScale, AssetScope};
 
 mod event_api;
@@ -35,20 +36,26 @@ pub enum SevenTvCacheMode {
 	Refresh,
 }
 
-pub async fn fetch_7tv_bundle(platform: SevenTvPlatform, platform_id: &str) -> anyhow::Result<AssetBundle> {
-	let (bundle, _) = fetch_7tv_bundle_with_sets(platform, platform_id, SevenTvCacheMode::UseCache).await?;
+pub async fn fetch_7tv_bundle(
+	cfg: &AssetProviderConfig,
+	platform: SevenTvPlatform,
+	platform_id: &str,
+) -> anyhow::Result<AssetBundle> {
+	let (bundle, _) = fetch_7tv_bundle_with_sets(cfg, platform, platform_id, SevenTvCacheMode::UseCache).await?;
 	Ok(bundle)
 }
 
 pub async fn fetch_7tv_bundle_with_sets(
+	cfg: &AssetProviderConfig,
 	platform: SevenTvPlatform,
 	platform_id: &str,
 	cache_mode: SevenTvCacheMode,
 ) -> anyhow::Result<(AssetBundle, SevenTvUserEmoteSets)> {
-	let client = SevenTvGqlClient::new();
+	cfg.seventv.ensure_enabled("7tv")?;
+	let client = SevenTvGqlClient::new(&cfg.seventv);
 
 	let sets = if matches!(cache_mode, SevenTvCacheMode::UseCache) {
-		get_cached_7tv_user_emote_sets(platform, platform_id)
+		get_cached_7tv_user_emote_sets(&cfg.seventv, platform, platform_id)
 	} else {
 		None
 	};
@@ -67,7 +74,7 @@ pub async fn fetch_7tv_bundle_with_sets(
 
 	let mut emotes_map: HashMap<String, AssetRef> = HashMap::new();
 	for set_id in sets.set_ids() {
-		let bundle = fetch_7tv_emote_set_bundle(&client, &set_id, cache_mode).await?;
+		let bundle = fetch_7tv_emote_set_bundle(&cfg.seventv, &client, &set_id, cache_mode).await?;
 		for asset in bundle.emotes {
 			emotes_map.entry(asset.id.clone()).or_insert(asset);
 		}
@@ -95,12 +102,13 @@ pub async fn fetch_7tv_bundle_with_sets(
 	Ok((bundle, sets))
 }
 
-pub async fn fetch_7tv_badges_bundle() -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_7tv_badges() {
+pub async fn fetch_7tv_badges_bundle(cfg: &AssetProviderConfig) -> anyhow::Result<AssetBundle> {
+	cfg.seventv.ensure_enabled("7tv")?;
+	if let Some(bundle) = get_cached_7tv_badges(&cfg.seventv) {
 		return Ok(bundle);
 	}
 
-	let client = SevenTvGqlClient::new();
+	let client = SevenTvGqlClient::new(&cfg.seventv);
 	let badges = client.global_badges().await.context("7tv badges gql request")?;
 	let badges: Vec<AssetRef> = badges.into_iter().filter_map(seventv_badge_to_asset).collect();
 	let etag = compute_bundle_etag(&[], &badges);
@@ -120,12 +128,17 @@ pub async fn fetch_7tv_badges_bundle() -> anyhow::Result<AssetBundle> {
 	Ok(bundle)
 }
 
-pub async fn fetch_7tv_channel_badges_bundle(platform: SevenTvPlatform, platform_id: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_7tv_channel_badges(platform, platform_id) {
+pub async fn fetch_7tv_channel_badges_bundle(
+	cfg: &AssetProviderConfig,
+	platform: SevenTvPlatform,
+	platform_id: &str,
+) -> anyhow::Result<AssetBundle> {
+	cfg.seventv.ensure_enabled("7tv")?;
+	if let Some(bundle) = get_cached_7tv_channel_badges(&cfg.seventv, platform, platform_id) {
 		return Ok(bundle);
 	}
 
-	let client = SevenTvGqlClient::new();
+	let client = SevenTvGqlClient::new(&cfg.seventv);
 	let user_badges = client
 		.channel_badges(platform, platform_id)
 		.await
@@ -151,18 +164,18 @@ pub async fn fetch_7tv_channel_badges_bundle(platform: SevenTvPlatform, platform
 	Ok(bundle)
 }
 
-pub(crate) fn prune_caches() {
+pub(crate) fn prune_caches(cfg: &AssetProviderConfig) {
 	if let Some(cache) = SEVENTV_USER_SETS_CACHE.get() {
-		prune_user_sets_cache(cache, SEVENTV_USER_SETS_TTL);
+		prune_user_sets_cache(cache, cfg.seventv.ttl(SEVENTV_USER_SETS_TTL));
 	}
 	if let Some(cache) = SEVENTV_EMOTE_SET_CACHE.get() {
-		prune_map_cache(cache, SEVENTV_EMOTE_SET_TTL);
+		prune_map_cache(cache, cfg.seventv.ttl(SEVENTV_EMOTE_SET_TTL));
 	}
 	if let Some(cache) = SEVENTV_CHANNEL_BADGES_CACHE.get() {
-		prune_map_cache(cache, SEVENTV_CHANNEL_BADGES_TTL);
+		prune_map_cache(cache, cfg.seventv.ttl(SEVENTV_CHANNEL_BADGES_TTL));
 	}
 	if let Some(cache) = SEVENTV_BADGES_CACHE.get() {
-		prune_optional_cache(cache, SEVENTV_BADGES_TTL);
+		prune_optional_cache(cache, cfg.seventv.ttl(SEVENTV_BADGES_TTL));
 	}
 }
 
@@ -186,12 +199,13 @@ fn merge_user_badges(dedupe: &mut HashMap<String, AssetRef>, user_badges: SevenT
 }
 
 async fn fetch_7tv_emote_set_bundle(
+	settings: &AssetProviderSettings,
 	client: &SevenTvGqlClient,
 	set_id: &str,
 	cache_mode: SevenTvCacheMode,
 ) -> anyhow::Result<AssetBundle> {
 	if matches!(cache_mode, SevenTvCacheMode::UseCache)
-		&& let Some(bundle) = get_cached_7tv_emote_set(set_id)
+		&& let Some(bundle) = get_cached_7tv_emote_set(settings, set_id)
 	{
 		return Ok(bundle);
 	}
@@ -292,12 +306,16 @@ fn seventv_images_to_assets(images: &[SevenTvImage]) -> Vec<AssetImage> {
 	out
 }
 
-fn get_cached_7tv_user_emote_sets(platform: SevenTvPlatform, platform_id: &str) -> Option<SevenTvUserEmoteSets> {
+fn get_cached_7tv_user_emote_sets(
+	settings: &AssetProviderSettings,
+	platform: SevenTvPlatform,
+	platform_id: &str,
+) -> Option<SevenTvUserEmoteSets> {
 	let cache = SEVENTV_USER_SETS_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let key = format!("{}:{}", platform.as_str(), platform_id);
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(&key) {
-		if entry.fetched_at.elapsed() <= SEVENTV_USER_SETS_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(SEVENTV_USER_SETS_TTL) {
 			Some(entry.sets.clone())
 		} else {
 			guard.remove(&key);
@@ -321,11 +339,11 @@ fn set_cached_7tv_user_emote_sets(platform: SevenTvPlatform, platform_id: &str,
 	);
 }
 
-fn get_cached_7tv_emote_set(set_id: &str) -> Option<AssetBundle> {
+fn get_cached_7tv_emote_set(settings: &AssetProviderSettings, set_id: &str) -> Option<AssetBundle> {
 	let cache = SEVENTV_EMOTE_SET_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(set_id) {
-		if entry.fetched_at.elapsed() <= SEVENTV_EMOTE_SET_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(SEVENTV_EMOTE_SET_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(set_id);
@@ -336,12 +354,16 @@ fn get_cached_7tv_emote_set(set_id: &str) -> Option<AssetBundle> {
 	}
 }
 
-fn get_cached_7tv_channel_badges(platform: SevenTvPlatform, platform_id: &str) -> Option<AssetBundle> {
+fn get_cached_7tv_channel_badges(
+	settings: &AssetProviderSettings,
+	platform: SevenTvPlatform,
+	platform_id: &str,
+) -> Option<AssetBundle> {
 	let cache = SEVENTV_CHANNEL_BADGES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let key = format!("{}:{}", platform.as_str(), platform_id);
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(&key) {
-		if entry.fetched_at.elapsed() <= SEVENTV_CHANNEL_BADGES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(SEVENTV_CHANNEL_BADGES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(&key);
@@ -352,11 +374,11 @@ fn get_cached_7tv_channel_badges(platform: SevenTvPlatform, platform_id: &str) -
 	}
 }
 
-fn get_cached_7tv_badges() -> Option<AssetBundle> {
+fn get_cached_7tv_badges(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = SEVENTV_BADGES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= SEVENTV_BADGES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(SEVENTV_BADGES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
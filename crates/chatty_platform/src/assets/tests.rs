@@ -0,0 +1,85 @@
+#![forbid(unsafe_code)]
+
+use std::time::Duration;
+
+use super::*;
+use crate::AssetProvider;
+use crate::test_http::{FixtureRequest, spawn_fixture_server};
+
+fn route(req: &FixtureRequest) -> (&'static str, String) {
+	match req.path() {
+		"/bttv/cached/users/twitch/fixture-channel" => (
+			"200 OK",
+			r#"{"channelEmotes":[{"id":"b1","code":"catJAM","animated":true}],"sharedEmotes":[{"id":"b2","code":"OMEGALUL","imageType":"png"}]}"#
+				.to_string(),
+		),
+		"/ffz/v1/room/fixture_channel" => (
+			"200 OK",
+			r#"{"room":{"set":7},"sets":{"7":{"emoticons":[{"id":42,"name":"ZrehplaR","urls":{"1":"https://cdn.example/42/1"}}]}}}"#
+				.to_string(),
+		),
+		_ => ("404 Not Found", "{}".to_string()),
+	}
+}
+
+fn fixture_config(addr: std::net::SocketAddr) -> AssetProviderConfig {
+	let mut cfg = AssetProviderConfig::new();
+	cfg.bttv = AssetProviderSettings::new(format!("http://{addr}/bttv"));
+	cfg.ffz = AssetProviderSettings::new(format!("http://{addr}/ffz/"));
+	cfg.ffz.cache_ttl = Some(Duration::ZERO);
+	cfg
+}
+
+#[tokio::test]
+async fn bttv_fetcher_uses_configured_base_url() {
+	let (addr, _) = spawn_fixture_server(route).await;
+	let cfg = fixture_config(addr);
+
+	let bttv = fetch_bttv_bundle(&cfg, "twitch", "fixture-channel")
+		.await
+		.expect("bttv bundle");
+	assert!(matches!(bttv.provider, AssetProvider::Bttv));
+	let mut names: Vec<_> = bttv.emotes.iter().map(|e| e.name.as_str()).collect();
+	names.sort();
+	assert_eq!(names, vec!["OMEGALUL", "catJAM"]);
+}
+
+#[tokio::test]
+async fn ffz_fetcher_honours_ttl_override() {
+	let (addr, recorded) = spawn_fixture_server(route).await;
+	let cfg = fixture_config(addr);
+
+	let ffz = fetch_ffz_bundle(&cfg, "fixture_channel").await.expect("ffz bundle");
+	assert_eq!(ffz.emotes.len(), 1);
+	assert_eq!(ffz.emotes[0].name, "ZrehplaR");
+
+	// A zero TTL override means the second fetch goes back to the fixture.
+	fetch_ffz_bundle(&cfg, "fixture_channel").await.expect("ffz bundle refetch");
+	let ffz_hits = recorded
+		.lock()
+		.await
+		.iter()
+		.filter(|r| r.path() == "/ffz/v1/room/fixture_channel")
+		.count();
+	assert_eq!(ffz_hits, 2);
+}
+
+#[tokio::test]
+async fn disabled_provider_is_not_fetched() {
+	let (addr, recorded) = spawn_fixture_server(route).await;
+	let mut cfg = fixture_config(addr);
+	cfg.bttv.enabled = false;
+
+	let err = fetch_bttv_bundle(&cfg, "twitch", "fixture-channel")
+		.await
+		.expect_err("bttv disabled");
+	assert!(err.to_string().contains("disabled"), "{err}");
+	assert!(recorded.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn disabled_seventv_event_api_is_not_started() {
+	let mut cfg = AssetProviderConfig::new();
+	cfg.seventv_event_api.enabled = false;
+	assert!(ensure_seventv_event_api(&cfg.seventv_event_api).is_none());
+}
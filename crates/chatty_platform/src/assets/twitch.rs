@@ -9,6 +9,7 @@ use parking_lot::Mutex;
 use serde::Deserialize;
 
 use super::common::{CachedBundle, compute_bundle_etag, prune_map_cache, prune_optional_cache};
+use super::config::{AssetProviderConfig, AssetProviderSettings};
 use crate::{AssetBundle, AssetImage, AssetProvider, AssetRef, AssetScale, AssetScope};
 
 const TWITCH_BADGES_TTL: Duration = Duration::from_secs(600);
@@ -19,12 +20,18 @@ static TWITCH_CHANNEL_BADGES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>
 static TWITCH_GLOBAL_EMOTES_CACHE: OnceLock<Mutex<Option<CachedBundle>>> = OnceLock::new();
 static TWITCH_CHANNEL_EMOTES_CACHE: OnceLock<Mutex<HashMap<String, CachedBundle>>> = OnceLock::new();
 
-pub async fn fetch_twitch_global_emotes_bundle(client_id: &str, bearer_token: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_twitch_global_emotes() {
+pub async fn fetch_twitch_global_emotes_bundle(
+	cfg: &AssetProviderConfig,
+	client_id: &str,
+	bearer_token: &str,
+) -> anyhow::Result<AssetBundle> {
+	cfg.twitch.ensure_enabled("twitch")?;
+
+	if let Some(bundle) = get_cached_twitch_global_emotes(&cfg.twitch) {
 		return Ok(bundle);
 	}
 
-	let url = "https://api.twitch.tv/helix/chat/emotes/global";
+	let url = cfg.twitch.url("/chat/emotes/global");
 	let resp = reqwest::Client::new()
 		.get(url)
 		.header("Client-Id", client_id)
@@ -53,15 +60,18 @@ pub async fn fetch_twitch_global_emotes_bundle(client_id: &str, bearer_token: &s
 }
 
 pub async fn fetch_twitch_channel_emotes_bundle(
+	cfg: &AssetProviderConfig,
 	client_id: &str,
 	bearer_token: &str,
 	broadcaster_id: &str,
 ) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_twitch_channel_emotes(broadcaster_id) {
+	cfg.twitch.ensure_enabled("twitch")?;
+
+	if let Some(bundle) = get_cached_twitch_channel_emotes(&cfg.twitch, broadcaster_id) {
 		return Ok(bundle);
 	}
 
-	let url = format!("https://api.twitch.tv/helix/chat/emotes?broadcaster_id={broadcaster_id}");
+	let url = cfg.twitch.url(&format!("/chat/emotes?broadcaster_id={broadcaster_id}"));
 	let resp = reqwest::Client::new()
 		.get(url)
 		.header("Client-Id", client_id)
@@ -89,12 +99,18 @@ pub async fn fetch_twitch_channel_emotes_bundle(
 	Ok(bundle)
 }
 
-pub async fn fetch_twitch_badges_bundle(client_id: &str, bearer_token: &str) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_twitch_badges() {
+pub async fn fetch_twitch_badges_bundle(
+	cfg: &AssetProviderConfig,
+	client_id: &str,
+	bearer_token: &str,
+) -> anyhow::Result<AssetBundle> {
+	cfg.twitch.ensure_enabled("twitch")?;
+
+	if let Some(bundle) = get_cached_twitch_badges(&cfg.twitch) {
 		return Ok(bundle);
 	}
 
-	let url = "https://api.twitch.tv/helix/chat/badges/global";
+	let url = cfg.twitch.url("/chat/badges/global");
 	let resp = reqwest::Client::new()
 		.get(url)
 		.header("Client-Id", client_id)
@@ -132,15 +148,18 @@ pub async fn fetch_twitch_badges_bundle(client_id: &str, bearer_token: &str) ->
 }
 
 pub async fn fetch_twitch_channel_badges_bundle(
+	cfg: &AssetProviderConfig,
 	client_id: &str,
 	bearer_token: &str,
 	broadcaster_id: &str,
 ) -> anyhow::Result<AssetBundle> {
-	if let Some(bundle) = get_cached_twitch_channel_badges(broadcaster_id) {
+	cfg.twitch.ensure_enabled("twitch")?;
+
+	if let Some(bundle) = get_cached_twitch_channel_badges(&cfg.twitch, broadcaster_id) {
 		return Ok(bundle);
 	}
 
-	let url = format!("https://api.twitch.tv/helix/chat/badges?broadcaster_id={broadcaster_id}");
+	let url = cfg.twitch.url(&format!("/chat/badges?broadcaster_id={broadcaster_id}"));
 	let resp = reqwest::Client::new()
 		.get(url)
 		.header("Client-Id", client_id)
@@ -176,18 +195,18 @@ pub async fn fetch_twitch_channel_badges_bundle(
 	Ok(bundle)
 }
 
-pub(crate) fn prune_caches() {
+pub(crate) fn prune_caches(cfg: &AssetProviderConfig) {
 	if let Some(cache) = TWITCH_BADGES_CACHE.get() {
-		prune_optional_cache(cache, TWITCH_BADGES_TTL);
+		prune_optional_cache(cache, cfg.twitch.ttl(TWITCH_BADGES_TTL));
 	}
 	if let Some(cache) = TWITCH_CHANNEL_BADGES_CACHE.get() {
-		prune_map_cache(cache, TWITCH_BADGES_TTL);
+		prune_map_cache(cache, cfg.twitch.ttl(TWITCH_BADGES_TTL));
 	}
 	if let Some(cache) = TWITCH_GLOBAL_EMOTES_CACHE.get() {
-		prune_optional_cache(cache, TWITCH_EMOTES_TTL);
+		prune_optional_cache(cache, cfg.twitch.ttl(TWITCH_EMOTES_TTL));
 	}
 	if let Some(cache) = TWITCH_CHANNEL_EMOTES_CACHE.get() {
-		prune_map_cache(cache, TWITCH_EMOTES_TTL);
+		prune_map_cache(cache, cfg.twitch.ttl(TWITCH_EMOTES_TTL));
 	}
 }
 
@@ -232,11 +251,11 @@ fn twitch_badge_to_asset(set_id: &str, badge: TwitchBadgeVersion) -> Option<Asse
 	})
 }
 
-fn get_cached_twitch_badges() -> Option<AssetBundle> {
+fn get_cached_twitch_badges(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = TWITCH_BADGES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= TWITCH_BADGES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(TWITCH_BADGES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
@@ -253,11 +272,11 @@ fn set_cached_twitch_badges(bundle: AssetBundle) {
 	});
 }
 
-fn get_cached_twitch_channel_badges(broadcaster_id: &str) -> Option<AssetBundle> {
+fn get_cached_twitch_channel_badges(settings: &AssetProviderSettings, broadcaster_id: &str) -> Option<AssetBundle> {
 	let cache = TWITCH_CHANNEL_BADGES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(broadcaster_id) {
-		if entry.fetched_at.elapsed() <= TWITCH_BADGES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(TWITCH_BADGES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(broadcaster_id);
@@ -334,11 +353,11 @@ fn twitch_emote_to_asset(emote: TwitchEmote) -> Option<AssetRef> {
 	})
 }
 
-fn get_cached_twitch_global_emotes() -> Option<AssetBundle> {
+fn get_cached_twitch_global_emotes(settings: &AssetProviderSettings) -> Option<AssetBundle> {
 	let cache = TWITCH_GLOBAL_EMOTES_CACHE.get_or_init(|| Mutex::new(None));
 	let mut guard = cache.lock();
 	let entry = guard.as_ref()?;
-	if entry.fetched_at.elapsed() <= TWITCH_EMOTES_TTL {
+	if entry.fetched_at.elapsed() <= settings.ttl(TWITCH_EMOTES_TTL) {
 		Some(entry.bundle.clone())
 	} else {
 		*guard = None;
@@ -355,11 +374,11 @@ fn set_cached_twitch_global_emotes(bundle: AssetBundle) {
 	});
 }
 
-fn get_cached_twitch_channel_emotes(broadcaster_id: &str) -> Option<AssetBundle> {
+fn get_cached_twitch_channel_emotes(settings: &AssetProviderSettings, broadcaster_id: &str) -> Option<AssetBundle> {
 	let cache = TWITCH_CHANNEL_EMOTES_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
 	let mut guard = cache.lock();
 	if let Some(entry) = guard.get(broadcaster_id) {
-		if entry.fetched_at.elapsed() <= TWITCH_EMOTES_TTL {
+		if entry.fetched_at.elapsed() <= settings.ttl(TWITCH_EMOTES_TTL) {
 			Some(entry.bundle.clone())
 		} else {
 			guard.remove(broadcaster_id);
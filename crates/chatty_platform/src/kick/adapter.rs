@@ -18,14 +18,15 @@ use url::Url;
 
 use super::client::KickClient;
 use crate::assets::{
-	DispatchType, SevenTvCacheMode, SevenTvPlatform, SevenTvSubscription, ensure_asset_cache_pruner,
+	AssetProviderConfig, DispatchType, SevenTvCacheMode, SevenTvPlatform, SevenTvSubscription, ensure_asset_cache_pruner,
 	ensure_seventv_event_api, fetch_7tv_badges_bundle, fetch_7tv_bundle_with_sets, fetch_7tv_channel_badges_bundle,
 	fetch_kick_badge_bundle, fetch_kick_emote_bundles,
 };
 use crate::{
 	AdapterAuth, AdapterControl, AdapterControlRx, AdapterEvent, AdapterEventTx, AssetBundle, AssetImage, AssetProvider,
 	AssetRef, AssetScale, AssetScope, ChatMessage, CommandError, CommandRequest, IngestEvent, IngestPayload,
-	ModerationAction, ModerationEvent, PermissionsInfo, PlatformAdapter, SecretString, UserRef, new_session_id, status,
+	ModerationAction, ModerationEvent, PermissionsInfo, PlatformAdapter, SecretString, StreamStatus, UserRef,
+	new_session_id, status,
 };
 
 #[derive(Clone)]
@@ -36,6 +37,8 @@ pub struct KickConfig {
 	pub pusher_ws_url: String,
 	pub reconnect_min_delay: Duration,
 	pub reconnect_max_delay: Duration,
+	/// Endpoints, enable flags and cache TTLs for the third-party emote/badge fetchers.
+	pub assets: Arc<AssetProviderConfig>,
 }
 
 impl Default for KickConfig {
@@ -53,6 +56,7 @@ impl KickConfig {
 			pusher_ws_url: format!("wss://ws-us2.pusher.com/app/{}", DEFAULT_KICK_PUSHER_APP_KEY),
 			reconnect_min_delay: Duration::from_millis(500),
 			reconnect_max_delay: Duration::from_secs(30),
+			assets: Arc::new(AssetProviderConfig::new()),
 		}
 	}
 }
@@ -72,6 +76,8 @@ pub struct KickEventAdapter {
 	broadcaster_id_by_room: HashMap<RoomKey, (u64, std::time::Instant)>,
 	chatroom_id_by_room: HashMap<RoomKey, (u64, std::time::Instant)>,
 	room_by_chatroom_id: HashMap<u64, RoomKey>,
+	channel_id_by_room: HashMap<RoomKey, u64>,
+	room_by_channel_id: HashMap<u64, RoomKey>,
 	seventv_subscriptions: Arc<RwLock<HashMap<RoomKey, Vec<SevenTvSubscription>>>>,
 	last_auth_error_notice: Option<String>,
 }
@@ -87,6 +93,8 @@ impl KickEventAdapter {
 			broadcaster_id_by_room: HashMap::new(),
 			chatroom_id_by_room: HashMap::new(),
 			room_by_chatroom_id: HashMap::new(),
+			channel_id_by_room: HashMap::new(),
+			room_by_channel_id: HashMap::new(),
 			seventv_subscriptions: Arc::new(RwLock::new(HashMap::new())),
 			last_auth_error_notice: None,
 		}
@@ -161,6 +169,10 @@ impl KickEventAdapter {
 				warn!("kick auth update missing user_id; scope visibility unavailable");
 			}
 
+			self.moderator_rooms.write().await.clear();
+		} else if matches!(auth, AdapterAuth::None) {
+			self.auth_user_ids.write().await.clear();
+			self.user_scopes.write().await.clear();
 			self.moderator_rooms.write().await.clear();
 		}
 	}
@@ -219,10 +231,15 @@ impl KickEventAdapter {
 		} else {
 			let client = KickClient::new(self.cfg.base_url.clone(), "");
 			let resolved = client
-				.resolve_chatroom_id(slug)
+				.resolve_chatroom(slug)
 				.await
 				.map_err(|e| CommandError::Internal(e.to_string()))?;
-			resolved.ok_or(CommandError::InvalidTopic(None))?
+			let chatroom = resolved.ok_or(CommandError::InvalidTopic(None))?;
+			if let Some(channel_id) = chatroom.channel_id {
+				self.channel_id_by_room.insert(room.clone(), channel_id);
+				self.room_by_channel_id.insert(channel_id, room.clone());
+			}
+			chatroom.id
 		};
 
 		self.chatroom_id_by_room.insert(room.clone(), (id, std::time::Instant::now()));
@@ -240,12 +257,7 @@ impl KickEventAdapter {
 	}
 
 	async fn execute_command(&mut self, request: CommandRequest, auth: Option<AdapterAuth>) -> Result<(), CommandError> {
-		let room = match &request {
-			CommandRequest::SendChat { room, .. }
-			| CommandRequest::DeleteMessage { room, .. }
-			| CommandRequest::TimeoutUser { room, .. }
-			| CommandRequest::BanUser { room, .. } => room,
-		};
+		let room = request.room();
 
 		if room.platform != Platform::Kick {
 			return Err(CommandError::InvalidTopic(None));
@@ -314,6 +326,56 @@ impl KickEventAdapter {
 					.await
 					.map_err(map_kick_error)?
 			}
+			CommandRequest::UnbanUser { user_id, .. } | CommandRequest::UntimeoutUser { user_id, .. } => {
+				if !can_moderate {
+					return Err(CommandError::NotAuthorized(None));
+				}
+				let parsed_user_id = parse_numeric_id(&user_id)?;
+				client
+					.unban_user(broadcaster_id, parsed_user_id)
+					.await
+					.map_err(map_kick_error)?
+			}
+			CommandRequest::ClearChat { .. } | CommandRequest::ClearUserMessages { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"kick public API has no chat clearing endpoint".to_string(),
+				)));
+			}
+			CommandRequest::UpdateRoomSettings { ref settings, .. } => {
+				if !can_moderate {
+					return Err(CommandError::NotAuthorized(None));
+				}
+				if settings.unique_chat.is_some() {
+					return Err(CommandError::NotSupported(Some("kick has no unique chat mode".to_string())));
+				}
+				client
+					.update_chatroom_settings(room.room_id.as_str(), settings)
+					.await
+					.map_err(map_kick_error)?
+			}
+			CommandRequest::AutoModResolve { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"kick public API has no automod review endpoint".to_string(),
+				)));
+			}
+			CommandRequest::ResolveUnbanRequest { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"kick public API has no unban request endpoint".to_string(),
+				)));
+			}
+			CommandRequest::CreatePoll { .. }
+			| CommandRequest::EndPoll { .. }
+			| CommandRequest::CreatePrediction { .. }
+			| CommandRequest::ResolvePrediction { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"kick public API has no poll or prediction endpoints".to_string(),
+				)));
+			}
+			CommandRequest::FulfillRedemption { .. } | CommandRequest::CancelRedemption { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"kick public API has no channel reward redemption endpoints".to_string(),
+				)));
+			}
 		};
 
 		Ok(())
@@ -346,6 +408,7 @@ impl KickEventAdapter {
 			can_ban: can_moderate,
 			is_moderator,
 			is_broadcaster,
+			can_manage_redemptions: false,
 		}
 	}
 
@@ -366,14 +429,23 @@ impl KickEventAdapter {
 		Duration::from_millis(delay_ms)
 	}
 
+	/// Pusher channels carrying a room's events: chat on the chatroom channel, livestream events on the channel one.
+	fn pusher_channels(&self, room: &RoomKey, chatroom_id: u64) -> Vec<String> {
+		let mut channels = vec![format!("chatrooms.{chatroom_id}.v2")];
+		if let Some(channel_id) = self.channel_id_by_room.get(room) {
+			channels.push(format!("channel.{channel_id}"));
+		}
+		channels
+	}
+
 	async fn send_pusher_subscribe(
 		&self,
 		ws_tx: &mut futures_util::stream::SplitSink<KickWs, Message>,
-		chatroom_id: u64,
+		channel: &str,
 	) -> anyhow::Result<()> {
 		let payload = serde_json::json!({
 			"event": "pusher:subscribe",
-			"data": { "auth": "", "channel": format!("chatrooms.{chatroom_id}.v2") }
+			"data": { "auth": "", "channel": channel }
 		});
 		ws_tx
 			.send(Message::Text(payload.to_string().into()))
@@ -384,11 +456,11 @@ impl KickEventAdapter {
 	async fn send_pusher_unsubscribe(
 		&self,
 		ws_tx: &mut futures_util::stream::SplitSink<KickWs, Message>,
-		chatroom_id: u64,
+		channel: &str,
 	) -> anyhow::Result<()> {
 		let payload = serde_json::json!({
 			"event": "pusher:unsubscribe",
-			"data": { "channel": format!("chatrooms.{chatroom_id}.v2") }
+			"data": { "channel": channel }
 		});
 		ws_tx
 			.send(Message::Text(payload.to_string().into()))
@@ -432,6 +504,13 @@ impl KickEventAdapter {
 						.await;
 				}
 			}
+			event @ ("App\\Events\\StreamerIsLive"
+			| "App\\Events\\StopStreamBroadcast"
+			| "App\\Events\\LivestreamUpdated") => {
+				if let Some(payload) = parse_pusher_payload::<KickWsLivestreamEvent>(envelope.data) {
+					self.handle_livestream_event(event, payload, envelope.channel.as_deref(), events_tx);
+				}
+			}
 			_ => {}
 		}
 		Ok(())
@@ -591,6 +670,28 @@ impl KickEventAdapter {
 		let _ = events_tx.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 	}
 
+	fn handle_livestream_event(
+		&self,
+		event: &str,
+		payload: KickWsLivestreamEvent,
+		channel: Option<&str>,
+		events_tx: &AdapterEventTx,
+	) {
+		let channel_id = channel
+			.and_then(channel_id_from_pusher_channel)
+			.or_else(|| payload.livestream.as_ref().and_then(|l| l.channel_id));
+		let Some(room) = channel_id.and_then(|id| self.room_by_channel_id.get(&id).cloned()) else {
+			return;
+		};
+
+		let ingest = IngestEvent::new(
+			Platform::Kick,
+			room.room_id.clone(),
+			IngestPayload::StreamStatus(stream_status_from_livestream_event(event, payload)),
+		);
+		let _ = events_tx.try_send(AdapterEvent::Ingest(Box::new(ingest)));
+	}
+
 	async fn handle_user_unbanned(&mut self, payload: KickWsUserUnban, channel: Option<&str>, events_tx: &AdapterEventTx) {
 		let chatroom_id = channel.and_then(chatroom_id_from_channel);
 		let room = chatroom_id.and_then(|id| self.room_by_chatroom_id.get(&id).cloned());
@@ -711,6 +812,67 @@ struct KickWsActor {
 	username: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct KickWsLivestreamEvent {
+	#[serde(default)]
+	livestream: Option<KickWsLivestream>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KickWsLivestream {
+	#[serde(default)]
+	channel_id: Option<u64>,
+	#[serde(default)]
+	session_title: Option<String>,
+	#[serde(default)]
+	created_at: Option<String>,
+	#[serde(default)]
+	is_live: Option<bool>,
+	#[serde(default)]
+	viewer_count: Option<u64>,
+	#[serde(default)]
+	categories: Vec<KickWsCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KickWsCategory {
+	name: String,
+}
+
+/// `StreamerIsLive` and `StopStreamBroadcast` are transitions; `LivestreamUpdated` carries `is_live` itself.
+pub(crate) fn stream_status_from_livestream_event(event: &str, payload: KickWsLivestreamEvent) -> StreamStatus {
+	let livestream = payload.livestream;
+	let live = match event {
+		"App\\Events\\StreamerIsLive" => Some(true),
+		"App\\Events\\StopStreamBroadcast" => Some(false),
+		_ => livestream.as_ref().and_then(|l| l.is_live),
+	};
+	let Some(livestream) = livestream.filter(|_| live != Some(false)) else {
+		return StreamStatus {
+			live,
+			..StreamStatus::default()
+		};
+	};
+
+	StreamStatus {
+		live,
+		started_at: livestream.created_at.as_deref().and_then(parse_kick_timestamp),
+		title: livestream.session_title,
+		category: livestream.categories.into_iter().next().map(|c| c.name),
+		viewer_count: livestream.viewer_count,
+	}
+}
+
+/// Kick sends both RFC3339 and `YYYY-MM-DD HH:MM:SS` (UTC) timestamps.
+fn parse_kick_timestamp(ts: &str) -> Option<std::time::SystemTime> {
+	if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(ts) {
+		return Some(parsed.with_timezone(&chrono::Utc).into());
+	}
+	chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S")
+		.ok()
+		.map(|naive| naive.and_utc().into())
+}
+
 fn parse_pusher_payload<T: DeserializeOwned>(data: JsonValue) -> Option<T> {
 	if let Some(s) = data.as_str() {
 		serde_json::from_str(s).ok()
@@ -764,6 +926,10 @@ fn normalize_kick_content(content: &str) -> (String, Vec<AssetRef>) {
 	(output, emotes)
 }
 
+fn channel_id_from_pusher_channel(channel: &str) -> Option<u64> {
+	channel.strip_prefix("channel.")?.parse::<u64>().ok()
+}
+
 fn chatroom_id_from_channel(channel: &str) -> Option<u64> {
 	if let Some(stripped) = channel.strip_prefix("chatrooms.") {
 		let mut parts = stripped.split('.');
@@ -784,7 +950,7 @@ impl PlatformAdapter for KickEventAdapter {
 	}
 
 	async fn run(self: Box<Self>, mut control_rx: AdapterControlRx, events_tx: AdapterEventTx) -> anyhow::Result<()> {
-		ensure_asset_cache_pruner();
+		ensure_asset_cache_pruner(self.cfg.assets.clone());
 		let mut this = *self;
 		let session_id = new_session_id();
 		let platform = this.platform();
@@ -821,10 +987,12 @@ impl PlatformAdapter for KickEventAdapter {
 				guard.iter().cloned().collect()
 			};
 			for room in rooms {
-				if let Ok(chatroom_id) = this.resolve_chatroom_id(&room).await
-					&& let Err(err) = this.send_pusher_subscribe(&mut ws_tx, chatroom_id).await
-				{
-					warn!(error = %err, chatroom_id, room = %room, "kick ws subscribe failed");
+				if let Ok(chatroom_id) = this.resolve_chatroom_id(&room).await {
+					for channel in this.pusher_channels(&room, chatroom_id) {
+						if let Err(err) = this.send_pusher_subscribe(&mut ws_tx, &channel).await {
+							warn!(error = %err, channel = %channel, room = %room, "kick ws subscribe failed");
+						}
+					}
 				}
 			}
 
@@ -888,16 +1056,18 @@ impl PlatformAdapter for KickEventAdapter {
 								}
 								drop(guard);
 
-								if inserted
-									&& let Ok(chatroom_id) = this.resolve_chatroom_id(&room).await
-									&& let Err(err) = this.send_pusher_subscribe(&mut ws_tx, chatroom_id).await
-								{
-									warn!(error = %err, chatroom_id, room = %room, "kick ws subscribe failed");
+								if inserted && let Ok(chatroom_id) = this.resolve_chatroom_id(&room).await {
+									for channel in this.pusher_channels(&room, chatroom_id) {
+										if let Err(err) = this.send_pusher_subscribe(&mut ws_tx, &channel).await {
+											warn!(error = %err, channel = %channel, room = %room, "kick ws subscribe failed");
+										}
+									}
 								}
 
 								let room_for_assets = room.clone();
 								let events_tx_spawn = events_tx.clone();
 								let seventv_subscriptions = this.seventv_subscriptions.clone();
+								let assets = this.cfg.assets.clone();
 								let broadcaster_id = this
 									.resolve_broadcaster_id(&room, &SecretString::new(String::new()))
 									.await
@@ -922,7 +1092,7 @@ impl PlatformAdapter for KickEventAdapter {
 									if let Some(id) = broadcaster_id {
 										info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, "fetching 7tv channel badges bundle (kick)");
 										if let Ok(bundle) =
-											fetch_7tv_channel_badges_bundle(SevenTvPlatform::Kick, &id.to_string()).await
+											fetch_7tv_channel_badges_bundle(&assets, SevenTvPlatform::Kick, &id.to_string()).await
 										{
 											info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 											let ingest = IngestEvent::new(
@@ -934,7 +1104,7 @@ impl PlatformAdapter for KickEventAdapter {
 										}
 
 										info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, "fetching 7tv emote set bundle (kick)");
-										match fetch_7tv_bundle_with_sets(SevenTvPlatform::Kick, &id.to_string(), SevenTvCacheMode::UseCache).await {
+										match fetch_7tv_bundle_with_sets(&assets, SevenTvPlatform::Kick, &id.to_string(), SevenTvCacheMode::UseCache).await {
 											Ok((bundle, sets)) => {
 												info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 												let ingest = IngestEvent::new(
@@ -945,18 +1115,21 @@ impl PlatformAdapter for KickEventAdapter {
 												let _ = events_tx_spawn.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 
 												let set_ids = sets.set_ids();
-												if !set_ids.is_empty() {
-													let api = ensure_seventv_event_api();
+												if !set_ids.is_empty()
+													&& let Some(api) = ensure_seventv_event_api(&assets.seventv_event_api)
+												{
 													let mut subscriptions = Vec::new();
 													for set_id in set_ids {
 														let (subscription, mut rx) = api.subscribe(DispatchType::EmoteSetUpdate, set_id.clone());
 														subscriptions.push(subscription);
 														let events_tx_updates = events_tx_spawn.clone();
 														let room_updates = room_for_assets.clone();
+														let assets_updates = assets.clone();
 														let platform_id = id.to_string();
 														tokio::spawn(async move {
 															while rx.recv().await.is_some() {
 																match fetch_7tv_bundle_with_sets(
+																	&assets_updates,
 																	SevenTvPlatform::Kick,
 																	&platform_id,
 																	SevenTvCacheMode::Refresh,
@@ -992,7 +1165,7 @@ impl PlatformAdapter for KickEventAdapter {
 										warn!(%platform, room=%room_for_assets.room_id, "kick broadcaster id unresolved; skipping 7tv asset fetches");
 									}
 
-									if let Ok(bundle) = fetch_7tv_badges_bundle().await {
+									if let Ok(bundle) = fetch_7tv_badges_bundle(&assets).await {
 										info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 										let ingest = IngestEvent::new(
 											Platform::Kick,
@@ -1002,7 +1175,7 @@ impl PlatformAdapter for KickEventAdapter {
 										let _ = events_tx_spawn.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 									}
 
-									if let Some(bundle) = fetch_kick_badge_bundle(room_for_assets.room_id.as_str()).await {
+									if let Some(bundle) = fetch_kick_badge_bundle(&assets, room_for_assets.room_id.as_str()).await {
 										info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 										let ingest = IngestEvent::new(
 											Platform::Kick,
@@ -1012,7 +1185,7 @@ impl PlatformAdapter for KickEventAdapter {
 										let _ = events_tx_spawn.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 									}
 
-									let bundles = fetch_kick_emote_bundles(room_for_assets.room_id.as_str()).await;
+									let bundles = fetch_kick_emote_bundles(&assets, room_for_assets.room_id.as_str()).await;
 									for bundle in bundles {
 										info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, scope=?bundle.scope, "emitting Kick emote bundle");
 										let ingest = IngestEvent::new(
@@ -1035,11 +1208,17 @@ impl PlatformAdapter for KickEventAdapter {
 								let _ = events_tx.try_send(status(platform, true, detail));
 							}
 							drop(guard);
-							if let Some((chatroom_id, _)) = this.chatroom_id_by_room.remove(&room) {
-								this.room_by_chatroom_id.remove(&chatroom_id);
-								if let Err(err) = this.send_pusher_unsubscribe(&mut ws_tx, chatroom_id).await {
-									warn!(error = %err, chatroom_id, room = %room, "kick ws unsubscribe failed");
+							if let Some((chatroom_id, _)) = this.chatroom_id_by_room.get(&room).copied() {
+								for channel in this.pusher_channels(&room, chatroom_id) {
+									if let Err(err) = this.send_pusher_unsubscribe(&mut ws_tx, &channel).await {
+										warn!(error = %err, channel = %channel, room = %room, "kick ws unsubscribe failed");
+									}
 								}
+								this.chatroom_id_by_room.remove(&room);
+								this.room_by_chatroom_id.remove(&chatroom_id);
+							}
+							if let Some(channel_id) = this.channel_id_by_room.remove(&room) {
+								this.room_by_channel_id.remove(&channel_id);
 							}
 
 							if let Some(subscriptions) = this.seventv_subscriptions.write().await.remove(&room) {
@@ -1063,6 +1242,11 @@ impl PlatformAdapter for KickEventAdapter {
 							let result = this.permissions_for_room(&room, auth).await;
 							let _ = resp.send(result);
 						}
+						AdapterControl::ListUnbanRequests { resp, .. } => {
+							let _ = resp.send(Err(CommandError::NotSupported(Some(
+								"kick public API has no unban request endpoint".to_string(),
+							))));
+						}
 						AdapterControl::QueryAuth { resp } => {
 							let _ = resp.send(None);
 						}
@@ -4,6 +4,8 @@ use anyhow::{Context, anyhow};
 use reqwest::StatusCode;
 use serde::Deserialize;
 
+use crate::RoomChatSettings;
+
 #[derive(Debug, Clone)]
 pub struct KickClient {
 	base_url: String,
@@ -114,6 +116,51 @@ impl KickClient {
 		}
 	}
 
+	/// Lifts a ban or an active timeout.
+	pub async fn unban_user(&self, broadcaster_user_id: u64, user_id: u64) -> anyhow::Result<()> {
+		let url = format!("{}/public/v1/moderation/bans", self.base_url.trim_end_matches('/'));
+		let body = KickBanRequest {
+			broadcaster_user_id,
+			user_id,
+			duration: None,
+			reason: None,
+		};
+		let resp = self
+			.client
+			.delete(url)
+			.header("Authorization", self.auth_header()?)
+			.json(&body)
+			.send()
+			.await
+			.context("kick unban user")?;
+
+		match resp.status() {
+			StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+			status => Err(anyhow!("kick unban user failed: status={}", status)),
+		}
+	}
+
+	/// Updates chat modes through the chatroom endpoint used by the kick.com web client.
+	pub async fn update_chatroom_settings(&self, slug: &str, settings: &RoomChatSettings) -> anyhow::Result<()> {
+		let url = format!("https://kick.com/api/v2/channels/{}/chatroom", urlencoding::encode(slug));
+		let body = KickChatroomSettingsRequest::from(settings);
+		let resp = self
+			.client
+			.put(url)
+			.header("Authorization", self.auth_header()?)
+			.header("Accept", "application/json")
+			.header("User-Agent", "chatty-server/0.1")
+			.json(&body)
+			.send()
+			.await
+			.context("kick update chatroom settings")?;
+
+		match resp.status() {
+			status if status.is_success() => Ok(()),
+			status => Err(anyhow!("kick update chatroom settings failed: status={}", status)),
+		}
+	}
+
 	pub async fn resolve_broadcaster_id(&self, slug: &str) -> anyhow::Result<Option<u64>> {
 		let url = format!(
 			"{}/public/v1/channels?slug={}",
@@ -154,7 +201,8 @@ impl KickClient {
 		self.resolve_broadcaster_id_v2(slug).await
 	}
 
-	pub async fn resolve_chatroom_id(&self, slug: &str) -> anyhow::Result<Option<u64>> {
+	/// Chatroom of a channel slug, with the channel id its livestream events are published under.
+	pub async fn resolve_chatroom(&self, slug: &str) -> anyhow::Result<Option<KickChatroom>> {
 		let url = format!("https://kick.com/api/v2/channels/{}/chatroom", urlencoding::encode(slug));
 		let resp = self
 			.client
@@ -173,7 +221,10 @@ impl KickClient {
 		}
 
 		let body: KickChatroomResponse = resp.json().await.context("parse kick chatroom response")?;
-		Ok(Some(body.id))
+		Ok(Some(KickChatroom {
+			id: body.id,
+			channel_id: body.channel_id,
+		}))
 	}
 
 	async fn resolve_broadcaster_id_v2(&self, slug: &str) -> anyhow::Result<Option<u64>> {
@@ -255,6 +306,35 @@ struct KickBanRequest {
 	reason: Option<String>,
 }
 
+#[derive(Debug, serde::Serialize)]
+struct KickChatroomSettingsRequest {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	emotes_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	subscribers_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	slow_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	message_interval: Option<u64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	followers_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	following_min_duration: Option<u64>,
+}
+
+impl From<&RoomChatSettings> for KickChatroomSettingsRequest {
+	fn from(s: &RoomChatSettings) -> Self {
+		Self {
+			emotes_mode: s.emote_only,
+			subscribers_mode: s.subscribers_only,
+			slow_mode: s.slow_mode,
+			message_interval: s.slow_mode_wait_time_seconds,
+			followers_mode: s.followers_only,
+			following_min_duration: s.followers_only_duration_minutes,
+		}
+	}
+}
+
 #[derive(Debug, Deserialize)]
 struct KickChannelsResponse {
 	data: Vec<KickChannelData>,
@@ -290,6 +370,14 @@ pub struct KickTokenIntrospection {
 #[derive(Debug, Deserialize)]
 struct KickChatroomResponse {
 	id: u64,
+	#[serde(default)]
+	channel_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct KickChatroom {
+	pub id: u64,
+	pub channel_id: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
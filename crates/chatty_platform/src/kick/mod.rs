@@ -2,6 +2,8 @@
 
 mod adapter;
 mod client;
+#[cfg(test)]
+mod tests;
 
 pub use adapter::{KickConfig, KickEventAdapter};
 use anyhow::{Context as _, anyhow};
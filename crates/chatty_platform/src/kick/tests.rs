@@ -0,0 +1,71 @@
+#![forbid(unsafe_code)]
+
+use std::time::{Duration, SystemTime};
+
+use serde_json::json;
+
+use super::adapter::{KickWsLivestreamEvent, stream_status_from_livestream_event};
+
+fn livestream_event(data: serde_json::Value) -> KickWsLivestreamEvent {
+	serde_json::from_value(data).expect("livestream payload")
+}
+
+#[test]
+fn streamer_is_live_starts_the_stream() {
+	let status = stream_status_from_livestream_event(
+		"App\\Events\\StreamerIsLive",
+		livestream_event(json!({
+			"livestream": {
+				"id": 1,
+				"channel_id": 42,
+				"session_title": "late night",
+				"created_at": "2024-01-01T00:00:00.000000Z"
+			}
+		})),
+	);
+
+	assert_eq!(status.live, Some(true));
+	assert_eq!(status.title.as_deref(), Some("late night"));
+	assert_eq!(
+		status.started_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200))
+	);
+	assert_eq!(status.viewer_count, None);
+}
+
+#[test]
+fn stop_stream_broadcast_drops_stream_metadata() {
+	let status = stream_status_from_livestream_event(
+		"App\\Events\\StopStreamBroadcast",
+		livestream_event(json!({ "livestream": { "id": 1, "channel": { "id": 42, "is_banned": false } } })),
+	);
+
+	assert_eq!(status.live, Some(false));
+	assert!(status.started_at.is_none());
+	assert!(status.title.is_none());
+}
+
+#[test]
+fn livestream_updated_reports_category_and_viewers() {
+	let status = stream_status_from_livestream_event(
+		"App\\Events\\LivestreamUpdated",
+		livestream_event(json!({
+			"livestream": {
+				"channel_id": 42,
+				"session_title": "ranked",
+				"created_at": "2024-01-01 00:00:00",
+				"is_live": true,
+				"viewer_count": 321,
+				"categories": [{ "id": 7, "name": "Just Chatting" }]
+			}
+		})),
+	);
+
+	assert_eq!(status.live, Some(true));
+	assert_eq!(status.category.as_deref(), Some("Just Chatting"));
+	assert_eq!(status.viewer_count, Some(321));
+	assert_eq!(
+		status.started_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200))
+	);
+}
@@ -3,6 +3,10 @@
 pub mod assets;
 pub mod kick;
 pub mod twitch;
+pub mod youtube;
+
+#[cfg(test)]
+mod test_http;
 
 use std::collections::BTreeMap;
 use std::fmt;
@@ -46,6 +50,14 @@ pub enum AdapterControl {
 		resp: oneshot::Sender<PermissionsInfo>,
 	},
 
+	/// List a room's unban requests with the given status (e.g. `pending`).
+	ListUnbanRequests {
+		room: RoomKey,
+		status: String,
+		auth: Option<AdapterAuth>,
+		resp: oneshot::Sender<Result<Vec<UnbanRequest>, CommandError>>,
+	},
+
 	/// Query current adapter auth snapshot (best-effort).
 	QueryAuth {
 		resp: oneshot::Sender<Option<AdapterAuth>>,
@@ -78,6 +90,90 @@ pub enum CommandRequest {
 		user_id: String,
 		reason: Option<String>,
 	},
+	UnbanUser {
+		room: RoomKey,
+		user_id: String,
+	},
+	UntimeoutUser {
+		room: RoomKey,
+		user_id: String,
+	},
+	ClearChat {
+		room: RoomKey,
+	},
+	ClearUserMessages {
+		room: RoomKey,
+		user_id: String,
+	},
+	/// Apply the fields that are set; `None` fields keep their current value.
+	UpdateRoomSettings {
+		room: RoomKey,
+		settings: RoomChatSettings,
+	},
+	/// Approve (`allow`) or deny a message held for review by AutoMod.
+	AutoModResolve {
+		room: RoomKey,
+		message_id: String,
+		allow: bool,
+	},
+	/// Approve (lifting the ban) or deny a banned user's unban request.
+	ResolveUnbanRequest {
+		room: RoomKey,
+		request_id: String,
+		approve: bool,
+		resolution_text: Option<String>,
+	},
+	/// Start a poll; Twitch accepts 2-5 choices and a 15-1800 second duration.
+	CreatePoll {
+		room: RoomKey,
+		title: String,
+		choices: Vec<String>,
+		duration_seconds: u32,
+	},
+	/// End an active poll early; `archive` also hides its results from viewers.
+	EndPoll {
+		room: RoomKey,
+		poll_id: String,
+		archive: bool,
+	},
+	/// Start a prediction; Twitch accepts 2-10 outcomes and a 30-1800 second window.
+	CreatePrediction {
+		room: RoomKey,
+		title: String,
+		outcomes: Vec<String>,
+		prediction_window_seconds: u32,
+	},
+	/// Pay out a prediction to `winning_outcome_id`, or cancel it and refund points when `None`.
+	ResolvePrediction {
+		room: RoomKey,
+		prediction_id: String,
+		winning_outcome_id: Option<String>,
+	},
+	/// Mark a channel points redemption as fulfilled; only unfulfilled redemptions can be updated.
+	FulfillRedemption {
+		room: RoomKey,
+		reward_id: String,
+		redemption_id: String,
+	},
+	/// Reject a channel points redemption and refund the points to the viewer.
+	CancelRedemption {
+		room: RoomKey,
+		reward_id: String,
+		redemption_id: String,
+	},
+}
+
+/// A banned user's request to be unbanned.
+#[derive(Debug, Clone)]
+pub struct UnbanRequest {
+	pub id: String,
+	pub user: UserRef,
+	pub text: Option<String>,
+	/// `pending`, `approved`, `denied`, `acknowledged` or `canceled`.
+	pub status: String,
+	pub created_at: Option<SystemTime>,
+	pub resolved_by: Option<UserRef>,
+	pub resolution_text: Option<String>,
 }
 
 /// Permission snapshot for a room.
@@ -90,17 +186,36 @@ pub struct PermissionsInfo {
 	pub can_ban: bool,
 	pub is_moderator: bool,
 	pub is_broadcaster: bool,
+	/// Whether channel points redemptions can be fulfilled or canceled with this auth.
+	pub can_manage_redemptions: bool,
 }
 
 impl CommandRequest {
-	pub fn platform(&self) -> Platform {
+	pub fn room(&self) -> &RoomKey {
 		match self {
 			Self::SendChat { room, .. }
 			| Self::DeleteMessage { room, .. }
 			| Self::TimeoutUser { room, .. }
-			| Self::BanUser { room, .. } => room.platform,
+			| Self::BanUser { room, .. }
+			| Self::UnbanUser { room, .. }
+			| Self::UntimeoutUser { room, .. }
+			| Self::ClearChat { room }
+			| Self::ClearUserMessages { room, .. }
+			| Self::UpdateRoomSettings { room, .. }
+			| Self::AutoModResolve { room, .. }
+			| Self::ResolveUnbanRequest { room, .. }
+			| Self::CreatePoll { room, .. }
+			| Self::EndPoll { room, .. }
+			| Self::CreatePrediction { room, .. }
+			| Self::ResolvePrediction { room, .. }
+			| Self::FulfillRedemption { room, .. }
+			| Self::CancelRedemption { room, .. } => room,
 		}
 	}
+
+	pub fn platform(&self) -> Platform {
+		self.room().platform
+	}
 }
 
 /// Command execution errors.
@@ -284,6 +399,18 @@ pub enum IngestPayload {
 
 	/// Room state changes.
 	RoomState(RoomState),
+
+	/// Live/offline transitions and stream metadata.
+	StreamStatus(StreamStatus),
+
+	/// Poll or prediction lifecycle updates with current tallies.
+	Poll(Poll),
+
+	/// Channel points custom reward redemptions and their status changes.
+	Redemption(Redemption),
+
+	/// Hype train, creator goal or charity campaign progress.
+	Progress(Progress),
 }
 
 /// Asset provider identifiers.
@@ -488,6 +615,9 @@ pub enum ModerationAction {
 		user: Option<UserRef>,
 		#[serde(default)]
 		reason: Option<String>,
+		/// Text of the held message, so it can be reviewed without the original chat event.
+		#[serde(default)]
+		text: Option<String>,
 	},
 	/// An automod message's status was updated (allowed/denied/etc).
 	AutoModUpdate {
@@ -584,6 +714,161 @@ pub struct RoomChatSettings {
 	pub followers_only_duration_minutes: Option<u64>,
 }
 
+/// Normalized stream status snapshot or delta; `None` fields are unknown or unchanged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StreamStatus {
+	#[serde(default)]
+	pub live: Option<bool>,
+
+	/// When the current broadcast started; only meaningful while live.
+	#[serde(default)]
+	pub started_at: Option<SystemTime>,
+
+	#[serde(default)]
+	pub title: Option<String>,
+
+	/// Game/category name.
+	#[serde(default)]
+	pub category: Option<String>,
+
+	/// Only known when polled; push events do not carry it.
+	#[serde(default)]
+	pub viewer_count: Option<u64>,
+}
+
+/// Whether a [`Poll`] is a regular poll or a channel points prediction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PollKind {
+	Poll,
+	Prediction,
+}
+
+/// Lifecycle state of a poll or prediction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PollStatus {
+	/// Accepting votes or predictions.
+	Active,
+	/// Prediction no longer accepts predictions and waits to be resolved.
+	Locked,
+	/// Voting finished, or the prediction was resolved; results stay visible.
+	Ended,
+	/// Poll archived or prediction canceled; nothing should be shown anymore.
+	Canceled,
+}
+
+/// Normalized poll or prediction snapshot; every update carries the full set of choices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poll {
+	pub id: String,
+	pub kind: PollKind,
+	pub title: String,
+	pub status: PollStatus,
+
+	#[serde(default)]
+	pub choices: Vec<PollChoice>,
+
+	/// Set once a prediction is resolved.
+	#[serde(default)]
+	pub winning_choice_id: Option<String>,
+
+	#[serde(default)]
+	pub started_at: Option<SystemTime>,
+
+	/// When voting closes (polls) or predictions lock (predictions).
+	#[serde(default)]
+	pub ends_at: Option<SystemTime>,
+}
+
+/// A poll choice or prediction outcome with its tally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollChoice {
+	pub id: String,
+	pub title: String,
+
+	/// Poll votes, or the number of users who predicted this outcome.
+	#[serde(default)]
+	pub votes: u64,
+
+	/// Channel points spent on this choice.
+	#[serde(default)]
+	pub channel_points: u64,
+}
+
+/// Fulfillment state of a channel points redemption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedemptionStatus {
+	/// Waiting in the reward queue.
+	Unfulfilled,
+	Fulfilled,
+	/// Rejected; the points were refunded.
+	Canceled,
+}
+
+/// Normalized channel points custom reward redemption.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Redemption {
+	pub id: String,
+	pub reward_id: String,
+	pub reward_title: String,
+
+	/// Channel points spent.
+	pub cost: u64,
+
+	pub user: UserRef,
+
+	/// Text the viewer entered, for rewards that ask for input.
+	#[serde(default)]
+	pub user_input: Option<String>,
+
+	pub status: RedemptionStatus,
+
+	#[serde(default)]
+	pub redeemed_at: Option<SystemTime>,
+}
+
+/// What a [`Progress`] update tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressKind {
+	HypeTrain,
+	Goal,
+	CharityCampaign,
+}
+
+/// Normalized progress towards a target; every update carries the full snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Progress {
+	pub id: String,
+	pub kind: ProgressKind,
+
+	/// Hype train level; unset for goals and charity campaigns.
+	#[serde(default)]
+	pub level: Option<u32>,
+
+	/// Goal description or charity name.
+	#[serde(default)]
+	pub title: Option<String>,
+
+	/// Progress towards `target`; for hype trains this is within the current level.
+	pub current: u64,
+	pub target: u64,
+
+	/// ISO 4217 code when amounts are money (charity campaigns, in whole units).
+	#[serde(default)]
+	pub currency: Option<String>,
+
+	/// When a hype train runs out unless it gets more contributions.
+	#[serde(default)]
+	pub expires_at: Option<SystemTime>,
+
+	/// The train, goal or campaign is over and should no longer be shown.
+	#[serde(default)]
+	pub ended: bool,
+}
+
 /// Adapter status event.
 #[derive(Debug, Clone)]
 pub struct AdapterStatus {
@@ -0,0 +1,109 @@
+#![forbid(unsafe_code)]
+
+//! Minimal HTTP/1.1 fixture server for exercising HTTP clients in tests.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+
+#[derive(Debug, Clone)]
+pub(crate) struct FixtureRequest {
+	pub(crate) method: String,
+	pub(crate) target: String,
+	pub(crate) authorization: Option<String>,
+	pub(crate) body: String,
+}
+
+impl FixtureRequest {
+	pub(crate) fn path(&self) -> &str {
+		self.target.split('?').next().unwrap_or_default()
+	}
+}
+
+/// Serve `route` on a loopback port; every request is recorded before it is answered.
+pub(crate) async fn spawn_fixture_server<F>(route: F) -> (SocketAddr, Arc<Mutex<Vec<FixtureRequest>>>)
+where
+	F: Fn(&FixtureRequest) -> (&'static str, String) + Send + Sync + 'static,
+{
+	let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind fixture server");
+	let addr = listener.local_addr().expect("fixture server addr");
+	let recorded = Arc::new(Mutex::new(Vec::new()));
+	let recorded_task = recorded.clone();
+	let route = Arc::new(route);
+
+	tokio::spawn(async move {
+		loop {
+			let Ok((mut socket, _)) = listener.accept().await else {
+				break;
+			};
+			let recorded = recorded_task.clone();
+			let route = route.clone();
+			tokio::spawn(async move {
+				let Some(req) = read_request(&mut socket).await else {
+					return;
+				};
+				let (status, body) = route(&req);
+				recorded.lock().await.push(req);
+				let resp = format!(
+					"HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+					body.len()
+				);
+				let _ = socket.write_all(resp.as_bytes()).await;
+				let _ = socket.shutdown().await;
+			});
+		}
+	});
+
+	(addr, recorded)
+}
+
+async fn read_request(socket: &mut TcpStream) -> Option<FixtureRequest> {
+	let mut buf = Vec::new();
+	let mut chunk = [0u8; 4096];
+	let header_end = loop {
+		let n = socket.read(&mut chunk).await.ok()?;
+		if n == 0 {
+			return None;
+		}
+		buf.extend_from_slice(&chunk[..n]);
+		if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+			break pos + 4;
+		}
+	};
+
+	let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+	let mut lines = head.lines();
+	let mut request_line = lines.next()?.split_whitespace();
+	let method = request_line.next()?.to_string();
+	let target = request_line.next()?.to_string();
+	let mut content_length = 0usize;
+	let mut authorization = None;
+	for line in lines {
+		if let Some((name, value)) = line.split_once(':') {
+			match name.trim().to_ascii_lowercase().as_str() {
+				"content-length" => content_length = value.trim().parse().unwrap_or(0),
+				"authorization" => authorization = Some(value.trim().to_string()),
+				_ => {}
+			}
+		}
+	}
+
+	while buf.len() < header_end + content_length {
+		let n = socket.read(&mut chunk).await.ok()?;
+		if n == 0 {
+			break;
+		}
+		buf.extend_from_slice(&chunk[..n]);
+	}
+	let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+	Some(FixtureRequest {
+		method,
+		target,
+		authorization,
+		body,
+	})
+}
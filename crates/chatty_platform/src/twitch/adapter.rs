@@ -15,10 +15,13 @@ use tokio_tungstenite::tungstenite::protocol::Message;
 use tracing::{debug, info, warn};
 use url::Url;
 
-use super::helix::{HelixClient, HelixCreateSubscriptionResponse, HelixSubscriptionData, refresh_user_token};
+use super::helix::{
+	HelixClient, HelixCreateSubscriptionResponse, HelixStream, HelixSubscriptionData, HelixUnbanRequest,
+	STREAMS_MAX_USER_IDS, refresh_user_token,
+};
 use super::{eventsub, notifications};
 use crate::assets::{
-	DispatchType, SevenTvCacheMode, SevenTvPlatform, SevenTvSubscription, ensure_asset_cache_pruner,
+	AssetProviderConfig, DispatchType, SevenTvCacheMode, SevenTvPlatform, SevenTvSubscription, ensure_asset_cache_pruner,
 	ensure_seventv_event_api, fetch_7tv_badges_bundle, fetch_7tv_bundle_with_sets, fetch_7tv_channel_badges_bundle,
 	fetch_bttv_badges_bundle, fetch_bttv_bundle, fetch_bttv_global_emotes_bundle, fetch_ffz_badges_bundle, fetch_ffz_bundle,
 	fetch_ffz_global_emotes_bundle, fetch_twitch_badges_bundle, fetch_twitch_channel_badges_bundle,
@@ -27,7 +30,7 @@ use crate::assets::{
 use crate::{
 	AdapterAuth, AdapterControl, AdapterControlRx, AdapterEvent, AdapterEventTx, AssetBundle, AssetProvider, AssetScope,
 	ChatMessage, CommandError, CommandRequest, IngestEvent, IngestMessageIds, IngestPayload, IngestTrace, PermissionsInfo,
-	PlatformAdapter, SecretString, new_session_id, status, status_error,
+	PlatformAdapter, SecretString, StreamStatus, UnbanRequest, UserRef, new_session_id, status, status_error,
 };
 
 pub(crate) type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
@@ -38,23 +41,156 @@ pub(crate) type WsConnector = Arc<dyn Fn(Url) -> BoxFuture<'static, anyhow::Resu
 enum TwitchSubscriptionType {
 	ChatMessage,
 	ChatMessageDelete,
+	ChatNotification,
+	ChatClear,
+	ChatClearUserMessages,
 	ChannelBan,
 	ChannelModerate,
 	ChannelRaid,
 	ChannelCheer,
 	ChannelSubscribe,
+	AutoModMessageHold,
+	AutoModMessageUpdate,
+	UnbanRequestCreate,
+	UnbanRequestResolve,
+	StreamOnline,
+	StreamOffline,
+	ChannelUpdate,
+	PollBegin,
+	PollProgress,
+	PollEnd,
+	PredictionBegin,
+	PredictionProgress,
+	PredictionLock,
+	PredictionEnd,
+	RedemptionAdd,
+	RedemptionUpdate,
+	HypeTrainBegin,
+	HypeTrainProgress,
+	HypeTrainEnd,
+	GoalBegin,
+	GoalProgress,
+	GoalEnd,
+	CharityCampaignStart,
+	CharityCampaignProgress,
+	CharityCampaignStop,
 }
 
 impl TwitchSubscriptionType {
+	const ALL: [Self; 35] = [
+		Self::ChatMessage,
+		Self::ChatMessageDelete,
+		Self::ChatNotification,
+		Self::ChatClear,
+		Self::ChatClearUserMessages,
+		Self::ChannelBan,
+		Self::ChannelModerate,
+		Self::ChannelRaid,
+		Self::ChannelCheer,
+		Self::ChannelSubscribe,
+		Self::AutoModMessageHold,
+		Self::AutoModMessageUpdate,
+		Self::UnbanRequestCreate,
+		Self::UnbanRequestResolve,
+		Self::StreamOnline,
+		Self::StreamOffline,
+		Self::ChannelUpdate,
+		Self::PollBegin,
+		Self::PollProgress,
+		Self::PollEnd,
+		Self::PredictionBegin,
+		Self::PredictionProgress,
+		Self::PredictionLock,
+		Self::PredictionEnd,
+		Self::RedemptionAdd,
+		Self::RedemptionUpdate,
+		Self::HypeTrainBegin,
+		Self::HypeTrainProgress,
+		Self::HypeTrainEnd,
+		Self::GoalBegin,
+		Self::GoalProgress,
+		Self::GoalEnd,
+		Self::CharityCampaignStart,
+		Self::CharityCampaignProgress,
+		Self::CharityCampaignStop,
+	];
+
+	/// Whether Twitch only accepts the subscription from a moderator or the broadcaster.
+	fn requires_moderator(&self) -> bool {
+		matches!(
+			self,
+			Self::ChannelBan
+				| Self::ChannelModerate
+				| Self::ChatMessageDelete
+				| Self::AutoModMessageHold
+				| Self::AutoModMessageUpdate
+				| Self::UnbanRequestCreate
+				| Self::UnbanRequestResolve
+		)
+	}
+
+	/// Whether Twitch only accepts the subscription with the broadcaster's own token.
+	fn requires_broadcaster(&self) -> bool {
+		matches!(
+			self,
+			Self::PollBegin
+				| Self::PollProgress
+				| Self::PollEnd
+				| Self::PredictionBegin
+				| Self::PredictionProgress
+				| Self::PredictionLock
+				| Self::PredictionEnd
+				| Self::RedemptionAdd
+				| Self::RedemptionUpdate
+				| Self::HypeTrainBegin
+				| Self::HypeTrainProgress
+				| Self::HypeTrainEnd
+				| Self::GoalBegin
+				| Self::GoalProgress
+				| Self::GoalEnd
+				| Self::CharityCampaignStart
+				| Self::CharityCampaignProgress
+				| Self::CharityCampaignStop
+		)
+	}
+
 	fn as_helix_type(&self) -> &'static str {
 		match self {
 			Self::ChatMessage => "channel.chat.message",
 			Self::ChatMessageDelete => "channel.chat.message_delete",
+			Self::ChatNotification => "channel.chat.notification",
+			Self::ChatClear => "channel.chat.clear",
+			Self::ChatClearUserMessages => "channel.chat.clear_user_messages",
 			Self::ChannelBan => "channel.ban",
 			Self::ChannelModerate => "channel.moderate",
 			Self::ChannelRaid => "channel.raid",
 			Self::ChannelCheer => "channel.cheer",
 			Self::ChannelSubscribe => "channel.subscribe",
+			Self::AutoModMessageHold => "automod.message.hold",
+			Self::AutoModMessageUpdate => "automod.message.update",
+			Self::UnbanRequestCreate => "channel.unban_request.create",
+			Self::UnbanRequestResolve => "channel.unban_request.resolve",
+			Self::StreamOnline => "stream.online",
+			Self::StreamOffline => "stream.offline",
+			Self::ChannelUpdate => "channel.update",
+			Self::PollBegin => "channel.poll.begin",
+			Self::PollProgress => "channel.poll.progress",
+			Self::PollEnd => "channel.poll.end",
+			Self::PredictionBegin => "channel.prediction.begin",
+			Self::PredictionProgress => "channel.prediction.progress",
+			Self::PredictionLock => "channel.prediction.lock",
+			Self::PredictionEnd => "channel.prediction.end",
+			Self::RedemptionAdd => "channel.channel_points_custom_reward_redemption.add",
+			Self::RedemptionUpdate => "channel.channel_points_custom_reward_redemption.update",
+			Self::HypeTrainBegin => "channel.hype_train.begin",
+			Self::HypeTrainProgress => "channel.hype_train.progress",
+			Self::HypeTrainEnd => "channel.hype_train.end",
+			Self::GoalBegin => "channel.goal.begin",
+			Self::GoalProgress => "channel.goal.progress",
+			Self::GoalEnd => "channel.goal.end",
+			Self::CharityCampaignStart => "channel.charity_campaign.start",
+			Self::CharityCampaignProgress => "channel.charity_campaign.progress",
+			Self::CharityCampaignStop => "channel.charity_campaign.stop",
 		}
 	}
 }
@@ -81,18 +217,71 @@ fn condition_matches(
 	user_id: &str,
 ) -> bool {
 	match sub_type {
-		TwitchSubscriptionType::ChatMessage | TwitchSubscriptionType::ChatMessageDelete => {
+		TwitchSubscriptionType::ChatMessage
+		| TwitchSubscriptionType::ChatMessageDelete
+		| TwitchSubscriptionType::ChatNotification
+		| TwitchSubscriptionType::ChatClear
+		| TwitchSubscriptionType::ChatClearUserMessages => {
 			condition_value(condition, "broadcaster_user_id") == broadcaster_user_id
 				&& condition_value(condition, "user_id") == user_id
 		}
-		TwitchSubscriptionType::ChannelModerate => {
+		TwitchSubscriptionType::ChannelModerate
+		| TwitchSubscriptionType::AutoModMessageHold
+		| TwitchSubscriptionType::AutoModMessageUpdate
+		| TwitchSubscriptionType::UnbanRequestCreate
+		| TwitchSubscriptionType::UnbanRequestResolve => {
 			condition_value(condition, "broadcaster_user_id") == broadcaster_user_id
 				&& condition_value(condition, "moderator_user_id") == user_id
 		}
 		TwitchSubscriptionType::ChannelRaid => condition_value(condition, "to_broadcaster_user_id") == broadcaster_user_id,
 		TwitchSubscriptionType::ChannelBan
 		| TwitchSubscriptionType::ChannelCheer
-		| TwitchSubscriptionType::ChannelSubscribe => condition_value(condition, "broadcaster_user_id") == broadcaster_user_id,
+		| TwitchSubscriptionType::ChannelSubscribe
+		| TwitchSubscriptionType::StreamOnline
+		| TwitchSubscriptionType::StreamOffline
+		| TwitchSubscriptionType::ChannelUpdate
+		| TwitchSubscriptionType::PollBegin
+		| TwitchSubscriptionType::PollProgress
+		| TwitchSubscriptionType::PollEnd
+		| TwitchSubscriptionType::PredictionBegin
+		| TwitchSubscriptionType::PredictionProgress
+		| TwitchSubscriptionType::PredictionLock
+		| TwitchSubscriptionType::PredictionEnd
+		| TwitchSubscriptionType::RedemptionAdd
+		| TwitchSubscriptionType::RedemptionUpdate
+		| TwitchSubscriptionType::HypeTrainBegin
+		| TwitchSubscriptionType::HypeTrainProgress
+		| TwitchSubscriptionType::HypeTrainEnd
+		| TwitchSubscriptionType::GoalBegin
+		| TwitchSubscriptionType::GoalProgress
+		| TwitchSubscriptionType::GoalEnd
+		| TwitchSubscriptionType::CharityCampaignStart
+		| TwitchSubscriptionType::CharityCampaignProgress
+		| TwitchSubscriptionType::CharityCampaignStop => condition_value(condition, "broadcaster_user_id") == broadcaster_user_id,
+	}
+}
+
+fn map_unban_request(r: HelixUnbanRequest) -> UnbanRequest {
+	let resolved_by = r.moderator_id.filter(|id| !id.is_empty()).map(|id| UserRef {
+		id,
+		login: r.moderator_login.unwrap_or_default(),
+		display: r.moderator_name,
+	});
+	UnbanRequest {
+		id: r.id,
+		user: UserRef {
+			id: r.user_id,
+			login: r.user_login,
+			display: r.user_name,
+		},
+		text: r.text.filter(|t| !t.is_empty()),
+		status: r.status,
+		created_at: r
+			.created_at
+			.as_deref()
+			.and_then(|ts| eventsub::parse_message_timestamp_system_time(ts).ok()),
+		resolved_by,
+		resolution_text: r.resolution_text.filter(|t| !t.is_empty()),
 	}
 }
 
@@ -129,6 +318,12 @@ struct BackpressureState {
 	last_report: Instant,
 }
 
+/// Helix client and user a command or permission query runs as.
+struct CommandIdentity {
+	helix: HelixClient,
+	user_id: String,
+}
+
 /// Twitch EventSub adapter configuration.
 #[derive(Clone)]
 pub struct TwitchConfig {
@@ -146,6 +341,10 @@ pub struct TwitchConfig {
 	pub migration_buffer_capacity: usize,
 	pub ws_connector: Option<WsConnector>,
 	pub mod_status_refresh_interval: Duration,
+	/// How often joined rooms are polled via Helix `get streams` for viewer counts and missed live transitions.
+	pub stream_status_poll_interval: Duration,
+	/// Endpoints, enable flags and cache TTLs for the third-party emote/badge fetchers.
+	pub assets: Arc<AssetProviderConfig>,
 }
 
 impl TwitchConfig {
@@ -165,6 +364,8 @@ impl TwitchConfig {
 			migration_buffer_capacity: 256,
 			ws_connector: None,
 			mod_status_refresh_interval: Duration::from_secs(60),
+			stream_status_poll_interval: Duration::from_secs(60),
+			assets: Arc::new(AssetProviderConfig::new()),
 		}
 	}
 }
@@ -177,13 +378,14 @@ pub struct TwitchEventSubAdapter {
 	token_user_id: Option<String>,
 	token_username: Option<String>,
 	subscription_id_by_room_and_type: HashMap<(RoomKey, TwitchSubscriptionType), String>,
-	is_token_user_mod_by_room: HashMap<RoomKey, bool>,
-	last_mod_status_refresh_by_room: HashMap<RoomKey, Instant>,
+	is_mod_by_room_and_user: HashMap<(RoomKey, String), bool>,
+	last_mod_status_refresh_by_room_and_user: HashMap<(RoomKey, String), Instant>,
 	seventv_subscriptions: Arc<RwLock<HashMap<RoomKey, Vec<SevenTvSubscription>>>>,
 	auth_expires_at: Option<SystemTime>,
 	last_auth_error_notice: Option<String>,
 	last_refresh_attempt: Option<Instant>,
 	helix_circuit_breaker: CircuitBreaker,
+	stream_status_poll_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 #[derive(Debug)]
@@ -252,13 +454,14 @@ impl TwitchEventSubAdapter {
 			token_user_id: None,
 			token_username: None,
 			subscription_id_by_room_and_type: HashMap::new(),
-			is_token_user_mod_by_room: HashMap::new(),
-			last_mod_status_refresh_by_room: HashMap::new(),
+			is_mod_by_room_and_user: HashMap::new(),
+			last_mod_status_refresh_by_room_and_user: HashMap::new(),
 			seventv_subscriptions: Arc::new(RwLock::new(HashMap::new())),
 			auth_expires_at: None,
 			last_auth_error_notice: None,
 			last_refresh_attempt: None,
 			helix_circuit_breaker: CircuitBreaker::new(),
+			stream_status_poll_task: None,
 		}
 	}
 
@@ -313,8 +516,8 @@ impl TwitchEventSubAdapter {
 			} => {
 				self.cfg.user_access_token = access_token;
 				self.token_user_id = None;
-				self.is_token_user_mod_by_room.clear();
-				self.last_mod_status_refresh_by_room.clear();
+				self.is_mod_by_room_and_user.clear();
+				self.last_mod_status_refresh_by_room_and_user.clear();
 				self.auth_expires_at = expires_in.and_then(|d| SystemTime::now().checked_add(d));
 				self.last_auth_error_notice = None;
 			}
@@ -338,20 +541,29 @@ impl TwitchEventSubAdapter {
 					.or(self.auth_expires_at);
 				self.last_auth_error_notice = None;
 
-				self.is_token_user_mod_by_room.clear();
-				self.last_mod_status_refresh_by_room.clear();
+				self.is_mod_by_room_and_user.clear();
+				self.last_mod_status_refresh_by_room_and_user.clear();
 			}
 			AdapterAuth::AppAccessToken { .. } => {}
 			AdapterAuth::OpaqueJson(_) => {}
-			AdapterAuth::None => {}
+			AdapterAuth::None => {
+				self.cfg.user_access_token = SecretString::new("");
+				self.cfg.refresh_token = None;
+				self.token_user_id = None;
+				self.token_username = None;
+				self.is_mod_by_room_and_user.clear();
+				self.last_mod_status_refresh_by_room_and_user.clear();
+				self.auth_expires_at = None;
+				self.last_auth_error_notice = None;
+			}
 		}
 	}
 
 	fn invalidate_auth(&mut self, reason: &str, events_tx: &AdapterEventTx) {
 		self.cfg.user_access_token = SecretString::new("");
 		self.token_user_id = None;
-		self.is_token_user_mod_by_room.clear();
-		self.last_mod_status_refresh_by_room.clear();
+		self.is_mod_by_room_and_user.clear();
+		self.last_mod_status_refresh_by_room_and_user.clear();
 		self.auth_expires_at = None;
 		self.last_auth_error_notice = Some(reason.to_string());
 		let _ = events_tx.try_send(status(Platform::Twitch, false, reason.to_string()));
@@ -474,33 +686,19 @@ impl TwitchEventSubAdapter {
 		(self.ws_connector())(url).await
 	}
 
-	async fn execute_command(&mut self, request: CommandRequest, _auth: Option<AdapterAuth>) -> Result<(), CommandError> {
-		let room = match &request {
-			CommandRequest::SendChat { room, .. }
-			| CommandRequest::DeleteMessage { room, .. }
-			| CommandRequest::TimeoutUser { room, .. }
-			| CommandRequest::BanUser { room, .. } => room.clone(),
-		};
+	async fn execute_command(&mut self, request: CommandRequest, auth: Option<AdapterAuth>) -> Result<(), CommandError> {
+		let room = request.room().clone();
 
 		if room.platform != Platform::Twitch {
 			return Err(CommandError::InvalidTopic(None));
 		}
-		if !self.has_auth() {
-			return Err(CommandError::NotAuthorized(Some(
-				"twitch auth missing access token".to_string(),
-			)));
-		}
+		let identity = self.command_identity(auth).await?;
 		let broadcaster_id = self
-			.resolve_broadcaster_id(&room)
+			.resolve_broadcaster_id_with(&room, Some(&identity.helix))
 			.await
 			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
-		let token_user_id = self
-			.resolve_token_user_id()
-			.await
-			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
-		let helix = self
-			.helix_client()
-			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		let token_user_id = identity.user_id.clone();
+		let helix = identity.helix.clone();
 
 		match request {
 			CommandRequest::SendChat {
@@ -517,12 +715,7 @@ impl TwitchEventSubAdapter {
 				.await
 				.map_err(|e| CommandError::Internal(format!("twitch {e}"))),
 			CommandRequest::DeleteMessage { platform_message_id, .. } => {
-				let is_mod = self.refresh_mod_status_if_needed(&room).await;
-				if !is_mod && token_user_id != broadcaster_id {
-					return Err(CommandError::NotAuthorized(Some(
-						"twitch moderator or broadcaster required".to_string(),
-					)));
-				}
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
 
 				helix
 					.delete_chat_message(&broadcaster_id, &token_user_id, &platform_message_id)
@@ -535,12 +728,7 @@ impl TwitchEventSubAdapter {
 				reason,
 				..
 			} => {
-				let is_mod = self.refresh_mod_status_if_needed(&room).await;
-				if !is_mod && token_user_id != broadcaster_id {
-					return Err(CommandError::NotAuthorized(Some(
-						"twitch moderator or broadcaster required".to_string(),
-					)));
-				}
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
 
 				helix
 					.ban_user(
@@ -554,19 +742,183 @@ impl TwitchEventSubAdapter {
 					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
 			}
 			CommandRequest::BanUser { user_id, reason, .. } => {
-				let is_mod = self.refresh_mod_status_if_needed(&room).await;
-				if !is_mod && token_user_id != broadcaster_id {
-					return Err(CommandError::NotAuthorized(Some(
-						"twitch moderator or broadcaster required".to_string(),
-					)));
-				}
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
 
 				helix
 					.ban_user(&broadcaster_id, &token_user_id, &user_id, None, reason.as_deref())
 					.await
 					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
 			}
+			// Helix removes bans and timeouts through the same endpoint.
+			CommandRequest::UnbanUser { user_id, .. } | CommandRequest::UntimeoutUser { user_id, .. } => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.unban_user(&broadcaster_id, &token_user_id, &user_id)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::ClearChat { .. } => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.clear_chat(&broadcaster_id, &token_user_id)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			// Helix has no per-user purge; a one-second timeout clears the user's messages.
+			CommandRequest::ClearUserMessages { user_id, .. } => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.ban_user(&broadcaster_id, &token_user_id, &user_id, Some(1), None)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::UpdateRoomSettings { settings, .. } => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.update_chat_settings(&broadcaster_id, &token_user_id, &settings)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::AutoModResolve { message_id, allow, .. } => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.manage_held_automod_message(&token_user_id, &message_id, allow)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::ResolveUnbanRequest {
+				request_id,
+				approve,
+				resolution_text,
+				..
+			} => {
+				self.require_moderator(&room, &identity, &broadcaster_id).await?;
+
+				helix
+					.resolve_unban_request(
+						&broadcaster_id,
+						&token_user_id,
+						&request_id,
+						approve,
+						resolution_text.as_deref(),
+					)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			// Helix requires the broadcaster's own token for polls and predictions; editors and
+			// moderators are rejected upstream, so fail early with a clear error.
+			CommandRequest::CreatePoll {
+				title,
+				choices,
+				duration_seconds,
+				..
+			} => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.create_poll(&broadcaster_id, &title, &choices, duration_seconds)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::EndPoll { poll_id, archive, .. } => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.end_poll(&broadcaster_id, &poll_id, archive)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::CreatePrediction {
+				title,
+				outcomes,
+				prediction_window_seconds,
+				..
+			} => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.create_prediction(&broadcaster_id, &title, &outcomes, prediction_window_seconds)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::ResolvePrediction {
+				prediction_id,
+				winning_outcome_id,
+				..
+			} => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.resolve_prediction(&broadcaster_id, &prediction_id, winning_outcome_id.as_deref())
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::FulfillRedemption {
+				reward_id,
+				redemption_id,
+				..
+			} => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.update_redemption_status(&broadcaster_id, &reward_id, &redemption_id, true)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+			CommandRequest::CancelRedemption {
+				reward_id,
+				redemption_id,
+				..
+			} => {
+				if token_user_id != broadcaster_id {
+					return Err(CommandError::NotAuthorized(Some("twitch broadcaster required".to_string())));
+				}
+
+				helix
+					.update_redemption_status(&broadcaster_id, &reward_id, &redemption_id, false)
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))
+			}
+		}
+	}
+
+	async fn list_unban_requests(
+		&mut self,
+		room: &RoomKey,
+		status: &str,
+		auth: Option<AdapterAuth>,
+	) -> Result<Vec<UnbanRequest>, CommandError> {
+		if room.platform != Platform::Twitch {
+			return Err(CommandError::InvalidTopic(None));
 		}
+		let identity = self.command_identity(auth).await?;
+		let broadcaster_id = self
+			.resolve_broadcaster_id_with(room, Some(&identity.helix))
+			.await
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		self.require_moderator(room, &identity, &broadcaster_id).await?;
+
+		let requests = identity
+			.helix
+			.list_unban_requests(&broadcaster_id, &identity.user_id, status)
+			.await
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		Ok(requests.into_iter().map(map_unban_request).collect())
 	}
 
 	async fn read_until_welcome(ws: &mut TwitchWs) -> anyhow::Result<eventsub::EventSubWelcomeSession> {
@@ -605,58 +957,149 @@ impl TwitchEventSubAdapter {
 		Ok(u.id)
 	}
 
+	/// Resolve the identity the adapter's own ingest token belongs to. Only used for ingest-side decisions such as
+	/// which EventSub topics to subscribe to; user commands must go through [`Self::command_identity`].
+	async fn ingest_identity(&mut self) -> Result<CommandIdentity, CommandError> {
+		if !self.has_auth() {
+			return Err(CommandError::NotAuthorized(Some(
+				"twitch auth missing access token".to_string(),
+			)));
+		}
+		let helix = self
+			.helix_client()
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		let user_id = self
+			.resolve_token_user_id()
+			.await
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		Ok(CommandIdentity { helix, user_id })
+	}
+
+	/// Resolve the identity a command or permission query runs as. Requires per-user auth; the adapter's ingest
+	/// token is never used to act on behalf of a client.
+	async fn command_identity(&mut self, auth: Option<AdapterAuth>) -> Result<CommandIdentity, CommandError> {
+		let (client_id, access_token, user_id) = match auth {
+			Some(AdapterAuth::TwitchUser {
+				client_id,
+				access_token,
+				user_id,
+				..
+			}) => (client_id, access_token, user_id),
+			Some(AdapterAuth::UserAccessToken {
+				access_token, user_id, ..
+			}) => (String::new(), access_token, user_id),
+			_ => {
+				return Err(CommandError::NotAuthorized(Some(
+					"twitch auth missing access token".to_string(),
+				)));
+			}
+		};
+
+		if access_token.expose().trim().is_empty() {
+			return Err(CommandError::NotAuthorized(Some(
+				"twitch auth missing access token".to_string(),
+			)));
+		}
+		let client_id = if client_id.trim().is_empty() {
+			self.cfg.client_id.clone()
+		} else {
+			client_id
+		};
+		if client_id.trim().is_empty() {
+			return Err(CommandError::Internal("twitch missing client_id".to_string()));
+		}
+
+		let base_url = self
+			.helix_base_url()
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		let helix = HelixClient::new(base_url, client_id, access_token.expose().to_string())
+			.map_err(|e| CommandError::Internal(format!("twitch {e}")))?;
+		let user_id = match user_id.filter(|id| !id.trim().is_empty()) {
+			Some(id) => id,
+			None => {
+				helix
+					.get_token_user()
+					.await
+					.map_err(|e| CommandError::Internal(format!("twitch {e}")))?
+					.id
+			}
+		};
+
+		Ok(CommandIdentity { helix, user_id })
+	}
+
 	async fn refresh_mod_status_if_needed(&mut self, room: &RoomKey) -> bool {
+		match self.ingest_identity().await {
+			Ok(identity) => self.refresh_mod_status_for(room, &identity).await,
+			Err(e) => {
+				debug!(%room, error=?e, "failed to resolve adapter identity; keeping previous mod cache value");
+				let key = (room.clone(), self.token_user_id.clone().unwrap_or_default());
+				*self.is_mod_by_room_and_user.get(&key).unwrap_or(&false)
+			}
+		}
+	}
+
+	/// Fail with `NotAuthorized` unless `identity` moderates `room` or is its broadcaster.
+	async fn require_moderator(
+		&mut self,
+		room: &RoomKey,
+		identity: &CommandIdentity,
+		broadcaster_id: &str,
+	) -> Result<(), CommandError> {
+		let is_mod = self.refresh_mod_status_for(room, identity).await;
+		if !is_mod && identity.user_id != broadcaster_id {
+			return Err(CommandError::NotAuthorized(Some(
+				"twitch moderator or broadcaster required".to_string(),
+			)));
+		}
+		Ok(())
+	}
+
+	async fn refresh_mod_status_for(&mut self, room: &RoomKey, identity: &CommandIdentity) -> bool {
 		let now = Instant::now();
+		let key = (room.clone(), identity.user_id.clone());
 
 		let should_refresh = self
-			.last_mod_status_refresh_by_room
-			.get(room)
+			.last_mod_status_refresh_by_room_and_user
+			.get(&key)
 			.map(|last| now.duration_since(*last) >= self.cfg.mod_status_refresh_interval)
 			.unwrap_or(true);
 
 		if !should_refresh {
-			return *self.is_token_user_mod_by_room.get(room).unwrap_or(&false);
+			return *self.is_mod_by_room_and_user.get(&key).unwrap_or(&false);
 		}
 
-		self.last_mod_status_refresh_by_room.insert(room.clone(), now);
+		self.last_mod_status_refresh_by_room_and_user.insert(key.clone(), now);
 
-		let broadcaster_id = match self.resolve_broadcaster_id(room).await {
+		let broadcaster_id = match self.resolve_broadcaster_id_with(room, Some(&identity.helix)).await {
 			Ok(id) => id,
 			Err(e) => {
 				debug!(%room, error=%e, "failed to resolve broadcaster id; keeping previous mod cache value");
-				return *self.is_token_user_mod_by_room.get(room).unwrap_or(&false);
+				return *self.is_mod_by_room_and_user.get(&key).unwrap_or(&false);
 			}
 		};
 
-		let token_user_id = match self.resolve_token_user_id().await {
-			Ok(id) => id,
-			Err(e) => {
-				debug!(%room, error=%e, "failed to resolve token user id; keeping previous mod cache value");
-				return *self.is_token_user_mod_by_room.get(room).unwrap_or(&false);
-			}
-		};
-
-		let helix = match self.helix_client() {
-			Ok(h) => h,
-			Err(e) => {
-				debug!(%room, error=%e, "failed to build helix client; keeping previous mod cache value");
-				return *self.is_token_user_mod_by_room.get(room).unwrap_or(&false);
-			}
-		};
-
-		match helix.is_user_moderator_in_channel(&broadcaster_id, &token_user_id).await {
+		match identity
+			.helix
+			.is_user_moderator_in_channel(&broadcaster_id, &identity.user_id)
+			.await
+		{
 			Ok(is_mod) => {
-				self.is_token_user_mod_by_room.insert(room.clone(), is_mod);
+				self.is_mod_by_room_and_user.insert(key, is_mod);
 				is_mod
 			}
 			Err(e) => {
 				debug!(%room, error=%e, "failed to refresh mod status; keeping previous mod cache value");
-				*self.is_token_user_mod_by_room.get(room).unwrap_or(&false)
+				*self.is_mod_by_room_and_user.get(&key).unwrap_or(&false)
 			}
 		}
 	}
 
 	async fn resolve_broadcaster_id(&mut self, room: &RoomKey) -> anyhow::Result<String> {
+		self.resolve_broadcaster_id_with(room, None).await
+	}
+
+	async fn resolve_broadcaster_id_with(&mut self, room: &RoomKey, helix: Option<&HelixClient>) -> anyhow::Result<String> {
 		let login = room.room_id.as_str().to_string();
 
 		if let Some(id) = self.cfg.broadcaster_id_overrides.get(&login) {
@@ -666,7 +1109,14 @@ impl TwitchEventSubAdapter {
 			return Ok(id.clone());
 		}
 
-		let helix = self.helix_client()?;
+		let owned_helix;
+		let helix = match helix {
+			Some(helix) => helix,
+			None => {
+				owned_helix = self.helix_client()?;
+				&owned_helix
+			}
+		};
 		let u = helix
 			.get_user_by_login(login.as_str())
 			.await?
@@ -677,28 +1127,21 @@ impl TwitchEventSubAdapter {
 	}
 
 	async fn ensure_subscription_for_room(&mut self, session_id: &str, room: &RoomKey) -> anyhow::Result<()> {
-		let perms = self.permissions_for_room(room).await;
+		let perms = match self.ingest_identity().await {
+			Ok(identity) => self.permissions_for_identity(room, &identity).await,
+			Err(_) => PermissionsInfo::default(),
+		};
 		let can_moderate = perms.is_moderator || perms.is_broadcaster;
 
-		for sub_type in [
-			TwitchSubscriptionType::ChatMessage,
-			TwitchSubscriptionType::ChatMessageDelete,
-			TwitchSubscriptionType::ChannelBan,
-			TwitchSubscriptionType::ChannelModerate,
-			TwitchSubscriptionType::ChannelRaid,
-			TwitchSubscriptionType::ChannelCheer,
-			TwitchSubscriptionType::ChannelSubscribe,
-		] {
-			if matches!(
-				sub_type,
-				TwitchSubscriptionType::ChannelBan
-					| TwitchSubscriptionType::ChannelModerate
-					| TwitchSubscriptionType::ChatMessageDelete
-			) && !can_moderate
-			{
+		for sub_type in TwitchSubscriptionType::ALL {
+			if sub_type.requires_moderator() && !can_moderate {
 				debug!(room=%room, sub_type=?sub_type, "skipping subscription; missing moderator/broadcaster permissions");
 				continue;
 			}
+			if sub_type.requires_broadcaster() && !perms.is_broadcaster {
+				debug!(room=%room, sub_type=?sub_type, "skipping subscription; token user is not the broadcaster");
+				continue;
+			}
 
 			if let Err(e) = self.ensure_subscription_for_room_and_type(session_id, room, sub_type).await {
 				if Self::is_helix_missing_authorization(&e) {
@@ -717,23 +1160,23 @@ impl TwitchEventSubAdapter {
 		Ok(())
 	}
 
-	async fn permissions_for_room(&mut self, room: &RoomKey) -> PermissionsInfo {
+	async fn permissions_for_room(&mut self, room: &RoomKey, auth: Option<AdapterAuth>) -> PermissionsInfo {
 		if room.platform != Platform::Twitch {
 			return PermissionsInfo::default();
 		}
-		if !self.has_auth() {
-			return PermissionsInfo::default();
+		match self.command_identity(auth).await {
+			Ok(identity) => self.permissions_for_identity(room, &identity).await,
+			Err(_) => PermissionsInfo::default(),
 		}
-		let broadcaster_id = match self.resolve_broadcaster_id(room).await {
-			Ok(id) => id,
-			Err(_) => return PermissionsInfo::default(),
-		};
-		let token_user_id = match self.resolve_token_user_id().await {
+	}
+
+	async fn permissions_for_identity(&mut self, room: &RoomKey, identity: &CommandIdentity) -> PermissionsInfo {
+		let broadcaster_id = match self.resolve_broadcaster_id_with(room, Some(&identity.helix)).await {
 			Ok(id) => id,
 			Err(_) => return PermissionsInfo::default(),
 		};
-		let is_moderator = self.refresh_mod_status_if_needed(room).await;
-		let is_broadcaster = token_user_id == broadcaster_id;
+		let is_moderator = self.refresh_mod_status_for(room, identity).await;
+		let is_broadcaster = identity.user_id == broadcaster_id;
 		let can_moderate = is_moderator || is_broadcaster;
 		PermissionsInfo {
 			can_send: true,
@@ -743,6 +1186,8 @@ impl TwitchEventSubAdapter {
 			can_ban: can_moderate,
 			is_moderator,
 			is_broadcaster,
+			// Redemption updates go through Helix with the broadcaster's own token.
+			can_manage_redemptions: is_broadcaster,
 		}
 	}
 
@@ -778,6 +1223,21 @@ impl TwitchEventSubAdapter {
 					.await
 					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
 
+				TwitchSubscriptionType::ChatNotification => helix
+					.create_chat_notification_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::ChatClear => helix
+					.create_chat_clear_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::ChatClearUserMessages => helix
+					.create_chat_clear_user_messages_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
 				TwitchSubscriptionType::ChannelBan => helix
 					.create_channel_ban_subscription(session_id, &broadcaster_user_id)
 					.await
@@ -802,6 +1262,70 @@ impl TwitchEventSubAdapter {
 					.create_channel_subscribe_subscription(session_id, &broadcaster_user_id)
 					.await
 					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::AutoModMessageHold => helix
+					.create_automod_message_hold_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::AutoModMessageUpdate => helix
+					.create_automod_message_update_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::UnbanRequestCreate => helix
+					.create_unban_request_create_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::UnbanRequestResolve => helix
+					.create_unban_request_resolve_subscription(session_id, &broadcaster_user_id, &user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::StreamOnline => helix
+					.create_stream_online_subscription(session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::StreamOffline => helix
+					.create_stream_offline_subscription(session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::ChannelUpdate => helix
+					.create_channel_update_subscription(session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::PollBegin
+				| TwitchSubscriptionType::PollProgress
+				| TwitchSubscriptionType::PollEnd
+				| TwitchSubscriptionType::PredictionBegin
+				| TwitchSubscriptionType::PredictionProgress
+				| TwitchSubscriptionType::PredictionLock
+				| TwitchSubscriptionType::PredictionEnd => helix
+					.create_poll_subscription(sub_type.as_helix_type(), session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::RedemptionAdd | TwitchSubscriptionType::RedemptionUpdate => helix
+					.create_redemption_subscription(sub_type.as_helix_type(), session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
+
+				TwitchSubscriptionType::HypeTrainBegin
+				| TwitchSubscriptionType::HypeTrainProgress
+				| TwitchSubscriptionType::HypeTrainEnd
+				| TwitchSubscriptionType::GoalBegin
+				| TwitchSubscriptionType::GoalProgress
+				| TwitchSubscriptionType::GoalEnd
+				| TwitchSubscriptionType::CharityCampaignStart
+				| TwitchSubscriptionType::CharityCampaignProgress
+				| TwitchSubscriptionType::CharityCampaignStop => helix
+					.create_progress_subscription(sub_type.as_helix_type(), session_id, &broadcaster_user_id)
+					.await
+					.with_context(|| format!("create subscription type={} room={room}", sub_type.as_helix_type())),
 			};
 
 			let created = match created {
@@ -888,15 +1412,7 @@ impl TwitchEventSubAdapter {
 	async fn remove_subscription_for_room(&mut self, room: &RoomKey) -> anyhow::Result<()> {
 		let helix = self.helix_client()?;
 
-		for sub_type in [
-			TwitchSubscriptionType::ChatMessage,
-			TwitchSubscriptionType::ChatMessageDelete,
-			TwitchSubscriptionType::ChannelBan,
-			TwitchSubscriptionType::ChannelModerate,
-			TwitchSubscriptionType::ChannelRaid,
-			TwitchSubscriptionType::ChannelCheer,
-			TwitchSubscriptionType::ChannelSubscribe,
-		] {
+		for sub_type in TwitchSubscriptionType::ALL {
 			let key = (room.clone(), sub_type);
 			let Some(sub_id) = self.subscription_id_by_room_and_type.remove(&key) else {
 				continue;
@@ -951,6 +1467,8 @@ impl TwitchEventSubAdapter {
 				let inserted = self.joined_rooms.insert(room.clone());
 				self.emit_assets_for_room(room.clone(), current_session_id, events_tx).await;
 				if inserted {
+					self.spawn_stream_status_poll(vec![room.clone()], current_session_id, events_tx)
+						.await;
 					let _ = events_tx.try_send(status(
 						platform,
 						true,
@@ -1003,8 +1521,13 @@ impl TwitchEventSubAdapter {
 			}
 
 			AdapterControl::UpdateAuth { auth } => {
+				let cleared = matches!(auth, AdapterAuth::None);
 				self.apply_auth_update(auth);
-				let _ = events_tx.try_send(status(platform, true, "updated auth (bearer token)"));
+				if cleared {
+					let _ = events_tx.try_send(status(platform, false, "auth cleared"));
+				} else {
+					let _ = events_tx.try_send(status(platform, true, "updated auth (bearer token)"));
+				}
 			}
 
 			AdapterControl::Command { request, auth, resp } => {
@@ -1012,8 +1535,18 @@ impl TwitchEventSubAdapter {
 				let _ = resp.send(result);
 			}
 
-			AdapterControl::QueryPermissions { room, auth: _, resp } => {
-				let result = self.permissions_for_room(&room).await;
+			AdapterControl::QueryPermissions { room, auth, resp } => {
+				let result = self.permissions_for_room(&room, auth).await;
+				let _ = resp.send(result);
+			}
+
+			AdapterControl::ListUnbanRequests {
+				room,
+				status,
+				auth,
+				resp,
+			} => {
+				let result = self.list_unban_requests(&room, &status, auth).await;
 				let _ = resp.send(result);
 			}
 
@@ -1038,6 +1571,82 @@ impl TwitchEventSubAdapter {
 		}
 	}
 
+	/// Emit StreamStatus snapshots from Helix `get streams`, the only source of viewer counts. Broadcaster ids are
+	/// resolved here (normally from cache); the Helix calls run on a spawned task, batched per request.
+	async fn spawn_stream_status_poll(
+		&mut self,
+		rooms: Vec<RoomKey>,
+		current_session_id: Option<&str>,
+		events_tx: &AdapterEventTx,
+	) -> Option<tokio::task::JoinHandle<()>> {
+		let helix = match self.helix_client() {
+			Ok(helix) => helix,
+			Err(e) => {
+				debug!(error=?e, "twitch stream status poll skipped; no helix client");
+				return None;
+			}
+		};
+		let mut rooms_by_id: HashMap<String, RoomKey> = HashMap::new();
+		for room in rooms {
+			match self.resolve_broadcaster_id_with(&room, Some(&helix)).await {
+				Ok(id) => {
+					rooms_by_id.insert(id, room);
+				}
+				Err(e) => debug!(room=%room, error=?e, "twitch stream status poll failed to resolve broadcaster"),
+			}
+		}
+		if rooms_by_id.is_empty() {
+			return None;
+		}
+
+		let session_id = current_session_id.map(|s| s.to_string());
+		let events_tx = events_tx.clone();
+		Some(tokio::spawn(async move {
+			let ids: Vec<String> = rooms_by_id.keys().cloned().collect();
+			for chunk in ids.chunks(STREAMS_MAX_USER_IDS) {
+				let mut live: HashMap<String, HelixStream> = match helix.get_streams(chunk).await {
+					Ok(streams) => streams.into_iter().map(|s| (s.user_id.clone(), s)).collect(),
+					Err(e) => {
+						debug!(error=?e, rooms = chunk.len(), "twitch stream status poll failed");
+						continue;
+					}
+				};
+				for id in chunk {
+					let Some(room) = rooms_by_id.get(id) else {
+						continue;
+					};
+					let status = match live.remove(id) {
+						Some(stream) => StreamStatus {
+							live: Some(true),
+							started_at: eventsub::parse_message_timestamp_system_time(&stream.started_at).ok(),
+							title: Some(stream.title),
+							category: Some(stream.game_name),
+							viewer_count: Some(stream.viewer_count),
+						},
+						None => StreamStatus {
+							live: Some(false),
+							..StreamStatus::default()
+						},
+					};
+					let mut ingest =
+						IngestEvent::new(Platform::Twitch, room.room_id.clone(), IngestPayload::StreamStatus(status));
+					ingest.trace.session_id = session_id.clone();
+					let _ = events_tx.try_send(AdapterEvent::Ingest(Box::new(ingest)));
+				}
+			}
+		}))
+	}
+
+	async fn poll_stream_status_for_joined_rooms(&mut self, session_id: &str, events_tx: &AdapterEventTx) {
+		// A slow Helix round must not stack up behind the next tick.
+		if self.stream_status_poll_task.as_ref().is_some_and(|task| !task.is_finished()) {
+			debug!("twitch stream status poll still running; skipping tick");
+			return;
+		}
+		let rooms: Vec<RoomKey> = self.joined_rooms.iter().cloned().collect();
+		self.stream_status_poll_task = self.spawn_stream_status_poll(rooms, Some(session_id), events_tx).await;
+	}
+
 	async fn emit_assets_for_room(&mut self, room: RoomKey, current_session_id: Option<&str>, events_tx: &AdapterEventTx) {
 		let platform = Platform::Twitch;
 		let cache_key = format!("twitch:channel:{}:native", room.room_id.as_str());
@@ -1064,8 +1673,9 @@ impl TwitchEventSubAdapter {
 		let client_id = self.cfg.client_id.clone();
 		let bearer_token = self.cfg.user_access_token.expose().to_string();
 		let seventv_subscriptions = self.seventv_subscriptions.clone();
+		let assets = self.cfg.assets.clone();
 		tokio::spawn(async move {
-			if let Ok(bundle) = fetch_twitch_badges_bundle(&client_id, &bearer_token).await {
+			if let Ok(bundle) = fetch_twitch_badges_bundle(&assets, &client_id, &bearer_token).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1076,7 +1686,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_twitch_global_emotes_bundle(&client_id, &bearer_token).await {
+			if let Ok(bundle) = fetch_twitch_global_emotes_bundle(&assets, &client_id, &bearer_token).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1087,7 +1697,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_ffz_bundle(room_for_assets.room_id.as_str()).await {
+			if let Ok(bundle) = fetch_ffz_bundle(&assets, room_for_assets.room_id.as_str()).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1098,7 +1708,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_ffz_global_emotes_bundle().await {
+			if let Ok(bundle) = fetch_ffz_global_emotes_bundle(&assets).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1109,7 +1719,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_bttv_global_emotes_bundle().await {
+			if let Ok(bundle) = fetch_bttv_global_emotes_bundle(&assets).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1120,7 +1730,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_bttv_badges_bundle("twitch").await {
+			if let Ok(bundle) = fetch_bttv_badges_bundle(&assets, "twitch").await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1131,7 +1741,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_ffz_badges_bundle().await {
+			if let Ok(bundle) = fetch_ffz_badges_bundle(&assets).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1142,7 +1752,7 @@ impl TwitchEventSubAdapter {
 				let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 			}
 
-			if let Ok(bundle) = fetch_7tv_badges_bundle().await {
+			if let Ok(bundle) = fetch_7tv_badges_bundle(&assets).await {
 				info!(%platform, room=%room_for_assets.room_id, cache_key=%bundle.cache_key, etag=?bundle.etag, "fetched 7tv global badges bundle");
 				let mut ingest = IngestEvent::new(
 					Platform::Twitch,
@@ -1154,7 +1764,7 @@ impl TwitchEventSubAdapter {
 			}
 
 			if let Some(id) = broadcaster_id {
-				if let Ok(bundle) = fetch_twitch_channel_badges_bundle(&client_id, &bearer_token, &id).await {
+				if let Ok(bundle) = fetch_twitch_channel_badges_bundle(&assets, &client_id, &bearer_token, &id).await {
 					info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 					let mut ingest = IngestEvent::new(
 						Platform::Twitch,
@@ -1165,7 +1775,7 @@ impl TwitchEventSubAdapter {
 					let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 				}
 
-				if let Ok(bundle) = fetch_twitch_channel_emotes_bundle(&client_id, &bearer_token, &id).await {
+				if let Ok(bundle) = fetch_twitch_channel_emotes_bundle(&assets, &client_id, &bearer_token, &id).await {
 					info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 					let mut ingest = IngestEvent::new(
 						Platform::Twitch,
@@ -1176,7 +1786,7 @@ impl TwitchEventSubAdapter {
 					let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 				}
 
-				if let Ok(bundle) = fetch_bttv_bundle("twitch", &id).await {
+				if let Ok(bundle) = fetch_bttv_bundle(&assets, "twitch", &id).await {
 					info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 					let mut ingest = IngestEvent::new(
 						Platform::Twitch,
@@ -1188,7 +1798,7 @@ impl TwitchEventSubAdapter {
 				}
 
 				info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, "fetching 7tv channel badges bundle");
-				match fetch_7tv_channel_badges_bundle(SevenTvPlatform::Twitch, &id).await {
+				match fetch_7tv_channel_badges_bundle(&assets, SevenTvPlatform::Twitch, &id).await {
 					Ok(bundle) => {
 						info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 						let mut ingest = IngestEvent::new(
@@ -1205,7 +1815,7 @@ impl TwitchEventSubAdapter {
 				}
 
 				info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, "fetching 7tv emote set bundle");
-				match fetch_7tv_bundle_with_sets(SevenTvPlatform::Twitch, &id, SevenTvCacheMode::UseCache).await {
+				match fetch_7tv_bundle_with_sets(&assets, SevenTvPlatform::Twitch, &id, SevenTvCacheMode::UseCache).await {
 					Ok((bundle, sets)) => {
 						info!(%platform, room=%room_for_assets.room_id, broadcaster_id=%id, cache_key=%bundle.cache_key, "emitting AssetBundle ingest");
 						let mut ingest = IngestEvent::new(
@@ -1217,19 +1827,22 @@ impl TwitchEventSubAdapter {
 						let _ = events_tx_clone.try_send(AdapterEvent::Ingest(Box::new(ingest)));
 
 						let set_ids = sets.set_ids();
-						if !set_ids.is_empty() {
-							let api = ensure_seventv_event_api();
+						if !set_ids.is_empty()
+							&& let Some(api) = ensure_seventv_event_api(&assets.seventv_event_api)
+						{
 							let mut subscriptions = Vec::new();
 							for set_id in set_ids {
 								let (subscription, mut rx) = api.subscribe(DispatchType::EmoteSetUpdate, set_id.clone());
 								subscriptions.push(subscription);
 								let events_tx_updates = events_tx_clone.clone();
 								let room_updates = room_for_assets.clone();
+								let assets_updates = assets.clone();
 								let session_updates = session_id.clone();
 								let platform_id = id.clone();
 								tokio::spawn(async move {
 									while rx.recv().await.is_some() {
 										match fetch_7tv_bundle_with_sets(
+											&assets_updates,
 											SevenTvPlatform::Twitch,
 											&platform_id,
 											SevenTvCacheMode::Refresh,
@@ -1450,6 +2063,10 @@ impl TwitchEventSubAdapter {
 			};
 			let backpressure_report_interval = Duration::from_secs(5);
 
+			let stream_status_poll_period = self.cfg.stream_status_poll_interval.max(Duration::from_secs(1));
+			let mut stream_status_poll =
+				tokio::time::interval_at(Instant::now() + stream_status_poll_period, stream_status_poll_period);
+
 			loop {
 				let mig_should_connect = migrating
 					.as_ref()
@@ -1464,6 +2081,13 @@ impl TwitchEventSubAdapter {
 						}
 						self.handle_control_message(cmd, Some(&session_id), &events_tx).await;
 
+						if self.cfg.user_access_token.expose().trim().is_empty() {
+							let _ = events_tx.try_send(status(platform, false, "no user OAuth; closing eventsub socket".to_string()));
+							let _ = ws.close(None).await;
+							current_ws_url = self.cfg.eventsub_ws_url.clone();
+							break;
+						}
+
 						if self.joined_rooms.is_empty() {
 							let _ = events_tx.try_send(status(platform, false, "no joined rooms; closing eventsub socket".to_string()));
 							let _ = ws.close(None).await;
@@ -1716,6 +2340,10 @@ impl TwitchEventSubAdapter {
 						}
 					}
 
+					_ = stream_status_poll.tick() => {
+						self.poll_stream_status_for_joined_rooms(&session_id, &events_tx).await;
+					}
+
 					_ = sleep(keepalive_timeout) => {
 						if last_activity_main.elapsed() > keepalive_timeout {
 							let _ = events_tx.try_send(status(platform, false, "keepalive watchdog triggered; reconnecting"));
@@ -1758,7 +2386,7 @@ impl PlatformAdapter for TwitchEventSubAdapter {
 	}
 
 	async fn run(self: Box<Self>, control_rx: AdapterControlRx, events_tx: AdapterEventTx) -> anyhow::Result<()> {
-		ensure_asset_cache_pruner();
+		ensure_asset_cache_pruner(self.cfg.assets.clone());
 		self.run_loop(control_rx, events_tx).await
 	}
 }
@@ -266,6 +266,75 @@ pub(crate) struct ChannelSubscribeEvent {
 	pub(crate) is_gift: bool,
 }
 
+/// Payload shared by `automod.message.hold` and `automod.message.update` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct AutoModMessageEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+
+	pub(crate) message_id: String,
+	pub(crate) message: AutoModMessageContent,
+	#[serde(default)]
+	pub(crate) category: Option<String>,
+	#[serde(default)]
+	pub(crate) level: Option<u32>,
+
+	/// Set on `automod.message.update` only.
+	#[serde(default)]
+	pub(crate) moderator_user_id: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_user_login: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_user_name: Option<String>,
+	/// `Approved`, `Denied` or `Expired`; set on `automod.message.update` only.
+	#[serde(default)]
+	pub(crate) status: Option<String>,
+}
+
+/// Payload shared by `channel.unban_request.create` and `channel.unban_request.resolve` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct UnbanRequestEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+
+	/// Set on `channel.unban_request.create` only.
+	#[serde(default)]
+	pub(crate) text: Option<String>,
+
+	/// Set on `channel.unban_request.resolve` only.
+	#[serde(default)]
+	pub(crate) moderator_id: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_login: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_name: Option<String>,
+	#[serde(default)]
+	pub(crate) resolution_text: Option<String>,
+	/// `approved`, `denied` or `canceled`; set on `channel.unban_request.resolve` only.
+	#[serde(default)]
+	pub(crate) status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct AutoModMessageContent {
+	pub(crate) text: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct ChannelChatMessageContent {
 	pub(crate) text: String,
@@ -303,6 +372,269 @@ pub(crate) struct ChannelChatMessageDeleteEvent {
 	pub(crate) message_id: String,
 }
 
+/// `channel.chat.notification` (v1): resubs, gift subs, announcements and other chat notices.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelChatNotificationEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	/// Chatter fields are empty or null for anonymous gifts.
+	#[serde(default)]
+	pub(crate) chatter_user_id: Option<String>,
+	#[serde(default)]
+	pub(crate) chatter_user_login: Option<String>,
+	#[serde(default)]
+	pub(crate) chatter_user_name: Option<String>,
+	#[serde(default)]
+	pub(crate) chatter_is_anonymous: bool,
+
+	/// Text Twitch renders for the notice, e.g. "X subscribed at Tier 1. They've subscribed for 5 months!".
+	#[serde(default)]
+	pub(crate) system_message: String,
+	pub(crate) message: ChannelChatMessageContent,
+	pub(crate) notice_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelChatClearEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelChatClearUserMessagesEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) target_user_id: String,
+	pub(crate) target_user_login: String,
+	pub(crate) target_user_name: String,
+}
+
+/// `stream.online` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct StreamOnlineEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	/// `live`, `playlist`, `watch_party`, `premiere` or `rerun`.
+	#[allow(dead_code)]
+	#[serde(rename = "type", default)]
+	pub(crate) stream_type: String,
+	pub(crate) started_at: String,
+}
+
+/// `stream.offline` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct StreamOfflineEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+}
+
+/// `channel.update` (v2): title/category changes, sent whether or not the channel is live.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelUpdateEvent {
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	#[serde(default)]
+	pub(crate) title: String,
+	#[serde(default)]
+	pub(crate) category_name: String,
+}
+
+/// `channel.poll.begin`, `channel.poll.progress` and `channel.poll.end` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPollEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) title: String,
+	pub(crate) choices: Vec<ChannelPollChoice>,
+
+	/// Only on `end`: `completed`, `terminated` or `archived`.
+	#[serde(default)]
+	pub(crate) status: Option<String>,
+	#[serde(default)]
+	pub(crate) started_at: Option<String>,
+	/// Not present on `end`.
+	#[serde(default)]
+	pub(crate) ends_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPollChoice {
+	pub(crate) id: String,
+	pub(crate) title: String,
+	/// Not present on `begin`.
+	#[serde(default)]
+	pub(crate) votes: u64,
+	#[serde(default)]
+	pub(crate) channel_points_votes: u64,
+}
+
+/// `channel.prediction.begin`, `.progress`, `.lock` and `.end` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPredictionEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) title: String,
+	pub(crate) outcomes: Vec<ChannelPredictionOutcome>,
+
+	/// Only on `end`.
+	#[serde(default)]
+	pub(crate) winning_outcome_id: Option<String>,
+	/// Only on `end`: `resolved` or `canceled`.
+	#[serde(default)]
+	pub(crate) status: Option<String>,
+	#[serde(default)]
+	pub(crate) started_at: Option<String>,
+	/// Only on `begin` and `progress`.
+	#[serde(default)]
+	pub(crate) locks_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPredictionOutcome {
+	pub(crate) id: String,
+	pub(crate) title: String,
+	/// Not present on `begin`.
+	#[serde(default)]
+	pub(crate) users: u64,
+	#[serde(default)]
+	pub(crate) channel_points: u64,
+}
+
+/// `channel.channel_points_custom_reward_redemption.add` and `.update` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPointsRedemptionEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+
+	#[serde(default)]
+	pub(crate) user_input: String,
+	/// `unknown`, `unfulfilled`, `fulfilled` or `canceled`.
+	pub(crate) status: String,
+	pub(crate) reward: ChannelPointsReward,
+	#[serde(default)]
+	pub(crate) redeemed_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelPointsReward {
+	pub(crate) id: String,
+	pub(crate) title: String,
+	pub(crate) cost: u64,
+}
+
+/// `channel.hype_train.begin`, `.progress` and `.end` (v2).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelHypeTrainEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	pub(crate) level: u32,
+	/// Points contributed within the current level; not present on `end`.
+	#[serde(default)]
+	pub(crate) progress: u64,
+	/// Points needed to reach the next level; not present on `end`.
+	#[serde(default)]
+	pub(crate) goal: u64,
+	/// Not present on `end`.
+	#[serde(default)]
+	pub(crate) expires_at: Option<String>,
+}
+
+/// `channel.goal.begin`, `.progress` and `.end` (v1).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelGoalEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_id: String,
+	pub(crate) broadcaster_user_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_user_name: String,
+
+	#[serde(default)]
+	pub(crate) description: String,
+	pub(crate) current_amount: u64,
+	pub(crate) target_amount: u64,
+}
+
+/// `channel.charity_campaign.start`, `.progress` and `.stop` (v1).
+///
+/// Unlike other channel events these name the broadcaster `broadcaster_id`/`broadcaster_login`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ChannelCharityCampaignEvent {
+	pub(crate) id: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_id: String,
+	pub(crate) broadcaster_login: String,
+	#[allow(dead_code)]
+	pub(crate) broadcaster_name: String,
+
+	pub(crate) charity_name: String,
+	pub(crate) current_amount: CharityAmount,
+	pub(crate) target_amount: CharityAmount,
+}
+
+/// Money amount as `value / 10^decimal_places` in `currency`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct CharityAmount {
+	pub(crate) value: u64,
+	pub(crate) decimal_places: u32,
+	pub(crate) currency: String,
+}
+
+impl CharityAmount {
+	/// Whole currency units, rounded down.
+	fn whole_units(&self) -> u64 {
+		10u64
+			.checked_pow(self.decimal_places)
+			.map(|scale| self.value / scale)
+			.unwrap_or(0)
+	}
+}
+
 /// Extract `metadata.message_type` from a raw EventSub WS JSON string.
 pub(crate) fn peek_message_type(raw_json: &str) -> anyhow::Result<String> {
 	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
@@ -368,6 +700,92 @@ pub(crate) fn parse_channel_subscribe_notification(
 	serde_json::from_str(raw_json).context("parse channel.subscribe notification")
 }
 
+/// Parse a raw WS message as `notification` of `automod.message.hold` or `automod.message.update`.
+pub(crate) fn parse_automod_message_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<AutoModMessageEvent>> {
+	serde_json::from_str(raw_json).context("parse automod.message notification")
+}
+
+/// Parse a raw WS message as `notification` of `channel.unban_request.create` or `channel.unban_request.resolve`.
+pub(crate) fn parse_unban_request_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<UnbanRequestEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.unban_request notification")
+}
+
+/// Parse a raw WS message as `notification` of `channel.chat.notification`.
+pub(crate) fn parse_channel_chat_notification_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelChatNotificationEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.chat.notification notification")
+}
+
+/// Parse a raw WS message as `notification` of `channel.chat.clear`.
+pub(crate) fn parse_channel_chat_clear_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelChatClearEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.chat.clear notification")
+}
+
+/// Parse a raw WS message as `notification` of `channel.chat.clear_user_messages`.
+pub(crate) fn parse_channel_chat_clear_user_messages_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelChatClearUserMessagesEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.chat.clear_user_messages notification")
+}
+
+/// Parse a raw WS message as `notification` of `stream.online`.
+pub(crate) fn parse_stream_online_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<StreamOnlineEvent>> {
+	serde_json::from_str(raw_json).context("parse stream.online notification")
+}
+
+/// Parse a raw WS message as `notification` of `stream.offline`.
+pub(crate) fn parse_stream_offline_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<StreamOfflineEvent>> {
+	serde_json::from_str(raw_json).context("parse stream.offline notification")
+}
+
+/// Parse a raw WS message as `notification` of `channel.update`.
+pub(crate) fn parse_channel_update_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<ChannelUpdateEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.update notification")
+}
+
+/// Parse a raw WS message as `notification` of any `channel.poll.*` type.
+pub(crate) fn parse_channel_poll_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<ChannelPollEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.poll notification")
+}
+
+/// Parse a raw WS message as `notification` of any `channel.prediction.*` type.
+pub(crate) fn parse_channel_prediction_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelPredictionEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.prediction notification")
+}
+
+/// Parse a raw WS message as `notification` of type `channel.channel_points_custom_reward_redemption.*`.
+pub(crate) fn parse_channel_points_redemption_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelPointsRedemptionEvent>> {
+	serde_json::from_str(raw_json).context("parse channel_points_custom_reward_redemption notification")
+}
+
+/// Parse a raw WS message as `notification` of any `channel.hype_train.*` type.
+pub(crate) fn parse_channel_hype_train_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelHypeTrainEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.hype_train notification")
+}
+
+/// Parse a raw WS message as `notification` of any `channel.goal.*` type.
+pub(crate) fn parse_channel_goal_notification(raw_json: &str) -> anyhow::Result<EventSubNotification<ChannelGoalEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.goal notification")
+}
+
+/// Parse a raw WS message as `notification` of any `channel.charity_campaign.*` type.
+pub(crate) fn parse_channel_charity_campaign_notification(
+	raw_json: &str,
+) -> anyhow::Result<EventSubNotification<ChannelCharityCampaignEvent>> {
+	serde_json::from_str(raw_json).context("parse channel.charity_campaign notification")
+}
+
 /// Convert a `metadata.message_timestamp` RFC3339 timestamp into `SystemTime`.
 ///
 /// EventSub timestamps are RFC3339 with fractional seconds and Zulu (UTC).
@@ -377,39 +795,172 @@ pub(crate) fn parse_message_timestamp_system_time(ts: &str) -> anyhow::Result<Sy
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChatNotification {
+pub(crate) struct NormalizedChatNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) platform_message_id: PlatformMessageId,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) chatter_user_id: String,
+	pub(crate) chatter_user_login: String,
+	pub(crate) chatter_user_name: String,
+
+	pub(crate) text: String,
+	pub(crate) reply: Option<NormalizedChatReply>,
+	pub(crate) badge_ids: Vec<String>,
+	pub(crate) emotes: Vec<crate::AssetRef>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChatReply {
+	pub(crate) parent_message_id: String,
+	pub(crate) parent_message_body: String,
+	pub(crate) parent_user_id: String,
+	pub(crate) parent_user_login: String,
+	pub(crate) parent_user_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChannelBanNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) target_user_id: String,
+	pub(crate) target_user_login: String,
+	pub(crate) target_user_name: String,
+
+	pub(crate) moderator_user_id: String,
+	pub(crate) moderator_user_login: String,
+	pub(crate) moderator_user_name: String,
+
+	pub(crate) is_permanent: bool,
+	pub(crate) ends_at: Option<SystemTime>,
+	pub(crate) reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChannelModerateNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) moderator_user_id: String,
+	pub(crate) moderator_user_login: String,
+	pub(crate) moderator_user_name: String,
+
+	pub(crate) action: String,
+	pub(crate) action_data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChannelRaidNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) from_broadcaster_user_id: String,
+	pub(crate) from_broadcaster_user_login: String,
+	pub(crate) from_broadcaster_user_name: String,
+
+	pub(crate) viewers: u64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChannelCheerNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) bits: u64,
+	pub(crate) message: Option<String>,
+
+	pub(crate) user_id: Option<String>,
+	pub(crate) user_login: Option<String>,
+	pub(crate) user_name: Option<String>,
+
+	pub(crate) is_anonymous: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedChannelSubscribeNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+
+	pub(crate) tier: String,
+	pub(crate) is_gift: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedAutoModMessageNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
+
+	pub(crate) message_id: String,
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+	pub(crate) text: String,
+	pub(crate) category: Option<String>,
+	pub(crate) level: Option<u32>,
+
+	pub(crate) moderator: Option<(String, String, String)>,
+	pub(crate) status: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedUnbanRequestNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
-	pub(crate) platform_message_id: PlatformMessageId,
 	pub(crate) ws_message_id: String,
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) chatter_user_id: String,
-	pub(crate) chatter_user_login: String,
-	pub(crate) chatter_user_name: String,
-
-	pub(crate) text: String,
-	pub(crate) reply: Option<NormalizedChatReply>,
-	pub(crate) badge_ids: Vec<String>,
-	pub(crate) emotes: Vec<crate::AssetRef>,
-}
+	pub(crate) request_id: String,
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	pub(crate) user_name: String,
+	pub(crate) text: Option<String>,
 
-#[derive(Debug, Clone)]
-pub(crate) struct NormalizedChatReply {
-	pub(crate) parent_message_id: String,
-	pub(crate) parent_message_body: String,
-	pub(crate) parent_user_id: String,
-	pub(crate) parent_user_login: String,
-	pub(crate) parent_user_name: String,
+	pub(crate) moderator: Option<(String, String, String)>,
+	pub(crate) resolution_text: Option<String>,
+	pub(crate) status: Option<String>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChannelBanNotification {
+pub(crate) struct NormalizedChatMessageDeleteNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
+	pub(crate) target_message_platform_id: PlatformMessageId,
 	pub(crate) ws_message_id: String,
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
@@ -417,18 +968,10 @@ pub(crate) struct NormalizedChannelBanNotification {
 	pub(crate) target_user_id: String,
 	pub(crate) target_user_login: String,
 	pub(crate) target_user_name: String,
-
-	pub(crate) moderator_user_id: String,
-	pub(crate) moderator_user_login: String,
-	pub(crate) moderator_user_name: String,
-
-	pub(crate) is_permanent: bool,
-	pub(crate) ends_at: Option<SystemTime>,
-	pub(crate) reason: Option<String>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChannelModerateNotification {
+pub(crate) struct NormalizedChatNoticeNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
@@ -436,16 +979,15 @@ pub(crate) struct NormalizedChannelModerateNotification {
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) moderator_user_id: String,
-	pub(crate) moderator_user_login: String,
-	pub(crate) moderator_user_name: String,
-
-	pub(crate) action: String,
-	pub(crate) action_data: Option<serde_json::Value>,
+	pub(crate) notice_type: String,
+	pub(crate) system_message: String,
+	pub(crate) text: String,
+	/// `(id, login, name)`; `None` for anonymous notices.
+	pub(crate) chatter: Option<(String, String, String)>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChannelRaidNotification {
+pub(crate) struct NormalizedChatClearNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
@@ -453,15 +995,12 @@ pub(crate) struct NormalizedChannelRaidNotification {
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) from_broadcaster_user_id: String,
-	pub(crate) from_broadcaster_user_login: String,
-	pub(crate) from_broadcaster_user_name: String,
-
-	pub(crate) viewers: u64,
+	/// Set for `channel.chat.clear_user_messages`: `(id, login, name)` of the user whose messages were removed.
+	pub(crate) target: Option<(String, String, String)>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChannelCheerNotification {
+pub(crate) struct NormalizedStreamStatusNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
@@ -469,18 +1008,27 @@ pub(crate) struct NormalizedChannelCheerNotification {
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) bits: u64,
-	pub(crate) message: Option<String>,
+	/// Set by `stream.online` / `stream.offline`; `channel.update` leaves it unknown.
+	pub(crate) live: Option<bool>,
+	pub(crate) started_at: Option<SystemTime>,
+	pub(crate) title: Option<String>,
+	pub(crate) category: Option<String>,
+}
 
-	pub(crate) user_id: Option<String>,
-	pub(crate) user_login: Option<String>,
-	pub(crate) user_name: Option<String>,
+#[derive(Debug, Clone)]
+pub(crate) struct NormalizedPollNotification {
+	#[allow(dead_code)]
+	pub(crate) platform: Platform,
+	pub(crate) room: RoomKey,
+	pub(crate) ws_message_id: String,
+	pub(crate) subscription_id: String,
+	pub(crate) platform_time: SystemTime,
 
-	pub(crate) is_anonymous: bool,
+	pub(crate) poll: crate::Poll,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChannelSubscribeNotification {
+pub(crate) struct NormalizedRedemptionNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
@@ -488,27 +1036,19 @@ pub(crate) struct NormalizedChannelSubscribeNotification {
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) user_id: String,
-	pub(crate) user_login: String,
-	pub(crate) user_name: String,
-
-	pub(crate) tier: String,
-	pub(crate) is_gift: bool,
+	pub(crate) redemption: crate::Redemption,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct NormalizedChatMessageDeleteNotification {
+pub(crate) struct NormalizedProgressNotification {
 	#[allow(dead_code)]
 	pub(crate) platform: Platform,
 	pub(crate) room: RoomKey,
-	pub(crate) target_message_platform_id: PlatformMessageId,
 	pub(crate) ws_message_id: String,
 	pub(crate) subscription_id: String,
 	pub(crate) platform_time: SystemTime,
 
-	pub(crate) target_user_id: String,
-	pub(crate) target_user_login: String,
-	pub(crate) target_user_name: String,
+	pub(crate) progress: crate::Progress,
 }
 
 pub(crate) fn try_normalize_channel_chat_message(raw_json: &str) -> anyhow::Result<Option<NormalizedChatNotification>> {
@@ -836,3 +1376,485 @@ pub(crate) fn try_normalize_channel_subscribe(
 		is_gift: msg.payload.event.is_gift,
 	}))
 }
+
+/// Normalize both AutoMod review notifications; `status` tells an update apart from a hold.
+pub(crate) fn try_normalize_automod_message(raw_json: &str) -> anyhow::Result<Option<NormalizedAutoModMessageNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+	if !matches!(
+		peek.metadata.subscription_type.as_deref(),
+		Some("automod.message.hold" | "automod.message.update")
+	) {
+		return Ok(None);
+	}
+
+	let msg: EventSubNotification<AutoModMessageEvent> = parse_automod_message_notification(raw_json)?;
+
+	let platform_time = parse_message_timestamp_system_time(&msg.metadata.message_timestamp)?;
+
+	let ev = msg.payload.event;
+	let room_id = RoomId::new(ev.broadcaster_user_login.clone()).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	let moderator = match (ev.moderator_user_id, ev.moderator_user_login, ev.moderator_user_name) {
+		(Some(id), Some(login), Some(name)) => Some((id, login, name)),
+		_ => None,
+	};
+
+	Ok(Some(NormalizedAutoModMessageNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: msg.metadata.message_id,
+		subscription_id: msg.payload.subscription.id,
+		platform_time,
+
+		message_id: ev.message_id,
+		user_id: ev.user_id,
+		user_login: ev.user_login,
+		user_name: ev.user_name,
+		text: ev.message.text,
+		category: ev.category,
+		level: ev.level,
+
+		moderator,
+		status: ev.status,
+	}))
+}
+
+/// Normalize both unban request notifications; `status` tells a resolution apart from a new request.
+pub(crate) fn try_normalize_unban_request(raw_json: &str) -> anyhow::Result<Option<NormalizedUnbanRequestNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+	if !matches!(
+		peek.metadata.subscription_type.as_deref(),
+		Some("channel.unban_request.create" | "channel.unban_request.resolve")
+	) {
+		return Ok(None);
+	}
+
+	let msg: EventSubNotification<UnbanRequestEvent> = parse_unban_request_notification(raw_json)?;
+
+	let platform_time = parse_message_timestamp_system_time(&msg.metadata.message_timestamp)?;
+
+	let ev = msg.payload.event;
+	let room_id = RoomId::new(ev.broadcaster_user_login.clone()).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	let moderator = match (ev.moderator_id, ev.moderator_login, ev.moderator_name) {
+		(Some(id), Some(login), Some(name)) => Some((id, login, name)),
+		_ => None,
+	};
+
+	Ok(Some(NormalizedUnbanRequestNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: msg.metadata.message_id,
+		subscription_id: msg.payload.subscription.id,
+		platform_time,
+
+		request_id: ev.id,
+		user_id: ev.user_id,
+		user_login: ev.user_login,
+		user_name: ev.user_name,
+		text: ev.text.filter(|t| !t.is_empty()),
+
+		moderator,
+		resolution_text: ev.resolution_text.filter(|t| !t.is_empty()),
+		status: ev.status,
+	}))
+}
+
+pub(crate) fn try_normalize_channel_chat_notification(
+	raw_json: &str,
+) -> anyhow::Result<Option<NormalizedChatNoticeNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+	if peek.metadata.subscription_type.as_deref() != Some("channel.chat.notification") {
+		return Ok(None);
+	}
+
+	let msg: EventSubNotification<ChannelChatNotificationEvent> = parse_channel_chat_notification_notification(raw_json)?;
+
+	let platform_time = parse_message_timestamp_system_time(&msg.metadata.message_timestamp)?;
+
+	let ev = msg.payload.event;
+	let room_id = RoomId::new(ev.broadcaster_user_login.clone()).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	let chatter = match (ev.chatter_user_id, ev.chatter_user_login, ev.chatter_user_name) {
+		(Some(id), Some(login), Some(name)) if !ev.chatter_is_anonymous && !id.is_empty() => Some((id, login, name)),
+		_ => None,
+	};
+
+	Ok(Some(NormalizedChatNoticeNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: msg.metadata.message_id,
+		subscription_id: msg.payload.subscription.id,
+		platform_time,
+
+		notice_type: ev.notice_type,
+		system_message: ev.system_message,
+		text: ev.message.text,
+		chatter,
+	}))
+}
+
+/// Normalize `channel.chat.clear` and `channel.chat.clear_user_messages`; `target` tells them apart.
+pub(crate) fn try_normalize_channel_chat_clear(raw_json: &str) -> anyhow::Result<Option<NormalizedChatClearNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+
+	let (broadcaster_user_login, target, metadata, subscription) = match peek.metadata.subscription_type.as_deref() {
+		Some("channel.chat.clear") => {
+			let msg = parse_channel_chat_clear_notification(raw_json)?;
+			(
+				msg.payload.event.broadcaster_user_login,
+				None,
+				msg.metadata,
+				msg.payload.subscription,
+			)
+		}
+		Some("channel.chat.clear_user_messages") => {
+			let msg = parse_channel_chat_clear_user_messages_notification(raw_json)?;
+			let ev = msg.payload.event;
+			(
+				ev.broadcaster_user_login,
+				Some((ev.target_user_id, ev.target_user_login, ev.target_user_name)),
+				msg.metadata,
+				msg.payload.subscription,
+			)
+		}
+		_ => return Ok(None),
+	};
+
+	let platform_time = parse_message_timestamp_system_time(&metadata.message_timestamp)?;
+
+	let room_id = RoomId::new(broadcaster_user_login).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	Ok(Some(NormalizedChatClearNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: metadata.message_id,
+		subscription_id: subscription.id,
+		platform_time,
+
+		target,
+	}))
+}
+
+/// Normalize `stream.online`, `stream.offline` and `channel.update` into one stream status delta.
+pub(crate) fn try_normalize_stream_status(raw_json: &str) -> anyhow::Result<Option<NormalizedStreamStatusNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+
+	let (broadcaster_user_login, live, started_at, title, category, metadata, subscription) =
+		match peek.metadata.subscription_type.as_deref() {
+			Some("stream.online") => {
+				let msg = parse_stream_online_notification(raw_json)?;
+				let started_at = parse_message_timestamp_system_time(&msg.payload.event.started_at)?;
+				(
+					msg.payload.event.broadcaster_user_login,
+					Some(true),
+					Some(started_at),
+					None,
+					None,
+					msg.metadata,
+					msg.payload.subscription,
+				)
+			}
+			Some("stream.offline") => {
+				let msg = parse_stream_offline_notification(raw_json)?;
+				(
+					msg.payload.event.broadcaster_user_login,
+					Some(false),
+					None,
+					None,
+					None,
+					msg.metadata,
+					msg.payload.subscription,
+				)
+			}
+			Some("channel.update") => {
+				let msg = parse_channel_update_notification(raw_json)?;
+				let ev = msg.payload.event;
+				(
+					ev.broadcaster_user_login,
+					None,
+					None,
+					Some(ev.title),
+					Some(ev.category_name),
+					msg.metadata,
+					msg.payload.subscription,
+				)
+			}
+			_ => return Ok(None),
+		};
+
+	let platform_time = parse_message_timestamp_system_time(&metadata.message_timestamp)?;
+
+	let room_id = RoomId::new(broadcaster_user_login).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	Ok(Some(NormalizedStreamStatusNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: metadata.message_id,
+		subscription_id: subscription.id,
+		platform_time,
+
+		live,
+		started_at,
+		title,
+		category,
+	}))
+}
+
+fn parse_optional_timestamp(ts: Option<&str>) -> Option<SystemTime> {
+	ts.filter(|ts| !ts.is_empty())
+		.and_then(|ts| parse_message_timestamp_system_time(ts).ok())
+}
+
+/// Normalize every `channel.poll.*` and `channel.prediction.*` notification into a poll snapshot.
+pub(crate) fn try_normalize_poll(raw_json: &str) -> anyhow::Result<Option<NormalizedPollNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+
+	let (broadcaster_user_login, poll, metadata, subscription) = match peek.metadata.subscription_type.as_deref() {
+		Some(sub_type @ ("channel.poll.begin" | "channel.poll.progress" | "channel.poll.end")) => {
+			let msg = parse_channel_poll_notification(raw_json)?;
+			let ev = msg.payload.event;
+			let status = match (sub_type, ev.status.as_deref()) {
+				(_, Some("archived")) => crate::PollStatus::Canceled,
+				("channel.poll.end", _) => crate::PollStatus::Ended,
+				_ => crate::PollStatus::Active,
+			};
+			let poll = crate::Poll {
+				id: ev.id,
+				kind: crate::PollKind::Poll,
+				title: ev.title,
+				status,
+				choices: ev
+					.choices
+					.into_iter()
+					.map(|c| crate::PollChoice {
+						id: c.id,
+						title: c.title,
+						votes: c.votes,
+						channel_points: c.channel_points_votes,
+					})
+					.collect(),
+				winning_choice_id: None,
+				started_at: parse_optional_timestamp(ev.started_at.as_deref()),
+				ends_at: parse_optional_timestamp(ev.ends_at.as_deref()),
+			};
+			(ev.broadcaster_user_login, poll, msg.metadata, msg.payload.subscription)
+		}
+		Some(
+			sub_type @ ("channel.prediction.begin"
+			| "channel.prediction.progress"
+			| "channel.prediction.lock"
+			| "channel.prediction.end"),
+		) => {
+			let msg = parse_channel_prediction_notification(raw_json)?;
+			let ev = msg.payload.event;
+			let status = match (sub_type, ev.status.as_deref()) {
+				(_, Some("canceled")) => crate::PollStatus::Canceled,
+				("channel.prediction.end", _) => crate::PollStatus::Ended,
+				("channel.prediction.lock", _) => crate::PollStatus::Locked,
+				_ => crate::PollStatus::Active,
+			};
+			let poll = crate::Poll {
+				id: ev.id,
+				kind: crate::PollKind::Prediction,
+				title: ev.title,
+				status,
+				choices: ev
+					.outcomes
+					.into_iter()
+					.map(|o| crate::PollChoice {
+						id: o.id,
+						title: o.title,
+						votes: o.users,
+						channel_points: o.channel_points,
+					})
+					.collect(),
+				winning_choice_id: ev.winning_outcome_id.filter(|id| !id.is_empty()),
+				started_at: parse_optional_timestamp(ev.started_at.as_deref()),
+				ends_at: parse_optional_timestamp(ev.locks_at.as_deref()),
+			};
+			(ev.broadcaster_user_login, poll, msg.metadata, msg.payload.subscription)
+		}
+		_ => return Ok(None),
+	};
+
+	let platform_time = parse_message_timestamp_system_time(&metadata.message_timestamp)?;
+
+	let room_id = RoomId::new(broadcaster_user_login).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	Ok(Some(NormalizedPollNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: metadata.message_id,
+		subscription_id: subscription.id,
+		platform_time,
+
+		poll,
+	}))
+}
+
+/// Normalize `channel.channel_points_custom_reward_redemption.add` and `.update` notifications.
+pub(crate) fn try_normalize_channel_points_redemption(
+	raw_json: &str,
+) -> anyhow::Result<Option<NormalizedRedemptionNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+	if !matches!(
+		peek.metadata.subscription_type.as_deref(),
+		Some(
+			"channel.channel_points_custom_reward_redemption.add" | "channel.channel_points_custom_reward_redemption.update"
+		)
+	) {
+		return Ok(None);
+	}
+
+	let msg = parse_channel_points_redemption_notification(raw_json)?;
+	let ev = msg.payload.event;
+	let platform_time = parse_message_timestamp_system_time(&msg.metadata.message_timestamp)?;
+
+	let room_id = RoomId::new(ev.broadcaster_user_login).context("construct RoomId from broadcaster_user_login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	// Anything short of a final state still sits in the reward queue.
+	let status = match ev.status.as_str() {
+		"fulfilled" => crate::RedemptionStatus::Fulfilled,
+		"canceled" => crate::RedemptionStatus::Canceled,
+		_ => crate::RedemptionStatus::Unfulfilled,
+	};
+
+	Ok(Some(NormalizedRedemptionNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: msg.metadata.message_id,
+		subscription_id: msg.payload.subscription.id,
+		platform_time,
+
+		redemption: crate::Redemption {
+			id: ev.id,
+			reward_id: ev.reward.id,
+			reward_title: ev.reward.title,
+			cost: ev.reward.cost,
+			user: crate::UserRef {
+				id: ev.user_id,
+				login: ev.user_login,
+				display: Some(ev.user_name),
+			},
+			user_input: Some(ev.user_input).filter(|input| !input.trim().is_empty()),
+			status,
+			redeemed_at: parse_optional_timestamp(ev.redeemed_at.as_deref()),
+		},
+	}))
+}
+
+/// Normalize hype train, goal and charity campaign notifications into a progress snapshot.
+pub(crate) fn try_normalize_progress(raw_json: &str) -> anyhow::Result<Option<NormalizedProgressNotification>> {
+	let peek: EventSubMetadataPeek = serde_json::from_str(raw_json).context("parse EventSub metadata peek")?;
+
+	if peek.metadata.message_type != "notification" {
+		return Ok(None);
+	}
+
+	let (broadcaster_login, progress, metadata, subscription) = match peek.metadata.subscription_type.as_deref() {
+		Some(sub_type @ ("channel.hype_train.begin" | "channel.hype_train.progress" | "channel.hype_train.end")) => {
+			let msg = parse_channel_hype_train_notification(raw_json)?;
+			let ev = msg.payload.event;
+			let progress = crate::Progress {
+				id: ev.id,
+				kind: crate::ProgressKind::HypeTrain,
+				level: Some(ev.level),
+				title: None,
+				current: ev.progress,
+				target: ev.goal,
+				currency: None,
+				expires_at: parse_optional_timestamp(ev.expires_at.as_deref()),
+				ended: sub_type == "channel.hype_train.end",
+			};
+			(ev.broadcaster_user_login, progress, msg.metadata, msg.payload.subscription)
+		}
+		Some(sub_type @ ("channel.goal.begin" | "channel.goal.progress" | "channel.goal.end")) => {
+			let msg = parse_channel_goal_notification(raw_json)?;
+			let ev = msg.payload.event;
+			let progress = crate::Progress {
+				id: ev.id,
+				kind: crate::ProgressKind::Goal,
+				level: None,
+				title: Some(ev.description).filter(|d| !d.trim().is_empty()),
+				current: ev.current_amount,
+				target: ev.target_amount,
+				currency: None,
+				expires_at: None,
+				ended: sub_type == "channel.goal.end",
+			};
+			(ev.broadcaster_user_login, progress, msg.metadata, msg.payload.subscription)
+		}
+		Some(
+			sub_type @ ("channel.charity_campaign.start"
+			| "channel.charity_campaign.progress"
+			| "channel.charity_campaign.stop"),
+		) => {
+			let msg = parse_channel_charity_campaign_notification(raw_json)?;
+			let ev = msg.payload.event;
+			let progress = crate::Progress {
+				id: ev.id,
+				kind: crate::ProgressKind::CharityCampaign,
+				level: None,
+				title: Some(ev.charity_name).filter(|n| !n.trim().is_empty()),
+				current: ev.current_amount.whole_units(),
+				target: ev.target_amount.whole_units(),
+				currency: Some(ev.current_amount.currency).filter(|c| !c.is_empty()),
+				expires_at: None,
+				ended: sub_type == "channel.charity_campaign.stop",
+			};
+			(ev.broadcaster_login, progress, msg.metadata, msg.payload.subscription)
+		}
+		_ => return Ok(None),
+	};
+
+	let platform_time = parse_message_timestamp_system_time(&metadata.message_timestamp)?;
+
+	let room_id = RoomId::new(broadcaster_login).context("construct RoomId from broadcaster login")?;
+	let room = RoomKey::new(Platform::Twitch, room_id);
+
+	Ok(Some(NormalizedProgressNotification {
+		platform: Platform::Twitch,
+		room,
+		ws_message_id: metadata.message_id,
+		subscription_id: subscription.id,
+		platform_time,
+
+		progress,
+	}))
+}
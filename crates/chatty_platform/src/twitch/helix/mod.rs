@@ -8,11 +8,25 @@ use reqwest::header::{HeaderMap, RETRY_AFTER};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::RoomChatSettings;
+
+#[cfg(test)]
+mod tests;
+
 const EVENTSUB_SUBSCRIPTIONS_PATH: &str = "/helix/eventsub/subscriptions";
+const CHANNEL_POINTS_REDEMPTIONS_PATH: &str = "/helix/channel_points/custom_rewards/redemptions";
 const CHAT_MESSAGES_PATH: &str = "/helix/chat/messages";
+const CHAT_SETTINGS_PATH: &str = "/helix/chat/settings";
+const MODERATION_AUTOMOD_MESSAGE_PATH: &str = "/helix/moderation/automod/message";
 const MODERATION_BANS_PATH: &str = "/helix/moderation/bans";
 const MODERATION_CHAT_PATH: &str = "/helix/moderation/chat";
 const MODERATION_MODERATORS_PATH: &str = "/helix/moderation/moderators";
+const MODERATION_UNBAN_REQUESTS_PATH: &str = "/helix/moderation/unban_requests";
+const POLLS_PATH: &str = "/helix/polls";
+const PREDICTIONS_PATH: &str = "/helix/predictions";
+const STREAMS_PATH: &str = "/helix/streams";
+/// Helix caps `get streams` at 100 `user_id` filters per request.
+pub(crate) const STREAMS_MAX_USER_IDS: usize = 100;
 const TOKEN_VALIDATE_URL: &str = "https://id.twitch.tv/oauth2/validate";
 const TOKEN_REFRESH_URL: &str = "https://id.twitch.tv/oauth2/token";
 
@@ -125,6 +139,83 @@ struct HelixSendChatMessage<'a> {
 	reply_parent_message_id: Option<&'a str>,
 }
 
+#[derive(Debug, Default, Serialize)]
+struct HelixChatSettingsPatch {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	emote_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	subscriber_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	unique_chat_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	slow_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	slow_mode_wait_time: Option<u64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	follower_mode: Option<bool>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	follower_mode_duration: Option<u64>,
+}
+
+impl From<&RoomChatSettings> for HelixChatSettingsPatch {
+	fn from(s: &RoomChatSettings) -> Self {
+		Self {
+			emote_mode: s.emote_only,
+			subscriber_mode: s.subscribers_only,
+			unique_chat_mode: s.unique_chat,
+			slow_mode: s.slow_mode,
+			// Helix rejects a wait time while slow mode is being turned off.
+			slow_mode_wait_time: s.slow_mode_wait_time_seconds.filter(|_| s.slow_mode != Some(false)),
+			follower_mode: s.followers_only,
+			follower_mode_duration: s.followers_only_duration_minutes.filter(|_| s.followers_only != Some(false)),
+		}
+	}
+}
+
+#[derive(Debug, Serialize)]
+struct HelixAutoModMessageAction<'a> {
+	user_id: &'a str,
+	msg_id: &'a str,
+	action: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct HelixCreatePollRequest<'a> {
+	broadcaster_id: &'a str,
+	title: &'a str,
+	choices: Vec<HelixTitle<'a>>,
+	duration: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct HelixCreatePredictionRequest<'a> {
+	broadcaster_id: &'a str,
+	title: &'a str,
+	outcomes: Vec<HelixTitle<'a>>,
+	prediction_window: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct HelixTitle<'a> {
+	title: &'a str,
+}
+
+/// Body for `PATCH /helix/polls` and `PATCH /helix/predictions`.
+#[derive(Debug, Serialize)]
+struct HelixEndPollRequest<'a> {
+	broadcaster_id: &'a str,
+	id: &'a str,
+	status: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	winning_outcome_id: Option<&'a str>,
+}
+
+/// Body for `PATCH /helix/channel_points/custom_rewards/redemptions`.
+#[derive(Debug, Serialize)]
+struct HelixUpdateRedemptionRequest<'a> {
+	status: &'a str,
+}
+
 #[derive(Debug, Serialize)]
 struct HelixBanRequest<'a> {
 	data: HelixBanData<'a>,
@@ -273,6 +364,35 @@ impl HelixClient {
 		Ok(parsed.data.iter().any(|m| m.user_id == user_id))
 	}
 
+	/// Current broadcasts of up to [`STREAMS_MAX_USER_IDS`] channels; offline channels are absent from the result.
+	pub(crate) async fn get_streams(&self, broadcaster_ids: &[String]) -> anyhow::Result<Vec<HelixStream>> {
+		anyhow::ensure!(
+			broadcaster_ids.len() <= STREAMS_MAX_USER_IDS,
+			"helix GET /helix/streams accepts at most {STREAMS_MAX_USER_IDS} user_id values"
+		);
+		let query = broadcaster_ids
+			.iter()
+			.map(|id| format!("user_id={}", urlencoding::encode(id)))
+			.collect::<Vec<_>>()
+			.join("&");
+		let url = self.url(&format!("{STREAMS_PATH}?first={STREAMS_MAX_USER_IDS}&{query}"))?;
+
+		let resp = self
+			.send_with_retry(self.authed(self.http.get(url)), "GET /helix/streams")
+			.await
+			.context("helix GET /helix/streams send")?;
+
+		let status = resp.status();
+		let body = resp.text().await.context("helix GET /helix/streams read body")?;
+
+		if !status.is_success() {
+			anyhow::bail!("helix GET /helix/streams failed: status={status} body={body}");
+		}
+
+		let parsed: HelixStreamsResponse = serde_json::from_str(&body).context("helix streams parse json")?;
+		Ok(parsed.data)
+	}
+
 	pub(crate) async fn create_chat_message_subscription(
 		&self,
 		session_id: &str,
@@ -309,6 +429,60 @@ impl HelixClient {
 		.await
 	}
 
+	pub(crate) async fn create_chat_notification_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.chat.notification",
+			"1",
+			session_id,
+			HelixChatMessageCondition {
+				broadcaster_user_id,
+				user_id,
+			},
+		)
+		.await
+	}
+
+	pub(crate) async fn create_chat_clear_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.chat.clear",
+			"1",
+			session_id,
+			HelixChatMessageCondition {
+				broadcaster_user_id,
+				user_id,
+			},
+		)
+		.await
+	}
+
+	pub(crate) async fn create_chat_clear_user_messages_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.chat.clear_user_messages",
+			"1",
+			session_id,
+			HelixChatMessageCondition {
+				broadcaster_user_id,
+				user_id,
+			},
+		)
+		.await
+	}
+
 	pub(crate) async fn send_chat_message(
 		&self,
 		broadcaster_id: &str,
@@ -393,6 +567,324 @@ impl HelixClient {
 		Ok(())
 	}
 
+	/// Allows or denies a message AutoMod is holding for review.
+	pub(crate) async fn manage_held_automod_message(
+		&self,
+		moderator_id: &str,
+		message_id: &str,
+		allow: bool,
+	) -> anyhow::Result<()> {
+		let url = self.url(MODERATION_AUTOMOD_MESSAGE_PATH)?;
+		let req = HelixAutoModMessageAction {
+			user_id: moderator_id,
+			msg_id: message_id,
+			action: if allow { "ALLOW" } else { "DENY" },
+		};
+		let resp = self
+			.send_with_retry(
+				self.authed(self.http.post(url)).json(&req),
+				"POST /helix/moderation/automod/message",
+			)
+			.await
+			.context("helix POST /helix/moderation/automod/message send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix manage held automod message failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn update_chat_settings(
+		&self,
+		broadcaster_id: &str,
+		moderator_id: &str,
+		settings: &RoomChatSettings,
+	) -> anyhow::Result<()> {
+		let url = self.url(&format!(
+			"{base}?broadcaster_id={b}&moderator_id={m}",
+			base = CHAT_SETTINGS_PATH,
+			b = urlencoding::encode(broadcaster_id),
+			m = urlencoding::encode(moderator_id),
+		))?;
+		let req = HelixChatSettingsPatch::from(settings);
+		let resp = self
+			.send_with_retry(self.authed(self.http.patch(url)).json(&req), "PATCH /helix/chat/settings")
+			.await
+			.context("helix PATCH /helix/chat/settings send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix update chat settings failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Lifts a ban or an active timeout.
+	pub(crate) async fn unban_user(&self, broadcaster_id: &str, moderator_id: &str, user_id: &str) -> anyhow::Result<()> {
+		let url = self.url(&format!(
+			"{base}?broadcaster_id={b}&moderator_id={m}&user_id={u}",
+			base = MODERATION_BANS_PATH,
+			b = urlencoding::encode(broadcaster_id),
+			m = urlencoding::encode(moderator_id),
+			u = urlencoding::encode(user_id),
+		))?;
+		let resp = self
+			.send_with_retry(self.authed(self.http.delete(url)), "DELETE /helix/moderation/bans")
+			.await
+			.context("helix DELETE /helix/moderation/bans send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix unban user failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Lists the channel's unban requests with the given status (`pending`, `approved`, `denied`, ...), following pagination.
+	pub(crate) async fn list_unban_requests(
+		&self,
+		broadcaster_id: &str,
+		moderator_id: &str,
+		status: &str,
+	) -> anyhow::Result<Vec<HelixUnbanRequest>> {
+		let mut out: Vec<HelixUnbanRequest> = Vec::new();
+		let mut after: Option<String> = None;
+
+		loop {
+			let mut path = format!(
+				"{base}?broadcaster_id={b}&moderator_id={m}&status={s}&first=100",
+				base = MODERATION_UNBAN_REQUESTS_PATH,
+				b = urlencoding::encode(broadcaster_id),
+				m = urlencoding::encode(moderator_id),
+				s = urlencoding::encode(status),
+			);
+			if let Some(after) = after.as_deref() {
+				path.push_str("&after=");
+				path.push_str(&urlencoding::encode(after));
+			}
+			let url = self.url(&path)?;
+
+			let resp = self
+				.send_with_retry(self.authed(self.http.get(url)), "GET /helix/moderation/unban_requests")
+				.await
+				.context("helix GET /helix/moderation/unban_requests send")?;
+			let status_code = resp.status();
+			let body = resp
+				.text()
+				.await
+				.context("helix GET /helix/moderation/unban_requests read body")?;
+			if !status_code.is_success() {
+				anyhow::bail!("helix list unban requests failed: status={status_code} body={body}");
+			}
+
+			let page: HelixUnbanRequestsResponse = serde_json::from_str(&body).context("helix unban requests parse json")?;
+			out.extend(page.data);
+
+			let next = page.pagination.and_then(|p| p.cursor).filter(|c| !c.is_empty());
+			if next.is_none() {
+				break;
+			}
+			after = next;
+		}
+
+		Ok(out)
+	}
+
+	/// Approves or denies an unban request; approving also lifts the ban.
+	pub(crate) async fn resolve_unban_request(
+		&self,
+		broadcaster_id: &str,
+		moderator_id: &str,
+		request_id: &str,
+		approve: bool,
+		resolution_text: Option<&str>,
+	) -> anyhow::Result<()> {
+		let mut path = format!(
+			"{base}?broadcaster_id={b}&moderator_id={m}&unban_request_id={r}&status={s}",
+			base = MODERATION_UNBAN_REQUESTS_PATH,
+			b = urlencoding::encode(broadcaster_id),
+			m = urlencoding::encode(moderator_id),
+			r = urlencoding::encode(request_id),
+			s = if approve { "approved" } else { "denied" },
+		);
+		if let Some(text) = resolution_text {
+			path.push_str("&resolution_text=");
+			path.push_str(&urlencoding::encode(text));
+		}
+		let url = self.url(&path)?;
+		let resp = self
+			.send_with_retry(self.authed(self.http.patch(url)), "PATCH /helix/moderation/unban_requests")
+			.await
+			.context("helix PATCH /helix/moderation/unban_requests send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix resolve unban request failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Helix only accepts the broadcaster's own token here.
+	pub(crate) async fn create_poll(
+		&self,
+		broadcaster_id: &str,
+		title: &str,
+		choices: &[String],
+		duration_seconds: u32,
+	) -> anyhow::Result<()> {
+		let url = self.url(POLLS_PATH)?;
+		let req = HelixCreatePollRequest {
+			broadcaster_id,
+			title,
+			choices: choices.iter().map(|title| HelixTitle { title }).collect(),
+			duration: duration_seconds,
+		};
+		let resp = self
+			.send_with_retry(self.authed(self.http.post(url)).json(&req), "POST /helix/polls")
+			.await
+			.context("helix POST /helix/polls send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix create poll failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Ends a poll as `TERMINATED` (results stay visible) or `ARCHIVED`.
+	pub(crate) async fn end_poll(&self, broadcaster_id: &str, poll_id: &str, archive: bool) -> anyhow::Result<()> {
+		let url = self.url(POLLS_PATH)?;
+		let req = HelixEndPollRequest {
+			broadcaster_id,
+			id: poll_id,
+			status: if archive { "ARCHIVED" } else { "TERMINATED" },
+			winning_outcome_id: None,
+		};
+		let resp = self
+			.send_with_retry(self.authed(self.http.patch(url)).json(&req), "PATCH /helix/polls")
+			.await
+			.context("helix PATCH /helix/polls send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix end poll failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Helix only accepts the broadcaster's own token here.
+	pub(crate) async fn create_prediction(
+		&self,
+		broadcaster_id: &str,
+		title: &str,
+		outcomes: &[String],
+		prediction_window_seconds: u32,
+	) -> anyhow::Result<()> {
+		let url = self.url(PREDICTIONS_PATH)?;
+		let req = HelixCreatePredictionRequest {
+			broadcaster_id,
+			title,
+			outcomes: outcomes.iter().map(|title| HelixTitle { title }).collect(),
+			prediction_window: prediction_window_seconds,
+		};
+		let resp = self
+			.send_with_retry(self.authed(self.http.post(url)).json(&req), "POST /helix/predictions")
+			.await
+			.context("helix POST /helix/predictions send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix create prediction failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Resolves a prediction with its winning outcome, or cancels it (refunding points) when `None`.
+	pub(crate) async fn resolve_prediction(
+		&self,
+		broadcaster_id: &str,
+		prediction_id: &str,
+		winning_outcome_id: Option<&str>,
+	) -> anyhow::Result<()> {
+		let url = self.url(PREDICTIONS_PATH)?;
+		let req = HelixEndPollRequest {
+			broadcaster_id,
+			id: prediction_id,
+			status: if winning_outcome_id.is_some() {
+				"RESOLVED"
+			} else {
+				"CANCELED"
+			},
+			winning_outcome_id,
+		};
+		let resp = self
+			.send_with_retry(self.authed(self.http.patch(url)).json(&req), "PATCH /helix/predictions")
+			.await
+			.context("helix PATCH /helix/predictions send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix resolve prediction failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Moves an unfulfilled redemption to `FULFILLED`, or `CANCELED` (refunding the points).
+	///
+	/// Helix only accepts the broadcaster's own token, and only for rewards created by the same client id.
+	pub(crate) async fn update_redemption_status(
+		&self,
+		broadcaster_id: &str,
+		reward_id: &str,
+		redemption_id: &str,
+		fulfill: bool,
+	) -> anyhow::Result<()> {
+		let url = self.url(&format!(
+			"{base}?broadcaster_id={b}&reward_id={r}&id={i}",
+			base = CHANNEL_POINTS_REDEMPTIONS_PATH,
+			b = urlencoding::encode(broadcaster_id),
+			r = urlencoding::encode(reward_id),
+			i = urlencoding::encode(redemption_id),
+		))?;
+		let req = HelixUpdateRedemptionRequest {
+			status: if fulfill { "FULFILLED" } else { "CANCELED" },
+		};
+		let resp = self
+			.send_with_retry(
+				self.authed(self.http.patch(url)).json(&req),
+				"PATCH /helix/channel_points/custom_rewards/redemptions",
+			)
+			.await
+			.context("helix PATCH /helix/channel_points/custom_rewards/redemptions send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix update redemption failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
+	/// Deletes every message in the chat room (no `message_id`).
+	pub(crate) async fn clear_chat(&self, broadcaster_id: &str, moderator_id: &str) -> anyhow::Result<()> {
+		let url = self.url(&format!(
+			"{base}?broadcaster_id={b}&moderator_id={m}",
+			base = MODERATION_CHAT_PATH,
+			b = urlencoding::encode(broadcaster_id),
+			m = urlencoding::encode(moderator_id),
+		))?;
+		let resp = self
+			.send_with_retry(self.authed(self.http.delete(url)), "DELETE /helix/moderation/chat")
+			.await
+			.context("helix DELETE /helix/moderation/chat send")?;
+		let status = resp.status();
+		let body = resp.text().await.unwrap_or_default();
+		if !status.is_success() {
+			anyhow::bail!("helix clear chat failed: status={status} body={body}");
+		}
+		Ok(())
+	}
+
 	pub(crate) async fn create_channel_ban_subscription(
 		&self,
 		session_id: &str,
@@ -407,6 +899,98 @@ impl HelixClient {
 		.await
 	}
 
+	pub(crate) async fn create_stream_online_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"stream.online",
+			"1",
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
+	pub(crate) async fn create_stream_offline_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"stream.offline",
+			"1",
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
+	pub(crate) async fn create_channel_update_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.update",
+			"2",
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
+	/// Any `channel.poll.*` or `channel.prediction.*` type; all are v1 with a broadcaster-only condition.
+	pub(crate) async fn create_poll_subscription(
+		&self,
+		kind: &'static str,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			kind,
+			"1",
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
+	/// `channel.channel_points_custom_reward_redemption.add` or `.update`; both v1 with a broadcaster-only condition.
+	pub(crate) async fn create_redemption_subscription(
+		&self,
+		kind: &'static str,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			kind,
+			"1",
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
+	/// Any `channel.hype_train.*` (v2), `channel.goal.*` or `channel.charity_campaign.*` (v1) type; all use a
+	/// broadcaster-only condition.
+	pub(crate) async fn create_progress_subscription(
+		&self,
+		kind: &'static str,
+		session_id: &str,
+		broadcaster_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		let version = if kind.starts_with("channel.hype_train.") { "2" } else { "1" };
+		self.create_eventsub_subscription(
+			kind,
+			version,
+			session_id,
+			HelixChannelBroadcasterOnlyCondition { broadcaster_user_id },
+		)
+		.await
+	}
+
 	#[allow(dead_code)]
 	pub(crate) async fn create_channel_unban_subscription(
 		&self,
@@ -482,6 +1066,78 @@ impl HelixClient {
 		.await
 	}
 
+	pub(crate) async fn create_automod_message_hold_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		moderator_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"automod.message.hold",
+			"1",
+			session_id,
+			HelixChannelModerateCondition {
+				broadcaster_user_id,
+				moderator_user_id,
+			},
+		)
+		.await
+	}
+
+	pub(crate) async fn create_automod_message_update_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		moderator_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"automod.message.update",
+			"1",
+			session_id,
+			HelixChannelModerateCondition {
+				broadcaster_user_id,
+				moderator_user_id,
+			},
+		)
+		.await
+	}
+
+	pub(crate) async fn create_unban_request_create_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		moderator_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.unban_request.create",
+			"1",
+			session_id,
+			HelixChannelModerateCondition {
+				broadcaster_user_id,
+				moderator_user_id,
+			},
+		)
+		.await
+	}
+
+	pub(crate) async fn create_unban_request_resolve_subscription(
+		&self,
+		session_id: &str,
+		broadcaster_user_id: &str,
+		moderator_user_id: &str,
+	) -> anyhow::Result<HelixCreateSubscriptionResponse> {
+		self.create_eventsub_subscription(
+			"channel.unban_request.resolve",
+			"1",
+			session_id,
+			HelixChannelModerateCondition {
+				broadcaster_user_id,
+				moderator_user_id,
+			},
+		)
+		.await
+	}
+
 	pub(crate) async fn list_eventsub_subscriptions_by_type(
 		&self,
 		subscription_type: &str,
@@ -629,6 +1285,24 @@ pub async fn refresh_user_token(
 	serde_json::from_str(&body).context("twitch refresh token parse json")
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct HelixStreamsResponse {
+	pub(crate) data: Vec<HelixStream>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct HelixStream {
+	pub(crate) user_id: String,
+	#[serde(default)]
+	pub(crate) title: String,
+	#[serde(default)]
+	pub(crate) game_name: String,
+	#[serde(default)]
+	pub(crate) viewer_count: u64,
+	/// RFC3339.
+	pub(crate) started_at: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct HelixUsersResponse {
 	pub(crate) data: Vec<HelixUser>,
@@ -665,6 +1339,36 @@ pub(crate) struct HelixModerator {
 	pub(crate) user_name: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub(crate) struct HelixUnbanRequestsResponse {
+	pub(crate) data: Vec<HelixUnbanRequest>,
+
+	#[serde(default)]
+	pub(crate) pagination: Option<HelixPagination>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct HelixUnbanRequest {
+	pub(crate) id: String,
+	pub(crate) user_id: String,
+	pub(crate) user_login: String,
+	#[serde(default)]
+	pub(crate) user_name: Option<String>,
+	#[serde(default)]
+	pub(crate) text: Option<String>,
+	pub(crate) status: String,
+	#[serde(default)]
+	pub(crate) created_at: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_id: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_login: Option<String>,
+	#[serde(default)]
+	pub(crate) moderator_name: Option<String>,
+	#[serde(default)]
+	pub(crate) resolution_text: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[allow(dead_code)]
 pub(crate) struct HelixCreateSubscriptionRequest<'a> {
@@ -0,0 +1,261 @@
+#![forbid(unsafe_code)]
+
+use url::Url;
+
+use super::HelixClient;
+use crate::RoomChatSettings;
+use crate::test_http::spawn_fixture_server;
+
+#[tokio::test]
+async fn unban_and_clear_chat_hit_moderation_endpoints() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.path() {
+		"/helix/moderation/bans" | "/helix/moderation/chat" => ("204 No Content", String::new()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	helix.unban_user("100", "200", "300").await.expect("unban");
+	helix.clear_chat("100", "200").await.expect("clear chat");
+
+	let requests = requests.lock().await;
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].method, "DELETE");
+	assert_eq!(
+		requests[0].target,
+		"/helix/moderation/bans?broadcaster_id=100&moderator_id=200&user_id=300"
+	);
+	assert_eq!(requests[0].authorization.as_deref(), Some("Bearer token"));
+	assert_eq!(requests[1].method, "DELETE");
+	assert_eq!(
+		requests[1].target, "/helix/moderation/chat?broadcaster_id=100&moderator_id=200",
+		"clearing chat omits message_id"
+	);
+}
+
+#[tokio::test]
+async fn update_chat_settings_patches_only_set_fields() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.path() {
+		"/helix/chat/settings" => ("200 OK", r#"{"data":[]}"#.to_string()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	let settings = RoomChatSettings {
+		slow_mode: Some(true),
+		slow_mode_wait_time_seconds: Some(30),
+		followers_only: Some(false),
+		followers_only_duration_minutes: Some(10),
+		..Default::default()
+	};
+	helix
+		.update_chat_settings("100", "200", &settings)
+		.await
+		.expect("update settings");
+
+	let requests = requests.lock().await;
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].method, "PATCH");
+	assert_eq!(requests[0].target, "/helix/chat/settings?broadcaster_id=100&moderator_id=200");
+	let body: serde_json::Value = serde_json::from_str(&requests[0].body).expect("json body");
+	assert_eq!(
+		body,
+		serde_json::json!({"slow_mode": true, "slow_mode_wait_time": 30, "follower_mode": false}),
+		"unset fields are omitted and durations are dropped when a mode is turned off"
+	);
+}
+
+#[tokio::test]
+async fn manage_held_automod_message_posts_action() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.path() {
+		"/helix/moderation/automod/message" => ("204 No Content", String::new()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	helix.manage_held_automod_message("200", "held-1", false).await.expect("deny");
+
+	let requests = requests.lock().await;
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].method, "POST");
+	let body: serde_json::Value = serde_json::from_str(&requests[0].body).expect("json body");
+	assert_eq!(
+		body,
+		serde_json::json!({"user_id": "200", "msg_id": "held-1", "action": "DENY"})
+	);
+}
+
+#[tokio::test]
+async fn unban_requests_are_paged_and_resolved() {
+	let (addr, requests) = spawn_fixture_server(|req| match (req.method.as_str(), req.path()) {
+		("GET", "/helix/moderation/unban_requests") if !req.target.contains("after=") => (
+			"200 OK",
+			r#"{"data":[{"id":"req-1","user_id":"300","user_login":"viewer","user_name":"Viewer","text":"sorry","status":"pending","created_at":"2024-01-01T00:00:00Z"}],"pagination":{"cursor":"next"}}"#.to_string(),
+		),
+		("GET", "/helix/moderation/unban_requests") => (
+			"200 OK",
+			r#"{"data":[{"id":"req-2","user_id":"301","user_login":"other","status":"pending"}],"pagination":{}}"#.to_string(),
+		),
+		("PATCH", "/helix/moderation/unban_requests") => ("200 OK", r#"{"data":[]}"#.to_string()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	let listed = helix.list_unban_requests("100", "200", "pending").await.expect("list");
+	assert_eq!(
+		listed.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(),
+		vec!["req-1", "req-2"]
+	);
+	assert_eq!(listed[0].text.as_deref(), Some("sorry"));
+
+	helix
+		.resolve_unban_request("100", "200", "req-1", true, Some("welcome back"))
+		.await
+		.expect("resolve");
+
+	let requests = requests.lock().await;
+	assert_eq!(requests.len(), 3);
+	assert!(requests[1].target.contains("after=next"));
+	assert_eq!(requests[2].method, "PATCH");
+	assert!(requests[2].target.contains("unban_request_id=req-1"));
+	assert!(requests[2].target.contains("status=approved"));
+	assert!(requests[2].target.contains("resolution_text=welcome%20back"));
+}
+
+#[tokio::test]
+async fn get_streams_batches_channels_and_omits_offline_ones() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.target.as_str() {
+		"/helix/streams?first=100&user_id=100&user_id=200" => (
+			"200 OK",
+			r#"{"data":[{"id":"s-1","user_id":"100","user_login":"chan","game_name":"Celeste","type":"live","title":"speedruns","viewer_count":42,"started_at":"2024-01-01T00:00:00Z"}],"pagination":{}}"#.to_string(),
+		),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	let streams = helix
+		.get_streams(&["100".to_string(), "200".to_string()])
+		.await
+		.expect("get streams");
+	assert_eq!(streams.len(), 1, "offline channels are absent");
+	let live = &streams[0];
+	assert_eq!(live.user_id, "100");
+	assert_eq!(live.title, "speedruns");
+	assert_eq!(live.game_name, "Celeste");
+	assert_eq!(live.viewer_count, 42);
+	assert_eq!(live.started_at, "2024-01-01T00:00:00Z");
+	assert_eq!(requests.lock().await.len(), 1);
+
+	let too_many: Vec<String> = (0..=super::STREAMS_MAX_USER_IDS).map(|i| i.to_string()).collect();
+	assert!(helix.get_streams(&too_many).await.is_err());
+}
+
+#[tokio::test]
+async fn polls_and_predictions_are_created_and_ended_with_json_bodies() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.path() {
+		"/helix/polls" | "/helix/predictions" => ("200 OK", r#"{"data":[]}"#.to_string()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	helix
+		.create_poll("100", "Next game?", &["Celeste".to_string(), "Hades".to_string()], 60)
+		.await
+		.expect("create poll");
+	helix.end_poll("100", "poll-1", false).await.expect("end poll");
+	helix
+		.create_prediction("100", "Will we win?", &["Yes".to_string(), "No".to_string()], 120)
+		.await
+		.expect("create prediction");
+	helix.resolve_prediction("100", "pred-1", Some("o1")).await.expect("resolve");
+	helix.resolve_prediction("100", "pred-2", None).await.expect("cancel");
+
+	let requests = requests.lock().await;
+	let bodies: Vec<(String, serde_json::Value)> = requests
+		.iter()
+		.map(|r| (r.method.clone(), serde_json::from_str(&r.body).expect("json body")))
+		.collect();
+	assert_eq!(
+		bodies,
+		vec![
+			(
+				"POST".to_string(),
+				serde_json::json!({
+					"broadcaster_id": "100",
+					"title": "Next game?",
+					"choices": [{"title": "Celeste"}, {"title": "Hades"}],
+					"duration": 60
+				})
+			),
+			(
+				"PATCH".to_string(),
+				serde_json::json!({"broadcaster_id": "100", "id": "poll-1", "status": "TERMINATED"})
+			),
+			(
+				"POST".to_string(),
+				serde_json::json!({
+					"broadcaster_id": "100",
+					"title": "Will we win?",
+					"outcomes": [{"title": "Yes"}, {"title": "No"}],
+					"prediction_window": 120
+				})
+			),
+			(
+				"PATCH".to_string(),
+				serde_json::json!({"broadcaster_id": "100", "id": "pred-1", "status": "RESOLVED", "winning_outcome_id": "o1"})
+			),
+			(
+				"PATCH".to_string(),
+				serde_json::json!({"broadcaster_id": "100", "id": "pred-2", "status": "CANCELED"}),
+			),
+		]
+	);
+}
+
+#[tokio::test]
+async fn redemption_status_is_patched_with_ids_in_query() {
+	let (addr, requests) = spawn_fixture_server(|req| match req.path() {
+		"/helix/channel_points/custom_rewards/redemptions" => ("200 OK", r#"{"data":[]}"#.to_string()),
+		_ => ("404 Not Found", "{}".to_string()),
+	})
+	.await;
+	let base = Url::parse(&format!("http://{addr}")).expect("fixture url");
+	let helix = HelixClient::new(base, "client".to_string(), "token".to_string()).expect("helix client");
+
+	helix
+		.update_redemption_status("100", "reward-1", "red-1", true)
+		.await
+		.expect("fulfill");
+	helix
+		.update_redemption_status("100", "reward-1", "red-2", false)
+		.await
+		.expect("cancel");
+
+	let requests = requests.lock().await;
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].method, "PATCH");
+	assert_eq!(
+		requests[0].target,
+		"/helix/channel_points/custom_rewards/redemptions?broadcaster_id=100&reward_id=reward-1&id=red-1"
+	);
+	assert_eq!(
+		serde_json::from_str::<serde_json::Value>(&requests[0].body).expect("json body"),
+		serde_json::json!({"status": "FULFILLED"})
+	);
+	assert!(requests[1].target.ends_with("&id=red-2"));
+	assert_eq!(
+		serde_json::from_str::<serde_json::Value>(&requests[1].body).expect("json body"),
+		serde_json::json!({"status": "CANCELED"})
+	);
+}
@@ -4,6 +4,8 @@ mod adapter;
 mod eventsub;
 mod helix;
 mod notifications;
+#[cfg(test)]
+mod tests;
 
 pub use adapter::{TwitchConfig, TwitchEventSubAdapter};
 pub use helix::{TwitchTokenValidation, refresh_user_token, validate_user_token};
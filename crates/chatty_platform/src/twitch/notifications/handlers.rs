@@ -127,6 +127,203 @@ pub(crate) fn handle_notification_json(
 			Ok((Some(room_for_gating), out))
 		}
 
+		Some("channel.chat.notification") => {
+			let Some(n) = eventsub::try_normalize_channel_chat_notification(raw_json)
+				.context("normalize channel.chat.notification")?
+			else {
+				return Ok((None, out));
+			};
+
+			// New subs and raids also arrive as `channel.subscribe` / `channel.raid`.
+			if matches!(n.notice_type.as_str(), "sub" | "raid") {
+				return Ok((None, out));
+			}
+
+			let room_for_gating = n.room.clone();
+
+			let text = [n.system_message.trim(), n.text.trim()]
+				.into_iter()
+				.filter(|part| !part.is_empty())
+				.collect::<Vec<_>>()
+				.join(" ");
+
+			let user = n.chatter.map(|(id, login, name)| crate::UserRef {
+				id,
+				login,
+				display: Some(name),
+			});
+
+			out.push(super::mk_user_notice_ingest(
+				n.room,
+				ingest_now,
+				Some(n.platform_time),
+				n.notice_type,
+				Some(text).filter(|t| !t.is_empty()),
+				user,
+				adapter_session_id,
+				Some(n.ws_message_id),
+				Some(n.subscription_id),
+			));
+
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some("channel.chat.clear" | "channel.chat.clear_user_messages") => {
+			let Some(c) = eventsub::try_normalize_channel_chat_clear(raw_json).context("normalize channel.chat.clear")?
+			else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = c.room.clone();
+
+			let target = c.target.map(|(id, login, name)| crate::UserRef {
+				id,
+				login,
+				display: Some(name),
+			});
+
+			let (kind, action) = match target.clone() {
+				Some(user) => ("clear_user_messages", crate::ModerationAction::ClearUserMessages { user }),
+				None => ("clear_chat", crate::ModerationAction::ClearChat {}),
+			};
+
+			let mut ingest = IngestEvent::new(
+				Platform::Twitch,
+				c.room.room_id.clone(),
+				IngestPayload::Moderation(Box::new(crate::ModerationEvent {
+					kind: kind.to_string(),
+					actor: None,
+					target,
+					target_message_platform_id: None,
+					notes: None,
+					action: Some(action),
+				})),
+			);
+
+			ingest.room = c.room;
+			ingest.ingest_time = ingest_now;
+			ingest.platform_time = Some(c.platform_time);
+
+			let mut trace = crate::IngestTrace {
+				session_id: Some(adapter_session_id.to_string()),
+				..crate::IngestTrace::default()
+			};
+			trace.fields.insert("twitch_ws_message_id".to_string(), c.ws_message_id);
+			trace.fields.insert("twitch_subscription_id".to_string(), c.subscription_id);
+			ingest.trace = trace;
+
+			out.push(AdapterEvent::Ingest(Box::new(ingest)));
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some("stream.online" | "stream.offline" | "channel.update") => {
+			let Some(st) = eventsub::try_normalize_stream_status(raw_json).context("normalize stream status")? else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = st.room.clone();
+
+			out.push(super::mk_stream_status_ingest(
+				st.room,
+				ingest_now,
+				Some(st.platform_time),
+				crate::StreamStatus {
+					live: st.live,
+					started_at: st.started_at,
+					title: st.title,
+					category: st.category,
+					viewer_count: None,
+				},
+				adapter_session_id,
+				Some(st.ws_message_id),
+				Some(st.subscription_id),
+			));
+
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some(
+			"channel.poll.begin"
+			| "channel.poll.progress"
+			| "channel.poll.end"
+			| "channel.prediction.begin"
+			| "channel.prediction.progress"
+			| "channel.prediction.lock"
+			| "channel.prediction.end",
+		) => {
+			let Some(p) = eventsub::try_normalize_poll(raw_json).context("normalize poll")? else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = p.room.clone();
+
+			out.push(super::mk_poll_ingest(
+				p.room,
+				ingest_now,
+				Some(p.platform_time),
+				p.poll,
+				adapter_session_id,
+				Some(p.ws_message_id),
+				Some(p.subscription_id),
+			));
+
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some(
+			"channel.hype_train.begin"
+			| "channel.hype_train.progress"
+			| "channel.hype_train.end"
+			| "channel.goal.begin"
+			| "channel.goal.progress"
+			| "channel.goal.end"
+			| "channel.charity_campaign.start"
+			| "channel.charity_campaign.progress"
+			| "channel.charity_campaign.stop",
+		) => {
+			let Some(p) = eventsub::try_normalize_progress(raw_json).context("normalize progress")? else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = p.room.clone();
+
+			out.push(super::mk_progress_ingest(
+				p.room,
+				ingest_now,
+				Some(p.platform_time),
+				p.progress,
+				adapter_session_id,
+				Some(p.ws_message_id),
+				Some(p.subscription_id),
+			));
+
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some(
+			"channel.channel_points_custom_reward_redemption.add" | "channel.channel_points_custom_reward_redemption.update",
+		) => {
+			let Some(r) = eventsub::try_normalize_channel_points_redemption(raw_json)
+				.context("normalize channel_points_custom_reward_redemption")?
+			else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = r.room.clone();
+
+			out.push(super::mk_redemption_ingest(
+				r.room,
+				ingest_now,
+				Some(r.platform_time),
+				r.redemption,
+				adapter_session_id,
+				Some(r.ws_message_id),
+				Some(r.subscription_id),
+			));
+
+			Ok((Some(room_for_gating), out))
+		}
+
 		Some("channel.ban") => {
 			let Some(b) = eventsub::try_normalize_channel_ban(raw_json).context("normalize channel.ban")? else {
 				return Ok((None, out));
@@ -320,6 +517,147 @@ pub(crate) fn handle_notification_json(
 			Ok((Some(room_for_gating), out))
 		}
 
+		Some("automod.message.hold" | "automod.message.update") => {
+			let Some(a) = eventsub::try_normalize_automod_message(raw_json).context("normalize automod.message")? else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = a.room.clone();
+
+			let user = crate::UserRef {
+				id: a.user_id,
+				login: a.user_login,
+				display: Some(a.user_name),
+			};
+			let actor = a.moderator.map(|(id, login, name)| crate::UserRef {
+				id,
+				login,
+				display: Some(name),
+			});
+
+			let (kind, action) = match a.status {
+				Some(status) => (
+					"automod_update",
+					crate::ModerationAction::AutoModUpdate {
+						message_id: Some(a.message_id.clone()),
+						user: Some(user.clone()),
+						status: Some(status.to_ascii_lowercase()),
+					},
+				),
+				None => {
+					let reason = match (a.category, a.level) {
+						(Some(category), Some(level)) => Some(format!("{category} (level {level})")),
+						(category, _) => category,
+					};
+					(
+						"automod_hold",
+						crate::ModerationAction::AutoModHold {
+							message_id: Some(a.message_id.clone()),
+							user: Some(user.clone()),
+							reason,
+							text: Some(a.text),
+						},
+					)
+				}
+			};
+
+			let mut ingest = IngestEvent::new(
+				Platform::Twitch,
+				a.room.room_id.clone(),
+				IngestPayload::Moderation(Box::new(crate::ModerationEvent {
+					kind: kind.to_string(),
+					actor,
+					target: Some(user),
+					target_message_platform_id: Some(a.message_id),
+					notes: None,
+					action: Some(action),
+				})),
+			);
+
+			ingest.room = a.room;
+			ingest.ingest_time = ingest_now;
+			ingest.platform_time = Some(a.platform_time);
+
+			let mut trace = crate::IngestTrace {
+				session_id: Some(adapter_session_id.to_string()),
+				..crate::IngestTrace::default()
+			};
+			trace.fields.insert("twitch_ws_message_id".to_string(), a.ws_message_id);
+			trace.fields.insert("twitch_subscription_id".to_string(), a.subscription_id);
+			ingest.trace = trace;
+
+			out.push(AdapterEvent::Ingest(Box::new(ingest)));
+			Ok((Some(room_for_gating), out))
+		}
+
+		Some("channel.unban_request.create" | "channel.unban_request.resolve") => {
+			let Some(u) = eventsub::try_normalize_unban_request(raw_json).context("normalize channel.unban_request")? else {
+				return Ok((None, out));
+			};
+
+			let room_for_gating = u.room.clone();
+
+			let user = crate::UserRef {
+				id: u.user_id,
+				login: u.user_login,
+				display: Some(u.user_name),
+			};
+			let actor = u.moderator.map(|(id, login, name)| crate::UserRef {
+				id,
+				login,
+				display: Some(name),
+			});
+
+			let (kind, action) = match u.status {
+				Some(status) => (
+					"unban_request_resolve",
+					crate::ModerationAction::UnbanRequestResolve {
+						request_id: Some(u.request_id),
+						user: user.clone(),
+						resolution: Some(status.to_ascii_lowercase()),
+						resolved_by: actor.clone(),
+						resolved_at: Some(u.platform_time),
+					},
+				),
+				None => (
+					"unban_request_create",
+					crate::ModerationAction::UnbanRequestCreate {
+						request_id: Some(u.request_id),
+						user: user.clone(),
+						text: u.text,
+					},
+				),
+			};
+
+			let mut ingest = IngestEvent::new(
+				Platform::Twitch,
+				u.room.room_id.clone(),
+				IngestPayload::Moderation(Box::new(crate::ModerationEvent {
+					kind: kind.to_string(),
+					actor,
+					target: Some(user),
+					target_message_platform_id: None,
+					notes: u.resolution_text,
+					action: Some(action),
+				})),
+			);
+
+			ingest.room = u.room;
+			ingest.ingest_time = ingest_now;
+			ingest.platform_time = Some(u.platform_time);
+
+			let mut trace = crate::IngestTrace {
+				session_id: Some(adapter_session_id.to_string()),
+				..crate::IngestTrace::default()
+			};
+			trace.fields.insert("twitch_ws_message_id".to_string(), u.ws_message_id);
+			trace.fields.insert("twitch_subscription_id".to_string(), u.subscription_id);
+			ingest.trace = trace;
+
+			out.push(AdapterEvent::Ingest(Box::new(ingest)));
+			Ok((Some(room_for_gating), out))
+		}
+
 		_ => Ok((None, out)),
 	}
 }
@@ -9,7 +9,10 @@ mod tests;
 
 pub(crate) use handlers::handle_notification_json;
 pub(crate) use moderation::decode_channel_moderate_to_ingest;
-pub(crate) use room_state::{mk_room_state_ingest, mk_user_notice_ingest};
+pub(crate) use room_state::{
+	mk_poll_ingest, mk_progress_ingest, mk_redemption_ingest, mk_room_state_ingest, mk_stream_status_ingest,
+	mk_user_notice_ingest,
+};
 
 /// Apply moderation gating policy.
 pub(crate) fn should_emit_payload(token_user_is_mod: bool, payload: &crate::IngestPayload) -> bool {
@@ -20,13 +23,22 @@ pub(crate) fn should_emit_payload(token_user_is_mod: bool, payload: &crate::Inge
 		IngestPayload::AssetBundle(_) => true,
 		IngestPayload::UserNotice(_) => true,
 		IngestPayload::RoomState(_) => true,
+		IngestPayload::StreamStatus(_) => true,
+		IngestPayload::Poll(_) => true,
+		IngestPayload::Redemption(_) => true,
+		IngestPayload::Progress(_) => true,
 		IngestPayload::Moderation(m) => {
 			if token_user_is_mod {
 				return true;
 			}
 
+			// Removed messages disappear for every viewer, not just moderators.
 			match &m.action {
-				Some(ModerationAction::DeleteMessage { .. }) => true,
+				Some(
+					ModerationAction::DeleteMessage { .. }
+					| ModerationAction::ClearChat {}
+					| ModerationAction::ClearUserMessages { .. },
+				) => true,
 				None if m.kind == "delete" => true,
 				_ => false,
 			}
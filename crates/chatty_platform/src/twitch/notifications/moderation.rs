@@ -301,23 +301,8 @@ pub(crate) fn decode_channel_moderate_to_ingest(
 			)
 		}
 
-		"clear" => {
-			let ev = mk_mod(
-				m.room.clone(),
-				m.platform_time,
-				ingest_now,
-				adapter_session_id,
-				m.ws_message_id.clone(),
-				m.subscription_id.clone(),
-				actor.clone(),
-				"clear_chat",
-				None,
-				None,
-				Some(ModerationAction::ClearChat {}),
-				None,
-			);
-			(Some(ev), None)
-		}
+		// `channel.chat.clear` already reports this, and reaches non-moderators too.
+		"clear" => (None, None),
 
 		"slow" => {
 			let mut settings = RoomChatSettings {
@@ -4,7 +4,10 @@ use std::time::SystemTime;
 
 use chatty_domain::Platform;
 
-use crate::{AdapterEvent, IngestEvent, IngestPayload, RoomChatSettings, RoomState, UserNotice};
+use crate::{
+	AdapterEvent, IngestEvent, IngestPayload, Poll, Progress, Redemption, RoomChatSettings, RoomState, StreamStatus,
+	UserNotice,
+};
 
 /// Create a RoomState ingest event.
 #[allow(clippy::too_many_arguments)]
@@ -94,3 +97,135 @@ pub(crate) fn mk_user_notice_ingest(
 
 	AdapterEvent::Ingest(Box::new(ingest))
 }
+
+/// Create a StreamStatus ingest event.
+pub(crate) fn mk_stream_status_ingest(
+	room: chatty_domain::RoomKey,
+	ingest_now: SystemTime,
+	platform_time: Option<SystemTime>,
+	status: StreamStatus,
+	adapter_session_id: &str,
+	ws_message_id: Option<String>,
+	subscription_id: Option<String>,
+) -> AdapterEvent {
+	let mut ingest = IngestEvent::new(Platform::Twitch, room.room_id.clone(), IngestPayload::StreamStatus(status));
+
+	ingest.room = room;
+	ingest.ingest_time = ingest_now;
+	ingest.platform_time = platform_time;
+
+	let mut trace = crate::IngestTrace {
+		session_id: Some(adapter_session_id.to_string()),
+		..crate::IngestTrace::default()
+	};
+
+	if let Some(ws_message_id) = ws_message_id {
+		trace.fields.insert("twitch_ws_message_id".to_string(), ws_message_id);
+	}
+	if let Some(subscription_id) = subscription_id {
+		trace.fields.insert("twitch_subscription_id".to_string(), subscription_id);
+	}
+
+	ingest.trace = trace;
+
+	AdapterEvent::Ingest(Box::new(ingest))
+}
+
+/// Create a Poll ingest event.
+pub(crate) fn mk_poll_ingest(
+	room: chatty_domain::RoomKey,
+	ingest_now: SystemTime,
+	platform_time: Option<SystemTime>,
+	poll: Poll,
+	adapter_session_id: &str,
+	ws_message_id: Option<String>,
+	subscription_id: Option<String>,
+) -> AdapterEvent {
+	let mut ingest = IngestEvent::new(Platform::Twitch, room.room_id.clone(), IngestPayload::Poll(poll));
+
+	ingest.room = room;
+	ingest.ingest_time = ingest_now;
+	ingest.platform_time = platform_time;
+
+	let mut trace = crate::IngestTrace {
+		session_id: Some(adapter_session_id.to_string()),
+		..crate::IngestTrace::default()
+	};
+
+	if let Some(ws_message_id) = ws_message_id {
+		trace.fields.insert("twitch_ws_message_id".to_string(), ws_message_id);
+	}
+	if let Some(subscription_id) = subscription_id {
+		trace.fields.insert("twitch_subscription_id".to_string(), subscription_id);
+	}
+
+	ingest.trace = trace;
+
+	AdapterEvent::Ingest(Box::new(ingest))
+}
+
+/// Create a Redemption ingest event.
+pub(crate) fn mk_redemption_ingest(
+	room: chatty_domain::RoomKey,
+	ingest_now: SystemTime,
+	platform_time: Option<SystemTime>,
+	redemption: Redemption,
+	adapter_session_id: &str,
+	ws_message_id: Option<String>,
+	subscription_id: Option<String>,
+) -> AdapterEvent {
+	let mut ingest = IngestEvent::new(Platform::Twitch, room.room_id.clone(), IngestPayload::Redemption(redemption));
+
+	ingest.room = room;
+	ingest.ingest_time = ingest_now;
+	ingest.platform_time = platform_time;
+
+	let mut trace = crate::IngestTrace {
+		session_id: Some(adapter_session_id.to_string()),
+		..crate::IngestTrace::default()
+	};
+
+	if let Some(ws_message_id) = ws_message_id {
+		trace.fields.insert("twitch_ws_message_id".to_string(), ws_message_id);
+	}
+	if let Some(subscription_id) = subscription_id {
+		trace.fields.insert("twitch_subscription_id".to_string(), subscription_id);
+	}
+
+	ingest.trace = trace;
+
+	AdapterEvent::Ingest(Box::new(ingest))
+}
+
+/// Create a Progress ingest event.
+pub(crate) fn mk_progress_ingest(
+	room: chatty_domain::RoomKey,
+	ingest_now: SystemTime,
+	platform_time: Option<SystemTime>,
+	progress: Progress,
+	adapter_session_id: &str,
+	ws_message_id: Option<String>,
+	subscription_id: Option<String>,
+) -> AdapterEvent {
+	let mut ingest = IngestEvent::new(Platform::Twitch, room.room_id.clone(), IngestPayload::Progress(progress));
+
+	ingest.room = room;
+	ingest.ingest_time = ingest_now;
+	ingest.platform_time = platform_time;
+
+	let mut trace = crate::IngestTrace {
+		session_id: Some(adapter_session_id.to_string()),
+		..crate::IngestTrace::default()
+	};
+
+	if let Some(ws_message_id) = ws_message_id {
+		trace.fields.insert("twitch_ws_message_id".to_string(), ws_message_id);
+	}
+	if let Some(subscription_id) = subscription_id {
+		trace.fields.insert("twitch_subscription_id".to_string(), subscription_id);
+	}
+
+	ingest.trace = trace;
+
+	AdapterEvent::Ingest(Box::new(ingest))
+}
@@ -4,8 +4,8 @@ use std::time::{Duration, SystemTime};
 
 use chatty_domain::{Platform, RoomId, RoomKey};
 
-use super::{decode_channel_moderate_to_ingest, should_emit_payload};
-use crate::{IngestPayload, ModerationAction};
+use super::{decode_channel_moderate_to_ingest, handle_notification_json, should_emit_payload};
+use crate::{AdapterEvent, IngestPayload, ModerationAction};
 
 fn mk_room(login: &str) -> RoomKey {
 	RoomKey::new(Platform::Twitch, RoomId::new(login.to_string()).expect("valid room id"))
@@ -272,3 +272,614 @@ fn channel_moderate_unknown_action_falls_back_to_moderation_event_with_notes() {
 	assert!(mev.action.is_none());
 	assert!(mev.notes.as_deref().unwrap_or("").contains("\"foo\""));
 }
+
+fn automod_notification(sub_type: &str, extra: serde_json::Value) -> String {
+	let mut event = serde_json::json!({
+		"broadcaster_user_id": "1",
+		"broadcaster_user_login": "chan",
+		"broadcaster_user_name": "Chan",
+		"user_id": "42",
+		"user_login": "spammer",
+		"user_name": "Spammer",
+		"message_id": "held-1",
+		"message": { "text": "buy followers", "fragments": { "emotes": [], "cheermotes": [] } },
+		"category": "aggressive",
+		"level": 3,
+		"held_at": "2026-01-01T00:00:00Z"
+	});
+	if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+		event.extend(extra.clone());
+	}
+	notification_json(sub_type, event)
+}
+
+fn notification_json(sub_type: &str, event: serde_json::Value) -> String {
+	serde_json::json!({
+		"metadata": {
+			"message_id": "ws-1",
+			"message_type": "notification",
+			"message_timestamp": "2026-01-01T00:00:00.123Z",
+			"subscription_type": sub_type,
+			"subscription_version": "1"
+		},
+		"payload": {
+			"subscription": {
+				"id": "sub-1",
+				"status": "enabled",
+				"type": sub_type,
+				"version": "1",
+				"condition": {},
+				"created_at": "2026-01-01T00:00:00Z"
+			},
+			"event": event
+		}
+	})
+	.to_string()
+}
+
+/// Handles one notification for `chan` and returns the payload of the single ingest event it produces.
+fn single_ingest(raw: &str) -> IngestPayload {
+	let (room, events) = handle_notification_json(raw, "session", SystemTime::now()).expect("handled");
+	assert_eq!(room, Some(mk_room("chan")));
+	let [AdapterEvent::Ingest(ingest)] = events.as_slice() else {
+		panic!("expected a single ingest event");
+	};
+	if !matches!(ingest.payload, IngestPayload::Moderation(_)) {
+		assert!(should_emit_payload(false, &ingest.payload));
+	}
+	ingest.payload.clone()
+}
+
+#[test]
+fn automod_hold_and_update_decode_to_review_actions() {
+	let IngestPayload::Moderation(hold) =
+		single_ingest(&automod_notification("automod.message.hold", serde_json::json!({})))
+	else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(hold.kind, "automod_hold");
+	assert_eq!(hold.target.as_ref().map(|u| u.login.as_str()), Some("spammer"));
+	match hold.action {
+		Some(ModerationAction::AutoModHold {
+			message_id,
+			reason,
+			text,
+			..
+		}) => {
+			assert_eq!(message_id.as_deref(), Some("held-1"));
+			assert_eq!(reason.as_deref(), Some("aggressive (level 3)"));
+			assert_eq!(text.as_deref(), Some("buy followers"));
+		}
+		other => panic!("unexpected action: {other:?}"),
+	}
+
+	let IngestPayload::Moderation(update) = single_ingest(&automod_notification(
+		"automod.message.update",
+		serde_json::json!({
+			"moderator_user_id": "7",
+			"moderator_user_login": "helper",
+			"moderator_user_name": "Helper",
+			"status": "Denied"
+		}),
+	)) else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(update.kind, "automod_update");
+	assert_eq!(update.actor.as_ref().map(|u| u.login.as_str()), Some("helper"));
+	match update.action {
+		Some(ModerationAction::AutoModUpdate { message_id, status, .. }) => {
+			assert_eq!(message_id.as_deref(), Some("held-1"));
+			assert_eq!(status.as_deref(), Some("denied"));
+		}
+		other => panic!("unexpected action: {other:?}"),
+	}
+}
+
+#[test]
+fn unban_request_create_and_resolve_carry_request_id() {
+	let user = serde_json::json!({
+		"id": "req-1",
+		"broadcaster_user_id": "1",
+		"broadcaster_user_login": "chan",
+		"broadcaster_user_name": "Chan",
+		"user_id": "42",
+		"user_login": "banned",
+		"user_name": "Banned",
+	});
+
+	let mut create = user.clone();
+	create["text"] = serde_json::json!("please unban me");
+	create["created_at"] = serde_json::json!("2026-01-01T00:00:00Z");
+	let IngestPayload::Moderation(create) = single_ingest(&notification_json("channel.unban_request.create", create)) else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(create.kind, "unban_request_create");
+	match create.action {
+		Some(ModerationAction::UnbanRequestCreate { request_id, user, text }) => {
+			assert_eq!(request_id.as_deref(), Some("req-1"));
+			assert_eq!(user.login, "banned");
+			assert_eq!(text.as_deref(), Some("please unban me"));
+		}
+		other => panic!("unexpected action: {other:?}"),
+	}
+
+	let mut resolve = user;
+	resolve["moderator_id"] = serde_json::json!("7");
+	resolve["moderator_login"] = serde_json::json!("helper");
+	resolve["moderator_name"] = serde_json::json!("Helper");
+	resolve["resolution_text"] = serde_json::json!("welcome back");
+	resolve["status"] = serde_json::json!("approved");
+	let IngestPayload::Moderation(resolve) = single_ingest(&notification_json("channel.unban_request.resolve", resolve))
+	else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(resolve.kind, "unban_request_resolve");
+	assert_eq!(resolve.actor.as_ref().map(|u| u.login.as_str()), Some("helper"));
+	assert_eq!(resolve.notes.as_deref(), Some("welcome back"));
+	match resolve.action {
+		Some(ModerationAction::UnbanRequestResolve {
+			request_id, resolution, ..
+		}) => {
+			assert_eq!(request_id.as_deref(), Some("req-1"));
+			assert_eq!(resolution.as_deref(), Some("approved"));
+		}
+		other => panic!("unexpected action: {other:?}"),
+	}
+}
+
+fn chat_notification_json(notice_type: &str, extra: serde_json::Value) -> String {
+	let mut event = serde_json::json!({
+		"broadcaster_user_id": "1",
+		"broadcaster_user_login": "chan",
+		"broadcaster_user_name": "Chan",
+		"chatter_user_id": "42",
+		"chatter_user_login": "viewer",
+		"chatter_user_name": "Viewer",
+		"chatter_is_anonymous": false,
+		"color": "#FF0000",
+		"badges": [],
+		"system_message": "",
+		"message_id": "notice-1",
+		"message": { "text": "", "fragments": [] },
+		"notice_type": notice_type
+	});
+	if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+		event.extend(extra.clone());
+	}
+	notification_json("channel.chat.notification", event)
+}
+
+#[test]
+fn chat_notification_resub_carries_system_message_and_text() {
+	let IngestPayload::UserNotice(notice) = single_ingest(&chat_notification_json(
+		"resub",
+		serde_json::json!({
+			"system_message": "Viewer subscribed at Tier 1. They've subscribed for 5 months!",
+			"message": { "text": "love the stream", "fragments": [{ "type": "text", "text": "love the stream" }] },
+			"resub": { "cumulative_months": 5, "duration_months": 1, "sub_tier": "1000", "is_prime": false, "is_gift": false }
+		}),
+	)) else {
+		panic!("expected user notice payload");
+	};
+	assert_eq!(notice.kind, "resub");
+	assert_eq!(
+		notice.text.as_deref(),
+		Some("Viewer subscribed at Tier 1. They've subscribed for 5 months! love the stream")
+	);
+	assert_eq!(notice.user.as_ref().map(|u| u.login.as_str()), Some("viewer"));
+}
+
+#[test]
+fn chat_notification_anonymous_gift_and_announcement() {
+	let IngestPayload::UserNotice(gift) = single_ingest(&chat_notification_json(
+		"community_sub_gift",
+		serde_json::json!({
+			"chatter_user_id": null,
+			"chatter_user_login": null,
+			"chatter_user_name": null,
+			"chatter_is_anonymous": true,
+			"system_message": "An anonymous user is gifting 5 Tier 1 Subs to Chan's community!",
+			"community_sub_gift": { "id": "gift-1", "total": 5, "sub_tier": "1000" }
+		}),
+	)) else {
+		panic!("expected user notice payload");
+	};
+	assert_eq!(gift.kind, "community_sub_gift");
+	assert!(gift.user.is_none());
+
+	let IngestPayload::UserNotice(announcement) = single_ingest(&chat_notification_json(
+		"announcement",
+		serde_json::json!({
+			"message": { "text": "giveaway at 8pm", "fragments": [] },
+			"announcement": { "color": "PRIMARY" }
+		}),
+	)) else {
+		panic!("expected user notice payload");
+	};
+	assert_eq!(announcement.kind, "announcement");
+	assert_eq!(announcement.text.as_deref(), Some("giveaway at 8pm"));
+}
+
+#[test]
+fn chat_notification_skips_notices_covered_by_other_subscriptions() {
+	for notice_type in ["sub", "raid"] {
+		let (room, events) = handle_notification_json(
+			&chat_notification_json(notice_type, serde_json::json!({})),
+			"session",
+			SystemTime::now(),
+		)
+		.expect("handled");
+		assert!(room.is_none());
+		assert!(events.is_empty(), "{notice_type} should be skipped");
+	}
+}
+
+#[test]
+fn chat_clear_and_clear_user_messages_decode_to_actions() {
+	let broadcaster = serde_json::json!({
+		"broadcaster_user_id": "1",
+		"broadcaster_user_login": "chan",
+		"broadcaster_user_name": "Chan",
+	});
+
+	let IngestPayload::Moderation(clear) = single_ingest(&notification_json("channel.chat.clear", broadcaster.clone()))
+	else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(clear.kind, "clear_chat");
+	assert!(matches!(clear.action, Some(ModerationAction::ClearChat {})));
+
+	let mut event = broadcaster;
+	event["target_user_id"] = serde_json::json!("42");
+	event["target_user_login"] = serde_json::json!("spammer");
+	event["target_user_name"] = serde_json::json!("Spammer");
+	let IngestPayload::Moderation(cleared) = single_ingest(&notification_json("channel.chat.clear_user_messages", event))
+	else {
+		panic!("expected moderation payload");
+	};
+	assert_eq!(cleared.kind, "clear_user_messages");
+	assert_eq!(cleared.target.as_ref().map(|u| u.id.as_str()), Some("42"));
+	match &cleared.action {
+		Some(ModerationAction::ClearUserMessages { user }) => assert_eq!(user.login, "spammer"),
+		other => panic!("unexpected action: {other:?}"),
+	}
+
+	let payload = IngestPayload::Moderation(cleared);
+	assert!(should_emit_payload(false, &payload));
+}
+
+#[test]
+fn chat_clear_is_reported_once_when_channel_moderate_also_fires() {
+	let clear = notification_json(
+		"channel.chat.clear",
+		serde_json::json!({
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+		}),
+	);
+	let moderate = notification_json(
+		"channel.moderate",
+		serde_json::json!({
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"moderator_user_id": "2",
+			"moderator_user_login": "modlogin",
+			"moderator_user_name": "Moderator",
+			"action": "clear",
+		}),
+	);
+
+	let mut clears = 0;
+	for raw in [clear, moderate] {
+		let (_, events) = handle_notification_json(&raw, "session", SystemTime::now()).expect("handled");
+		clears += events
+			.iter()
+			.filter(|ev| {
+				matches!(ev, AdapterEvent::Ingest(ing)
+					if matches!(&ing.payload, IngestPayload::Moderation(m) if matches!(m.action, Some(ModerationAction::ClearChat {}))))
+			})
+			.count();
+	}
+	assert_eq!(clears, 1);
+}
+
+#[test]
+fn stream_online_offline_and_channel_update_decode_to_stream_status() {
+	let broadcaster = serde_json::json!({
+		"broadcaster_user_id": "1",
+		"broadcaster_user_login": "chan",
+		"broadcaster_user_name": "Chan"
+	});
+	let with = |extra: serde_json::Value| {
+		let mut event = broadcaster.clone();
+		if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+			event.extend(extra.clone());
+		}
+		event
+	};
+
+	let IngestPayload::StreamStatus(online) = single_ingest(&notification_json(
+		"stream.online",
+		with(serde_json::json!({ "id": "stream-1", "type": "live", "started_at": "2024-01-01T00:00:00Z" })),
+	)) else {
+		panic!("expected stream status payload");
+	};
+	assert_eq!(online.live, Some(true));
+	assert_eq!(
+		online.started_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200))
+	);
+	assert!(online.title.is_none(), "stream.online does not carry the title");
+
+	let IngestPayload::StreamStatus(offline) = single_ingest(&notification_json("stream.offline", broadcaster.clone()))
+	else {
+		panic!("expected stream status payload");
+	};
+	assert_eq!(offline.live, Some(false));
+	assert!(offline.started_at.is_none());
+
+	let IngestPayload::StreamStatus(update) = single_ingest(&notification_json(
+		"channel.update",
+		with(serde_json::json!({
+			"title": "speedruns",
+			"language": "en",
+			"category_id": "123",
+			"category_name": "Celeste",
+			"content_classification_labels": []
+		})),
+	)) else {
+		panic!("expected stream status payload");
+	};
+	assert_eq!(update.live, None, "channel.update does not say whether the channel is live");
+	assert_eq!(update.title.as_deref(), Some("speedruns"));
+	assert_eq!(update.category.as_deref(), Some("Celeste"));
+	assert_eq!(update.viewer_count, None);
+}
+
+#[test]
+fn poll_progress_and_end_decode_with_tallies() {
+	let IngestPayload::Poll(progress) = single_ingest(&notification_json(
+		"channel.poll.progress",
+		serde_json::json!({
+			"id": "poll-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"title": "Next game?",
+			"choices": [
+				{ "id": "c1", "title": "Celeste", "bits_votes": 0, "channel_points_votes": 5, "votes": 12 },
+				{ "id": "c2", "title": "Hades", "bits_votes": 0, "channel_points_votes": 0, "votes": 3 }
+			],
+			"bits_voting": { "is_enabled": false, "amount_per_vote": 0 },
+			"channel_points_voting": { "is_enabled": true, "amount_per_vote": 10 },
+			"started_at": "2024-01-01T00:00:00Z",
+			"ends_at": "2024-01-01T00:01:00Z"
+		}),
+	)) else {
+		panic!("expected poll payload");
+	};
+	assert_eq!(progress.id, "poll-1");
+	assert_eq!(progress.kind, crate::PollKind::Poll);
+	assert_eq!(progress.status, crate::PollStatus::Active);
+	assert_eq!(
+		progress
+			.choices
+			.iter()
+			.map(|c| (c.title.as_str(), c.votes, c.channel_points))
+			.collect::<Vec<_>>(),
+		vec![("Celeste", 12, 5), ("Hades", 3, 0)]
+	);
+	assert_eq!(
+		progress.ends_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_260))
+	);
+
+	let IngestPayload::Poll(archived) = single_ingest(&notification_json(
+		"channel.poll.end",
+		serde_json::json!({
+			"id": "poll-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"title": "Next game?",
+			"choices": [],
+			"status": "archived",
+			"started_at": "2024-01-01T00:00:00Z",
+			"ended_at": "2024-01-01T00:01:00Z"
+		}),
+	)) else {
+		panic!("expected poll payload");
+	};
+	assert_eq!(archived.status, crate::PollStatus::Canceled, "archived polls are hidden");
+}
+
+#[test]
+fn prediction_lock_and_resolve_decode_with_winner() {
+	let event = |extra: serde_json::Value| {
+		let mut event = serde_json::json!({
+			"id": "pred-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"title": "Will we win?",
+			"outcomes": [
+				{ "id": "o1", "title": "Yes", "color": "blue", "users": 4, "channel_points": 1500 },
+				{ "id": "o2", "title": "No", "color": "pink", "users": 2, "channel_points": 300 }
+			],
+			"started_at": "2024-01-01T00:00:00Z"
+		});
+		if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+			event.extend(extra.clone());
+		}
+		event
+	};
+
+	let IngestPayload::Poll(locked) = single_ingest(&notification_json(
+		"channel.prediction.lock",
+		event(serde_json::json!({ "locked_at": "2024-01-01T00:02:00Z" })),
+	)) else {
+		panic!("expected poll payload");
+	};
+	assert_eq!(locked.kind, crate::PollKind::Prediction);
+	assert_eq!(locked.status, crate::PollStatus::Locked);
+	assert_eq!(locked.choices[0].votes, 4, "prediction users count as votes");
+	assert_eq!(locked.choices[0].channel_points, 1500);
+	assert!(locked.winning_choice_id.is_none());
+
+	let IngestPayload::Poll(resolved) = single_ingest(&notification_json(
+		"channel.prediction.end",
+		event(serde_json::json!({ "winning_outcome_id": "o2", "status": "resolved" })),
+	)) else {
+		panic!("expected poll payload");
+	};
+	assert_eq!(resolved.status, crate::PollStatus::Ended);
+	assert_eq!(resolved.winning_choice_id.as_deref(), Some("o2"));
+
+	let IngestPayload::Poll(canceled) = single_ingest(&notification_json(
+		"channel.prediction.end",
+		event(serde_json::json!({ "winning_outcome_id": null, "status": "canceled" })),
+	)) else {
+		panic!("expected poll payload");
+	};
+	assert_eq!(canceled.status, crate::PollStatus::Canceled);
+}
+
+#[test]
+fn channel_points_redemption_add_and_update_decode() {
+	let event = |status: &str| {
+		serde_json::json!({
+			"id": "red-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"user_id": "42",
+			"user_login": "viewer",
+			"user_name": "Viewer",
+			"user_input": "play Celeste please",
+			"status": status,
+			"reward": { "id": "reward-1", "title": "Song request", "cost": 500, "prompt": "Which song?" },
+			"redeemed_at": "2024-01-01T00:00:00Z"
+		})
+	};
+	let IngestPayload::Redemption(added) = single_ingest(&notification_json(
+		"channel.channel_points_custom_reward_redemption.add",
+		event("unfulfilled"),
+	)) else {
+		panic!("expected redemption payload");
+	};
+	assert_eq!(added.id, "red-1");
+	assert_eq!(added.reward_id, "reward-1");
+	assert_eq!(added.reward_title, "Song request");
+	assert_eq!(added.cost, 500);
+	assert_eq!(added.user.login, "viewer");
+	assert_eq!(added.user_input.as_deref(), Some("play Celeste please"));
+	assert_eq!(added.status, crate::RedemptionStatus::Unfulfilled);
+	assert_eq!(
+		added.redeemed_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200))
+	);
+
+	let IngestPayload::Redemption(canceled) = single_ingest(&notification_json(
+		"channel.channel_points_custom_reward_redemption.update",
+		event("canceled"),
+	)) else {
+		panic!("expected redemption payload");
+	};
+	assert_eq!(canceled.status, crate::RedemptionStatus::Canceled);
+}
+
+#[test]
+fn hype_train_goal_and_charity_decode_to_progress() {
+	let IngestPayload::Progress(train) = single_ingest(&notification_json(
+		"channel.hype_train.progress",
+		serde_json::json!({
+			"id": "train-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"total": 2300,
+			"progress": 300,
+			"goal": 1800,
+			"top_contributions": [],
+			"level": 3,
+			"started_at": "2024-01-01T00:00:00Z",
+			"expires_at": "2024-01-01T00:05:00Z",
+			"type": "regular",
+			"is_shared_train": false
+		}),
+	)) else {
+		panic!("expected progress payload");
+	};
+	assert_eq!(train.kind, crate::ProgressKind::HypeTrain);
+	assert_eq!(train.level, Some(3));
+	assert_eq!((train.current, train.target), (300, 1800));
+	assert_eq!(
+		train.expires_at,
+		Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_500))
+	);
+	assert!(!train.ended);
+
+	let IngestPayload::Progress(train_end) = single_ingest(&notification_json(
+		"channel.hype_train.end",
+		serde_json::json!({
+			"id": "train-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"level": 4,
+			"total": 4000,
+			"top_contributions": [],
+			"started_at": "2024-01-01T00:00:00Z",
+			"ended_at": "2024-01-01T00:10:00Z",
+			"cooldown_ends_at": "2024-01-01T01:10:00Z"
+		}),
+	)) else {
+		panic!("expected progress payload");
+	};
+	assert!(train_end.ended);
+	assert_eq!(train_end.level, Some(4));
+
+	let IngestPayload::Progress(goal) = single_ingest(&notification_json(
+		"channel.goal.progress",
+		serde_json::json!({
+			"id": "goal-1",
+			"broadcaster_user_id": "1",
+			"broadcaster_user_login": "chan",
+			"broadcaster_user_name": "Chan",
+			"type": "follower",
+			"description": "Road to 500",
+			"current_amount": 420,
+			"target_amount": 500,
+			"started_at": "2024-01-01T00:00:00Z"
+		}),
+	)) else {
+		panic!("expected progress payload");
+	};
+	assert_eq!(goal.kind, crate::ProgressKind::Goal);
+	assert_eq!(goal.title.as_deref(), Some("Road to 500"));
+	assert_eq!((goal.current, goal.target), (420, 500));
+	assert_eq!(goal.level, None);
+
+	let IngestPayload::Progress(charity) = single_ingest(&notification_json(
+		"channel.charity_campaign.progress",
+		serde_json::json!({
+			"id": "charity-1",
+			"broadcaster_id": "1",
+			"broadcaster_login": "chan",
+			"broadcaster_name": "Chan",
+			"charity_name": "Example Charity",
+			"charity_description": "",
+			"charity_logo": "",
+			"charity_website": "",
+			"current_amount": { "value": 260000, "decimal_places": 2, "currency": "USD" },
+			"target_amount": { "value": 1500000, "decimal_places": 2, "currency": "USD" }
+		}),
+	)) else {
+		panic!("expected progress payload");
+	};
+	assert_eq!(charity.kind, crate::ProgressKind::CharityCampaign);
+	assert_eq!((charity.current, charity.target), (2600, 15000));
+	assert_eq!(charity.currency.as_deref(), Some("USD"));
+}
@@ -0,0 +1,51 @@
+#![forbid(unsafe_code)]
+
+use chatty_domain::{Platform, RoomId, RoomKey};
+use tokio::sync::oneshot;
+
+use super::{TwitchConfig, TwitchEventSubAdapter};
+use crate::test_http::spawn_fixture_server;
+use crate::{AdapterControl, CommandError, CommandRequest, PlatformAdapter, SecretString, bounded_adapter_channels};
+
+#[tokio::test]
+async fn commands_without_user_auth_never_use_the_ingest_token() {
+	let (addr, requests) =
+		spawn_fixture_server(|_| ("200 OK", r#"{"data":[{"id":"1","login":"ingest"}]}"#.to_string())).await;
+	let mut cfg = TwitchConfig::new("client", SecretString::new("ingest-token".to_string()));
+	cfg.disable_refresh = true;
+	cfg.helix_base_url = format!("http://{addr}");
+	let (control_tx, control_rx, events_tx, _events_rx) = bounded_adapter_channels(8, 64);
+	let adapter = Box::new(TwitchEventSubAdapter::new(cfg));
+	let task = tokio::spawn(adapter.run(control_rx, events_tx));
+
+	let room = RoomKey::new(Platform::Twitch, RoomId::new("ingest".to_string()).expect("valid room id"));
+	let (resp, rx) = oneshot::channel();
+	control_tx
+		.send(AdapterControl::Command {
+			request: CommandRequest::SendChat {
+				room: room.clone(),
+				text: "hello".to_string(),
+				reply_to_platform_message_id: None,
+			},
+			auth: None,
+			resp,
+		})
+		.await
+		.expect("send command");
+	let result = rx.await.expect("command response");
+	assert!(matches!(result, Err(CommandError::NotAuthorized(_))), "got {result:?}");
+
+	let (resp, rx) = oneshot::channel();
+	control_tx
+		.send(AdapterControl::QueryPermissions { room, auth: None, resp })
+		.await
+		.expect("send permission query");
+	let perms = rx.await.expect("permissions response");
+	assert!(!perms.can_send && !perms.is_broadcaster && !perms.can_manage_redemptions);
+
+	assert!(requests.lock().await.is_empty(), "no helix calls made with the ingest token");
+
+	control_tx.send(AdapterControl::Shutdown).await.expect("send shutdown");
+	drop(control_tx);
+	task.await.expect("adapter task").expect("adapter run");
+}
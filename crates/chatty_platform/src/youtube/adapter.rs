@@ -0,0 +1,687 @@
+#![forbid(unsafe_code)]
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chatty_domain::{Platform, RoomKey};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::YOUTUBE_API_BASE_URL;
+use super::client::{YouTubeAuthorDetails, YouTubeClient, YouTubeLiveChat, YouTubeLiveChatMessage, YouTubeLiveChatPage};
+use crate::{
+	AdapterAuth, AdapterControl, AdapterControlRx, AdapterEvent, AdapterEventTx, ChatMessage, CommandError, CommandRequest,
+	IngestEvent, IngestPayload, ModerationAction, ModerationEvent, PermissionsInfo, PlatformAdapter, SecretString,
+	UserNotice, UserRef, new_session_id, status,
+};
+
+#[derive(Clone)]
+pub struct YouTubeConfig {
+	/// Data API base URL; point it at a local fake for tests.
+	pub base_url: String,
+	/// API key used for read-only polling when no user token is needed.
+	pub api_key: Option<SecretString>,
+	/// Room id -> live chat id, skipping video/channel resolution.
+	pub live_chat_id_overrides: HashMap<String, String>,
+	pub max_results: u32,
+	/// Lower bound applied to the server-provided `pollingIntervalMillis`.
+	pub min_poll_interval: Duration,
+	/// How often to re-check a room that has no active live chat.
+	pub offline_poll_interval: Duration,
+	pub reconnect_min_delay: Duration,
+	pub reconnect_max_delay: Duration,
+}
+
+impl Default for YouTubeConfig {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl YouTubeConfig {
+	pub fn new() -> Self {
+		Self {
+			base_url: YOUTUBE_API_BASE_URL.to_string(),
+			api_key: None,
+			live_chat_id_overrides: HashMap::new(),
+			max_results: 200,
+			min_poll_interval: Duration::from_secs(1),
+			offline_poll_interval: Duration::from_secs(60),
+			reconnect_min_delay: Duration::from_millis(500),
+			reconnect_max_delay: Duration::from_secs(30),
+		}
+	}
+}
+
+pub struct YouTubeLiveAdapter {
+	cfg: YouTubeConfig,
+	pollers: HashMap<RoomKey, JoinHandle<()>>,
+	live_chats: Arc<RwLock<HashMap<RoomKey, YouTubeLiveChat>>>,
+	access_token: Arc<RwLock<Option<SecretString>>>,
+	auth_channel_ids: Arc<RwLock<HashSet<String>>>,
+	moderator_rooms: Arc<RwLock<HashMap<String, HashSet<RoomKey>>>>,
+}
+
+impl YouTubeLiveAdapter {
+	pub fn new(cfg: YouTubeConfig) -> Self {
+		Self {
+			cfg,
+			pollers: HashMap::new(),
+			live_chats: Arc::new(RwLock::new(HashMap::new())),
+			access_token: Arc::new(RwLock::new(None)),
+			auth_channel_ids: Arc::new(RwLock::new(HashSet::new())),
+			moderator_rooms: Arc::new(RwLock::new(HashMap::new())),
+		}
+	}
+
+	fn platform(&self) -> Platform {
+		Platform::YouTube
+	}
+
+	fn client_for_token(&self, token: &SecretString) -> YouTubeClient {
+		YouTubeClient::new(self.cfg.base_url.clone(), "", token.expose().to_string())
+	}
+
+	fn start_poller(&mut self, room: RoomKey, events_tx: &AdapterEventTx) {
+		let poller = RoomPoller {
+			cfg: self.cfg.clone(),
+			room: room.clone(),
+			live_chats: self.live_chats.clone(),
+			access_token: self.access_token.clone(),
+			auth_channel_ids: self.auth_channel_ids.clone(),
+			moderator_rooms: self.moderator_rooms.clone(),
+			events_tx: events_tx.clone(),
+		};
+		self.pollers.insert(room, tokio::spawn(poller.run()));
+	}
+
+	async fn stop_poller(&mut self, room: &RoomKey) -> bool {
+		self.live_chats.write().await.remove(room);
+		match self.pollers.remove(room) {
+			Some(handle) => {
+				handle.abort();
+				true
+			}
+			None => false,
+		}
+	}
+
+	async fn apply_auth_update(&mut self, auth: AdapterAuth) -> bool {
+		let AdapterAuth::UserAccessToken {
+			access_token, user_id, ..
+		} = auth
+		else {
+			return false;
+		};
+
+		if let Some(channel_id) = user_id.as_deref().map(str::trim).filter(|id| !id.is_empty()) {
+			self.auth_channel_ids.write().await.insert(channel_id.to_string());
+		} else {
+			warn!("youtube auth update missing channel id; moderator detection unavailable");
+		}
+		*self.access_token.write().await = Some(access_token);
+		self.moderator_rooms.write().await.clear();
+		true
+	}
+
+	async fn resolve_live_chat(&self, room: &RoomKey, token: &SecretString) -> Result<YouTubeLiveChat, CommandError> {
+		if room.platform != Platform::YouTube {
+			return Err(CommandError::InvalidTopic(None));
+		}
+		if let Some(chat) = self.live_chats.read().await.get(room) {
+			return Ok(chat.clone());
+		}
+		if let Some(live_chat_id) = self.cfg.live_chat_id_overrides.get(room.room_id.as_str()) {
+			return Ok(YouTubeLiveChat {
+				live_chat_id: live_chat_id.clone(),
+				video_id: None,
+				channel_id: None,
+			});
+		}
+
+		let client = self.client_for_token(token);
+		let resolved = client
+			.resolve_live_chat(room.room_id.as_str())
+			.await
+			.map_err(map_youtube_error)?;
+		let Some(chat) = resolved else {
+			return Err(CommandError::InvalidTopic(Some("youtube room is not live".to_string())));
+		};
+		self.live_chats.write().await.insert(room.clone(), chat.clone());
+		Ok(chat)
+	}
+
+	fn command_auth(auth: Option<AdapterAuth>) -> Option<(SecretString, Option<String>)> {
+		match auth {
+			Some(AdapterAuth::UserAccessToken {
+				access_token, user_id, ..
+			}) => Some((access_token, user_id)),
+			_ => None,
+		}
+	}
+
+	async fn execute_command(&mut self, request: CommandRequest, auth: Option<AdapterAuth>) -> Result<(), CommandError> {
+		let room = request.room();
+
+		if room.platform != Platform::YouTube {
+			return Err(CommandError::InvalidTopic(None));
+		}
+
+		let Some((token, auth_channel_id)) = Self::command_auth(auth) else {
+			return Err(CommandError::NotAuthorized(Some(
+				"youtube auth missing access token".to_string(),
+			)));
+		};
+		let chat = self.resolve_live_chat(room, &token).await?;
+		let is_broadcaster = is_channel_owner(&chat, auth_channel_id.as_deref());
+		let is_moderator = self.is_moderator_for_room(room, auth_channel_id.as_deref()).await;
+		let can_moderate = is_moderator || is_broadcaster;
+		let client = self.client_for_token(&token);
+
+		match request {
+			// YouTube live chat has no threaded replies; the reply target is dropped.
+			CommandRequest::SendChat { text, .. } => client
+				.send_chat_message(&chat.live_chat_id, &text)
+				.await
+				.map_err(map_youtube_error)?,
+			CommandRequest::DeleteMessage { platform_message_id, .. } => {
+				if !can_moderate {
+					return Err(CommandError::NotAuthorized(None));
+				}
+				client
+					.delete_chat_message(&platform_message_id)
+					.await
+					.map_err(map_youtube_error)?
+			}
+			CommandRequest::TimeoutUser {
+				user_id,
+				duration_seconds,
+				..
+			} => {
+				if !can_moderate {
+					return Err(CommandError::NotAuthorized(None));
+				}
+				client
+					.ban_user(&chat.live_chat_id, &user_id, Some(duration_seconds))
+					.await
+					.map_err(map_youtube_error)?
+			}
+			CommandRequest::BanUser { user_id, .. } => {
+				if !can_moderate {
+					return Err(CommandError::NotAuthorized(None));
+				}
+				client
+					.ban_user(&chat.live_chat_id, &user_id, None)
+					.await
+					.map_err(map_youtube_error)?
+			}
+			CommandRequest::UnbanUser { .. }
+			| CommandRequest::UntimeoutUser { .. }
+			| CommandRequest::ClearChat { .. }
+			| CommandRequest::ClearUserMessages { .. }
+			| CommandRequest::UpdateRoomSettings { .. }
+			| CommandRequest::AutoModResolve { .. }
+			| CommandRequest::ResolveUnbanRequest { .. }
+			| CommandRequest::CreatePoll { .. }
+			| CommandRequest::EndPoll { .. }
+			| CommandRequest::CreatePrediction { .. }
+			| CommandRequest::ResolvePrediction { .. }
+			| CommandRequest::FulfillRedemption { .. }
+			| CommandRequest::CancelRedemption { .. } => {
+				return Err(CommandError::NotSupported(Some(
+					"youtube live chat does not support this command".to_string(),
+				)));
+			}
+		};
+
+		Ok(())
+	}
+
+	async fn permissions_for_room(&mut self, room: &RoomKey, auth: Option<AdapterAuth>) -> PermissionsInfo {
+		if room.platform != Platform::YouTube {
+			return PermissionsInfo::default();
+		}
+		let Some((token, auth_channel_id)) = Self::command_auth(auth) else {
+			return PermissionsInfo::default();
+		};
+		let chat = match self.resolve_live_chat(room, &token).await {
+			Ok(chat) => chat,
+			Err(_) => return PermissionsInfo::default(),
+		};
+		let is_broadcaster = is_channel_owner(&chat, auth_channel_id.as_deref());
+		let is_moderator = self.is_moderator_for_room(room, auth_channel_id.as_deref()).await;
+		let can_moderate = is_moderator || is_broadcaster;
+
+		PermissionsInfo {
+			can_send: true,
+			can_reply: false,
+			can_delete: can_moderate,
+			can_timeout: can_moderate,
+			can_ban: can_moderate,
+			is_moderator,
+			is_broadcaster,
+			can_manage_redemptions: false,
+		}
+	}
+
+	async fn is_moderator_for_room(&self, room: &RoomKey, channel_id: Option<&str>) -> bool {
+		let Some(channel_id) = channel_id else {
+			return false;
+		};
+
+		let guard = self.moderator_rooms.read().await;
+		guard.get(channel_id).map(|rooms| rooms.contains(room)).unwrap_or(false)
+	}
+}
+
+fn is_channel_owner(chat: &YouTubeLiveChat, channel_id: Option<&str>) -> bool {
+	match (chat.channel_id.as_deref(), channel_id) {
+		(Some(owner), Some(id)) => !id.trim().is_empty() && owner == id.trim(),
+		_ => false,
+	}
+}
+
+fn backoff_delay(attempt: u32, min: Duration, max: Duration) -> Duration {
+	let min_ms = min.as_millis() as u64;
+	let max_ms = max.as_millis() as u64;
+	let exp = 2u64.saturating_pow(attempt.min(10));
+	let delay_ms = min_ms.saturating_mul(exp).min(max_ms);
+	Duration::from_millis(delay_ms)
+}
+
+fn map_youtube_error(err: anyhow::Error) -> CommandError {
+	let msg = err.to_string();
+	if msg.contains("missing youtube access token") || msg.contains("status=401") || msg.contains("status=403") {
+		CommandError::NotAuthorized(Some(msg))
+	} else if msg.contains("status=404") {
+		CommandError::InvalidTopic(Some(msg))
+	} else {
+		CommandError::Internal(msg)
+	}
+}
+
+/// The live chat went away (stream ended or chat disabled); the room must be re-resolved.
+fn is_live_chat_gone(err: &anyhow::Error) -> bool {
+	let msg = err.to_string();
+	msg.contains("status=404")
+		|| msg.contains("liveChatEnded")
+		|| msg.contains("liveChatNotFound")
+		|| msg.contains("liveChatDisabled")
+}
+
+fn page_ended(page: &YouTubeLiveChatPage) -> bool {
+	page.offline_at.is_some() || page.items.iter().any(|item| item.snippet.kind == "chatEndedEvent")
+}
+
+/// Poll delay for the next page: the server-provided interval, clamped from below.
+pub(crate) fn poll_delay(page: &YouTubeLiveChatPage, min: Duration) -> Duration {
+	page.polling_interval_millis
+		.map(Duration::from_millis)
+		.unwrap_or(min)
+		.max(min)
+}
+
+struct RoomPoller {
+	cfg: YouTubeConfig,
+	room: RoomKey,
+	live_chats: Arc<RwLock<HashMap<RoomKey, YouTubeLiveChat>>>,
+	access_token: Arc<RwLock<Option<SecretString>>>,
+	auth_channel_ids: Arc<RwLock<HashSet<String>>>,
+	moderator_rooms: Arc<RwLock<HashMap<String, HashSet<RoomKey>>>>,
+	events_tx: AdapterEventTx,
+}
+
+impl RoomPoller {
+	/// Reads use the API key when configured and fall back to the user token otherwise.
+	async fn read_client(&self) -> YouTubeClient {
+		let api_key = self.cfg.api_key.as_ref().map(|k| k.expose().to_string()).unwrap_or_default();
+		let token = if api_key.trim().is_empty() {
+			self.access_token
+				.read()
+				.await
+				.as_ref()
+				.map(|t| t.expose().to_string())
+				.unwrap_or_default()
+		} else {
+			String::new()
+		};
+		YouTubeClient::new(self.cfg.base_url.clone(), api_key, token)
+	}
+
+	async fn resolve(&self) -> anyhow::Result<Option<YouTubeLiveChat>> {
+		if let Some(live_chat_id) = self.cfg.live_chat_id_overrides.get(self.room.room_id.as_str()) {
+			return Ok(Some(YouTubeLiveChat {
+				live_chat_id: live_chat_id.clone(),
+				video_id: None,
+				channel_id: None,
+			}));
+		}
+		self.read_client().await.resolve_live_chat(self.room.room_id.as_str()).await
+	}
+
+	async fn run(self) {
+		let platform = Platform::YouTube;
+		let room_id = self.room.room_id.as_str().to_string();
+		let mut attempt: u32 = 0;
+
+		loop {
+			let chat = match self.resolve().await {
+				Ok(Some(chat)) => chat,
+				Ok(None) => {
+					debug!(room = %self.room, "youtube room has no active live chat");
+					tokio::time::sleep(self.cfg.offline_poll_interval).await;
+					continue;
+				}
+				Err(err) => {
+					warn!(error = %err, room = %self.room, "youtube live chat resolve failed");
+					tokio::time::sleep(backoff_delay(
+						attempt,
+						self.cfg.reconnect_min_delay,
+						self.cfg.reconnect_max_delay,
+					))
+					.await;
+					attempt = attempt.saturating_add(1);
+					continue;
+				}
+			};
+
+			attempt = 0;
+			self.live_chats.write().await.insert(self.room.clone(), chat.clone());
+			let _ = self
+				.events_tx
+				.try_send(status(platform, true, format!("youtube live chat found room:{room_id}")));
+
+			// The first page is the chat backlog; it only seeds the page token.
+			let mut page_token: Option<String> = None;
+			let mut seeded = false;
+			loop {
+				let client = self.read_client().await;
+				let page = match client
+					.list_messages(&chat.live_chat_id, page_token.as_deref(), self.cfg.max_results)
+					.await
+				{
+					Ok(page) => page,
+					Err(err) if is_live_chat_gone(&err) => {
+						info!(error = %err, room = %self.room, "youtube live chat ended");
+						break;
+					}
+					Err(err) => {
+						warn!(error = %err, room = %self.room, "youtube live chat poll failed");
+						tokio::time::sleep(backoff_delay(
+							attempt,
+							self.cfg.reconnect_min_delay,
+							self.cfg.reconnect_max_delay,
+						))
+						.await;
+						attempt = attempt.saturating_add(1);
+						continue;
+					}
+				};
+				attempt = 0;
+
+				let ended = page_ended(&page);
+				let delay = poll_delay(&page, self.cfg.min_poll_interval);
+				if let Some(token) = page.next_page_token.clone() {
+					page_token = Some(token);
+				}
+				if seeded {
+					for item in page.items {
+						if let Some(author) = item.author_details.as_ref() {
+							self.track_moderator(author).await;
+						}
+						if let Some(ingest) = normalize_live_chat_item(&self.room, item)
+							&& self.events_tx.try_send(AdapterEvent::Ingest(Box::new(ingest))).is_err()
+						{
+							warn!("youtube ingest channel closed");
+						}
+					}
+				}
+				seeded = true;
+
+				if ended {
+					info!(room = %self.room, "youtube live chat went offline");
+					break;
+				}
+				tokio::time::sleep(delay).await;
+			}
+
+			self.live_chats.write().await.remove(&self.room);
+			let _ = self
+				.events_tx
+				.try_send(status(platform, true, format!("youtube live chat ended room:{room_id}")));
+			tokio::time::sleep(self.cfg.offline_poll_interval).await;
+		}
+	}
+
+	async fn track_moderator(&self, author: &YouTubeAuthorDetails) {
+		if !self.auth_channel_ids.read().await.contains(&author.channel_id) {
+			return;
+		}
+		let mut guard = self.moderator_rooms.write().await;
+		let rooms = guard.entry(author.channel_id.clone()).or_default();
+		if author.is_chat_moderator || author.is_chat_owner {
+			rooms.insert(self.room.clone());
+		} else {
+			rooms.remove(&self.room);
+		}
+	}
+}
+
+fn author_ref(author: &YouTubeAuthorDetails) -> UserRef {
+	UserRef {
+		id: author.channel_id.clone(),
+		login: author.display_name.clone(),
+		display: Some(author.display_name.clone()),
+	}
+}
+
+fn author_badges(author: &YouTubeAuthorDetails) -> Vec<String> {
+	let mut badges = Vec::new();
+	if author.is_chat_owner {
+		badges.push("youtube:owner".to_string());
+	}
+	if author.is_chat_moderator {
+		badges.push("youtube:moderator".to_string());
+	}
+	if author.is_chat_sponsor {
+		badges.push("youtube:member".to_string());
+	}
+	if author.is_verified {
+		badges.push("youtube:verified".to_string());
+	}
+	badges
+}
+
+/// Normalize one `liveChatMessages` item into an ingest event.
+///
+/// Returns `None` for item types the server has no representation for.
+pub(crate) fn normalize_live_chat_item(room: &RoomKey, item: YouTubeLiveChatMessage) -> Option<IngestEvent> {
+	let snippet = item.snippet;
+	let author = item.author_details.or_else(|| {
+		snippet.author_channel_id.clone().map(|channel_id| YouTubeAuthorDetails {
+			channel_id,
+			..Default::default()
+		})
+	});
+
+	let payload = match snippet.kind.as_str() {
+		"textMessageEvent" => {
+			let author = author.as_ref()?;
+			let text = snippet
+				.text_message_details
+				.map(|d| d.message_text)
+				.or(snippet.display_message)
+				.unwrap_or_default();
+			let mut chat_message = ChatMessage::new(author_ref(author), text);
+			chat_message.badges = author_badges(author);
+			chat_message.ids.platform_id = Some(item.id.clone());
+			IngestPayload::ChatMessage(chat_message)
+		}
+		"messageDeletedEvent" => {
+			let deleted = snippet.message_deleted_details?;
+			IngestPayload::Moderation(Box::new(ModerationEvent {
+				kind: "delete".to_string(),
+				actor: author.as_ref().map(author_ref),
+				target: None,
+				target_message_platform_id: Some(deleted.deleted_message_id.clone()),
+				notes: None,
+				action: Some(ModerationAction::DeleteMessage {
+					message_id: deleted.deleted_message_id,
+				}),
+			}))
+		}
+		"userBannedEvent" => {
+			let banned = snippet.user_banned_details?;
+			let is_timeout = banned.ban_type.as_deref() == Some("temporary");
+			let target = UserRef {
+				id: banned.banned_user_details.channel_id.clone(),
+				login: banned
+					.banned_user_details
+					.display_name
+					.clone()
+					.unwrap_or_else(|| banned.banned_user_details.channel_id.clone()),
+				display: banned.banned_user_details.display_name.clone(),
+			};
+			IngestPayload::Moderation(Box::new(ModerationEvent {
+				kind: if is_timeout {
+					"timeout".to_string()
+				} else {
+					"ban".to_string()
+				},
+				actor: author.as_ref().map(author_ref),
+				target: Some(target),
+				target_message_platform_id: None,
+				notes: None,
+				action: Some(if is_timeout {
+					ModerationAction::Timeout {
+						duration_seconds: banned.ban_duration_seconds,
+						expires_at: None,
+						reason: None,
+					}
+				} else {
+					ModerationAction::Ban {
+						is_permanent: Some(true),
+						reason: None,
+					}
+				}),
+			}))
+		}
+		"superChatEvent"
+		| "superStickerEvent"
+		| "newSponsorEvent"
+		| "memberMilestoneChatEvent"
+		| "membershipGiftingEvent"
+		| "giftMembershipReceivedEvent" => IngestPayload::UserNotice(UserNotice {
+			kind: notice_kind(&snippet.kind).to_string(),
+			text: snippet.display_message.filter(|t| !t.trim().is_empty()),
+			user: author.as_ref().map(author_ref),
+		}),
+		_ => return None,
+	};
+
+	let mut ingest = IngestEvent::new(Platform::YouTube, room.room_id.clone(), payload);
+	if let Ok(platform_id) = chatty_domain::PlatformMessageId::new(item.id) {
+		ingest.platform_message_id = Some(platform_id);
+	}
+	if let Some(ts) = snippet.published_at.as_deref()
+		&& let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(ts)
+	{
+		let utc = parsed.with_timezone(&chrono::Utc);
+		let st = std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(utc.timestamp_millis() as u64);
+		ingest.platform_time = Some(st);
+	}
+	Some(ingest)
+}
+
+fn notice_kind(kind: &str) -> &'static str {
+	match kind {
+		"superChatEvent" => "super_chat",
+		"superStickerEvent" => "super_sticker",
+		"newSponsorEvent" => "new_member",
+		"memberMilestoneChatEvent" => "member_milestone",
+		"membershipGiftingEvent" => "membership_gifting",
+		_ => "gift_membership_received",
+	}
+}
+
+#[async_trait]
+impl PlatformAdapter for YouTubeLiveAdapter {
+	fn platform(&self) -> Platform {
+		Platform::YouTube
+	}
+
+	async fn run(self: Box<Self>, mut control_rx: AdapterControlRx, events_tx: AdapterEventTx) -> anyhow::Result<()> {
+		let mut this = *self;
+		let session_id = new_session_id();
+		let platform = this.platform();
+
+		let _ = events_tx.try_send(status(
+			platform,
+			true,
+			format!("youtube adapter online (session_id={session_id})"),
+		));
+
+		while let Some(cmd) = control_rx.recv().await {
+			match cmd {
+				AdapterControl::Join { room } => {
+					if room.platform != platform {
+						debug!(%platform, room=%room, "ignoring Join for non-matching platform");
+						continue;
+					}
+					if this.pollers.contains_key(&room) {
+						continue;
+					}
+					let detail = format!("joined youtube room:{}", room.room_id.as_str());
+					this.start_poller(room, &events_tx);
+					let _ = events_tx.try_send(status(platform, true, detail));
+				}
+				AdapterControl::Leave { room } => {
+					if room.platform != platform {
+						debug!(%platform, room=%room, "ignoring Leave for non-matching platform");
+						continue;
+					}
+					if this.stop_poller(&room).await {
+						let detail = format!("left youtube room:{}", room.room_id.as_str());
+						let _ = events_tx.try_send(status(platform, true, detail));
+					}
+				}
+				AdapterControl::UpdateAuth { auth } => {
+					if this.apply_auth_update(auth).await {
+						let _ = events_tx.try_send(status(platform, true, "youtube auth updated"));
+					} else {
+						let _ = events_tx.try_send(status(platform, false, "youtube auth missing access token"));
+					}
+				}
+				AdapterControl::Command { request, auth, resp } => {
+					let result = this.execute_command(request, auth).await;
+					let _ = resp.send(result);
+				}
+				AdapterControl::QueryPermissions { room, auth, resp } => {
+					let result = this.permissions_for_room(&room, auth).await;
+					let _ = resp.send(result);
+				}
+				AdapterControl::ListUnbanRequests { resp, .. } => {
+					let _ = resp.send(Err(CommandError::NotSupported(Some(
+						"youtube live chat does not support this command".to_string(),
+					))));
+				}
+				AdapterControl::QueryAuth { resp } => {
+					let _ = resp.send(None);
+				}
+				AdapterControl::Shutdown => {
+					info!(%platform, "youtube adapter received Shutdown");
+					break;
+				}
+			}
+		}
+
+		for (_, handle) in this.pollers.drain() {
+			handle.abort();
+		}
+		let _ = events_tx.try_send(status(platform, false, "youtube adapter offline"));
+		Ok(())
+	}
+}
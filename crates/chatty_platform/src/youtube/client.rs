@@ -0,0 +1,462 @@
+#![forbid(unsafe_code)]
+
+use anyhow::{Context, anyhow};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Live chat resolved for a room (video id, channel id or `@handle`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YouTubeLiveChat {
+	pub live_chat_id: String,
+	pub video_id: Option<String>,
+	pub channel_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct YouTubeClient {
+	base_url: String,
+	api_key: String,
+	access_token: String,
+	client: reqwest::Client,
+}
+
+impl YouTubeClient {
+	pub fn new(base_url: impl Into<String>, api_key: impl Into<String>, access_token: impl Into<String>) -> Self {
+		Self {
+			base_url: base_url.into(),
+			api_key: api_key.into(),
+			access_token: access_token.into(),
+			client: reqwest::Client::new(),
+		}
+	}
+
+	pub fn set_access_token(&mut self, token: impl Into<String>) {
+		self.access_token = token.into();
+	}
+
+	fn url(&self, path: &str, query: &[(&str, &str)]) -> anyhow::Result<Url> {
+		let raw = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+		let mut url = Url::parse(&raw).with_context(|| format!("parse youtube url {raw}"))?;
+		{
+			let mut pairs = url.query_pairs_mut();
+			for (k, v) in query {
+				pairs.append_pair(k, v);
+			}
+			let key = self.api_key.trim();
+			if !key.is_empty() && self.access_token.trim().is_empty() {
+				pairs.append_pair("key", key);
+			}
+		}
+		Ok(url)
+	}
+
+	fn authorize(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+		let token = self.access_token.trim();
+		if token.is_empty() {
+			req
+		} else {
+			req.header("Authorization", format!("Bearer {}", token))
+		}
+	}
+
+	fn require_token(&self) -> anyhow::Result<()> {
+		if self.access_token.trim().is_empty() {
+			return Err(anyhow!("missing youtube access token"));
+		}
+		Ok(())
+	}
+
+	async fn get_json<T: serde::de::DeserializeOwned>(&self, url: Url, label: &str) -> anyhow::Result<T> {
+		let resp = self
+			.authorize(self.client.get(url))
+			.send()
+			.await
+			.with_context(|| format!("youtube {label}"))?;
+		let status = resp.status();
+		let body = resp.text().await.with_context(|| format!("youtube {label} read body"))?;
+		if !status.is_success() {
+			return Err(anyhow!(
+				"youtube {label} failed: status={} reason={}",
+				status,
+				error_reason(&body).unwrap_or_default()
+			));
+		}
+		serde_json::from_str(&body).with_context(|| format!("youtube {label} parse json"))
+	}
+
+	/// Resolve the active live chat for a room id.
+	///
+	/// Accepts a video id, a `UC…` channel id or an `@handle`; returns `None` when nothing is live.
+	pub async fn resolve_live_chat(&self, room_id: &str) -> anyhow::Result<Option<YouTubeLiveChat>> {
+		let room_id = room_id.trim();
+		let video_id = if let Some(handle) = room_id.strip_prefix('@') {
+			let Some(channel_id) = self.channel_id_for_handle(handle).await? else {
+				return Ok(None);
+			};
+			self.live_video_for_channel(&channel_id).await?
+		} else if is_channel_id(room_id) {
+			self.live_video_for_channel(room_id).await?
+		} else {
+			Some(room_id.to_string())
+		};
+
+		let Some(video_id) = video_id else {
+			return Ok(None);
+		};
+		self.live_chat_for_video(&video_id).await
+	}
+
+	pub async fn channel_id_for_handle(&self, handle: &str) -> anyhow::Result<Option<String>> {
+		let url = self.url("/channels", &[("part", "id"), ("forHandle", handle)])?;
+		let resp: YouTubeListResponse<YouTubeChannelItem> = self.get_json(url, "channel lookup").await?;
+		Ok(resp.items.into_iter().next().map(|c| c.id))
+	}
+
+	/// Channel id owning the current access token (`mine=true`).
+	pub async fn own_channel_id(&self) -> anyhow::Result<Option<String>> {
+		self.require_token()?;
+		let url = self.url("/channels", &[("part", "id"), ("mine", "true")])?;
+		let resp: YouTubeListResponse<YouTubeChannelItem> = self.get_json(url, "own channel lookup").await?;
+		Ok(resp.items.into_iter().next().map(|c| c.id))
+	}
+
+	pub async fn live_video_for_channel(&self, channel_id: &str) -> anyhow::Result<Option<String>> {
+		let url = self.url(
+			"/search",
+			&[
+				("part", "id"),
+				("channelId", channel_id),
+				("eventType", "live"),
+				("type", "video"),
+			],
+		)?;
+		let resp: YouTubeListResponse<YouTubeSearchItem> = self.get_json(url, "live search").await?;
+		Ok(resp.items.into_iter().find_map(|item| item.id.video_id))
+	}
+
+	pub async fn live_chat_for_video(&self, video_id: &str) -> anyhow::Result<Option<YouTubeLiveChat>> {
+		let url = self.url("/videos", &[("part", "liveStreamingDetails,snippet"), ("id", video_id)])?;
+		let resp: YouTubeListResponse<YouTubeVideoItem> = self.get_json(url, "video lookup").await?;
+		let Some(video) = resp.items.into_iter().next() else {
+			return Ok(None);
+		};
+		let Some(live_chat_id) = video.live_streaming_details.and_then(|d| d.active_live_chat_id) else {
+			return Ok(None);
+		};
+		Ok(Some(YouTubeLiveChat {
+			live_chat_id,
+			video_id: Some(video.id),
+			channel_id: video.snippet.and_then(|s| s.channel_id),
+		}))
+	}
+
+	pub async fn list_messages(
+		&self,
+		live_chat_id: &str,
+		page_token: Option<&str>,
+		max_results: u32,
+	) -> anyhow::Result<YouTubeLiveChatPage> {
+		let max_results = max_results.to_string();
+		let mut query = vec![
+			("liveChatId", live_chat_id),
+			("part", "snippet,authorDetails"),
+			("maxResults", max_results.as_str()),
+		];
+		if let Some(token) = page_token {
+			query.push(("pageToken", token));
+		}
+		let url = self.url("/liveChat/messages", &query)?;
+		self.get_json(url, "list messages").await
+	}
+
+	pub async fn send_chat_message(&self, live_chat_id: &str, text: &str) -> anyhow::Result<()> {
+		self.require_token()?;
+		let url = self.url("/liveChat/messages", &[("part", "snippet")])?;
+		let body = serde_json::json!({
+			"snippet": {
+				"liveChatId": live_chat_id,
+				"type": "textMessageEvent",
+				"textMessageDetails": { "messageText": text },
+			}
+		});
+		let resp = self
+			.authorize(self.client.post(url))
+			.json(&body)
+			.send()
+			.await
+			.context("youtube send chat")?;
+
+		let status = resp.status();
+		if status.is_success() {
+			Ok(())
+		} else {
+			let body = resp.text().await.unwrap_or_default();
+			Err(anyhow!(
+				"youtube send chat failed: status={} reason={}",
+				status,
+				error_reason(&body).unwrap_or_default()
+			))
+		}
+	}
+
+	pub async fn delete_chat_message(&self, message_id: &str) -> anyhow::Result<()> {
+		self.require_token()?;
+		let url = self.url("/liveChat/messages", &[("id", message_id)])?;
+		let resp = self
+			.authorize(self.client.delete(url))
+			.send()
+			.await
+			.context("youtube delete chat")?;
+
+		let status = resp.status();
+		if status.is_success() {
+			Ok(())
+		} else {
+			let body = resp.text().await.unwrap_or_default();
+			Err(anyhow!(
+				"youtube delete chat failed: status={} reason={}",
+				status,
+				error_reason(&body).unwrap_or_default()
+			))
+		}
+	}
+
+	/// Ban a channel from the live chat; a duration makes it a temporary ban (timeout).
+	pub async fn ban_user(&self, live_chat_id: &str, channel_id: &str, duration_seconds: Option<u32>) -> anyhow::Result<()> {
+		self.require_token()?;
+		let url = self.url("/liveChat/bans", &[("part", "snippet")])?;
+		let body = YouTubeBanRequest {
+			snippet: YouTubeBanSnippet {
+				live_chat_id: live_chat_id.to_string(),
+				ban_type: if duration_seconds.is_some() {
+					"temporary".to_string()
+				} else {
+					"permanent".to_string()
+				},
+				ban_duration_seconds: duration_seconds.map(u64::from),
+				banned_user_details: YouTubeBannedUserRef {
+					channel_id: channel_id.to_string(),
+				},
+			},
+		};
+		let resp = self
+			.authorize(self.client.post(url))
+			.json(&body)
+			.send()
+			.await
+			.context("youtube ban user")?;
+
+		let status = resp.status();
+		if status.is_success() {
+			Ok(())
+		} else {
+			let body = resp.text().await.unwrap_or_default();
+			Err(anyhow!(
+				"youtube ban user failed: status={} reason={}",
+				status,
+				error_reason(&body).unwrap_or_default()
+			))
+		}
+	}
+}
+
+fn is_channel_id(value: &str) -> bool {
+	value.len() == 24 && value.starts_with("UC")
+}
+
+fn error_reason(body: &str) -> Option<String> {
+	let parsed: YouTubeErrorResponse = serde_json::from_str(body).ok()?;
+	parsed.error.errors.into_iter().find_map(|e| e.reason)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeLiveChatPage {
+	#[serde(default)]
+	pub next_page_token: Option<String>,
+	#[serde(default)]
+	pub polling_interval_millis: Option<u64>,
+	#[serde(default)]
+	pub offline_at: Option<String>,
+	#[serde(default)]
+	pub items: Vec<YouTubeLiveChatMessage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeLiveChatMessage {
+	pub id: String,
+	pub snippet: YouTubeMessageSnippet,
+	#[serde(default)]
+	pub author_details: Option<YouTubeAuthorDetails>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeMessageSnippet {
+	#[serde(rename = "type")]
+	pub kind: String,
+	#[serde(default)]
+	pub author_channel_id: Option<String>,
+	#[serde(default)]
+	pub published_at: Option<String>,
+	#[serde(default)]
+	pub display_message: Option<String>,
+	#[serde(default)]
+	pub text_message_details: Option<YouTubeTextMessageDetails>,
+	#[serde(default)]
+	pub message_deleted_details: Option<YouTubeMessageDeletedDetails>,
+	#[serde(default)]
+	pub user_banned_details: Option<YouTubeUserBannedDetails>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeTextMessageDetails {
+	pub message_text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeMessageDeletedDetails {
+	pub deleted_message_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeUserBannedDetails {
+	pub banned_user_details: YouTubeChannelDetails,
+	#[serde(default)]
+	pub ban_type: Option<String>,
+	#[serde(default, deserialize_with = "de_opt_u64")]
+	pub ban_duration_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeChannelDetails {
+	pub channel_id: String,
+	#[serde(default)]
+	pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YouTubeAuthorDetails {
+	pub channel_id: String,
+	#[serde(default)]
+	pub display_name: String,
+	#[serde(default)]
+	pub is_verified: bool,
+	#[serde(default)]
+	pub is_chat_owner: bool,
+	#[serde(default)]
+	pub is_chat_sponsor: bool,
+	#[serde(default)]
+	pub is_chat_moderator: bool,
+}
+
+/// The Data API encodes 64-bit integers as strings.
+fn de_opt_u64<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum NumOrString {
+		Num(u64),
+		Str(String),
+	}
+
+	Ok(match Option::<NumOrString>::deserialize(deserializer)? {
+		Some(NumOrString::Num(n)) => Some(n),
+		Some(NumOrString::Str(s)) => s.trim().parse::<u64>().ok(),
+		None => None,
+	})
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeListResponse<T> {
+	#[serde(default = "Vec::new")]
+	items: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeChannelItem {
+	id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeSearchItem {
+	id: YouTubeSearchItemId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeSearchItemId {
+	#[serde(default)]
+	video_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeVideoItem {
+	id: String,
+	#[serde(default)]
+	snippet: Option<YouTubeVideoSnippet>,
+	#[serde(default)]
+	live_streaming_details: Option<YouTubeLiveStreamingDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeVideoSnippet {
+	#[serde(default)]
+	channel_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeLiveStreamingDetails {
+	#[serde(default)]
+	active_live_chat_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct YouTubeBanRequest {
+	snippet: YouTubeBanSnippet,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeBanSnippet {
+	live_chat_id: String,
+	#[serde(rename = "type")]
+	ban_type: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	ban_duration_seconds: Option<u64>,
+	banned_user_details: YouTubeBannedUserRef,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct YouTubeBannedUserRef {
+	channel_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeErrorResponse {
+	error: YouTubeErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeErrorBody {
+	#[serde(default)]
+	errors: Vec<YouTubeErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YouTubeErrorDetail {
+	#[serde(default)]
+	reason: Option<String>,
+}
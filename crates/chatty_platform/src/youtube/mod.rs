@@ -0,0 +1,21 @@
+#![forbid(unsafe_code)]
+
+mod adapter;
+mod client;
+#[cfg(test)]
+mod tests;
+
+pub use adapter::{YouTubeConfig, YouTubeLiveAdapter};
+use anyhow::anyhow;
+pub use client::{YouTubeClient, YouTubeLiveChat};
+
+pub const YOUTUBE_API_BASE_URL: &str = "https://www.googleapis.com/youtube/v3";
+
+/// Validate a user OAuth token and return the channel id it belongs to.
+pub async fn validate_user_token(base_url: &str, token: &str) -> anyhow::Result<String> {
+	let client = YouTubeClient::new(base_url, "", token);
+	client
+		.own_channel_id()
+		.await?
+		.ok_or_else(|| anyhow!("youtube token has no channel"))
+}
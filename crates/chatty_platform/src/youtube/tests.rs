@@ -0,0 +1,357 @@
+#![forbid(unsafe_code)]
+
+use std::time::Duration;
+
+use chatty_domain::{Platform, RoomId, RoomKey};
+use serde_json::json;
+use tokio::sync::oneshot;
+
+use super::adapter::{normalize_live_chat_item, poll_delay};
+use super::client::{YouTubeLiveChatMessage, YouTubeLiveChatPage};
+use super::{YouTubeConfig, YouTubeLiveAdapter};
+use crate::test_http::{FixtureRequest, spawn_fixture_server};
+use crate::{
+	AdapterAuth, AdapterControl, AdapterEvent, AdapterEventRx, CommandRequest, IngestEvent, IngestPayload, ModerationAction,
+	PlatformAdapter, SecretString, bounded_adapter_channels,
+};
+
+const CHANNEL_ID: &str = "UCxxxxxxxxxxxxxxxxxxxxxx";
+
+fn mk_room(room_id: &str) -> RoomKey {
+	RoomKey::new(Platform::YouTube, RoomId::new(room_id.to_string()).expect("valid room id"))
+}
+
+fn mk_item(value: serde_json::Value) -> YouTubeLiveChatMessage {
+	serde_json::from_value(value).expect("valid live chat message")
+}
+
+fn text_message(id: &str, text: &str) -> serde_json::Value {
+	json!({
+		"id": id,
+		"snippet": {
+			"type": "textMessageEvent",
+			"authorChannelId": "UCauthor",
+			"publishedAt": "2026-01-02T03:04:05.678Z",
+			"displayMessage": text,
+			"textMessageDetails": { "messageText": text }
+		},
+		"authorDetails": {
+			"channelId": "UCauthor",
+			"displayName": "@author",
+			"isVerified": false,
+			"isChatOwner": false,
+			"isChatSponsor": true,
+			"isChatModerator": true
+		}
+	})
+}
+
+fn moderator_details() -> serde_json::Value {
+	json!({ "channelId": "UCmod", "displayName": "@mod", "isChatModerator": true })
+}
+
+#[test]
+fn normalizes_text_message() {
+	let room = mk_room("video123");
+	let ingest = normalize_live_chat_item(&room, mk_item(text_message("msg-1", "hello yt"))).expect("ingest");
+
+	assert_eq!(ingest.room, room);
+	assert_eq!(ingest.platform_message_id.as_ref().map(|id| id.as_str()), Some("msg-1"));
+	assert!(ingest.platform_time.is_some());
+	let IngestPayload::ChatMessage(msg) = ingest.payload else {
+		panic!("expected chat message");
+	};
+	assert_eq!(msg.text, "hello yt");
+	assert_eq!(msg.author.id, "UCauthor");
+	assert_eq!(msg.author.login, "@author");
+	assert_eq!(msg.ids.platform_id.as_deref(), Some("msg-1"));
+	assert_eq!(
+		msg.badges,
+		vec!["youtube:moderator".to_string(), "youtube:member".to_string()]
+	);
+}
+
+#[test]
+fn normalizes_message_deletion() {
+	let item = mk_item(json!({
+		"id": "del-1",
+		"snippet": {
+			"type": "messageDeletedEvent",
+			"messageDeletedDetails": { "deletedMessageId": "msg-1" }
+		},
+		"authorDetails": moderator_details()
+	}));
+	let ingest = normalize_live_chat_item(&mk_room("video123"), item).expect("ingest");
+
+	let IngestPayload::Moderation(ev) = ingest.payload else {
+		panic!("expected moderation");
+	};
+	assert_eq!(ev.kind, "delete");
+	assert_eq!(ev.target_message_platform_id.as_deref(), Some("msg-1"));
+	assert_eq!(ev.actor.as_ref().map(|u| u.id.as_str()), Some("UCmod"));
+	assert!(matches!(ev.action, Some(ModerationAction::DeleteMessage { ref message_id }) if message_id == "msg-1"));
+}
+
+#[test]
+fn normalizes_temporary_and_permanent_bans() {
+	let temporary = mk_item(json!({
+		"id": "ban-1",
+		"snippet": {
+			"type": "userBannedEvent",
+			"userBannedDetails": {
+				"bannedUserDetails": { "channelId": "UCtarget", "displayName": "@target" },
+				"banType": "temporary",
+				"banDurationSeconds": "300"
+			}
+		},
+		"authorDetails": moderator_details()
+	}));
+	let ingest = normalize_live_chat_item(&mk_room("video123"), temporary).expect("ingest");
+	let IngestPayload::Moderation(ev) = ingest.payload else {
+		panic!("expected moderation");
+	};
+	assert_eq!(ev.kind, "timeout");
+	assert_eq!(ev.target.as_ref().map(|u| u.id.as_str()), Some("UCtarget"));
+	assert!(matches!(
+		ev.action,
+		Some(ModerationAction::Timeout {
+			duration_seconds: Some(300),
+			..
+		})
+	));
+
+	let permanent = mk_item(json!({
+		"id": "ban-2",
+		"snippet": {
+			"type": "userBannedEvent",
+			"userBannedDetails": {
+				"bannedUserDetails": { "channelId": "UCtarget" },
+				"banType": "permanent"
+			}
+		},
+		"authorDetails": moderator_details()
+	}));
+	let ingest = normalize_live_chat_item(&mk_room("video123"), permanent).expect("ingest");
+	let IngestPayload::Moderation(ev) = ingest.payload else {
+		panic!("expected moderation");
+	};
+	assert_eq!(ev.kind, "ban");
+	assert_eq!(ev.target.as_ref().map(|u| u.login.as_str()), Some("UCtarget"));
+	assert!(matches!(
+		ev.action,
+		Some(ModerationAction::Ban {
+			is_permanent: Some(true),
+			..
+		})
+	));
+}
+
+#[test]
+fn normalizes_super_chat_as_notice_and_drops_unknown_types() {
+	let super_chat = mk_item(json!({
+		"id": "sc-1",
+		"snippet": {
+			"type": "superChatEvent",
+			"displayMessage": "$5.00 from @author: hi"
+		},
+		"authorDetails": { "channelId": "UCauthor", "displayName": "@author" }
+	}));
+	let ingest = normalize_live_chat_item(&mk_room("video123"), super_chat).expect("ingest");
+	let IngestPayload::UserNotice(notice) = ingest.payload else {
+		panic!("expected user notice");
+	};
+	assert_eq!(notice.kind, "super_chat");
+	assert_eq!(notice.text.as_deref(), Some("$5.00 from @author: hi"));
+
+	let tombstone = mk_item(json!({ "id": "t-1", "snippet": { "type": "tombstone" } }));
+	assert!(normalize_live_chat_item(&mk_room("video123"), tombstone).is_none());
+}
+
+#[test]
+fn poll_delay_respects_server_interval_and_minimum() {
+	let page = |ms: Option<u64>| YouTubeLiveChatPage {
+		next_page_token: None,
+		polling_interval_millis: ms,
+		offline_at: None,
+		items: Vec::new(),
+	};
+	let min = Duration::from_millis(500);
+	assert_eq!(poll_delay(&page(Some(2000)), min), Duration::from_millis(2000));
+	assert_eq!(poll_delay(&page(Some(100)), min), min);
+	assert_eq!(poll_delay(&page(None), min), min);
+}
+
+fn route(req: &FixtureRequest) -> (&'static str, String) {
+	let path = req.target.split('?').next().unwrap_or_default();
+	let body = match (req.method.as_str(), path) {
+		("GET", "/youtube/v3/videos") => json!({
+			"items": [{
+				"id": "video123",
+				"snippet": { "channelId": CHANNEL_ID },
+				"liveStreamingDetails": { "activeLiveChatId": "chat-1" }
+			}]
+		}),
+		("GET", "/youtube/v3/liveChat/messages") if !req.target.contains("pageToken=") => json!({
+			"nextPageToken": "page-2",
+			"pollingIntervalMillis": 10,
+			"items": [text_message("backlog-1", "old message")]
+		}),
+		("GET", "/youtube/v3/liveChat/messages") if req.target.contains("pageToken=page-2") => json!({
+			"nextPageToken": "page-3",
+			"pollingIntervalMillis": 10,
+			"items": [
+				text_message("msg-1", "hello from fake"),
+				{
+					"id": "del-1",
+					"snippet": {
+						"type": "messageDeletedEvent",
+						"messageDeletedDetails": { "deletedMessageId": "msg-1" }
+					},
+					"authorDetails": moderator_details()
+				},
+				{
+					"id": "ban-1",
+					"snippet": {
+						"type": "userBannedEvent",
+						"userBannedDetails": {
+							"bannedUserDetails": { "channelId": "UCtarget", "displayName": "@target" },
+							"banType": "permanent"
+						}
+					},
+					"authorDetails": moderator_details()
+				}
+			]
+		}),
+		("GET", "/youtube/v3/liveChat/messages") => json!({
+			"nextPageToken": "page-3",
+			"pollingIntervalMillis": 1000,
+			"items": []
+		}),
+		("POST", "/youtube/v3/liveChat/messages") | ("POST", "/youtube/v3/liveChat/bans") => json!({ "id": "created" }),
+		("DELETE", "/youtube/v3/liveChat/messages") => return ("204 No Content", String::new()),
+		_ => {
+			return (
+				"404 Not Found",
+				json!({ "error": { "errors": [{ "reason": "notFound" }] } }).to_string(),
+			);
+		}
+	};
+	("200 OK", body.to_string())
+}
+
+fn test_config(base_url: String) -> YouTubeConfig {
+	let mut cfg = YouTubeConfig::new();
+	cfg.base_url = base_url;
+	cfg.api_key = Some(SecretString::new("test-key"));
+	cfg.min_poll_interval = Duration::from_millis(10);
+	cfg.reconnect_min_delay = Duration::from_millis(10);
+	cfg.reconnect_max_delay = Duration::from_millis(50);
+	cfg
+}
+
+async fn next_ingest(events_rx: &mut AdapterEventRx) -> IngestEvent {
+	loop {
+		let ev = tokio::time::timeout(Duration::from_secs(5), events_rx.recv())
+			.await
+			.expect("ingest within timeout")
+			.expect("events channel open");
+		if let AdapterEvent::Ingest(ingest) = ev {
+			return *ingest;
+		}
+	}
+}
+
+#[tokio::test]
+async fn adapter_polls_fake_api_and_executes_commands() {
+	let (addr, recorded) = spawn_fixture_server(route).await;
+	let base_url = format!("http://{addr}/youtube/v3");
+	let adapter = Box::new(YouTubeLiveAdapter::new(test_config(base_url)));
+	let (control_tx, control_rx, events_tx, mut events_rx) = bounded_adapter_channels(16, 64);
+	let task = tokio::spawn(adapter.run(control_rx, events_tx));
+
+	let room = mk_room("video123");
+	control_tx
+		.send(AdapterControl::Join { room: room.clone() })
+		.await
+		.expect("join");
+
+	let first = next_ingest(&mut events_rx).await;
+	let IngestPayload::ChatMessage(msg) = first.payload else {
+		panic!("expected chat message, backlog should be skipped");
+	};
+	assert_eq!(msg.text, "hello from fake");
+
+	let second = next_ingest(&mut events_rx).await;
+	assert!(matches!(second.payload, IngestPayload::Moderation(ref ev) if ev.kind == "delete"));
+	let third = next_ingest(&mut events_rx).await;
+	assert!(matches!(third.payload, IngestPayload::Moderation(ref ev) if ev.kind == "ban"));
+
+	let auth = AdapterAuth::UserAccessToken {
+		access_token: SecretString::new("user-token"),
+		refresh_token: None,
+		user_id: Some(CHANNEL_ID.to_string()),
+		expires_in: None,
+	};
+	let (resp_tx, resp_rx) = oneshot::channel();
+	control_tx
+		.send(AdapterControl::Command {
+			request: CommandRequest::SendChat {
+				room: room.clone(),
+				text: "hi chat".to_string(),
+				reply_to_platform_message_id: None,
+			},
+			auth: Some(auth.clone()),
+			resp: resp_tx,
+		})
+		.await
+		.expect("send command");
+	resp_rx.await.expect("command response").expect("send chat ok");
+
+	let (resp_tx, resp_rx) = oneshot::channel();
+	control_tx
+		.send(AdapterControl::Command {
+			request: CommandRequest::TimeoutUser {
+				room: room.clone(),
+				user_id: "UCtarget".to_string(),
+				duration_seconds: 60,
+				reason: None,
+			},
+			auth: Some(auth),
+			resp: resp_tx,
+		})
+		.await
+		.expect("timeout command");
+	resp_rx.await.expect("command response").expect("timeout ok");
+
+	control_tx.send(AdapterControl::Shutdown).await.expect("shutdown");
+	task.await.expect("adapter task").expect("adapter run");
+
+	let recorded = recorded.lock().await;
+	let polls: Vec<_> = recorded
+		.iter()
+		.filter(|r| r.method == "GET" && r.target.starts_with("/youtube/v3/liveChat/messages"))
+		.collect();
+	assert!(
+		polls
+			.iter()
+			.all(|r| r.target.contains("key=test-key") && r.authorization.is_none())
+	);
+
+	let send = recorded
+		.iter()
+		.find(|r| r.method == "POST" && r.target.starts_with("/youtube/v3/liveChat/messages"))
+		.expect("send chat request");
+	assert_eq!(send.authorization.as_deref(), Some("Bearer user-token"));
+	let body: serde_json::Value = serde_json::from_str(&send.body).expect("send body json");
+	assert_eq!(body["snippet"]["liveChatId"], "chat-1");
+	assert_eq!(body["snippet"]["textMessageDetails"]["messageText"], "hi chat");
+
+	let ban = recorded
+		.iter()
+		.find(|r| r.method == "POST" && r.target.starts_with("/youtube/v3/liveChat/bans"))
+		.expect("ban request");
+	let body: serde_json::Value = serde_json::from_str(&ban.body).expect("ban body json");
+	assert_eq!(body["snippet"]["type"], "temporary");
+	assert_eq!(body["snippet"]["banDurationSeconds"], 60);
+	assert_eq!(body["snippet"]["bannedUserDetails"]["channelId"], "UCtarget");
+}
@@ -19,6 +19,12 @@ fn main() {
 	config.protoc_arg("--experimental_allow_proto3_optional");
 	config.type_attribute(".chatty.v1.EventEnvelope.Event", "#[allow(clippy::large_enum_variant)]");
 
+	// serde derives back the CBOR codec; proto3 fields are optional on the wire, so messages default missing
+	// fields and oneof variants use the proto field names.
+	config.type_attribute(".", "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]");
+	config.message_attribute(".", "#[cfg_attr(feature = \"serde\", serde(default))]");
+	config.enum_attribute(".", "#[cfg_attr(feature = \"serde\", serde(rename_all = \"snake_case\"))]");
+
 	config
 		.compile_protos(&protos, &includes)
 		.expect("failed to compile protobuf definitions with prost");
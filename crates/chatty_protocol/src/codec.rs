@@ -0,0 +1,128 @@
+#![forbid(unsafe_code)]
+
+use prost::Message;
+
+use crate::framing::FramingError;
+use crate::pb;
+
+/// ALPN protocol selecting protobuf frames.
+pub const ALPN_PROTOBUF: &[u8] = b"chatty-v1";
+
+/// ALPN protocol selecting CBOR frames.
+#[cfg(feature = "cbor")]
+pub const ALPN_CBOR: &[u8] = b"chatty-v1-cbor";
+
+/// Payload codec for frames, selected per connection by the TLS ALPN protocol.
+///
+/// The codec is fixed by the QUIC handshake, before the first frame, so every frame on the control and
+/// events streams uses it, `Hello`, `Welcome` and handshake errors included. `Welcome.selected_codec`
+/// echoes it back. CBOR is only available with the `cbor` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Codec {
+	#[default]
+	Protobuf,
+	#[cfg(feature = "cbor")]
+	Cbor,
+}
+
+/// Messages that can be carried by every [`Codec`].
+#[cfg(feature = "cbor")]
+pub trait CodecMessage: Message + Default + serde::Serialize + serde::de::DeserializeOwned {}
+
+#[cfg(feature = "cbor")]
+impl<T: Message + Default + serde::Serialize + serde::de::DeserializeOwned> CodecMessage for T {}
+
+/// Messages that can be carried by every [`Codec`].
+#[cfg(not(feature = "cbor"))]
+pub trait CodecMessage: Message + Default {}
+
+#[cfg(not(feature = "cbor"))]
+impl<T: Message + Default> CodecMessage for T {}
+
+impl Codec {
+	/// Map a wire codec value (`CODEC_UNSPECIFIED` means protobuf); `None` if this build lacks the codec.
+	pub fn from_pb(codec: pb::Codec) -> Option<Self> {
+		match codec {
+			pb::Codec::Unspecified | pb::Codec::Protobuf => Some(Codec::Protobuf),
+			#[cfg(feature = "cbor")]
+			pb::Codec::Cbor => Some(Codec::Cbor),
+			#[cfg(not(feature = "cbor"))]
+			pb::Codec::Cbor => None,
+		}
+	}
+
+	/// Wire value for `Hello`/`Welcome`.
+	pub fn as_pb(self) -> pb::Codec {
+		match self {
+			Codec::Protobuf => pb::Codec::Protobuf,
+			#[cfg(feature = "cbor")]
+			Codec::Cbor => pb::Codec::Cbor,
+		}
+	}
+
+	/// ALPN protocol identifier that selects this codec.
+	pub fn alpn(self) -> &'static [u8] {
+		match self {
+			Codec::Protobuf => ALPN_PROTOBUF,
+			#[cfg(feature = "cbor")]
+			Codec::Cbor => ALPN_CBOR,
+		}
+	}
+
+	/// Codec selected by a negotiated ALPN protocol; `None` if unknown or not in this build.
+	pub fn from_alpn(protocol: &[u8]) -> Option<Self> {
+		Self::all().iter().copied().find(|c| c.alpn() == protocol)
+	}
+
+	/// Every codec of this build, most preferred first (servers advertise their ALPN list in this order).
+	pub fn all() -> &'static [Codec] {
+		#[cfg(feature = "cbor")]
+		{
+			&[Codec::Cbor, Codec::Protobuf]
+		}
+		#[cfg(not(feature = "cbor"))]
+		{
+			&[Codec::Protobuf]
+		}
+	}
+
+	/// Encode a message payload (without length prefix).
+	pub fn encode<M: CodecMessage>(self, msg: &M) -> Result<Vec<u8>, FramingError> {
+		match self {
+			Codec::Protobuf => Ok(msg.encode_to_vec()),
+			#[cfg(feature = "cbor")]
+			Codec::Cbor => {
+				let mut out = Vec::new();
+				ciborium::into_writer(msg, &mut out)?;
+				Ok(out)
+			}
+		}
+	}
+
+	/// Decode a message payload (without length prefix).
+	pub fn decode<M: CodecMessage>(self, payload: &[u8]) -> Result<M, FramingError> {
+		match self {
+			Codec::Protobuf => Ok(M::decode(payload)?),
+			#[cfg(feature = "cbor")]
+			Codec::Cbor => {
+				let mut rest = payload;
+				let msg = ciborium::from_reader(&mut rest)?;
+				if !rest.is_empty() {
+					return Err(FramingError::CborDecode(ciborium::de::Error::Semantic(
+						None,
+						format!("trailing bytes after cbor item: {}", rest.len()),
+					)));
+				}
+				Ok(msg)
+			}
+		}
+	}
+}
+
+impl TryFrom<i32> for Codec {
+	type Error = prost::UnknownEnumValue;
+
+	fn try_from(value: i32) -> Result<Self, Self::Error> {
+		pb::Codec::try_from(value).and_then(|c| Codec::from_pb(c).ok_or(prost::UnknownEnumValue(value)))
+	}
+}
@@ -4,6 +4,8 @@ use bytes::{BufMut, BytesMut};
 use prost::Message;
 use thiserror::Error;
 
+use crate::codec::{Codec, CodecMessage};
+
 /// Default maximum frame payload size for v1.
 pub const DEFAULT_MAX_FRAME_SIZE: usize = 2 * 1024 * 1024; // 2 MiB
 
@@ -26,6 +28,14 @@ pub enum FramingError {
 
 	#[error("protobuf encode error: {0}")]
 	Encode(#[from] prost::EncodeError),
+
+	#[cfg(feature = "cbor")]
+	#[error("cbor encode error: {0}")]
+	CborEncode(#[from] ciborium::ser::Error<std::io::Error>),
+
+	#[cfg(feature = "cbor")]
+	#[error("cbor decode error: {0}")]
+	CborDecode(#[from] ciborium::de::Error<std::io::Error>),
 }
 
 /// Encode a protobuf message into a length-prefixed frame.
@@ -65,6 +75,55 @@ pub fn encode_frame_into<M: Message>(buf: &mut BytesMut, msg: &M, max_frame_size
 	Ok(())
 }
 
+/// Encode a message into a length-prefixed frame using `codec`.
+pub fn encode_frame_with_codec<M: CodecMessage>(
+	codec: Codec,
+	msg: &M,
+	max_frame_size: usize,
+) -> Result<Vec<u8>, FramingError> {
+	if codec == Codec::Protobuf {
+		return encode_frame(msg, max_frame_size);
+	}
+
+	let payload = codec.encode(msg)?;
+	if payload.len() > max_frame_size {
+		return Err(FramingError::FrameTooLarge {
+			len: payload.len(),
+			max: max_frame_size,
+		});
+	}
+
+	let mut out = Vec::with_capacity(4 + payload.len());
+	out.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+	out.extend_from_slice(&payload);
+	Ok(out)
+}
+
+/// Append a frame encoded with `codec` into the provided buffer.
+pub fn encode_frame_into_with_codec<M: CodecMessage>(
+	codec: Codec,
+	buf: &mut BytesMut,
+	msg: &M,
+	max_frame_size: usize,
+) -> Result<(), FramingError> {
+	if codec == Codec::Protobuf {
+		return encode_frame_into(buf, msg, max_frame_size);
+	}
+
+	let payload = codec.encode(msg)?;
+	if payload.len() > max_frame_size {
+		return Err(FramingError::FrameTooLarge {
+			len: payload.len(),
+			max: max_frame_size,
+		});
+	}
+
+	buf.reserve(4 + payload.len());
+	buf.put_u32(payload.len() as u32);
+	buf.put_slice(&payload);
+	Ok(())
+}
+
 /// Compute total frame length (prefix + payload).
 #[inline]
 pub fn frame_len_from_payload_len(payload_len: usize) -> usize {
@@ -97,6 +156,36 @@ pub fn decode_frame<M: Message + Default>(src: &[u8], max_frame_size: usize) ->
 	Ok((msg, need))
 }
 
+/// Decode a single frame encoded with `codec` from the start of `src`.
+pub fn decode_frame_with_codec<M: CodecMessage>(
+	codec: Codec,
+	src: &[u8],
+	max_frame_size: usize,
+) -> Result<(M, usize), FramingError> {
+	if src.len() < 4 {
+		return Err(FramingError::InsufficientData {
+			need: 4,
+			have: src.len(),
+		});
+	}
+
+	let len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+	if len > max_frame_size {
+		return Err(FramingError::FrameTooLarge {
+			len,
+			max: max_frame_size,
+		});
+	}
+
+	let need = 4 + len;
+	if src.len() < need {
+		return Err(FramingError::InsufficientData { need, have: src.len() });
+	}
+
+	let msg = codec.decode(&src[4..need])?;
+	Ok((msg, need))
+}
+
 /// Try to decode a single frame from a growable buffer.
 pub fn try_decode_frame_from_buffer<M: Message + Default>(
 	buf: &mut BytesMut,
@@ -124,6 +213,34 @@ pub fn try_decode_frame_from_buffer<M: Message + Default>(
 	Ok(Some(msg))
 }
 
+/// Try to decode a single frame encoded with `codec` from a growable buffer.
+pub fn try_decode_frame_from_buffer_with_codec<M: CodecMessage>(
+	codec: Codec,
+	buf: &mut BytesMut,
+	max_frame_size: usize,
+) -> Result<Option<M>, FramingError> {
+	if buf.len() < 4 {
+		return Ok(None);
+	}
+
+	let len = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+	if len > max_frame_size {
+		return Err(FramingError::FrameTooLarge {
+			len,
+			max: max_frame_size,
+		});
+	}
+
+	let need = 4 + len;
+	if buf.len() < need {
+		return Ok(None);
+	}
+
+	let frame = buf.split_to(need);
+	let msg = codec.decode(&frame[4..])?;
+	Ok(Some(msg))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -222,4 +339,49 @@ mod tests {
 			other => panic!("unexpected error: {other:?}"),
 		}
 	}
+
+	#[cfg(feature = "cbor")]
+	#[test]
+	fn cbor_envelope_roundtrip() {
+		use crate::pb;
+
+		let env = pb::Envelope {
+			version: 1,
+			request_id: "req-1".to_string(),
+			msg: Some(pb::envelope::Msg::Ping(pb::Ping {
+				client_time_unix_ms: 1_700_000_000_000,
+			})),
+		};
+
+		let frame = encode_frame_with_codec(Codec::Cbor, &env, DEFAULT_MAX_FRAME_SIZE).expect("encode");
+		let (decoded, consumed) =
+			decode_frame_with_codec::<pb::Envelope>(Codec::Cbor, &frame, DEFAULT_MAX_FRAME_SIZE).expect("decode");
+		assert_eq!(consumed, frame.len());
+		assert_eq!(decoded, env);
+
+		let mut buf = BytesMut::new();
+		encode_frame_into_with_codec(Codec::Cbor, &mut buf, &env, DEFAULT_MAX_FRAME_SIZE).expect("encode into");
+		buf.extend_from_slice(&frame[..3]);
+		let first = try_decode_frame_from_buffer_with_codec::<pb::Envelope>(Codec::Cbor, &mut buf, DEFAULT_MAX_FRAME_SIZE)
+			.expect("decode")
+			.expect("complete frame");
+		assert_eq!(first, env);
+		assert!(
+			try_decode_frame_from_buffer_with_codec::<pb::Envelope>(Codec::Cbor, &mut buf, DEFAULT_MAX_FRAME_SIZE)
+				.expect("decode")
+				.is_none()
+		);
+	}
+
+	#[cfg(feature = "cbor")]
+	#[test]
+	fn cbor_rejects_protobuf_payload() {
+		let msg = TestMsg {
+			s: "hello".to_string(),
+			n: 42,
+		};
+		let frame = encode_frame_default(&msg).expect("encode");
+		let err = decode_frame_with_codec::<crate::pb::Envelope>(Codec::Cbor, &frame, DEFAULT_MAX_FRAME_SIZE).unwrap_err();
+		assert!(matches!(err, FramingError::CborDecode(_)), "unexpected error: {err:?}");
+	}
 }
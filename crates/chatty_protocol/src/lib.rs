@@ -1,10 +1,13 @@
 #![forbid(unsafe_code)]
 
+pub mod codec;
 pub mod framing;
 
+pub use codec::{Codec, CodecMessage};
 pub use framing::{
-	DEFAULT_MAX_FRAME_SIZE, FramingError, decode_frame, encode_frame, encode_frame_default, encode_frame_into,
-	frame_len_from_payload_len, try_decode_frame_from_buffer,
+	DEFAULT_MAX_FRAME_SIZE, FramingError, decode_frame, decode_frame_with_codec, encode_frame, encode_frame_default,
+	encode_frame_into, encode_frame_into_with_codec, encode_frame_with_codec, frame_len_from_payload_len,
+	try_decode_frame_from_buffer, try_decode_frame_from_buffer_with_codec,
 };
 
 /// Generated protobuf types (`chatty.v1`).
@@ -23,3 +26,9 @@ pub mod version {
 	/// Compact representation useful for logs/metrics.
 	pub const PROTOCOL_VERSION_U32: u32 = (PROTOCOL_MAJOR << 16) | PROTOCOL_MINOR;
 }
+
+/// Machine-readable `Notice` codes.
+pub mod notice {
+	/// The server is draining connections before it stops; clients should reconnect without backoff.
+	pub const SERVER_SHUTTING_DOWN: &str = "SERVER_SHUTTING_DOWN";
+}
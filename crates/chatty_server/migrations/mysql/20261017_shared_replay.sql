@@ -0,0 +1,18 @@
+DROP TABLE IF EXISTS replay_events;
+DROP TABLE IF EXISTS replay_cursors;
+
+CREATE TABLE IF NOT EXISTS replay_topic_cursors (
+    topic VARCHAR(255) PRIMARY KEY,
+    cursor BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS replay_topic_events (
+    topic VARCHAR(255) NOT NULL,
+    cursor BIGINT NOT NULL,
+    payload LONGBLOB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (topic, cursor)
+);
+
+CREATE INDEX idx_replay_topic_events_created_at
+ON replay_topic_events (topic, created_at);
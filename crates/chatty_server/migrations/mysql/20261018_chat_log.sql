@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS chat_log (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    topic VARCHAR(255) NOT NULL,
+    kind VARCHAR(32) NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    server_message_id VARCHAR(255),
+    platform_message_id VARCHAR(255),
+    user_id VARCHAR(255),
+    user_login VARCHAR(255),
+    user_display VARCHAR(255),
+    text TEXT NOT NULL,
+    action VARCHAR(64),
+    actor_login VARCHAR(255)
+);
+
+CREATE INDEX idx_chat_log_topic_id
+ON chat_log (topic, id);
+
+CREATE INDEX idx_chat_log_topic_user
+ON chat_log (topic, user_id);
+
+CREATE INDEX idx_chat_log_created_at
+ON chat_log (created_at_ms);
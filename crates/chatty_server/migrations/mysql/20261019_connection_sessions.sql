@@ -0,0 +1,8 @@
+ALTER TABLE connection_sessions
+    ADD COLUMN client_name VARCHAR(255),
+    ADD COLUMN client_instance_id VARCHAR(255),
+    ADD COLUMN auth_subject VARCHAR(255),
+    ADD COLUMN platform_user_ids TEXT;
+
+CREATE INDEX idx_connection_sessions_started_at
+ON connection_sessions (started_at);
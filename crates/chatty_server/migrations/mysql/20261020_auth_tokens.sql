@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS issued_tokens (
+    jti VARCHAR(64) PRIMARY KEY,
+    subject VARCHAR(255) NOT NULL,
+    key_id VARCHAR(255),
+    role VARCHAR(32),
+    topics TEXT,
+    commands TEXT,
+    issued_at BIGINT NOT NULL,
+    expires_at BIGINT NOT NULL
+);
+
+CREATE INDEX idx_issued_tokens_issued_at
+ON issued_tokens (issued_at);
+
+CREATE TABLE IF NOT EXISTS revoked_tokens (
+    jti VARCHAR(64) PRIMARY KEY,
+    revoked_at BIGINT NOT NULL
+);
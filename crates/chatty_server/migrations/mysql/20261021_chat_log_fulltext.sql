@@ -0,0 +1,2 @@
+CREATE FULLTEXT INDEX idx_chat_log_text_ft
+ON chat_log (text);
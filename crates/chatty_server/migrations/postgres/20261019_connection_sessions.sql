@@ -0,0 +1,8 @@
+ALTER TABLE connection_sessions
+    ADD COLUMN IF NOT EXISTS client_name TEXT,
+    ADD COLUMN IF NOT EXISTS client_instance_id TEXT,
+    ADD COLUMN IF NOT EXISTS auth_subject TEXT,
+    ADD COLUMN IF NOT EXISTS platform_user_ids TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_connection_sessions_started_at
+ON connection_sessions (started_at);
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS issued_tokens (
+    jti TEXT PRIMARY KEY,
+    subject TEXT NOT NULL,
+    key_id TEXT,
+    role TEXT,
+    topics TEXT,
+    commands TEXT,
+    issued_at BIGINT NOT NULL,
+    expires_at BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_issued_tokens_issued_at
+ON issued_tokens (issued_at);
+
+CREATE TABLE IF NOT EXISTS revoked_tokens (
+    jti TEXT PRIMARY KEY,
+    revoked_at BIGINT NOT NULL
+);
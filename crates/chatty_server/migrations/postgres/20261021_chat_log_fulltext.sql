@@ -0,0 +1,6 @@
+ALTER TABLE chat_log
+ADD COLUMN IF NOT EXISTS text_tsv tsvector
+GENERATED ALWAYS AS (to_tsvector('simple', text)) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_chat_log_text_tsv
+ON chat_log USING GIN (text_tsv);
@@ -0,0 +1,18 @@
+DROP TABLE IF EXISTS replay_events;
+DROP TABLE IF EXISTS replay_cursors;
+
+CREATE TABLE IF NOT EXISTS replay_topic_cursors (
+    topic TEXT PRIMARY KEY,
+    cursor INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS replay_topic_events (
+    topic TEXT NOT NULL,
+    cursor INTEGER NOT NULL,
+    payload BLOB NOT NULL,
+    created_at INTEGER NOT NULL,
+    PRIMARY KEY (topic, cursor)
+);
+
+CREATE INDEX IF NOT EXISTS idx_replay_topic_events_created_at
+ON replay_topic_events (topic, created_at);
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS chat_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    topic TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    created_at_ms INTEGER NOT NULL,
+    server_message_id TEXT,
+    platform_message_id TEXT,
+    user_id TEXT,
+    user_login TEXT,
+    user_display TEXT,
+    text TEXT NOT NULL,
+    action TEXT,
+    actor_login TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_chat_log_topic_id
+ON chat_log (topic, id);
+
+CREATE INDEX IF NOT EXISTS idx_chat_log_topic_user
+ON chat_log (topic, user_id);
+
+CREATE INDEX IF NOT EXISTS idx_chat_log_created_at
+ON chat_log (created_at_ms);
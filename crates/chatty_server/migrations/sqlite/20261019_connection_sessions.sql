@@ -0,0 +1,7 @@
+ALTER TABLE connection_sessions ADD COLUMN client_name TEXT;
+ALTER TABLE connection_sessions ADD COLUMN client_instance_id TEXT;
+ALTER TABLE connection_sessions ADD COLUMN auth_subject TEXT;
+ALTER TABLE connection_sessions ADD COLUMN platform_user_ids TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_connection_sessions_started_at
+ON connection_sessions (started_at);
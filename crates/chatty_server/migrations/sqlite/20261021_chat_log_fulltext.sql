@@ -0,0 +1,20 @@
+CREATE VIRTUAL TABLE IF NOT EXISTS chat_log_fts USING fts5(
+    text,
+    content = 'chat_log',
+    content_rowid = 'id'
+);
+
+INSERT INTO chat_log_fts (chat_log_fts) VALUES ('rebuild');
+
+CREATE TRIGGER IF NOT EXISTS chat_log_fts_insert AFTER INSERT ON chat_log BEGIN
+    INSERT INTO chat_log_fts (rowid, text) VALUES (new.id, new.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS chat_log_fts_delete AFTER DELETE ON chat_log BEGIN
+    INSERT INTO chat_log_fts (chat_log_fts, rowid, text) VALUES ('delete', old.id, old.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS chat_log_fts_update AFTER UPDATE OF text ON chat_log BEGIN
+    INSERT INTO chat_log_fts (chat_log_fts, rowid, text) VALUES ('delete', old.id, old.text);
+    INSERT INTO chat_log_fts (rowid, text) VALUES (new.id, new.text);
+END;
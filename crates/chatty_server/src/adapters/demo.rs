@@ -171,6 +171,12 @@ impl PlatformAdapter for DemoAdapter {
 							let _ = resp.send(PermissionsInfo::default());
 						}
 
+						AdapterControl::ListUnbanRequests { resp, .. } => {
+							let _ = resp.send(Err(chatty_platform::CommandError::NotSupported(Some(
+								"demo adapter".to_string(),
+							))));
+						}
+
 						AdapterControl::QueryAuth { resp } => {
 							let _ = resp.send(None);
 						}
@@ -1,7 +1,5 @@
 #![forbid(unsafe_code)]
 
 pub mod demo;
-pub mod null;
 
 pub use demo::DemoAdapter;
-pub use null::NullAdapter;
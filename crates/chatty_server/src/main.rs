@@ -7,57 +7,157 @@ mod server;
 mod util;
 
 use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use anyhow::Context as _;
 use chatty_platform::SecretString;
 use chatty_platform::kick::{KickConfig, KickEventAdapter};
 use chatty_platform::twitch::{TwitchConfig, TwitchEventSubAdapter};
+use chatty_platform::youtube::{YouTubeConfig, YouTubeLiveAdapter};
 use chatty_util::endpoint::QuicEndpoint;
+use futures::StreamExt as _;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
+use crate::config::ServerSettings;
 use crate::quic::config::QuicServerConfig;
 use crate::server::adapter_manager::{AdapterManagerConfig, start_global_adapter_manager};
-use crate::server::audit::AuditService;
+use crate::server::audit::{AuditService, SessionQuery};
+use crate::server::auth::{AuthClaims, HmacKeyring, TokenRole};
+use crate::server::chat_log::{ChatLogService, spawn_chat_log_pruner};
 use crate::server::connection::{ConnectionSettings, handle_connection};
 use crate::server::health::{HealthState, spawn_health_server};
 use crate::server::replay::{PersistentReplayBackend, ReplayService, ReplayStoreConfig};
+use crate::server::resume::ResumeRegistry;
 use crate::server::room_hub::{RoomHub, RoomHubConfig};
 use crate::server::router::{RouterConfig, spawn_ingest_router};
+use crate::server::shutdown::ShutdownSignal;
 use crate::server::state::GlobalState;
+use crate::server::tokens::TokenStore;
 
 /// Dev-only fake/demo adapter enable flag.
 const CHATTY_ENABLE_FAKE_ADAPTER_ENV: &str = "CHATTY_ENABLE_FAKE_ADAPTER";
 
+/// Lifetime of tokens minted by `token issue` when `--ttl` is not given (30 days).
+const DEFAULT_TOKEN_TTL_SECS: u64 = 30 * 86_400;
+
 fn usage_and_exit() -> ! {
 	eprintln!(
-		"Usage: chatty_server [--bind quic://host:port]\n\
+		"Usage: chatty_server [--config PATH] [--bind quic://host:port]...\n\
+       chatty_server [--config PATH] check-config\n\
+       chatty_server sessions [--active] [--at UNIX_SECS] [--limit N]\n\
+       chatty_server token issue --sub NAME [--ttl SECS] [--role ROLE] [--topic PATTERN]... [--command KIND]...\n\
+       chatty_server token list [--limit N]\n\
+       chatty_server token revoke JTI\n\
 \n\
 Options:\n\
-\t--bind    Bind endpoint (default: quic://127.0.0.1:18203)\n\
+\t--config  Config file (default: ~/.chatty/config.toml); applies to every subcommand\n\
+\t--bind    Bind endpoint, repeatable; overrides `[server] bind` (default: quic://127.0.0.1:18203)\n\
 \t         Format: quic://host:port\n\
 \t--help   Show this help\n\
+\n\
+Subcommands:\n\
+\tcheck-config  Validate the config and print the effective values with secrets redacted\n\
+\tsessions  List recorded client sessions, newest first (requires persistence)\n\
+\t         --active  only sessions that have not ended\n\
+\t         --at      only sessions open at this unix time (seconds)\n\
+\t         --limit   maximum rows (default: 50)\n\
+\ttoken     Manage HMAC access tokens (list/revoke require persistence)\n\
+\t         issue   mint a token signed with the active key and print it\n\
+\t                 --ttl      lifetime in seconds (default: 30 days)\n\
+\t                 --role     viewer, moderator or admin\n\
+\t                 --topic    allowed topic pattern, `*` matches anything (repeatable)\n\
+\t                 --command  allowed command kind (repeatable)\n\
+\t         list    issued tokens, newest first\n\
+\t         revoke  reject a token by its jti from now on\n\
 "
 	);
 	std::process::exit(2)
 }
 
-fn parse_args() -> SocketAddr {
-	let mut bind_endpoint = "quic://127.0.0.1:18203".to_string();
+struct CliArgs {
+	/// Explicit `--config` path; the default path is used when `None`.
+	config_path: Option<PathBuf>,
+	command: Cli,
+}
+
+enum Cli {
+	/// Bind endpoints given with `--bind`; empty keeps the configured ones.
+	Serve(Vec<String>),
+	CheckConfig,
+	Sessions(SessionQuery),
+	Token(TokenCommand),
+}
 
-	let mut it = std::env::args().skip(1);
+enum TokenCommand {
+	/// Claims to sign; `exp` is filled in from `ttl_secs` when issuing.
+	Issue {
+		claims: AuthClaims,
+		ttl_secs: u64,
+	},
+	List {
+		limit: usize,
+	},
+	Revoke {
+		jti: String,
+	},
+}
+
+fn parse_args() -> CliArgs {
+	let mut config_path = None;
+	let mut args = Vec::new();
+	let mut raw = std::env::args().skip(1);
+	while let Some(arg) = raw.next() {
+		if arg == "--config" {
+			let v = raw.next().filter(|v| !v.trim().is_empty()).unwrap_or_else(|| {
+				eprintln!("--config expects a path");
+				usage_and_exit();
+			});
+			config_path = Some(PathBuf::from(v));
+		} else {
+			args.push(arg);
+		}
+	}
+
+	let mut args = args.into_iter().peekable();
+	let command = match args.peek().map(String::as_str) {
+		Some("sessions") => {
+			args.next();
+			Cli::Sessions(parse_sessions_args(args))
+		}
+		Some("token") => {
+			args.next();
+			Cli::Token(parse_token_args(args))
+		}
+		Some("check-config") => {
+			args.next();
+			if let Some(other) = args.next() {
+				eprintln!("Unknown argument: {other}");
+				usage_and_exit();
+			}
+			Cli::CheckConfig
+		}
+		_ => Cli::Serve(parse_serve_args(args)),
+	};
+
+	CliArgs { config_path, command }
+}
+
+fn parse_serve_args(mut it: impl Iterator<Item = String>) -> Vec<String> {
+	let mut binds = Vec::new();
 	while let Some(arg) = it.next() {
 		match arg.as_str() {
 			"--help" | "-h" => usage_and_exit(),
 			"--bind" | "--listen" => {
 				let v = it.next().unwrap_or_else(|| usage_and_exit());
-				if v.trim().is_empty() {
-					eprintln!("--bind must be non-empty (expected quic://host:port)");
+				if let Err(e) = QuicEndpoint::parse(&v).and_then(|bind| bind.to_socket_addr_if_ip_literal()) {
+					eprintln!("{e}");
 					usage_and_exit();
 				}
-				bind_endpoint = v;
+				binds.push(v.trim().to_string());
 			}
 			other => {
 				eprintln!("Unknown argument: {other}");
@@ -65,18 +165,265 @@ fn parse_args() -> SocketAddr {
 			}
 		}
 	}
+	binds
+}
 
-	let bind = QuicEndpoint::parse(&bind_endpoint).unwrap_or_else(|e| {
-		eprintln!("{e}");
-		usage_and_exit();
-	});
+fn parse_sessions_args(mut it: impl Iterator<Item = String>) -> SessionQuery {
+	let mut query = SessionQuery {
+		limit: 50,
+		..SessionQuery::default()
+	};
+	while let Some(arg) = it.next() {
+		match arg.as_str() {
+			"--help" | "-h" => usage_and_exit(),
+			"--active" => query.active_only = true,
+			"--at" => {
+				let v = it.next().unwrap_or_else(|| usage_and_exit());
+				query.at_unix = Some(v.trim().parse().unwrap_or_else(|_| {
+					eprintln!("--at expects unix seconds");
+					usage_and_exit();
+				}));
+			}
+			"--limit" => {
+				let v = it.next().unwrap_or_else(|| usage_and_exit());
+				query.limit = v.trim().parse().unwrap_or_else(|_| {
+					eprintln!("--limit expects a number");
+					usage_and_exit();
+				});
+			}
+			other => {
+				eprintln!("Unknown argument: {other}");
+				usage_and_exit();
+			}
+		}
+	}
+	query
+}
 
-	let addr: SocketAddr = bind.to_socket_addr_if_ip_literal().unwrap_or_else(|e| {
-		eprintln!("{e}");
-		usage_and_exit();
-	});
+fn parse_token_args(mut it: impl Iterator<Item = String>) -> TokenCommand {
+	let action = it.next().unwrap_or_else(|| usage_and_exit());
+	match action.as_str() {
+		"issue" => {
+			let mut claims = AuthClaims::default();
+			let mut ttl_secs = DEFAULT_TOKEN_TTL_SECS;
+			while let Some(arg) = it.next() {
+				let mut value = || it.next().unwrap_or_else(|| usage_and_exit());
+				match arg.as_str() {
+					"--help" | "-h" => usage_and_exit(),
+					"--sub" => claims.sub = value().trim().to_string(),
+					"--ttl" => {
+						ttl_secs = value().trim().parse().unwrap_or_else(|_| {
+							eprintln!("--ttl expects seconds");
+							usage_and_exit();
+						});
+					}
+					"--role" => {
+						claims.role = Some(TokenRole::parse(&value()).unwrap_or_else(|| {
+							eprintln!("--role expects viewer, moderator or admin");
+							usage_and_exit();
+						}));
+					}
+					"--topic" => claims.topics.get_or_insert_default().push(value().trim().to_string()),
+					"--command" => claims.commands.get_or_insert_default().push(value().trim().to_string()),
+					other => {
+						eprintln!("Unknown argument: {other}");
+						usage_and_exit();
+					}
+				}
+			}
+			if claims.sub.is_empty() {
+				eprintln!("--sub is required");
+				usage_and_exit();
+			}
+			TokenCommand::Issue { claims, ttl_secs }
+		}
+		"list" => {
+			let mut limit = 50;
+			while let Some(arg) = it.next() {
+				match arg.as_str() {
+					"--help" | "-h" => usage_and_exit(),
+					"--limit" => {
+						let v = it.next().unwrap_or_else(|| usage_and_exit());
+						limit = v.trim().parse().unwrap_or_else(|_| {
+							eprintln!("--limit expects a number");
+							usage_and_exit();
+						});
+					}
+					other => {
+						eprintln!("Unknown argument: {other}");
+						usage_and_exit();
+					}
+				}
+			}
+			TokenCommand::List { limit }
+		}
+		"revoke" => {
+			let jti = it.next().filter(|v| !v.trim().is_empty()).unwrap_or_else(|| usage_and_exit());
+			if let Some(other) = it.next() {
+				eprintln!("Unknown argument: {other}");
+				usage_and_exit();
+			}
+			TokenCommand::Revoke {
+				jti: jti.trim().to_string(),
+			}
+		}
+		_ => usage_and_exit(),
+	}
+}
+
+fn persistence_database_url(server_cfg: &crate::config::ServerConfig) -> Option<&str> {
+	server_cfg
+		.persistence
+		.database_url
+		.as_deref()
+		.filter(|_| server_cfg.persistence.enabled)
+}
 
-	addr
+async fn run_token_command(
+	server_cfg: &crate::config::ServerConfig,
+	keyring: &HmacKeyring,
+	cmd: TokenCommand,
+) -> anyhow::Result<()> {
+	let store = match persistence_database_url(server_cfg) {
+		Some(database_url) => TokenStore::connect(database_url).await?,
+		None => TokenStore::disabled(),
+	};
+	let now = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.unwrap_or_default()
+		.as_secs();
+
+	match cmd {
+		TokenCommand::Issue { mut claims, ttl_secs } => {
+			claims.exp = now.saturating_add(ttl_secs);
+			claims.jti = Some(uuid::Uuid::new_v4().to_string());
+			claims.kid = keyring.active_key().map(str::to_string);
+			let token = keyring.issue(claims.clone())?;
+			if store.is_enabled() {
+				store.record_issued(&claims, now as i64).await?;
+			} else {
+				eprintln!("persistence is disabled: the token is not recorded and cannot be listed");
+			}
+			eprintln!(
+				"jti {} for {} expires at {}",
+				claims.jti.as_deref().unwrap_or_default(),
+				claims.sub,
+				claims.exp
+			);
+			println!("{token}");
+		}
+		TokenCommand::List { limit } => {
+			if !store.is_enabled() {
+				return Err(anyhow::anyhow!("tokens are only recorded when persistence is enabled"));
+			}
+			println!("JTI\tSUBJECT\tKEY\tROLE\tTOPICS\tCOMMANDS\tISSUED\tEXPIRES\tREVOKED");
+			let join = |list: &[String]| if list.is_empty() { "-".to_string() } else { list.join(",") };
+			for t in store.list(limit).await? {
+				println!(
+					"{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+					t.jti,
+					t.subject,
+					t.key_id.as_deref().unwrap_or("-"),
+					t.role.as_deref().unwrap_or("-"),
+					join(&t.topics),
+					join(&t.commands),
+					t.issued_at_unix,
+					t.expires_at_unix,
+					t.revoked_at_unix.map(|r| r.to_string()).unwrap_or_else(|| "-".to_string()),
+				);
+			}
+		}
+		TokenCommand::Revoke { jti } => {
+			if store.revoke(&jti, now as i64).await? {
+				println!("revoked {jti}");
+			} else {
+				println!("{jti} was already revoked");
+			}
+		}
+	}
+	Ok(())
+}
+
+/// `check-config`: print the effective config, then fail on anything the server would reject.
+fn check_config(config_path: &Path, server_cfg: &crate::config::ServerConfig) -> anyhow::Result<()> {
+	println!("# effective config: {} (+ env overrides)", config_path.display());
+	print!("{}", server_cfg.to_redacted_toml());
+
+	server_cfg.validate()?;
+	let bind_addrs = server_cfg.server.bind_addrs()?;
+	let quic_cfg = quic_server_config(&server_cfg.server, bind_addrs[0]);
+	quic_cfg.transport_config()?;
+	if let (Some(cert_path), Some(key_path)) = (
+		server_cfg.server.tls_cert_path.as_deref(),
+		server_cfg.server.tls_key_path.as_deref(),
+	) {
+		quic_cfg.build_server_config_with_tls(cert_path, key_path)?;
+	}
+
+	eprintln!("config ok");
+	Ok(())
+}
+
+/// QUIC settings for one bind address, with transport tuning from the config.
+fn quic_server_config(settings: &ServerSettings, bind_addr: SocketAddr) -> QuicServerConfig {
+	QuicServerConfig {
+		max_concurrent_bidi_streams: settings.quic_max_concurrent_bidi_streams,
+		max_concurrent_uni_streams: settings.quic_max_concurrent_uni_streams,
+		idle_timeout: settings.quic_idle_timeout,
+		keep_alive_interval: settings.quic_keep_alive_interval,
+		..QuicServerConfig::dev(bind_addr)
+	}
+}
+
+async fn list_sessions(server_cfg: &crate::config::ServerConfig, query: &SessionQuery) -> anyhow::Result<()> {
+	let Some(database_url) = persistence_database_url(server_cfg) else {
+		return Err(anyhow::anyhow!("sessions are only recorded when persistence is enabled"));
+	};
+
+	let audit = AuditService::connect(database_url).await?;
+	let sessions = audit.list_sessions(query).await?;
+	println!("SESSION\tCLIENT\tNAME\tREMOTE\tSUBJECT\tPLATFORM_USERS\tSTARTED\tENDED");
+	for s in sessions {
+		println!(
+			"{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+			s.session_id,
+			s.client_id,
+			s.client_name.as_deref().unwrap_or("-"),
+			s.remote_addr.as_deref().unwrap_or("-"),
+			s.auth_subject.as_deref().unwrap_or("-"),
+			if s.platform_user_ids.is_empty() {
+				"-".to_string()
+			} else {
+				s.platform_user_ids.join(",")
+			},
+			s.started_at_unix,
+			s.ended_at_unix.map(|t| t.to_string()).unwrap_or_else(|| "active".to_string()),
+		);
+	}
+	Ok(())
+}
+
+/// Resolve on Ctrl-C, or SIGTERM on unix.
+async fn wait_for_termination() {
+	#[cfg(unix)]
+	{
+		let mut sigterm = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+			Ok(sigterm) => sigterm,
+			Err(e) => {
+				warn!(error = %e, "failed to install SIGTERM handler");
+				let _ = tokio::signal::ctrl_c().await;
+				return;
+			}
+		};
+		tokio::select! {
+			_ = tokio::signal::ctrl_c() => {}
+			_ = sigterm.recv() => {}
+		}
+	}
+	#[cfg(not(unix))]
+	{
+		let _ = tokio::signal::ctrl_c().await;
+	}
 }
 
 fn init_rustls_crypto_provider() {
@@ -152,12 +499,41 @@ async fn main() -> anyhow::Result<()> {
 	init_rustls_crypto_provider();
 	init_tracing();
 
-	let bind_addr = parse_args();
+	let CliArgs { config_path, command } = parse_args();
 
-	let config_path = crate::config::default_config_path()?;
-	let server_cfg = crate::config::load_server_config_from_path(&config_path)?;
+	let config_path = match config_path {
+		Some(path) if !path.exists() => {
+			return Err(anyhow::anyhow!("config file not found: {}", path.display()));
+		}
+		Some(path) => path,
+		None => crate::config::default_config_path()?,
+	};
+	let mut server_cfg = crate::config::load_server_config_from_path(&config_path)?;
 	info!(path = %config_path.display(), "loaded server config (toml + env overrides)");
 
+	let hmac_keyring = HmacKeyring::new(
+		server_cfg.server.auth_hmac_secret.clone(),
+		server_cfg
+			.server
+			.auth_hmac_keys
+			.iter()
+			.map(|k| (k.id.clone(), k.secret.clone()))
+			.collect(),
+		server_cfg.server.auth_hmac_active_key.clone(),
+	)?;
+
+	let cli_binds = match command {
+		Cli::Serve(binds) => binds,
+		Cli::CheckConfig => return check_config(&config_path, &server_cfg),
+		Cli::Sessions(query) => return list_sessions(&server_cfg, &query).await,
+		Cli::Token(cmd) => return run_token_command(&server_cfg, &hmac_keyring, cmd).await,
+	};
+
+	if !cli_binds.is_empty() {
+		server_cfg.server.binds = cli_binds;
+	}
+	let bind_addrs = server_cfg.server.bind_addrs()?;
+
 	init_metrics(server_cfg.server.metrics_bind.as_deref());
 
 	let health_state = HealthState::new();
@@ -171,22 +547,30 @@ async fn main() -> anyhow::Result<()> {
 		}
 	}
 
-	let quic_cfg = QuicServerConfig::dev(bind_addr);
-	let endpoint = if let (Some(cert_path), Some(key_path)) = (
-		server_cfg.server.tls_cert_path.as_deref(),
-		server_cfg.server.tls_key_path.as_deref(),
-	) {
-		info!(cert = %cert_path.display(), key = %key_path.display(), "loading TLS cert/key");
-		quic_cfg.bind_endpoint_with_tls(cert_path, key_path)?
-	} else {
-		let (endpoint, server_cert_der) = quic_cfg.bind_dev_endpoint()?;
-		info!(
-			bind = %bind_addr,
-			cert_der_len = server_cert_der.len(),
-			"chatty_server: QUIC endpoint ready (dev self-signed cert)"
-		);
-		endpoint
-	};
+	let mut endpoints = Vec::with_capacity(bind_addrs.len());
+	for bind_addr in bind_addrs {
+		let quic_cfg = quic_server_config(&server_cfg.server, bind_addr);
+		let endpoint = if let (Some(cert_path), Some(key_path)) = (
+			server_cfg.server.tls_cert_path.as_deref(),
+			server_cfg.server.tls_key_path.as_deref(),
+		) {
+			info!(cert = %cert_path.display(), key = %key_path.display(), "loading TLS cert/key");
+			let endpoint = quic_cfg
+				.bind_endpoint_with_tls(cert_path, key_path)
+				.with_context(|| format!("bind {bind_addr}"))?;
+			info!(bind = %bind_addr, "chatty_server: QUIC endpoint ready");
+			endpoint
+		} else {
+			let (endpoint, server_cert_der) = quic_cfg.bind_dev_endpoint().with_context(|| format!("bind {bind_addr}"))?;
+			info!(
+				bind = %bind_addr,
+				cert_der_len = server_cert_der.len(),
+				"chatty_server: QUIC endpoint ready (dev self-signed cert)"
+			);
+			endpoint
+		};
+		endpoints.push(endpoint);
+	}
 
 	let twitch_client_id = std::env::var("TWITCH_CLIENT_ID").ok().filter(|v| !v.trim().is_empty());
 	let twitch_client_secret = std::env::var("TWITCH_CLIENT_SECRET")
@@ -201,11 +585,12 @@ async fn main() -> anyhow::Result<()> {
 
 	let conn_settings = ConnectionSettings {
 		auth_token: server_cfg.auth_token.clone(),
-		auth_hmac_secret: server_cfg.server.auth_hmac_secret.clone(),
+		auth_hmac_keys: (!hmac_keyring.is_empty()).then_some(hmac_keyring),
 		twitch_client_id: twitch_client_id.clone(),
 		twitch_client_secret: twitch_client_secret.clone(),
 		kick_client_id: kick_client_id.clone(),
 		kick_client_secret: kick_client_secret.clone(),
+		youtube_base_url: server_cfg.youtube.base_url.clone(),
 		command_rate_limit_per_conn_burst: server_cfg.server.command_rate_limit_per_conn_burst,
 		command_rate_limit_per_conn_per_minute: server_cfg.server.command_rate_limit_per_conn_per_minute,
 		command_rate_limit_per_topic_burst: server_cfg.server.command_rate_limit_per_topic_burst,
@@ -246,11 +631,42 @@ async fn main() -> anyhow::Result<()> {
 		Arc::new(AuditService::disabled())
 	};
 
+	let token_store = if server_cfg.persistence.enabled {
+		let Some(database_url) = server_cfg.persistence.database_url.as_deref() else {
+			return Err(anyhow::anyhow!("persistence enabled but no database_url configured"));
+		};
+		Arc::new(TokenStore::connect(database_url).await?)
+	} else {
+		Arc::new(TokenStore::disabled())
+	};
+
+	let chat_log = if server_cfg.persistence.enabled && server_cfg.persistence.chat_log_enabled {
+		let Some(database_url) = server_cfg.persistence.database_url.as_deref() else {
+			return Err(anyhow::anyhow!("persistence enabled but no database_url configured"));
+		};
+		let chat_log = Arc::new(ChatLogService::connect(database_url).await?);
+		if let Some(days) = server_cfg.persistence.chat_log_retention_days {
+			spawn_chat_log_pruner(
+				Arc::clone(&chat_log),
+				std::time::Duration::from_secs(days.saturating_mul(86_400)),
+			);
+		}
+		info!("chat log enabled");
+		chat_log
+	} else {
+		if server_cfg.persistence.chat_log_enabled {
+			warn!("chat_log_enabled requires persistence; chat log disabled");
+		}
+		Arc::new(ChatLogService::disabled())
+	};
+
 	health_state.mark_ready();
 
 	let state = Arc::new(RwLock::new(GlobalState::default()));
 	let mut platform_adapters: Vec<Box<dyn chatty_platform::PlatformAdapter>> = Vec::new();
 
+	let asset_providers = Arc::new(server_cfg.assets.provider_config());
+
 	{
 		let client_id = twitch_client_id.clone().unwrap_or_default();
 		if server_cfg.twitch.user_access_token.is_some() {
@@ -284,9 +700,11 @@ async fn main() -> anyhow::Result<()> {
 				.map(|(k, v)| (k.clone(), v.clone()))
 				.collect();
 		}
+		twitch_cfg.assets = asset_providers.clone();
 		platform_adapters.push(Box::new(TwitchEventSubAdapter::new(twitch_cfg)));
 
 		let mut kick_cfg = KickConfig::new();
+		kick_cfg.assets = asset_providers.clone();
 		if let Some(base_url) = server_cfg.kick.base_url.clone() {
 			kick_cfg.base_url = base_url;
 		}
@@ -309,7 +727,29 @@ async fn main() -> anyhow::Result<()> {
 		}
 		platform_adapters.push(Box::new(KickEventAdapter::new(kick_cfg)));
 
-		platform_adapters.push(Box::new(crate::adapters::NullAdapter::new(chatty_domain::Platform::YouTube)));
+		let mut youtube_cfg = YouTubeConfig::new();
+		if let Some(base_url) = server_cfg.youtube.base_url.clone() {
+			youtube_cfg.base_url = base_url;
+		}
+		youtube_cfg.api_key = server_cfg.youtube.api_key.clone();
+		if let Some(min) = server_cfg.youtube.min_poll_interval {
+			youtube_cfg.min_poll_interval = min;
+		}
+		if let Some(min) = server_cfg.youtube.reconnect_min_delay {
+			youtube_cfg.reconnect_min_delay = min;
+		}
+		if let Some(max) = server_cfg.youtube.reconnect_max_delay {
+			youtube_cfg.reconnect_max_delay = max;
+		}
+		if !server_cfg.youtube.live_chat_id_overrides.is_empty() {
+			youtube_cfg.live_chat_id_overrides = server_cfg
+				.youtube
+				.live_chat_id_overrides
+				.iter()
+				.map(|(k, v)| (k.clone(), v.clone()))
+				.collect();
+		}
+		platform_adapters.push(Box::new(YouTubeLiveAdapter::new(youtube_cfg)));
 
 		let fake_enabled = cfg!(debug_assertions)
 			&& std::env::var(CHATTY_ENABLE_FAKE_ADAPTER_ENV)
@@ -324,18 +764,49 @@ async fn main() -> anyhow::Result<()> {
 		}
 	}
 
+	let adapter_manager_cfg = AdapterManagerConfig {
+		twitch_client_secret: twitch_client_secret.clone(),
+		kick_client_id: kick_client_id.clone(),
+		kick_client_secret: kick_client_secret.clone(),
+		..AdapterManagerConfig::default()
+	};
 	let adapter_manager = Arc::new(start_global_adapter_manager(
 		Arc::clone(&state),
-		AdapterManagerConfig::default(),
+		adapter_manager_cfg,
 		platform_adapters,
 	));
 	let room_hub = RoomHub::new(RoomHubConfig::default());
-	let _router = spawn_ingest_router(Arc::clone(&adapter_manager), room_hub.clone(), RouterConfig::default());
+	let _router = spawn_ingest_router(
+		Arc::clone(&adapter_manager),
+		room_hub.clone(),
+		Arc::clone(&replay_service),
+		Arc::clone(&chat_log),
+		RouterConfig::default(),
+	);
 
+	let shutdown = ShutdownSignal::new();
+	let resume = Arc::new(ResumeRegistry::new(server_cfg.server.resume_grace));
 	let mut next_conn_id: u64 = 1;
 
+	let termination = wait_for_termination();
+	tokio::pin!(termination);
+
+	let mut incoming = futures::stream::select_all(endpoints.iter().map(|endpoint| {
+		Box::pin(futures::stream::unfold(endpoint.clone(), |endpoint| async move {
+			let incoming = endpoint.accept().await?;
+			Some((incoming, endpoint))
+		}))
+	}));
+
 	loop {
-		let Some(connecting) = endpoint.accept().await else {
+		let connecting = tokio::select! {
+			connecting = incoming.next() => connecting,
+			_ = &mut termination => {
+				info!("shutdown requested");
+				break;
+			}
+		};
+		let Some(connecting) = connecting else {
 			break;
 		};
 
@@ -346,9 +817,13 @@ async fn main() -> anyhow::Result<()> {
 		let conn_settings = conn_settings.clone();
 		let replay_service = Arc::clone(&replay_service);
 		let audit_service = Arc::clone(&audit_service);
+		let token_store = Arc::clone(&token_store);
+		let chat_log = Arc::clone(&chat_log);
 		let state = Arc::clone(&state);
 		let adapter_manager = Arc::clone(&adapter_manager);
 		let room_hub = room_hub.clone();
+		let shutdown = shutdown.clone();
+		let resume = Arc::clone(&resume);
 
 		tokio::spawn(async move {
 			match connecting.await {
@@ -363,6 +838,10 @@ async fn main() -> anyhow::Result<()> {
 						room_hub,
 						replay_service,
 						audit_service,
+						token_store,
+						chat_log,
+						shutdown,
+						resume,
 						conn_settings,
 					)
 					.await
@@ -377,5 +856,31 @@ async fn main() -> anyhow::Result<()> {
 		});
 	}
 
+	drop(incoming);
+	health_state.mark_not_ready();
+	for endpoint in &endpoints {
+		endpoint.set_server_config(None);
+	}
+	shutdown.trigger(server_cfg.server.shutdown_reconnect_delay);
+
+	let open_connections = || endpoints.iter().map(quinn::Endpoint::open_connections).sum::<usize>();
+	let drain = server_cfg.server.shutdown_drain;
+	info!(
+		open_connections = open_connections(),
+		drain_secs = drain.as_secs(),
+		"draining connections"
+	);
+	let drain_deadline = tokio::time::Instant::now() + drain;
+	while open_connections() > 0 && tokio::time::Instant::now() < drain_deadline {
+		tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+	}
+
+	adapter_manager.shutdown().await;
+	for endpoint in &endpoints {
+		endpoint.close(0u32.into(), b"server shutting down");
+	}
+	futures::future::join_all(endpoints.iter().map(quinn::Endpoint::wait_idle)).await;
+	info!("shutdown complete");
+
 	Ok(())
 }
@@ -5,8 +5,10 @@ use std::io::BufReader;
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context as _, anyhow};
+use chatty_protocol::Codec;
 use quinn::{Endpoint, ServerConfig};
 use rustls_pemfile::{certs, private_key};
 
@@ -14,12 +16,18 @@ use rustls_pemfile::{certs, private_key};
 pub struct QuicServerConfig {
 	pub bind_addr: SocketAddr,
 
-	/// ALPN protocol identifiers accepted by the server.
+	/// ALPN protocol identifiers accepted by the server, most preferred first; each one selects a frame codec.
 	pub alpn_protocols: Vec<Vec<u8>>,
 
 	pub max_concurrent_bidi_streams: u32,
 
 	pub max_concurrent_uni_streams: u32,
+
+	/// Connections without any traffic for this long are closed; zero disables the timeout.
+	pub idle_timeout: Duration,
+
+	/// Interval of server-sent keep-alive pings; `None` leaves keep-alives to the client.
+	pub keep_alive_interval: Option<Duration>,
 }
 
 impl QuicServerConfig {
@@ -27,9 +35,11 @@ impl QuicServerConfig {
 	pub fn dev(bind_addr: SocketAddr) -> Self {
 		Self {
 			bind_addr,
-			alpn_protocols: vec![b"chatty-v1".to_vec()],
+			alpn_protocols: Codec::all().iter().map(|c| c.alpn().to_vec()).collect(),
 			max_concurrent_bidi_streams: 64,
 			max_concurrent_uni_streams: 64,
+			idle_timeout: Duration::from_secs(30),
+			keep_alive_interval: None,
 		}
 	}
 
@@ -42,7 +52,7 @@ impl QuicServerConfig {
 
 	/// Build a bound QUIC `Endpoint` using the provided TLS cert and key.
 	pub fn bind_endpoint_with_tls(&self, cert_path: &Path, key_path: &Path) -> anyhow::Result<Endpoint> {
-		let server_config = self.build_server_config_from_files(cert_path, key_path)?;
+		let server_config = self.build_server_config_with_tls(cert_path, key_path)?;
 		let endpoint = Endpoint::server(server_config, self.bind_addr).context("bind quinn endpoint")?;
 		Ok(endpoint)
 	}
@@ -68,16 +78,13 @@ impl QuicServerConfig {
 			.context("convert rustls ServerConfig -> quinn QuicServerConfig")?;
 
 		let mut server_config = ServerConfig::with_crypto(Arc::new(quic_tls));
-
-		let mut transport = quinn::TransportConfig::default();
-		transport.max_concurrent_bidi_streams(quinn::VarInt::from_u32(self.max_concurrent_bidi_streams));
-		transport.max_concurrent_uni_streams(quinn::VarInt::from_u32(self.max_concurrent_uni_streams));
-		server_config.transport_config(Arc::new(transport));
+		server_config.transport_config(Arc::new(self.transport_config()?));
 
 		Ok((server_config, cert_der))
 	}
 
-	fn build_server_config_from_files(&self, cert_path: &Path, key_path: &Path) -> anyhow::Result<ServerConfig> {
+	/// Build a `ServerConfig` from PEM-encoded TLS cert and key files.
+	pub fn build_server_config_with_tls(&self, cert_path: &Path, key_path: &Path) -> anyhow::Result<ServerConfig> {
 		let cert_chain = load_cert_chain(cert_path)?;
 		let key = load_private_key(key_path)?;
 
@@ -92,13 +99,31 @@ impl QuicServerConfig {
 			.context("convert rustls ServerConfig -> quinn QuicServerConfig")?;
 
 		let mut server_config = ServerConfig::with_crypto(Arc::new(quic_tls));
+		server_config.transport_config(Arc::new(self.transport_config()?));
+
+		Ok(server_config)
+	}
+
+	/// Transport parameters shared by every endpoint built from this config.
+	pub fn transport_config(&self) -> anyhow::Result<quinn::TransportConfig> {
+		let idle_timeout = (!self.idle_timeout.is_zero())
+			.then(|| quinn::IdleTimeout::try_from(self.idle_timeout))
+			.transpose()
+			.context("quic idle timeout out of range")?;
+		if let Some(interval) = self.keep_alive_interval
+			&& (interval.is_zero() || (idle_timeout.is_some() && interval >= self.idle_timeout))
+		{
+			return Err(anyhow!(
+				"quic keep-alive interval must be non-zero and shorter than the idle timeout"
+			));
+		}
 
 		let mut transport = quinn::TransportConfig::default();
 		transport.max_concurrent_bidi_streams(quinn::VarInt::from_u32(self.max_concurrent_bidi_streams));
 		transport.max_concurrent_uni_streams(quinn::VarInt::from_u32(self.max_concurrent_uni_streams));
-		server_config.transport_config(Arc::new(transport));
-
-		Ok(server_config)
+		transport.max_idle_timeout(idle_timeout);
+		transport.keep_alive_interval(self.keep_alive_interval);
+		Ok(transport)
 	}
 }
 
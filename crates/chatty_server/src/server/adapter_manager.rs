@@ -2,10 +2,12 @@
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use chatty_domain::{Platform, RoomKey, RoomTopic};
 use chatty_platform::{
-	AdapterAuth, AdapterControl, AdapterEvent, CommandError, CommandRequest, IngestEvent, PermissionsInfo, PlatformAdapter,
+	AdapterAuth, AdapterControl, AdapterEvent, AdapterStatus, CommandError, CommandRequest, IngestEvent, PermissionsInfo,
+	PlatformAdapter, SecretString, UnbanRequest,
 };
 use tokio::sync::{RwLock, broadcast, mpsc, oneshot};
 use tracing::{debug, info, warn};
@@ -15,12 +17,26 @@ use crate::server::state::GlobalState;
 /// Maximum number of in-flight ingest events buffered in the broadcast channel.
 pub const DEFAULT_INGEST_BROADCAST_CAPACITY: usize = 8_192;
 
+/// Adapter status updates buffered for slow subscribers; only the latest state matters.
+const STATUS_BROADCAST_CAPACITY: usize = 64;
+
+/// Minimum delay between refresh attempts for a single user session.
+const USER_SESSION_REFRESH_RETRY: Duration = Duration::from_secs(30);
+
 /// Adapter manager configuration.
 #[derive(Debug, Clone)]
 pub struct AdapterManagerConfig {
 	pub ingest_broadcast_capacity: usize,
 	pub control_channel_capacity: usize,
 	pub adapter_events_channel_capacity: usize,
+
+	/// Client credentials used to refresh per-user tokens.
+	pub twitch_client_secret: Option<SecretString>,
+	pub kick_client_id: Option<String>,
+	pub kick_client_secret: Option<SecretString>,
+
+	/// Refresh a user session token when it expires within this window.
+	pub user_token_refresh_buffer: Duration,
 }
 
 impl Default for AdapterManagerConfig {
@@ -29,13 +45,80 @@ impl Default for AdapterManagerConfig {
 			ingest_broadcast_capacity: DEFAULT_INGEST_BROADCAST_CAPACITY,
 			control_channel_capacity: 512,
 			adapter_events_channel_capacity: 8_192,
+			twitch_client_secret: None,
+			kick_client_id: None,
+			kick_client_secret: None,
+			user_token_refresh_buffer: Duration::from_secs(120),
+		}
+	}
+}
+
+/// Identifies a per-user command context (platform + validated platform user id).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UserSessionKey {
+	pub platform: Platform,
+	pub user_id: String,
+}
+
+/// Per-user auth shared by every connection of the same platform identity.
+#[derive(Debug)]
+struct UserSession {
+	auth: AdapterAuth,
+	expires_at: Option<Instant>,
+	connections: usize,
+	last_refresh_attempt: Option<Instant>,
+}
+
+impl UserSession {
+	fn new(auth: AdapterAuth) -> Self {
+		let expires_at = auth_expires_in(&auth).and_then(|d| Instant::now().checked_add(d));
+		Self {
+			auth,
+			expires_at,
+			connections: 0,
+			last_refresh_attempt: None,
+		}
+	}
+
+	fn needs_refresh(&self, buffer: Duration) -> bool {
+		let Some(expires_at) = self.expires_at else {
+			return false;
+		};
+		if auth_refresh_token(&self.auth).is_none() {
+			return false;
+		}
+		if self
+			.last_refresh_attempt
+			.is_some_and(|last| last.elapsed() < USER_SESSION_REFRESH_RETRY)
+		{
+			return false;
+		}
+		expires_at.saturating_duration_since(Instant::now()) <= buffer
+	}
+}
+
+fn auth_expires_in(auth: &AdapterAuth) -> Option<Duration> {
+	match auth {
+		AdapterAuth::UserAccessToken { expires_in, .. } | AdapterAuth::TwitchUser { expires_in, .. } => *expires_in,
+		_ => None,
+	}
+}
+
+fn auth_refresh_token(auth: &AdapterAuth) -> Option<&SecretString> {
+	match auth {
+		AdapterAuth::UserAccessToken { refresh_token, .. } | AdapterAuth::TwitchUser { refresh_token, .. } => {
+			refresh_token.as_ref()
 		}
+		_ => None,
 	}
 }
 
 /// Subscription to global ingest events.
 pub type IngestBroadcastRx = broadcast::Receiver<IngestEvent>;
 
+/// Subscription to adapter connection status updates.
+pub type StatusBroadcastRx = broadcast::Receiver<AdapterStatus>;
+
 /// Global adapter manager handle.
 #[derive(Debug)]
 pub struct AdapterManager {
@@ -47,8 +130,20 @@ pub struct AdapterManager {
 
 	ingest_tx: broadcast::Sender<IngestEvent>,
 
-	#[allow(dead_code)]
-	shutdown_tx: Option<oneshot::Sender<()>>,
+	status_tx: broadcast::Sender<AdapterStatus>,
+
+	/// Most recent status reported by each adapter.
+	last_status: Arc<RwLock<HashMap<Platform, AdapterStatus>>>,
+
+	/// Per-user command contexts, keyed by validated platform identity.
+	user_sessions: RwLock<HashMap<UserSessionKey, UserSession>>,
+
+	/// Identity whose token the shared (ingest) adapter currently runs with, per platform.
+	ingest_identity: RwLock<HashMap<Platform, UserSessionKey>>,
+
+	cfg: AdapterManagerConfig,
+
+	shutdown_tx: std::sync::Mutex<Option<oneshot::Sender<()>>>,
 }
 
 impl AdapterManager {
@@ -59,6 +154,8 @@ impl AdapterManager {
 		cfg: AdapterManagerConfig,
 	) -> Self {
 		let (ingest_tx, _ingest_rx) = broadcast::channel(cfg.ingest_broadcast_capacity);
+		let (status_tx, _status_rx) = broadcast::channel(STATUS_BROADCAST_CAPACITY);
+		let last_status: Arc<RwLock<HashMap<Platform, AdapterStatus>>> = Arc::new(RwLock::new(HashMap::new()));
 
 		let joined_rooms: Arc<RwLock<HashSet<RoomKey>>> = Arc::new(RwLock::new(HashSet::new()));
 
@@ -80,7 +177,14 @@ impl AdapterManager {
 				}
 			});
 
-			Self::spawn_adapter_forwarder(platform, events_rx, ingest_tx.clone(), shutdown_rx.clone());
+			Self::spawn_adapter_forwarder(
+				platform,
+				events_rx,
+				ingest_tx.clone(),
+				status_tx.clone(),
+				Arc::clone(&last_status),
+				shutdown_rx.clone(),
+			);
 
 			control_by_platform.insert(platform, control_tx);
 		}
@@ -90,7 +194,12 @@ impl AdapterManager {
 			control_by_platform,
 			joined_rooms,
 			ingest_tx,
-			shutdown_tx: Some(shutdown_tx),
+			status_tx,
+			last_status,
+			user_sessions: RwLock::new(HashMap::new()),
+			ingest_identity: RwLock::new(HashMap::new()),
+			cfg,
+			shutdown_tx: std::sync::Mutex::new(Some(shutdown_tx)),
 		}
 	}
 
@@ -98,6 +207,8 @@ impl AdapterManager {
 		platform: Platform,
 		mut events_rx: mpsc::Receiver<AdapterEvent>,
 		ingest_tx: broadcast::Sender<IngestEvent>,
+		status_tx: broadcast::Sender<AdapterStatus>,
+		last_status: Arc<RwLock<HashMap<Platform, AdapterStatus>>>,
 		shutdown_rx: Arc<tokio::sync::Mutex<Option<oneshot::Receiver<()>>>>,
 	) {
 		tokio::spawn(async move {
@@ -133,6 +244,8 @@ impl AdapterManager {
 									last_error = ?st.last_error,
 									"adapter status"
 								);
+								last_status.write().await.insert(platform, st.clone());
+								let _ = status_tx.send(st);
 							}
 						}
 					}
@@ -155,6 +268,16 @@ impl AdapterManager {
 		self.ingest_tx.subscribe()
 	}
 
+	/// Subscribe to adapter connection status updates.
+	pub fn subscribe_status(&self) -> StatusBroadcastRx {
+		self.status_tx.subscribe()
+	}
+
+	/// Most recent status reported by the adapter for `platform`, if any.
+	pub async fn last_status(&self, platform: Platform) -> Option<AdapterStatus> {
+		self.last_status.read().await.get(&platform).cloned()
+	}
+
 	/// Update authentication for a specific platform adapter (best-effort).
 	pub async fn update_auth(&self, platform: Platform, auth: AdapterAuth) -> bool {
 		let Some(ctrl) = self.control_by_platform.get(&platform) else {
@@ -163,6 +286,175 @@ impl AdapterManager {
 		ctrl.send(AdapterControl::UpdateAuth { auth }).await.is_ok()
 	}
 
+	/// Register a connection for a validated platform identity and return its session key.
+	///
+	/// Each identity keeps its own auth for commands and permission queries. The shared adapter
+	/// only receives the token of the first identity on a platform, which it uses for read-only ingest.
+	pub async fn register_user_session(&self, platform: Platform, user_id: String, auth: AdapterAuth) -> UserSessionKey {
+		let key = UserSessionKey { platform, user_id };
+
+		{
+			let mut sessions = self.user_sessions.write().await;
+			let connections = sessions.get(&key).map(|s| s.connections).unwrap_or(0);
+			let mut session = UserSession::new(auth.clone());
+			session.connections = connections + 1;
+			sessions.insert(key.clone(), session);
+		}
+
+		let apply_to_adapter = {
+			let mut ingest = self.ingest_identity.write().await;
+			match ingest.get(&platform) {
+				Some(current) => *current == key,
+				None => {
+					ingest.insert(platform, key.clone());
+					true
+				}
+			}
+		};
+		if apply_to_adapter && self.update_auth(platform, auth).await {
+			info!(%platform, user_id = %key.user_id, "applied user session auth to shared adapter");
+		}
+
+		key
+	}
+
+	/// Release a connection's reference to a user session.
+	///
+	/// When the shared adapter ran on this identity, it is handed to another session on the same platform,
+	/// or reset to `AdapterAuth::None` so it stops using the departed user's token.
+	pub async fn release_user_session(&self, key: &UserSessionKey) {
+		let handover = {
+			let mut sessions = self.user_sessions.write().await;
+			let Some(session) = sessions.get_mut(key) else {
+				return;
+			};
+			session.connections = session.connections.saturating_sub(1);
+			if session.connections > 0 {
+				return;
+			}
+			sessions.remove(key);
+
+			let mut ingest = self.ingest_identity.write().await;
+			if ingest.get(&key.platform) != Some(key) {
+				return;
+			}
+			ingest.remove(&key.platform);
+
+			let next = sessions.iter().find(|(k, _)| k.platform == key.platform);
+			next.map(|(k, s)| {
+				ingest.insert(key.platform, k.clone());
+				(k.user_id.clone(), s.auth.clone())
+			})
+		};
+
+		match handover {
+			Some((user_id, auth)) => {
+				if self.update_auth(key.platform, auth).await {
+					info!(platform = %key.platform, %user_id, "handed shared adapter auth over to another user session");
+				}
+			}
+			None => {
+				if self.update_auth(key.platform, AdapterAuth::None).await {
+					info!(platform = %key.platform, "cleared shared adapter auth after last user session left");
+				}
+			}
+		}
+	}
+
+	/// Current auth for a user session, refreshing the token first when it is close to expiry.
+	pub async fn user_auth(&self, key: &UserSessionKey) -> Option<AdapterAuth> {
+		let auth = {
+			let mut sessions = self.user_sessions.write().await;
+			let session = sessions.get_mut(key)?;
+			if !session.needs_refresh(self.cfg.user_token_refresh_buffer) {
+				return Some(session.auth.clone());
+			}
+			session.last_refresh_attempt = Some(Instant::now());
+			session.auth.clone()
+		};
+
+		let refreshed = match self.refresh_user_auth(key.platform, &auth).await {
+			Ok(refreshed) => refreshed,
+			Err(e) => {
+				warn!(platform = %key.platform, user_id = %key.user_id, error = %e, "user session token refresh failed");
+				return Some(auth);
+			}
+		};
+
+		{
+			let mut sessions = self.user_sessions.write().await;
+			let Some(session) = sessions.get_mut(key) else {
+				return Some(refreshed);
+			};
+			session.expires_at = auth_expires_in(&refreshed).and_then(|d| Instant::now().checked_add(d));
+			session.auth = refreshed.clone();
+		}
+		debug!(platform = %key.platform, user_id = %key.user_id, "refreshed user session token");
+
+		let is_ingest_identity = self.ingest_identity.read().await.get(&key.platform) == Some(key);
+		if is_ingest_identity {
+			self.update_auth(key.platform, refreshed.clone()).await;
+		}
+
+		Some(refreshed)
+	}
+
+	async fn refresh_user_auth(&self, platform: Platform, auth: &AdapterAuth) -> anyhow::Result<AdapterAuth> {
+		let Some(refresh_token) = auth_refresh_token(auth) else {
+			anyhow::bail!("no refresh token");
+		};
+
+		match (platform, auth) {
+			(
+				Platform::Twitch,
+				AdapterAuth::TwitchUser {
+					client_id,
+					user_id,
+					username,
+					..
+				},
+			) => {
+				let Some(secret) = self.cfg.twitch_client_secret.as_ref() else {
+					anyhow::bail!("twitch client secret not configured");
+				};
+				let resp =
+					chatty_platform::twitch::refresh_user_token(client_id, secret.expose(), refresh_token.expose()).await?;
+				Ok(AdapterAuth::TwitchUser {
+					client_id: client_id.clone(),
+					access_token: SecretString::new(resp.access_token),
+					refresh_token: Some(
+						resp.refresh_token
+							.map(SecretString::new)
+							.unwrap_or_else(|| refresh_token.clone()),
+					),
+					user_id: user_id.clone(),
+					username: username.clone(),
+					expires_in: Some(Duration::from_secs(resp.expires_in)),
+				})
+			}
+			(Platform::Kick, AdapterAuth::UserAccessToken { user_id, .. }) => {
+				let (Some(client_id), Some(secret)) =
+					(self.cfg.kick_client_id.as_ref(), self.cfg.kick_client_secret.as_ref())
+				else {
+					anyhow::bail!("kick client credentials not configured");
+				};
+				let resp =
+					chatty_platform::kick::refresh_user_token(client_id, secret.expose(), refresh_token.expose()).await?;
+				Ok(AdapterAuth::UserAccessToken {
+					access_token: SecretString::new(resp.access_token),
+					refresh_token: Some(
+						resp.refresh_token
+							.map(SecretString::new)
+							.unwrap_or_else(|| refresh_token.clone()),
+					),
+					user_id: user_id.clone(),
+					expires_in: Some(Duration::from_secs(resp.expires_in)),
+				})
+			}
+			_ => anyhow::bail!("token refresh not supported for {platform}"),
+		}
+	}
+
 	/// Execute a command against a specific platform adapter.
 	pub async fn execute_command(&self, request: CommandRequest, auth: Option<AdapterAuth>) -> Result<(), CommandError> {
 		let platform = request.platform();
@@ -183,6 +475,40 @@ impl AdapterManager {
 		}
 	}
 
+	/// List a room's unban requests with the given status.
+	pub async fn list_unban_requests(
+		&self,
+		room: &RoomKey,
+		status: String,
+		auth: Option<AdapterAuth>,
+	) -> Result<Vec<UnbanRequest>, CommandError> {
+		let Some(ctrl) = self.control_by_platform.get(&room.platform) else {
+			return Err(CommandError::NotSupported(Some(format!(
+				"platform {} not configured",
+				room.platform
+			))));
+		};
+		let (tx, rx) = oneshot::channel();
+		if ctrl
+			.send(AdapterControl::ListUnbanRequests {
+				room: room.clone(),
+				status,
+				auth,
+				resp: tx,
+			})
+			.await
+			.is_err()
+		{
+			return Err(CommandError::Internal("adapter control channel closed".to_string()));
+		}
+
+		match tokio::time::timeout(std::time::Duration::from_secs(5), rx).await {
+			Ok(Ok(result)) => result,
+			Ok(Err(_)) => Err(CommandError::Internal("adapter response dropped".to_string())),
+			Err(_) => Err(CommandError::Internal("adapter command timed out".to_string())),
+		}
+	}
+
 	/// Query permission snapshot for a room.
 	pub async fn query_permissions(&self, room: &RoomKey, auth: Option<AdapterAuth>) -> Option<PermissionsInfo> {
 		let ctrl = self.control_by_platform.get(&room.platform)?;
@@ -206,6 +532,7 @@ impl AdapterManager {
 	}
 
 	/// Query current auth snapshot for a platform adapter (best-effort).
+	#[allow(dead_code)]
 	pub async fn query_auth(&self, platform: Platform) -> Option<AdapterAuth> {
 		let ctrl = self.control_by_platform.get(&platform)?;
 		let (tx, rx) = oneshot::channel();
@@ -343,13 +670,13 @@ impl AdapterManager {
 	}
 
 	/// Shutdown the adapter manager.
-	#[allow(dead_code)]
-	pub async fn shutdown(mut self) {
-		if let Some(tx) = self.shutdown_tx.take() {
+	pub async fn shutdown(&self) {
+		let shutdown_tx = self.shutdown_tx.lock().expect("shutdown_tx").take();
+		if let Some(tx) = shutdown_tx {
 			let _ = tx.send(());
 		}
 
-		for (platform, ctrl) in self.control_by_platform.drain() {
+		for (platform, ctrl) in &self.control_by_platform {
 			let _ = ctrl.send(AdapterControl::Shutdown).await;
 			debug!(%platform, "sent adapter Shutdown");
 		}
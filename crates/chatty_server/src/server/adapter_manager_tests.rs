@@ -3,8 +3,10 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use async_trait::async_trait;
 use chatty_domain::{Platform, RoomId, RoomKey};
-use tokio::sync::RwLock;
+use chatty_platform::{AdapterAuth, AdapterControl, AdapterControlRx, AdapterEventTx, PlatformAdapter, SecretString};
+use tokio::sync::{RwLock, mpsc};
 use tokio::time::timeout;
 
 use crate::adapters::DemoAdapter;
@@ -27,6 +29,7 @@ async fn demo_adapter_emits_after_join() {
 			ingest_broadcast_capacity: 32,
 			control_channel_capacity: 8,
 			adapter_events_channel_capacity: 32,
+			..AdapterManagerConfig::default()
 		},
 	);
 
@@ -56,3 +59,126 @@ async fn demo_adapter_emits_after_join() {
 
 	manager.shutdown().await;
 }
+
+fn twitch_user(user_id: &str, token: &str) -> AdapterAuth {
+	AdapterAuth::TwitchUser {
+		client_id: "client".to_string(),
+		access_token: SecretString::new(token.to_string()),
+		refresh_token: None,
+		user_id: Some(user_id.to_string()),
+		username: Some(user_id.to_string()),
+		expires_in: None,
+	}
+}
+
+fn access_token(auth: Option<AdapterAuth>) -> Option<String> {
+	match auth? {
+		AdapterAuth::TwitchUser { access_token, .. } => Some(access_token.expose().to_string()),
+		_ => None,
+	}
+}
+
+#[tokio::test]
+async fn user_sessions_keep_separate_auth_per_identity() {
+	let state = Arc::new(RwLock::new(GlobalState::default()));
+	let manager = AdapterManager::start(state, Vec::new(), AdapterManagerConfig::default());
+
+	let alice = manager
+		.register_user_session(Platform::Twitch, "1".to_string(), twitch_user("1", "token-a"))
+		.await;
+	let bob = manager
+		.register_user_session(Platform::Twitch, "2".to_string(), twitch_user("2", "token-b"))
+		.await;
+
+	assert_eq!(access_token(manager.user_auth(&alice).await).as_deref(), Some("token-a"));
+	assert_eq!(access_token(manager.user_auth(&bob).await).as_deref(), Some("token-b"));
+
+	manager.shutdown().await;
+}
+
+#[tokio::test]
+async fn user_session_released_after_last_connection() {
+	let state = Arc::new(RwLock::new(GlobalState::default()));
+	let manager = AdapterManager::start(state, Vec::new(), AdapterManagerConfig::default());
+
+	let first = manager
+		.register_user_session(Platform::Twitch, "1".to_string(), twitch_user("1", "token-a"))
+		.await;
+	let second = manager
+		.register_user_session(Platform::Twitch, "1".to_string(), twitch_user("1", "token-a2"))
+		.await;
+	assert_eq!(first, second);
+	assert_eq!(access_token(manager.user_auth(&first).await).as_deref(), Some("token-a2"));
+
+	manager.release_user_session(&first).await;
+	assert!(
+		manager.user_auth(&second).await.is_some(),
+		"session should outlive one of two connections"
+	);
+
+	manager.release_user_session(&second).await;
+	assert!(
+		manager.user_auth(&second).await.is_none(),
+		"session should be dropped with its last connection"
+	);
+
+	manager.shutdown().await;
+}
+
+/// Adapter that forwards every `UpdateAuth` it receives to the test.
+struct AuthRecordingAdapter {
+	auth_tx: mpsc::UnboundedSender<AdapterAuth>,
+}
+
+#[async_trait]
+impl PlatformAdapter for AuthRecordingAdapter {
+	fn platform(&self) -> Platform {
+		Platform::Twitch
+	}
+
+	async fn run(self: Box<Self>, mut control_rx: AdapterControlRx, _events_tx: AdapterEventTx) -> anyhow::Result<()> {
+		while let Some(cmd) = control_rx.recv().await {
+			match cmd {
+				AdapterControl::UpdateAuth { auth } => {
+					let _ = self.auth_tx.send(auth);
+				}
+				AdapterControl::Shutdown => break,
+				_ => {}
+			}
+		}
+		Ok(())
+	}
+}
+
+#[tokio::test]
+async fn shared_adapter_auth_is_cleared_when_last_user_session_leaves() {
+	let state = Arc::new(RwLock::new(GlobalState::default()));
+	let (auth_tx, mut auth_rx) = mpsc::unbounded_channel();
+	let manager = AdapterManager::start(
+		state,
+		vec![Box::new(AuthRecordingAdapter { auth_tx })],
+		AdapterManagerConfig::default(),
+	);
+
+	let alice = manager
+		.register_user_session(Platform::Twitch, "1".to_string(), twitch_user("1", "token-a"))
+		.await;
+	let bob = manager
+		.register_user_session(Platform::Twitch, "2".to_string(), twitch_user("2", "token-b"))
+		.await;
+	let applied = timeout(Duration::from_secs(1), auth_rx.recv()).await.expect("auth update");
+	assert_eq!(access_token(applied).as_deref(), Some("token-a"));
+
+	manager.release_user_session(&alice).await;
+	let handed_over = timeout(Duration::from_secs(1), auth_rx.recv()).await.expect("auth update");
+	assert_eq!(access_token(handed_over).as_deref(), Some("token-b"));
+
+	manager.release_user_session(&bob).await;
+	let cleared = timeout(Duration::from_secs(1), auth_rx.recv())
+		.await
+		.expect("auth update")
+		.expect("adapter running");
+	assert!(matches!(cleared, AdapterAuth::None), "unexpected auth: {cleared:?}");
+
+	manager.shutdown().await;
+}
@@ -1,6 +1,7 @@
 #![forbid(unsafe_code)]
 
 use anyhow::{Context, anyhow};
+use sqlx::QueryBuilder;
 
 #[derive(Clone)]
 pub struct AuditService {
@@ -14,20 +15,83 @@ enum AuditBackend {
 	Mysql(sqlx::MySqlPool),
 }
 
+/// Who opened a client connection, recorded once the handshake has authenticated it.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStart {
+	pub session_id: String,
+	/// Matches `command_audit.client_id` for commands sent over this connection.
+	pub client_id: String,
+	pub client_name: String,
+	pub client_instance_id: String,
+	pub remote_addr: Option<String>,
+	/// `sub` of the HMAC auth token, when one was presented.
+	pub auth_subject: Option<String>,
+	/// `platform:user_id` for every platform account the client signed in with.
+	pub platform_user_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRow {
+	pub session_id: String,
+	pub client_id: String,
+	pub client_name: Option<String>,
+	pub client_instance_id: Option<String>,
+	pub remote_addr: Option<String>,
+	pub auth_subject: Option<String>,
+	pub platform_user_ids: Vec<String>,
+	pub started_at_unix: i64,
+	/// `None` while the session is still open (or was cut off by a crash).
+	pub ended_at_unix: Option<i64>,
+}
+
+/// Session listing filters; results are ordered newest first.
+#[derive(Debug, Clone, Default)]
+pub struct SessionQuery {
+	pub active_only: bool,
+	/// Only sessions that were open at this unix time (seconds).
+	pub at_unix: Option<i64>,
+	pub limit: usize,
+}
+
+type SessionDbRow = (
+	String,
+	String,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	i64,
+	Option<i64>,
+);
+
 impl AuditService {
+	/// Connect and apply migrations.
 	pub async fn connect(database_url: &str) -> anyhow::Result<Self> {
 		if database_url.starts_with("sqlite:") {
 			let pool = sqlx::SqlitePool::connect(database_url).await.context("connect sqlite")?;
+			sqlx::migrate!("migrations/sqlite")
+				.run(&pool)
+				.await
+				.context("run sqlite migrations")?;
 			Ok(Self {
 				backend: Some(AuditBackend::Sqlite(pool)),
 			})
 		} else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
 			let pool = sqlx::PgPool::connect(database_url).await.context("connect postgres")?;
+			sqlx::migrate!("migrations/postgres")
+				.run(&pool)
+				.await
+				.context("run postgres migrations")?;
 			Ok(Self {
 				backend: Some(AuditBackend::Postgres(pool)),
 			})
 		} else if database_url.starts_with("mysql:") || database_url.starts_with("mariadb:") {
 			let pool = sqlx::MySqlPool::connect(database_url).await.context("connect mysql")?;
+			sqlx::migrate!("migrations/mysql")
+				.run(&pool)
+				.await
+				.context("run mysql migrations")?;
 			Ok(Self {
 				backend: Some(AuditBackend::Mysql(pool)),
 			})
@@ -99,4 +163,191 @@ impl AuditService {
 
 		Ok(())
 	}
+
+	pub async fn record_session_start(&self, session: &SessionStart) -> anyhow::Result<()> {
+		let Some(backend) = &self.backend else {
+			return Ok(());
+		};
+
+		let platform_user_ids = (!session.platform_user_ids.is_empty()).then(|| session.platform_user_ids.join(","));
+		match backend {
+			AuditBackend::Sqlite(pool) => {
+				sqlx::query(
+					"INSERT INTO connection_sessions (session_id, client_id, client_name, client_instance_id, remote_addr, \
+					auth_subject, platform_user_ids, started_at) VALUES (?, ?, ?, ?, ?, ?, ?, strftime('%s','now'))",
+				)
+				.bind(&session.session_id)
+				.bind(&session.client_id)
+				.bind(&session.client_name)
+				.bind(&session.client_instance_id)
+				.bind(session.remote_addr.as_deref())
+				.bind(session.auth_subject.as_deref())
+				.bind(platform_user_ids.as_deref())
+				.execute(pool)
+				.await
+				.context("insert connection_sessions (sqlite)")?;
+			}
+			AuditBackend::Postgres(pool) => {
+				sqlx::query(
+					"INSERT INTO connection_sessions (session_id, client_id, client_name, client_instance_id, remote_addr, \
+					auth_subject, platform_user_ids, started_at) VALUES ($1, $2, $3, $4, $5, $6, $7, NOW())",
+				)
+				.bind(&session.session_id)
+				.bind(&session.client_id)
+				.bind(&session.client_name)
+				.bind(&session.client_instance_id)
+				.bind(session.remote_addr.as_deref())
+				.bind(session.auth_subject.as_deref())
+				.bind(platform_user_ids.as_deref())
+				.execute(pool)
+				.await
+				.context("insert connection_sessions (postgres)")?;
+			}
+			AuditBackend::Mysql(pool) => {
+				sqlx::query(
+					"INSERT INTO connection_sessions (session_id, client_id, client_name, client_instance_id, remote_addr, \
+					auth_subject, platform_user_ids, started_at) VALUES (?, ?, ?, ?, ?, ?, ?, NOW())",
+				)
+				.bind(&session.session_id)
+				.bind(&session.client_id)
+				.bind(&session.client_name)
+				.bind(&session.client_instance_id)
+				.bind(session.remote_addr.as_deref())
+				.bind(session.auth_subject.as_deref())
+				.bind(platform_user_ids.as_deref())
+				.execute(pool)
+				.await
+				.context("insert connection_sessions (mysql)")?;
+			}
+		}
+
+		Ok(())
+	}
+
+	pub async fn record_session_end(&self, session_id: &str) -> anyhow::Result<()> {
+		let Some(backend) = &self.backend else {
+			return Ok(());
+		};
+
+		match backend {
+			AuditBackend::Sqlite(pool) => {
+				sqlx::query("UPDATE connection_sessions SET ended_at = strftime('%s','now') WHERE session_id = ?")
+					.bind(session_id)
+					.execute(pool)
+					.await
+					.context("update connection_sessions (sqlite)")?;
+			}
+			AuditBackend::Postgres(pool) => {
+				sqlx::query("UPDATE connection_sessions SET ended_at = NOW() WHERE session_id = $1")
+					.bind(session_id)
+					.execute(pool)
+					.await
+					.context("update connection_sessions (postgres)")?;
+			}
+			AuditBackend::Mysql(pool) => {
+				sqlx::query("UPDATE connection_sessions SET ended_at = NOW() WHERE session_id = ?")
+					.bind(session_id)
+					.execute(pool)
+					.await
+					.context("update connection_sessions (mysql)")?;
+			}
+		}
+
+		Ok(())
+	}
+
+	pub async fn list_sessions(&self, query: &SessionQuery) -> anyhow::Result<Vec<SessionRow>> {
+		let Some(backend) = &self.backend else {
+			return Err(anyhow!("audit disabled"));
+		};
+
+		let rows: Vec<SessionDbRow> = match backend {
+			AuditBackend::Sqlite(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_session_select(&mut qb, query, "started_at", "ended_at");
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list connection_sessions (sqlite)")?
+			}
+			AuditBackend::Postgres(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_session_select(
+					&mut qb,
+					query,
+					"CAST(EXTRACT(EPOCH FROM started_at) AS BIGINT)",
+					"CAST(EXTRACT(EPOCH FROM ended_at) AS BIGINT)",
+				);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list connection_sessions (postgres)")?
+			}
+			AuditBackend::Mysql(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_session_select(
+					&mut qb,
+					query,
+					"CAST(UNIX_TIMESTAMP(started_at) AS SIGNED)",
+					"CAST(UNIX_TIMESTAMP(ended_at) AS SIGNED)",
+				);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list connection_sessions (mysql)")?
+			}
+		};
+
+		Ok(rows.into_iter().map(session_from_row).collect())
+	}
+}
+
+/// `started` and `ended` are the backend's expressions for the columns as unix seconds.
+fn push_session_select<'a, DB>(qb: &mut QueryBuilder<'a, DB>, query: &SessionQuery, started: &str, ended: &str)
+where
+	DB: sqlx::Database,
+	i64: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+{
+	qb.push(format!(
+		"SELECT session_id, client_id, client_name, client_instance_id, remote_addr, auth_subject, platform_user_ids, \
+		{started}, {ended} FROM connection_sessions WHERE 1 = 1"
+	));
+	if query.active_only {
+		qb.push(" AND ended_at IS NULL");
+	}
+	if let Some(at) = query.at_unix {
+		qb.push(format!(" AND {started} <= ")).push_bind(at);
+		qb.push(format!(" AND ({ended} IS NULL OR {ended} >= "))
+			.push_bind(at)
+			.push(")");
+	}
+	qb.push(" ORDER BY started_at DESC, session_id DESC LIMIT ")
+		.push_bind(query.limit.max(1) as i64);
+}
+
+fn session_from_row(row: SessionDbRow) -> SessionRow {
+	let (
+		session_id,
+		client_id,
+		client_name,
+		client_instance_id,
+		remote_addr,
+		auth_subject,
+		platform_user_ids,
+		started_at_unix,
+		ended_at_unix,
+	) = row;
+	SessionRow {
+		session_id,
+		client_id,
+		client_name,
+		client_instance_id,
+		remote_addr,
+		auth_subject,
+		platform_user_ids: platform_user_ids
+			.map(|ids| ids.split(',').filter(|id| !id.is_empty()).map(str::to_string).collect())
+			.unwrap_or_default(),
+		started_at_unix,
+		ended_at_unix,
+	}
 }
@@ -0,0 +1,88 @@
+#![forbid(unsafe_code)]
+
+use crate::server::audit::{AuditService, SessionQuery, SessionStart};
+use crate::util::time::unix_ms_now;
+
+fn session(conn_id: u64, platform_user_ids: Vec<String>) -> SessionStart {
+	SessionStart {
+		session_id: format!("conn-{conn_id}-1"),
+		client_id: format!("conn-{conn_id}"),
+		client_name: "chatty-test".to_string(),
+		client_instance_id: format!("instance-{conn_id}"),
+		remote_addr: Some("127.0.0.1:50000".to_string()),
+		auth_subject: Some("operator".to_string()),
+		platform_user_ids,
+	}
+}
+
+fn query(f: impl FnOnce(&mut SessionQuery)) -> SessionQuery {
+	let mut q = SessionQuery {
+		limit: 50,
+		..Default::default()
+	};
+	f(&mut q);
+	q
+}
+
+#[tokio::test]
+async fn sqlite_sessions_record_start_end_and_filter_by_time() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-sessions-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let res = async {
+		let audit = AuditService::connect(&url).await?;
+		audit.record_session_start(&session(1, Vec::new())).await?;
+		audit.record_session_end("conn-1-1").await?;
+		audit
+			.record_session_start(&session(2, vec!["kick:7".to_string(), "twitch:42".to_string()]))
+			.await?;
+
+		let all = audit.list_sessions(&query(|_| {})).await?;
+		assert_eq!(all.len(), 2);
+		let open = &all[0];
+		assert_eq!(open.session_id, "conn-2-1");
+		assert_eq!(open.client_id, "conn-2");
+		assert_eq!(open.client_name.as_deref(), Some("chatty-test"));
+		assert_eq!(open.auth_subject.as_deref(), Some("operator"));
+		assert_eq!(open.platform_user_ids, vec!["kick:7", "twitch:42"]);
+		assert!(open.ended_at_unix.is_none());
+		assert!(all[1].ended_at_unix.is_some());
+		assert!(all[1].platform_user_ids.is_empty());
+
+		let active = audit.list_sessions(&query(|q| q.active_only = true)).await?;
+		assert_eq!(
+			active.iter().map(|s| s.client_id.as_str()).collect::<Vec<_>>(),
+			vec!["conn-2"]
+		);
+
+		let later = unix_ms_now() / 1000 + 3600;
+		let open_later = audit.list_sessions(&query(|q| q.at_unix = Some(later))).await?;
+		assert_eq!(
+			open_later.iter().map(|s| s.client_id.as_str()).collect::<Vec<_>>(),
+			vec!["conn-2"],
+			"ended sessions are not open after they end"
+		);
+		assert!(audit.list_sessions(&query(|q| q.at_unix = Some(0))).await?.is_empty());
+
+		let limited = audit.list_sessions(&query(|q| q.limit = 1)).await?;
+		assert_eq!(limited.len(), 1);
+
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn disabled_audit_ignores_sessions_and_rejects_listing() {
+	let audit = AuditService::disabled();
+	audit
+		.record_session_start(&session(1, Vec::new()))
+		.await
+		.expect("record is a no-op");
+	audit.record_session_end("conn-1-1").await.expect("record is a no-op");
+	assert!(audit.list_sessions(&query(|_| {})).await.is_err());
+}
@@ -5,27 +5,206 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use anyhow::{Context, anyhow};
 use base64::Engine as _;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chatty_platform::SecretString;
 use hmac::{Hmac, Mac};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 
-#[allow(dead_code)]
-#[derive(Debug, Clone, Deserialize)]
+/// Access level granted by a token; each role includes everything below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenRole {
+	/// Read-only: may subscribe and read history, e.g. for overlays.
+	Viewer,
+	/// May chat and moderate individual users and messages.
+	Moderator,
+	/// May also change the room and run broadcaster features (polls, predictions, redemptions).
+	Admin,
+}
+
+impl TokenRole {
+	/// Minimum role needed to issue a command of `kind`; unknown kinds need `Admin`.
+	pub fn required_for(kind: &str) -> Self {
+		match kind {
+			"fetch_history" | "search_chat_log" => TokenRole::Viewer,
+			"send_chat"
+			| "delete_message"
+			| "timeout_user"
+			| "untimeout_user"
+			| "ban_user"
+			| "unban_user"
+			| "clear_user_messages"
+			| "automod_resolve"
+			| "list_unban_requests" => TokenRole::Moderator,
+			_ => TokenRole::Admin,
+		}
+	}
+
+	pub fn parse(s: &str) -> Option<Self> {
+		match s.trim().to_ascii_lowercase().as_str() {
+			"viewer" => Some(TokenRole::Viewer),
+			"moderator" => Some(TokenRole::Moderator),
+			"admin" => Some(TokenRole::Admin),
+			_ => None,
+		}
+	}
+
+	pub fn as_str(self) -> &'static str {
+		match self {
+			TokenRole::Viewer => "viewer",
+			TokenRole::Moderator => "moderator",
+			TokenRole::Admin => "admin",
+		}
+	}
+}
+
+/// Claims carried by a v1 HMAC token. Scopes that are absent leave that dimension unrestricted,
+/// so tokens minted before scopes existed keep full access.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AuthClaims {
 	pub sub: String,
 	pub exp: u64,
+
+	/// Unique token id; only tokens that carry one can be revoked.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub jti: Option<String>,
+
+	/// Id of the keyring secret that signed the token; absent for tokens signed with the legacy secret.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub kid: Option<String>,
+
+	/// Topic patterns the token may subscribe to and issue commands on; `*` matches any run of characters.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub topics: Option<Vec<String>>,
+
+	/// Command kinds (e.g. `send_chat`, `ban_user`) the token may issue.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub commands: Option<Vec<String>>,
+
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub role: Option<TokenRole>,
+}
+
+impl AuthClaims {
+	pub fn allows_topic(&self, topic: &str) -> bool {
+		self.topics
+			.as_ref()
+			.is_none_or(|patterns| patterns.iter().any(|p| topic_matches(p, topic)))
+	}
+
+	pub fn allows_command(&self, kind: &str) -> bool {
+		self.role.is_none_or(|role| role >= TokenRole::required_for(kind))
+			&& self.commands.as_ref().is_none_or(|kinds| kinds.iter().any(|k| k == kind))
+	}
+}
+
+/// Match `topic` against a pattern where `*` stands for any (possibly empty) run of characters.
+fn topic_matches(pattern: &str, topic: &str) -> bool {
+	let mut parts = pattern.split('*');
+	let Some(mut rest) = topic.strip_prefix(parts.next().unwrap_or_default()) else {
+		return false;
+	};
+	let parts: Vec<&str> = parts.collect();
+	let Some((last, middle)) = parts.split_last() else {
+		return rest.is_empty();
+	};
+	for part in middle {
+		match rest.find(part) {
+			Some(idx) => rest = &rest[idx + part.len()..],
+			None => return false,
+		}
+	}
+	rest.ends_with(last)
+}
+
+/// Secrets accepted for v1 tokens. Several keys can be active at once so a secret can be rotated
+/// without invalidating tokens it already signed; new tokens are signed with the active key.
+#[derive(Debug, Clone, Default)]
+pub struct HmacKeyring {
+	/// Single secret from `auth_hmac_secret`, used for tokens without a `kid`.
+	legacy: Option<SecretString>,
+	keys: Vec<(String, SecretString)>,
+	active: Option<String>,
 }
 
-pub fn verify_hmac_token(token: &str, secret: &str) -> anyhow::Result<AuthClaims> {
+impl HmacKeyring {
+	/// `active` defaults to the last key in `keys`; when there are no keys, tokens are issued with
+	/// the legacy secret.
+	pub fn new(
+		legacy: Option<SecretString>,
+		keys: Vec<(String, SecretString)>,
+		active: Option<String>,
+	) -> anyhow::Result<Self> {
+		for (idx, (kid, _)) in keys.iter().enumerate() {
+			if kid.trim().is_empty() {
+				return Err(anyhow!("hmac key ids must be non-empty"));
+			}
+			if keys[..idx].iter().any(|(other, _)| other == kid) {
+				return Err(anyhow!("duplicate hmac key id {kid:?}"));
+			}
+		}
+		let active = match active {
+			Some(kid) if !keys.iter().any(|(k, _)| *k == kid) => {
+				return Err(anyhow!("active hmac key {kid:?} is not configured"));
+			}
+			Some(kid) => Some(kid),
+			None => keys.last().map(|(kid, _)| kid.clone()),
+		};
+		Ok(Self { legacy, keys, active })
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.legacy.is_none() && self.keys.is_empty()
+	}
+
+	/// Key id stamped into newly issued tokens.
+	pub fn active_key(&self) -> Option<&str> {
+		self.active.as_deref()
+	}
+
+	fn key(&self, kid: &str) -> Option<&SecretString> {
+		self.keys.iter().find(|(k, _)| k == kid).map(|(_, secret)| secret)
+	}
+
+	/// Verify `token` against the key named by its `kid` claim, or the legacy secret when it has none.
+	pub fn verify(&self, token: &str) -> anyhow::Result<AuthClaims> {
+		let (payload_b64, sig_b64) = split_token(token)?;
+		let unverified = decode_claims(payload_b64)?;
+		let secret = match unverified.kid.as_deref() {
+			Some(kid) => self.key(kid).ok_or_else(|| anyhow!("unknown token key id"))?,
+			None => self.legacy.as_ref().ok_or_else(|| anyhow!("token has no key id"))?,
+		};
+		verify_signed(payload_b64, sig_b64, secret.expose())
+	}
+
+	/// Sign `claims` with the active key, overwriting their `kid`.
+	pub fn issue(&self, mut claims: AuthClaims) -> anyhow::Result<String> {
+		let secret = match self.active.as_deref() {
+			Some(kid) => self.key(kid).ok_or_else(|| anyhow!("active hmac key missing"))?,
+			None => self.legacy.as_ref().ok_or_else(|| anyhow!("no hmac secret configured"))?,
+		};
+		claims.kid = self.active.clone();
+		let payload = serde_json::to_vec(&claims).context("encode token claims")?;
+		let payload_b64 = URL_SAFE_NO_PAD.encode(payload);
+		let sig_b64 = URL_SAFE_NO_PAD.encode(sign(payload_b64.as_bytes(), secret.expose().as_bytes()));
+		Ok(format!("v1.{payload_b64}.{sig_b64}"))
+	}
+}
+
+fn split_token(token: &str) -> anyhow::Result<(&str, &str)> {
 	let parts = token.split('.').collect::<Vec<_>>();
 	if parts.len() != 3 || parts[0] != "v1" {
 		return Err(anyhow!("invalid token format"));
 	}
+	Ok((parts[1], parts[2]))
+}
 
-	let payload_b64 = parts[1];
-	let sig_b64 = parts[2];
-
+fn decode_claims(payload_b64: &str) -> anyhow::Result<AuthClaims> {
 	let payload = URL_SAFE_NO_PAD.decode(payload_b64).context("decode token payload")?;
+	serde_json::from_slice(&payload).context("parse token claims")
+}
+
+fn verify_signed(payload_b64: &str, sig_b64: &str, secret: &str) -> anyhow::Result<AuthClaims> {
 	let expected_sig = sign(payload_b64.as_bytes(), secret.as_bytes());
 	let provided_sig = URL_SAFE_NO_PAD.decode(sig_b64).context("decode token signature")?;
 
@@ -33,7 +212,7 @@ pub fn verify_hmac_token(token: &str, secret: &str) -> anyhow::Result<AuthClaims
 		return Err(anyhow!("invalid token signature"));
 	}
 
-	let claims: AuthClaims = serde_json::from_slice(&payload).context("parse token claims")?;
+	let claims = decode_claims(payload_b64)?;
 	let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
 	if claims.exp <= now {
 		return Err(anyhow!("token expired"));
@@ -0,0 +1,193 @@
+#![forbid(unsafe_code)]
+
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chatty_platform::SecretString;
+use chatty_protocol::pb;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::server::auth::{AuthClaims, HmacKeyring, TokenRole};
+use crate::server::state::GlobalState;
+
+const SECRET: &str = "test-secret";
+
+fn mint(claims: serde_json::Value) -> String {
+	let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+	let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).expect("hmac key");
+	mac.update(payload.as_bytes());
+	let sig = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+	format!("v1.{payload}.{sig}")
+}
+
+fn claims(extra: serde_json::Value) -> AuthClaims {
+	let mut value = serde_json::json!({ "sub": "overlay", "exp": u64::MAX });
+	if let (Some(obj), Some(extra)) = (value.as_object_mut(), extra.as_object()) {
+		obj.extend(extra.clone());
+	}
+	keyring(&[], None).verify(&mint(value)).expect("valid token")
+}
+
+#[test]
+fn unscoped_tokens_keep_full_access() {
+	let c = claims(serde_json::json!({}));
+	assert!(c.role.is_none());
+	assert!(c.allows_topic("room:twitch/anyone"));
+	assert!(c.allows_command("ban_user"));
+}
+
+#[test]
+fn topic_patterns_support_wildcards() {
+	let c = claims(serde_json::json!({ "topics": ["room:twitch/*", "room:kick/exact"] }));
+	assert!(c.allows_topic("room:twitch/xqc"));
+	assert!(c.allows_topic("room:kick/exact"));
+	assert!(!c.allows_topic("room:kick/exactly"));
+	assert!(!c.allows_topic("room:youtube/xqc"));
+
+	let middle = claims(serde_json::json!({ "topics": ["room:*/shared"] }));
+	assert!(middle.allows_topic("room:kick/shared"));
+	assert!(!middle.allows_topic("room:kick/shared2"));
+}
+
+#[test]
+fn role_and_command_list_both_restrict_commands() {
+	let viewer = claims(serde_json::json!({ "role": "viewer" }));
+	assert_eq!(viewer.role, Some(TokenRole::Viewer));
+	assert!(viewer.allows_command("fetch_history"));
+	assert!(!viewer.allows_command("send_chat"));
+
+	let read_only = claims(serde_json::json!({ "role": "viewer", "commands": ["fetch_history"] }));
+	assert!(read_only.allows_command("fetch_history"));
+	assert!(!read_only.allows_command("search_chat_log"), "not in the command list");
+
+	let moderator = claims(serde_json::json!({ "role": "moderator", "commands": ["ban_user", "send_chat"] }));
+	assert!(moderator.allows_command("ban_user"));
+	assert!(!moderator.allows_command("clear_chat"), "not in the command list");
+}
+
+#[test]
+fn each_command_kind_requires_its_role() {
+	use TokenRole::{Admin, Moderator, Viewer};
+
+	let required = [
+		("fetch_history", Viewer),
+		("search_chat_log", Viewer),
+		("send_chat", Moderator),
+		("delete_message", Moderator),
+		("timeout_user", Moderator),
+		("untimeout_user", Moderator),
+		("ban_user", Moderator),
+		("unban_user", Moderator),
+		("clear_user_messages", Moderator),
+		("automod_resolve", Moderator),
+		("list_unban_requests", Moderator),
+		("clear_chat", Admin),
+		("update_room_settings", Admin),
+		("resolve_unban_request", Admin),
+		("create_poll", Admin),
+		("end_poll", Admin),
+		("create_prediction", Admin),
+		("resolve_prediction", Admin),
+		("fulfill_redemption", Admin),
+		("cancel_redemption", Admin),
+		("some_future_command", Admin),
+	];
+
+	for role in [Viewer, Moderator, Admin] {
+		let c = AuthClaims {
+			role: Some(role),
+			..Default::default()
+		};
+		for (kind, min) in required {
+			assert_eq!(
+				c.allows_command(kind),
+				role >= min,
+				"{} token and {kind} (needs {})",
+				role.as_str(),
+				min.as_str()
+			);
+		}
+	}
+}
+
+#[test]
+fn subscribe_rejects_topics_outside_token_scope() {
+	let c = claims(serde_json::json!({ "topics": ["room:twitch/allowed"] }));
+	let mut state = GlobalState::default();
+	let sub = pb::Subscribe {
+		subs: vec![
+			pb::Subscription {
+				topic: "room:twitch/allowed".to_string(),
+				last_cursor: 0,
+			},
+			pb::Subscription {
+				topic: "room:twitch/other".to_string(),
+				last_cursor: 0,
+			},
+		],
+	};
+
+	let (results, to_join) = state.handle_subscribe(1, sub, Some(&c));
+	assert_eq!(results[0].status, pb::subscription_result::Status::Ok as i32);
+	assert_eq!(results[1].status, pb::subscription_result::Status::NotAuthorized as i32);
+	assert_eq!(to_join, vec!["room:twitch/allowed".to_string()]);
+	assert_eq!(state.topics_for_conn(1).len(), 1);
+}
+
+fn keyring(keys: &[(&str, &str)], active: Option<&str>) -> HmacKeyring {
+	HmacKeyring::new(
+		Some(SecretString::new(SECRET)),
+		keys.iter()
+			.map(|(kid, secret)| (kid.to_string(), SecretString::new(*secret)))
+			.collect(),
+		active.map(str::to_string),
+	)
+	.expect("valid keyring")
+}
+
+#[test]
+fn keyring_verifies_tokens_of_every_configured_key() {
+	let claims = AuthClaims {
+		sub: "overlay".to_string(),
+		exp: u64::MAX,
+		jti: Some("jti-1".to_string()),
+		role: Some(TokenRole::Viewer),
+		..Default::default()
+	};
+
+	let old = keyring(&[("k1", "first")], None);
+	let old_token = old.issue(claims.clone()).expect("issue");
+
+	let rotated = keyring(&[("k1", "first"), ("k2", "second")], None);
+	assert_eq!(rotated.active_key(), Some("k2"), "the last key signs by default");
+	let new_token = rotated.issue(claims).expect("issue");
+
+	let verified = rotated.verify(&old_token).expect("old key still accepted");
+	assert_eq!(verified.kid.as_deref(), Some("k1"));
+	assert_eq!(verified.jti.as_deref(), Some("jti-1"));
+	assert_eq!(rotated.verify(&new_token).expect("new key").kid.as_deref(), Some("k2"));
+	assert!(old.verify(&new_token).is_err(), "unknown key ids are rejected");
+
+	let legacy = mint(serde_json::json!({ "sub": "legacy", "exp": u64::MAX }));
+	assert_eq!(rotated.verify(&legacy).expect("legacy secret").sub, "legacy");
+}
+
+#[test]
+fn keyring_rejects_tokens_relabelled_with_another_key() {
+	let ring = keyring(&[("k1", "first"), ("k2", "second")], Some("k1"));
+	let token = ring
+		.issue(AuthClaims {
+			sub: "overlay".to_string(),
+			exp: u64::MAX,
+			..Default::default()
+		})
+		.expect("issue");
+	let parts: Vec<&str> = token.split('.').collect();
+	let mut claims: serde_json::Value =
+		serde_json::from_slice(&URL_SAFE_NO_PAD.decode(parts[1]).expect("payload")).expect("json");
+	claims["kid"] = serde_json::json!("k2");
+	let forged = format!("v1.{}.{}", URL_SAFE_NO_PAD.encode(claims.to_string()), parts[2]);
+	assert!(ring.verify(&forged).is_err());
+
+	assert!(HmacKeyring::new(None, Vec::new(), Some("missing".to_string())).is_err());
+}
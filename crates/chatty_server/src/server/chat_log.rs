@@ -0,0 +1,504 @@
+#![forbid(unsafe_code)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use chatty_platform::{IngestEvent, IngestPayload, ModerationAction, UserRef};
+use chatty_protocol::pb;
+use sqlx::QueryBuilder;
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use crate::util::time::{unix_ms_from, unix_ms_now};
+
+const PRUNE_INTERVAL: Duration = Duration::from_secs(3600);
+/// Most records per multi-row insert; 11 binds each stays well under every backend's bind limit.
+const WRITE_BATCH: usize = 256;
+const WRITE_QUEUE: usize = 4096;
+/// InnoDB's default `innodb_ft_min_token_size`; shorter words are not in the MySQL `FULLTEXT` index.
+const MYSQL_FT_MIN_TOKEN_LEN: usize = 3;
+
+/// Opt-in persistent log of chat messages and moderation actions, searchable per room.
+#[derive(Clone)]
+pub struct ChatLogService {
+	backend: Option<ChatLogBackend>,
+}
+
+#[derive(Clone)]
+enum ChatLogBackend {
+	Sqlite(sqlx::SqlitePool),
+	Postgres(sqlx::PgPool),
+	Mysql(sqlx::MySqlPool),
+}
+
+/// Per-backend full-text index over `chat_log.text`, created by the `chat_log_fulltext` migrations.
+#[derive(Debug, Clone, Copy)]
+enum TextIndex {
+	/// SQLite FTS5 external-content table `chat_log_fts`, kept in sync by triggers.
+	Fts5,
+	/// Postgres generated `text_tsv` column with a GIN index.
+	TsVector,
+	/// MySQL `FULLTEXT` index on `text`.
+	Fulltext,
+}
+
+/// A normalized chat log row derived from an ingest event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatLogRecord {
+	pub topic: String,
+	pub kind: pb::chat_log_entry::Kind,
+	pub time_unix_ms: i64,
+	pub server_message_id: Option<String>,
+	pub platform_message_id: Option<String>,
+	pub user_id: Option<String>,
+	pub user_login: Option<String>,
+	pub user_display: Option<String>,
+	pub text: String,
+	pub action: Option<String>,
+	pub actor_login: Option<String>,
+}
+
+/// Search filters; `None` leaves a filter open.
+#[derive(Debug, Clone, Default)]
+pub struct ChatLogQuery {
+	pub topic: String,
+	/// Author user id or login (case-insensitive).
+	pub user: Option<String>,
+	/// Words that must all appear in the text, matched case-insensitively through the full-text index.
+	/// Queries without indexable words fall back to a substring match.
+	pub text: Option<String>,
+	pub since_unix_ms: Option<i64>,
+	pub until_unix_ms: Option<i64>,
+	pub before_id: Option<u64>,
+	pub limit: usize,
+}
+
+type ChatLogRow = (
+	i64,
+	String,
+	String,
+	i64,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	String,
+	Option<String>,
+	Option<String>,
+);
+
+const SELECT_COLUMNS: &str = "SELECT id, topic, kind, created_at_ms, server_message_id, platform_message_id, user_id, \
+	user_login, user_display, text, action, actor_login FROM chat_log WHERE topic = ";
+
+const INSERT_SQL: &str = "INSERT INTO chat_log (topic, kind, created_at_ms, server_message_id, platform_message_id, user_id, \
+	user_login, user_display, text, action, actor_login) ";
+
+impl ChatLogService {
+	/// Connect and apply migrations.
+	pub async fn connect(database_url: &str) -> anyhow::Result<Self> {
+		if database_url.starts_with("sqlite:") {
+			let pool = sqlx::SqlitePool::connect(database_url).await.context("connect sqlite")?;
+			sqlx::migrate!("migrations/sqlite")
+				.run(&pool)
+				.await
+				.context("run sqlite migrations")?;
+			Ok(Self {
+				backend: Some(ChatLogBackend::Sqlite(pool)),
+			})
+		} else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
+			let pool = sqlx::PgPool::connect(database_url).await.context("connect postgres")?;
+			sqlx::migrate!("migrations/postgres")
+				.run(&pool)
+				.await
+				.context("run postgres migrations")?;
+			Ok(Self {
+				backend: Some(ChatLogBackend::Postgres(pool)),
+			})
+		} else if database_url.starts_with("mysql:") || database_url.starts_with("mariadb:") {
+			let pool = sqlx::MySqlPool::connect(database_url).await.context("connect mysql")?;
+			sqlx::migrate!("migrations/mysql")
+				.run(&pool)
+				.await
+				.context("run mysql migrations")?;
+			Ok(Self {
+				backend: Some(ChatLogBackend::Mysql(pool)),
+			})
+		} else {
+			Err(anyhow!("unsupported database_url for chat log"))
+		}
+	}
+
+	pub fn disabled() -> Self {
+		Self { backend: None }
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		self.backend.is_some()
+	}
+
+	/// Append several records with one multi-row insert.
+	pub async fn record_batch(&self, recs: &[ChatLogRecord]) -> anyhow::Result<()> {
+		let Some(backend) = &self.backend else {
+			return Ok(());
+		};
+		if recs.is_empty() {
+			return Ok(());
+		}
+
+		match backend {
+			ChatLogBackend::Sqlite(pool) => {
+				let mut qb = QueryBuilder::new(INSERT_SQL);
+				push_values(&mut qb, recs);
+				qb.build().execute(pool).await.context("insert chat_log (sqlite)")?;
+			}
+			ChatLogBackend::Postgres(pool) => {
+				let mut qb = QueryBuilder::new(INSERT_SQL);
+				push_values(&mut qb, recs);
+				qb.build().execute(pool).await.context("insert chat_log (postgres)")?;
+			}
+			ChatLogBackend::Mysql(pool) => {
+				let mut qb = QueryBuilder::new(INSERT_SQL);
+				push_values(&mut qb, recs);
+				qb.build().execute(pool).await.context("insert chat_log (mysql)")?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Matching entries, newest first.
+	pub async fn search(&self, query: &ChatLogQuery) -> anyhow::Result<Vec<pb::ChatLogEntry>> {
+		let Some(backend) = &self.backend else {
+			return Err(anyhow!("chat log disabled"));
+		};
+
+		let rows: Vec<ChatLogRow> = match backend {
+			ChatLogBackend::Sqlite(pool) => {
+				let mut qb = QueryBuilder::new(SELECT_COLUMNS);
+				push_filters(&mut qb, query, TextIndex::Fts5);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("search chat_log (sqlite)")?
+			}
+			ChatLogBackend::Postgres(pool) => {
+				let mut qb = QueryBuilder::new(SELECT_COLUMNS);
+				push_filters(&mut qb, query, TextIndex::TsVector);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("search chat_log (postgres)")?
+			}
+			ChatLogBackend::Mysql(pool) => {
+				let mut qb = QueryBuilder::new(SELECT_COLUMNS);
+				push_filters(&mut qb, query, TextIndex::Fulltext);
+				qb.build_query_as().fetch_all(pool).await.context("search chat_log (mysql)")?
+			}
+		};
+
+		Ok(rows.into_iter().map(row_to_entry).collect())
+	}
+
+	/// Delete entries older than `cutoff_unix_ms`; returns the number of removed rows.
+	pub async fn prune_older_than(&self, cutoff_unix_ms: i64) -> anyhow::Result<u64> {
+		let Some(backend) = &self.backend else {
+			return Ok(0);
+		};
+
+		let removed = match backend {
+			ChatLogBackend::Sqlite(pool) => sqlx::query("DELETE FROM chat_log WHERE created_at_ms < ?")
+				.bind(cutoff_unix_ms)
+				.execute(pool)
+				.await
+				.context("prune chat_log (sqlite)")?
+				.rows_affected(),
+			ChatLogBackend::Postgres(pool) => sqlx::query("DELETE FROM chat_log WHERE created_at_ms < $1")
+				.bind(cutoff_unix_ms)
+				.execute(pool)
+				.await
+				.context("prune chat_log (postgres)")?
+				.rows_affected(),
+			ChatLogBackend::Mysql(pool) => sqlx::query("DELETE FROM chat_log WHERE created_at_ms < ?")
+				.bind(cutoff_unix_ms)
+				.execute(pool)
+				.await
+				.context("prune chat_log (mysql)")?
+				.rows_affected(),
+		};
+
+		Ok(removed)
+	}
+}
+
+/// Periodically delete chat log entries older than `retention`.
+pub fn spawn_chat_log_pruner(chat_log: Arc<ChatLogService>, retention: Duration) {
+	tokio::spawn(async move {
+		let mut interval = tokio::time::interval(PRUNE_INTERVAL);
+		loop {
+			interval.tick().await;
+			let cutoff = unix_ms_now().saturating_sub(retention.as_millis() as i64);
+			match chat_log.prune_older_than(cutoff).await {
+				Ok(0) => {}
+				Ok(removed) => info!(removed, "pruned expired chat log entries"),
+				Err(e) => warn!(error = %e, "failed to prune chat log"),
+			}
+		}
+	});
+}
+
+/// Queue chat log records for a background task that writes them in multi-row batches, so routing never
+/// waits on the database.
+pub fn spawn_chat_log_writer(chat_log: Arc<ChatLogService>) -> mpsc::Sender<ChatLogRecord> {
+	let (tx, mut rx) = mpsc::channel(WRITE_QUEUE);
+	tokio::spawn(async move {
+		let mut batch = Vec::with_capacity(WRITE_BATCH);
+		while rx.recv_many(&mut batch, WRITE_BATCH).await > 0 {
+			if let Err(e) = chat_log.record_batch(&batch).await {
+				warn!(records = batch.len(), error = %e, "failed to append to chat log");
+			}
+			batch.clear();
+		}
+	});
+	tx
+}
+
+fn push_values<'a, DB>(qb: &mut QueryBuilder<'a, DB>, recs: &'a [ChatLogRecord])
+where
+	DB: sqlx::Database,
+	&'a str: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+	Option<&'a str>: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+	i64: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+{
+	qb.push_values(recs, |mut row, rec| {
+		row.push_bind(rec.topic.as_str())
+			.push_bind(kind_name(rec.kind))
+			.push_bind(rec.time_unix_ms)
+			.push_bind(rec.server_message_id.as_deref())
+			.push_bind(rec.platform_message_id.as_deref())
+			.push_bind(rec.user_id.as_deref())
+			.push_bind(rec.user_login.as_deref())
+			.push_bind(rec.user_display.as_deref())
+			.push_bind(rec.text.as_str())
+			.push_bind(rec.action.as_deref())
+			.push_bind(rec.actor_login.as_deref());
+	});
+}
+
+fn push_filters<'a, DB>(qb: &mut QueryBuilder<'a, DB>, query: &'a ChatLogQuery, index: TextIndex)
+where
+	DB: sqlx::Database,
+	&'a str: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+	String: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+	i64: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+{
+	qb.push_bind(query.topic.as_str());
+
+	if let Some(user) = query.user.as_deref() {
+		qb.push(" AND (user_id = ")
+			.push_bind(user)
+			.push(" OR LOWER(user_login) = ")
+			.push_bind(user.to_lowercase())
+			.push(")");
+	}
+	if let Some(text) = query.text.as_deref() {
+		match index.match_query(text) {
+			Some(terms) => index.push_match(qb, terms),
+			None => {
+				qb.push(" AND LOWER(text) LIKE ")
+					.push_bind(format!("%{}%", escape_like(&text.to_lowercase())))
+					.push(" ESCAPE '!'");
+			}
+		}
+	}
+	if let Some(since) = query.since_unix_ms {
+		qb.push(" AND created_at_ms >= ").push_bind(since);
+	}
+	if let Some(until) = query.until_unix_ms {
+		qb.push(" AND created_at_ms <= ").push_bind(until);
+	}
+	if let Some(before_id) = query.before_id {
+		qb.push(" AND id < ").push_bind(before_id as i64);
+	}
+
+	qb.push(" ORDER BY id DESC LIMIT ").push_bind(query.limit as i64);
+}
+
+impl TextIndex {
+	/// Backend query string for the index, or `None` when `text` has no words the index can match.
+	fn match_query(self, text: &str) -> Option<String> {
+		let words: Vec<&str> = text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+		if words.is_empty() {
+			return None;
+		}
+		match self {
+			// Quoted FTS5 strings are implicitly ANDed and never parsed as operators.
+			Self::Fts5 => Some(words.iter().map(|w| format!("\"{w}\"")).collect::<Vec<_>>().join(" ")),
+			// plainto_tsquery does its own parsing and ANDs the words.
+			Self::TsVector => Some(text.to_string()),
+			Self::Fulltext => {
+				if words.iter().any(|w| w.chars().count() < MYSQL_FT_MIN_TOKEN_LEN) {
+					return None;
+				}
+				Some(words.iter().map(|w| format!("+{w}")).collect::<Vec<_>>().join(" "))
+			}
+		}
+	}
+
+	fn push_match<'a, DB>(self, qb: &mut QueryBuilder<'a, DB>, terms: String)
+	where
+		DB: sqlx::Database,
+		String: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+	{
+		match self {
+			Self::Fts5 => {
+				qb.push(" AND id IN (SELECT rowid FROM chat_log_fts WHERE chat_log_fts MATCH ")
+					.push_bind(terms)
+					.push(")");
+			}
+			Self::TsVector => {
+				qb.push(" AND text_tsv @@ plainto_tsquery('simple', ")
+					.push_bind(terms)
+					.push(")");
+			}
+			Self::Fulltext => {
+				qb.push(" AND MATCH (text) AGAINST (")
+					.push_bind(terms)
+					.push(" IN BOOLEAN MODE)");
+			}
+		}
+	}
+}
+
+fn escape_like(s: &str) -> String {
+	let mut out = String::with_capacity(s.len());
+	for c in s.chars() {
+		if matches!(c, '!' | '%' | '_') {
+			out.push('!');
+		}
+		out.push(c);
+	}
+	out
+}
+
+fn kind_name(kind: pb::chat_log_entry::Kind) -> &'static str {
+	match kind {
+		pb::chat_log_entry::Kind::Moderation => "moderation",
+		_ => "chat_message",
+	}
+}
+
+fn row_to_entry(row: ChatLogRow) -> pb::ChatLogEntry {
+	let (
+		id,
+		topic,
+		kind,
+		time_unix_ms,
+		server_message_id,
+		platform_message_id,
+		user_id,
+		user_login,
+		user_display,
+		text,
+		action,
+		actor_login,
+	) = row;
+	let kind = match kind.as_str() {
+		"moderation" => pb::chat_log_entry::Kind::Moderation,
+		_ => pb::chat_log_entry::Kind::ChatMessage,
+	};
+
+	pb::ChatLogEntry {
+		id: id.max(0) as u64,
+		topic,
+		kind: kind as i32,
+		time_unix_ms,
+		server_message_id: server_message_id.unwrap_or_default(),
+		platform_message_id: platform_message_id.unwrap_or_default(),
+		user_id: user_id.unwrap_or_default(),
+		user_login: user_login.unwrap_or_default(),
+		user_display: user_display.unwrap_or_default(),
+		text,
+		action: action.unwrap_or_default(),
+		actor_login: actor_login.unwrap_or_default(),
+	}
+}
+
+impl ChatLogRecord {
+	/// Build a record for chat messages and moderation actions; other payloads are not logged.
+	pub fn from_ingest(topic: &str, ev: &IngestEvent) -> Option<Self> {
+		let time_unix_ms = unix_ms_from(ev.platform_time.unwrap_or(ev.ingest_time));
+		match &ev.payload {
+			IngestPayload::ChatMessage(m) => Some(Self {
+				topic: topic.to_string(),
+				kind: pb::chat_log_entry::Kind::ChatMessage,
+				time_unix_ms,
+				server_message_id: Some(m.ids.server_id.to_string()),
+				platform_message_id: m
+					.ids
+					.platform_id
+					.clone()
+					.or_else(|| ev.platform_message_id.as_ref().map(|id| id.as_str().to_string())),
+				user_id: Some(m.author.id.clone()),
+				user_login: Some(m.author.login.clone()),
+				user_display: m.author.display.clone(),
+				text: m.text.clone(),
+				action: None,
+				actor_login: None,
+			}),
+			IngestPayload::Moderation(m) => {
+				let (action, action_user, reason) = match &m.action {
+					Some(action) => moderation_details(action),
+					None => (m.kind.as_str(), None, None),
+				};
+				let user = m.target.as_ref().or(action_user);
+				let platform_message_id = match &m.action {
+					Some(ModerationAction::DeleteMessage { message_id }) => Some(message_id.clone()),
+					_ => m.target_message_platform_id.clone(),
+				};
+				Some(Self {
+					topic: topic.to_string(),
+					kind: pb::chat_log_entry::Kind::Moderation,
+					time_unix_ms,
+					server_message_id: None,
+					platform_message_id,
+					user_id: user.map(|u| u.id.clone()),
+					user_login: user.map(|u| u.login.clone()),
+					user_display: user.and_then(|u| u.display.clone()),
+					text: reason.or(m.notes.clone()).unwrap_or_default(),
+					action: Some(action.to_string()),
+					actor_login: m.actor.as_ref().map(|u| u.login.clone()),
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+fn moderation_details(action: &ModerationAction) -> (&'static str, Option<&UserRef>, Option<String>) {
+	match action {
+		ModerationAction::Timeout { reason, .. } => ("timeout", None, reason.clone()),
+		ModerationAction::Untimeout {} => ("untimeout", None, None),
+		ModerationAction::Ban { reason, .. } => ("ban", None, reason.clone()),
+		ModerationAction::Unban {} => ("unban", None, None),
+		ModerationAction::DeleteMessage { .. } => ("delete_message", None, None),
+		ModerationAction::ClearChat {} => ("clear_chat", None, None),
+		ModerationAction::ClearUserMessages { user } => ("clear_user_messages", Some(user), None),
+		ModerationAction::AutoModHold { user, reason, .. } => ("automod_hold", user.as_ref(), reason.clone()),
+		ModerationAction::AutoModUpdate { user, status, .. } => ("automod_update", user.as_ref(), status.clone()),
+		ModerationAction::AutoModTermsUpdate { terms, .. } => {
+			("automod_terms_update", None, terms.as_ref().map(|t| t.join(", ")))
+		}
+		ModerationAction::ShieldModeBegin { .. } => ("shield_mode_begin", None, None),
+		ModerationAction::ShieldModeEnd { .. } => ("shield_mode_end", None, None),
+		ModerationAction::ModeratorAdd { user } => ("moderator_add", Some(user), None),
+		ModerationAction::ModeratorRemove { user } => ("moderator_remove", Some(user), None),
+		ModerationAction::VipAdd { user } => ("vip_add", Some(user), None),
+		ModerationAction::VipRemove { user } => ("vip_remove", Some(user), None),
+		ModerationAction::UnbanRequestCreate { user, text, .. } => ("unban_request_create", Some(user), text.clone()),
+		ModerationAction::UnbanRequestResolve { user, resolution, .. } => {
+			("unban_request_resolve", Some(user), resolution.clone())
+		}
+	}
+}
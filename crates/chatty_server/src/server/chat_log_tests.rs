@@ -0,0 +1,210 @@
+#![forbid(unsafe_code)]
+
+use std::time::{Duration, SystemTime};
+
+use chatty_domain::{Platform, RoomId};
+use chatty_platform::{ChatMessage, IngestEvent, IngestPayload, ModerationAction, ModerationEvent, UserNotice, UserRef};
+use chatty_protocol::pb;
+
+use crate::server::chat_log::{ChatLogQuery, ChatLogRecord, ChatLogService};
+
+const TOPIC: &str = "room:twitch/chan";
+
+fn user(id: &str) -> UserRef {
+	UserRef {
+		id: id.to_string(),
+		login: format!("{id}_Login"),
+		display: None,
+	}
+}
+
+fn chat_ingest(author: &str, text: &str, at_ms: u64) -> IngestEvent {
+	let mut ev = IngestEvent::new(
+		Platform::Twitch,
+		RoomId::new("chan".to_string()).expect("valid RoomId"),
+		IngestPayload::ChatMessage(ChatMessage::new(user(author), text)),
+	);
+	ev.platform_time = Some(SystemTime::UNIX_EPOCH + Duration::from_millis(at_ms));
+	ev
+}
+
+fn query(f: impl FnOnce(&mut ChatLogQuery)) -> ChatLogQuery {
+	let mut q = ChatLogQuery {
+		topic: TOPIC.to_string(),
+		limit: 50,
+		..Default::default()
+	};
+	f(&mut q);
+	q
+}
+
+#[test]
+fn records_chat_and_moderation_but_skips_other_payloads() {
+	let chat = ChatLogRecord::from_ingest(TOPIC, &chat_ingest("alice", "hello", 1_000)).expect("chat record");
+	assert_eq!(chat.kind, pb::chat_log_entry::Kind::ChatMessage);
+	assert_eq!(chat.time_unix_ms, 1_000);
+	assert_eq!(chat.user_login.as_deref(), Some("alice_Login"));
+	assert_eq!(chat.text, "hello");
+
+	let ban = IngestEvent::new(
+		Platform::Twitch,
+		RoomId::new("chan".to_string()).expect("valid RoomId"),
+		IngestPayload::Moderation(Box::new(ModerationEvent {
+			kind: "ban".to_string(),
+			actor: Some(user("mod")),
+			target: Some(user("alice")),
+			target_message_platform_id: None,
+			notes: None,
+			action: Some(ModerationAction::Ban {
+				is_permanent: Some(true),
+				reason: Some("spam".to_string()),
+			}),
+		})),
+	);
+	let ban = ChatLogRecord::from_ingest(TOPIC, &ban).expect("moderation record");
+	assert_eq!(ban.kind, pb::chat_log_entry::Kind::Moderation);
+	assert_eq!(ban.action.as_deref(), Some("ban"));
+	assert_eq!(ban.user_id.as_deref(), Some("alice"));
+	assert_eq!(ban.actor_login.as_deref(), Some("mod_Login"));
+	assert_eq!(ban.text, "spam");
+
+	let notice = IngestEvent::new(
+		Platform::Twitch,
+		RoomId::new("chan".to_string()).expect("valid RoomId"),
+		IngestPayload::UserNotice(UserNotice {
+			kind: "sub".to_string(),
+			text: None,
+			user: None,
+		}),
+	);
+	assert!(ChatLogRecord::from_ingest(TOPIC, &notice).is_none());
+}
+
+#[tokio::test]
+async fn sqlite_chat_log_searches_by_user_text_and_time() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-chat-log-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let res = async {
+		let chat_log = ChatLogService::connect(&url).await?;
+		let messages = [
+			("alice", "hello there", 1_000),
+			("bob", "Hello 100% of you", 2_000),
+			("alice", "buy cheap followers", 3_000),
+			("alice", "sorry about that", 4_000),
+		];
+		let recs: Vec<_> = messages
+			.into_iter()
+			.map(|(author, text, at)| ChatLogRecord::from_ingest(TOPIC, &chat_ingest(author, text, at)).expect("record"))
+			.collect();
+		chat_log.record_batch(&recs).await?;
+		let other_room =
+			ChatLogRecord::from_ingest("room:twitch/other", &chat_ingest("alice", "elsewhere", 5_000)).expect("record");
+		chat_log.record_batch(&[other_room]).await?;
+
+		let alice = chat_log.search(&query(|q| q.user = Some("ALICE_login".to_string()))).await?;
+		assert_eq!(
+			alice.iter().map(|e| e.text.as_str()).collect::<Vec<_>>(),
+			vec!["sorry about that", "buy cheap followers", "hello there"],
+			"newest first, scoped to the room, login matched case-insensitively"
+		);
+
+		let by_id = chat_log.search(&query(|q| q.user = Some("bob".to_string()))).await?;
+		assert_eq!(by_id.len(), 1);
+
+		let hello = chat_log.search(&query(|q| q.text = Some("HELLO".to_string()))).await?;
+		assert_eq!(hello.len(), 2);
+
+		let percent = chat_log.search(&query(|q| q.text = Some("100%".to_string()))).await?;
+		assert_eq!(percent.len(), 1, "LIKE wildcards in the query are matched literally");
+		let no_wildcard = chat_log.search(&query(|q| q.text = Some("1%0".to_string()))).await?;
+		assert!(no_wildcard.is_empty());
+
+		let ranged = chat_log
+			.search(&query(|q| {
+				q.since_unix_ms = Some(2_000);
+				q.until_unix_ms = Some(3_000);
+			}))
+			.await?;
+		assert_eq!(ranged.iter().map(|e| e.time_unix_ms).collect::<Vec<_>>(), vec![3_000, 2_000]);
+
+		let first_page = chat_log.search(&query(|q| q.limit = 2)).await?;
+		assert_eq!(first_page.len(), 2);
+		let next = chat_log
+			.search(&query(|q| {
+				q.limit = 2;
+				q.before_id = first_page.last().map(|e| e.id);
+			}))
+			.await?;
+		assert_eq!(next.iter().map(|e| e.time_unix_ms).collect::<Vec<_>>(), vec![2_000, 1_000]);
+
+		let removed = chat_log.prune_older_than(3_000).await?;
+		assert_eq!(removed, 2);
+		assert_eq!(chat_log.search(&query(|_| {})).await?.len(), 2);
+
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn sqlite_chat_log_text_search_uses_the_fts_index() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-chat-log-fts-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let res = async {
+		let chat_log = ChatLogService::connect(&url).await?;
+		let messages = [
+			("alice", "Cheap followers here", 1_000),
+			("bob", "followers are cheap today", 2_000),
+			("carol", "nothing to see ???", 3_000),
+		];
+		let recs: Vec<_> = messages
+			.into_iter()
+			.map(|(author, text, at)| ChatLogRecord::from_ingest(TOPIC, &chat_ingest(author, text, at)).expect("record"))
+			.collect();
+		chat_log.record_batch(&recs).await?;
+
+		let words = chat_log
+			.search(&query(|q| q.text = Some("CHEAP, followers".to_string())))
+			.await?;
+		assert_eq!(
+			words.iter().map(|e| e.time_unix_ms).collect::<Vec<_>>(),
+			vec![2_000, 1_000],
+			"every word must match, in any order and case"
+		);
+		let partial = chat_log.search(&query(|q| q.text = Some("cheap spam".to_string()))).await?;
+		assert!(partial.is_empty());
+
+		let punctuation = chat_log.search(&query(|q| q.text = Some("???".to_string()))).await?;
+		assert_eq!(punctuation.len(), 1, "queries without words fall back to a substring match");
+
+		chat_log.prune_older_than(2_000).await?;
+		let pool = sqlx::SqlitePool::connect(&url).await?;
+		let (indexed,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM chat_log_fts WHERE chat_log_fts MATCH 'cheap'")
+			.fetch_one(&pool)
+			.await?;
+		assert_eq!(indexed, 1, "the delete trigger drops pruned rows from the index");
+		pool.close().await;
+
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn disabled_chat_log_ignores_records_and_rejects_search() {
+	let chat_log = ChatLogService::disabled();
+	assert!(!chat_log.is_enabled());
+	let rec = ChatLogRecord::from_ingest(TOPIC, &chat_ingest("alice", "hi", 1)).expect("record");
+	chat_log.record_batch(&[rec]).await.expect("record is a no-op");
+	assert!(chat_log.search(&query(|_| {})).await.is_err());
+}
@@ -11,24 +11,30 @@ use chatty_domain::{Platform, RoomKey, RoomTopic};
 use chatty_platform::kick::{
 	refresh_user_token as refresh_kick_user_token, validate_user_token as validate_kick_user_token,
 };
-use chatty_platform::twitch::{refresh_user_token, validate_user_token};
+use chatty_platform::twitch::validate_user_token;
+use chatty_platform::youtube::{YOUTUBE_API_BASE_URL, validate_user_token as validate_youtube_user_token};
 use chatty_platform::{
-	AdapterAuth, AssetBundle, AssetProvider, AssetScale, AssetScope, CommandError, CommandRequest, IngestPayload,
-	SecretString,
+	AdapterAuth, AdapterStatus, AssetBundle, AssetProvider, AssetScale, AssetScope, CommandError, CommandRequest,
+	IngestEvent, IngestPayload, ModerationAction, ModerationEvent, Poll, PollKind, PollStatus, Progress, ProgressKind,
+	Redemption, RedemptionStatus, RoomChatSettings, SecretString, UnbanRequest, UserNotice, UserRef,
 };
-use chatty_protocol::framing::{DEFAULT_MAX_FRAME_SIZE, encode_frame};
-use chatty_protocol::pb;
+use chatty_protocol::framing::{DEFAULT_MAX_FRAME_SIZE, encode_frame_with_codec};
+use chatty_protocol::{Codec, pb};
 use prost::Message;
 use tokio::sync::{Mutex, RwLock, mpsc};
 use tracing::{debug, error, info, warn};
 
-use crate::server::adapter_manager::AdapterManager;
-use crate::server::audit::AuditService;
-use crate::server::auth::{AuthClaims, verify_hmac_token};
+use crate::server::adapter_manager::{AdapterManager, UserSessionKey};
+use crate::server::audit::{AuditService, SessionStart};
+use crate::server::auth::{AuthClaims, HmacKeyring};
+use crate::server::chat_log::{ChatLogQuery, ChatLogService};
 use crate::server::replay::ReplayService;
+use crate::server::resume::{ParkedSession, ResumeRegistry};
 use crate::server::room_hub::{RoomHub, RoomHubItem};
+use crate::server::shutdown::ShutdownSignal;
 use crate::server::state::GlobalState;
-use crate::util::time::unix_ms_now;
+use crate::server::tokens::TokenStore;
+use crate::util::time::{unix_ms_from, unix_ms_now};
 
 /// v1 protocol version written into `pb::Envelope.version`.
 pub const PROTOCOL_VERSION: u32 = 1;
@@ -207,6 +213,519 @@ fn build_asset_bundle_chunks(topic: &str, assets: &pb::AssetBundleEvent, max_fra
 	}
 }
 
+fn map_platform(platform: Platform) -> i32 {
+	match platform {
+		Platform::Twitch => pb::Platform::Twitch as i32,
+		Platform::Kick => pb::Platform::Kick as i32,
+		Platform::YouTube => pb::Platform::Youtube as i32,
+	}
+}
+
+fn map_origin(room: &RoomKey) -> pb::Origin {
+	pb::Origin {
+		platform: map_platform(room.platform),
+		channel: room.room_id.as_str().to_string(),
+		channel_display: room.room_id.as_str().to_string(),
+	}
+}
+
+fn map_user_ref(user: UserRef) -> pb::UserRef {
+	pb::UserRef {
+		id: user.id,
+		login: user.login,
+		display: user.display.unwrap_or_default(),
+	}
+}
+
+fn map_moderation_action(action: ModerationAction) -> pb::ModerationAction {
+	use pb::moderation_action::{self as ma, Action};
+
+	let action = match action {
+		ModerationAction::Timeout {
+			duration_seconds,
+			expires_at,
+			reason,
+		} => Action::Timeout(ma::Timeout {
+			duration_seconds,
+			expires_at_unix_ms: expires_at.map(unix_ms_from),
+			reason: reason.unwrap_or_default(),
+		}),
+		ModerationAction::Untimeout {} => Action::Untimeout(ma::Untimeout {}),
+		ModerationAction::Ban { is_permanent, reason } => Action::Ban(ma::Ban {
+			is_permanent,
+			reason: reason.unwrap_or_default(),
+		}),
+		ModerationAction::Unban {} => Action::Unban(ma::Unban {}),
+		ModerationAction::DeleteMessage { message_id } => Action::DeleteMessage(ma::DeleteMessage { message_id }),
+		ModerationAction::ClearChat {} => Action::ClearChat(ma::ClearChat {}),
+		ModerationAction::ClearUserMessages { user } => Action::ClearUserMessages(ma::ClearUserMessages {
+			user: Some(map_user_ref(user)),
+		}),
+		ModerationAction::AutoModHold {
+			message_id,
+			user,
+			reason,
+			text,
+		} => Action::AutomodHold(ma::AutoModHold {
+			message_id: message_id.unwrap_or_default(),
+			user: user.map(map_user_ref),
+			reason: reason.unwrap_or_default(),
+			text: text.unwrap_or_default(),
+		}),
+		ModerationAction::AutoModUpdate {
+			message_id,
+			user,
+			status,
+		} => Action::AutomodUpdate(ma::AutoModUpdate {
+			message_id: message_id.unwrap_or_default(),
+			user: user.map(map_user_ref),
+			status: status.unwrap_or_default(),
+		}),
+		ModerationAction::AutoModTermsUpdate { action, terms } => Action::AutomodTermsUpdate(ma::AutoModTermsUpdate {
+			action: action.unwrap_or_default(),
+			terms: terms.unwrap_or_default(),
+		}),
+		ModerationAction::ShieldModeBegin { started_at } => Action::ShieldModeBegin(ma::ShieldModeBegin {
+			started_at_unix_ms: started_at.map(unix_ms_from),
+		}),
+		ModerationAction::ShieldModeEnd {
+			ended_at,
+			duration_seconds,
+		} => Action::ShieldModeEnd(ma::ShieldModeEnd {
+			ended_at_unix_ms: ended_at.map(unix_ms_from),
+			duration_seconds,
+		}),
+		ModerationAction::ModeratorAdd { user } => Action::ModeratorAdd(ma::RoleChange {
+			user: Some(map_user_ref(user)),
+		}),
+		ModerationAction::ModeratorRemove { user } => Action::ModeratorRemove(ma::RoleChange {
+			user: Some(map_user_ref(user)),
+		}),
+		ModerationAction::VipAdd { user } => Action::VipAdd(ma::RoleChange {
+			user: Some(map_user_ref(user)),
+		}),
+		ModerationAction::VipRemove { user } => Action::VipRemove(ma::RoleChange {
+			user: Some(map_user_ref(user)),
+		}),
+		ModerationAction::UnbanRequestCreate { request_id, user, text } => {
+			Action::UnbanRequestCreate(ma::UnbanRequestCreate {
+				request_id: request_id.unwrap_or_default(),
+				user: Some(map_user_ref(user)),
+				text: text.unwrap_or_default(),
+			})
+		}
+		ModerationAction::UnbanRequestResolve {
+			request_id,
+			user,
+			resolution,
+			resolved_by,
+			resolved_at,
+		} => Action::UnbanRequestResolve(ma::UnbanRequestResolve {
+			request_id: request_id.unwrap_or_default(),
+			user: Some(map_user_ref(user)),
+			resolution: resolution.unwrap_or_default(),
+			resolved_by: resolved_by.map(map_user_ref),
+			resolved_at_unix_ms: resolved_at.map(unix_ms_from),
+		}),
+	};
+
+	pb::ModerationAction { action: Some(action) }
+}
+
+pub(crate) fn map_moderation_event(room: &RoomKey, ev: ModerationEvent) -> pb::ModerationEvent {
+	pb::ModerationEvent {
+		origin: Some(map_origin(room)),
+		kind: ev.kind,
+		actor: ev.actor.map(map_user_ref),
+		target: ev.target.map(map_user_ref),
+		target_message_platform_id: ev.target_message_platform_id.unwrap_or_default(),
+		notes: ev.notes.unwrap_or_default(),
+		action: ev.action.map(map_moderation_action),
+	}
+}
+
+pub(crate) fn map_adapter_status_event(status: AdapterStatus) -> pb::AdapterStatusEvent {
+	pb::AdapterStatusEvent {
+		platform: map_platform(status.platform),
+		connected: status.connected,
+		detail: status.detail,
+		last_error: status.last_error.unwrap_or_default(),
+		time_unix_ms: unix_ms_from(status.time),
+	}
+}
+
+/// `SERVER_SHUTTING_DOWN` notice sent on the control stream while the server drains.
+fn shutdown_notice_envelope(reconnect_after: std::time::Duration) -> pb::Envelope {
+	pb::Envelope {
+		version: PROTOCOL_VERSION,
+		request_id: String::new(),
+		msg: Some(pb::envelope::Msg::Notice(pb::Notice {
+			code: chatty_protocol::notice::SERVER_SHUTTING_DOWN.to_string(),
+			message: "server is shutting down; reconnect".to_string(),
+			topic: String::new(),
+			reconnect_after_ms: reconnect_after.as_millis() as u64,
+		})),
+	}
+}
+
+/// Status envelopes describe live upstream state, so they carry no cursor and are never replayed.
+fn adapter_status_envelope(topic: &str, status: AdapterStatus) -> pb::EventEnvelope {
+	pb::EventEnvelope {
+		topic: topic.to_string(),
+		cursor: 0,
+		server_time_unix_ms: unix_ms_now(),
+		event: Some(pb::event_envelope::Event::AdapterStatus(map_adapter_status_event(status))),
+	}
+}
+
+pub(crate) fn map_user_notice_event(room: &RoomKey, notice: UserNotice) -> pb::UserNoticeEvent {
+	pb::UserNoticeEvent {
+		origin: Some(map_origin(room)),
+		kind: notice.kind,
+		text: notice.text.unwrap_or_default(),
+		user: notice.user.map(map_user_ref),
+	}
+}
+
+fn map_asset_refs(assets: Vec<chatty_platform::AssetRef>) -> Vec<pb::AssetRef> {
+	assets
+		.into_iter()
+		.map(|asset| pb::AssetRef {
+			id: asset.id,
+			name: asset.name,
+			images: asset
+				.images
+				.into_iter()
+				.map(|img| pb::AssetImage {
+					scale: map_asset_scale(img.scale),
+					url: img.url,
+					format: img.format,
+					width: img.width,
+					height: img.height,
+				})
+				.collect(),
+		})
+		.collect()
+}
+
+fn map_asset_bundle_events(topic: &str, room: &RoomKey, bundle: AssetBundle) -> Vec<pb::AssetBundleEvent> {
+	let provider = match bundle.provider {
+		AssetProvider::Twitch => 1,
+		AssetProvider::Kick => 2,
+		AssetProvider::SevenTv => 3,
+		AssetProvider::Ffz => 4,
+		AssetProvider::Bttv => 5,
+	};
+	let scope = match bundle.scope {
+		AssetScope::Global => 1,
+		AssetScope::Channel => 2,
+	};
+
+	let etag = bundle.etag.clone().unwrap_or_else(|| compute_asset_bundle_etag(&bundle));
+	let assets = pb::AssetBundleEvent {
+		origin: Some(map_origin(room)),
+		provider,
+		scope,
+		cache_key: bundle.cache_key,
+		etag,
+		emotes: map_asset_refs(bundle.emotes),
+		badges: map_asset_refs(bundle.badges),
+	};
+
+	let original_emotes = assets.emotes.len();
+	let original_badges = assets.badges.len();
+	let chunks = build_asset_bundle_chunks(topic, &assets, DEFAULT_MAX_FRAME_SIZE);
+	if chunks.events.is_empty() {
+		warn!(
+			topic = %topic,
+			cache_key = %assets.cache_key,
+			provider = assets.provider,
+			scope = assets.scope,
+			original_emotes,
+			original_badges,
+			dropped_emotes = chunks.dropped_emotes,
+			dropped_badges = chunks.dropped_badges,
+			"dropping AssetBundle; no chunk fits within max frame size"
+		);
+	} else if chunks.dropped_emotes > 0 || chunks.dropped_badges > 0 {
+		warn!(
+			topic = %topic,
+			cache_key = %assets.cache_key,
+			provider = assets.provider,
+			scope = assets.scope,
+			original_emotes,
+			original_badges,
+			dropped_emotes = chunks.dropped_emotes,
+			dropped_badges = chunks.dropped_badges,
+			"some AssetBundle entries dropped; too large for max frame size"
+		);
+	}
+
+	chunks.events
+}
+
+/// Map an ingest event into the event envelopes delivered on `topic` (cursor unassigned).
+///
+/// Asset bundles may be split into several envelopes so each fits within a frame.
+pub(crate) fn map_ingest_event(topic: &str, ingest: IngestEvent) -> Vec<pb::EventEnvelope> {
+	let room = ingest.room;
+	let events: Vec<pb::event_envelope::Event> = match ingest.payload {
+		IngestPayload::ChatMessage(m) => {
+			let platform_time_unix_ms = ingest
+				.platform_time
+				.and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+				.map(|d| d.as_millis() as i64)
+				.unwrap_or(0);
+
+			let message = pb::ChatMessage {
+				author_id: m.author.id,
+				author_login: m.author.login,
+				author_display: m.author.display.unwrap_or_default(),
+				text: m.text,
+				platform_time_unix_ms,
+				badge_ids: m.badges,
+				emotes: map_asset_refs(m.emotes),
+			};
+
+			vec![pb::event_envelope::Event::ChatMessage(pb::ChatMessageEvent {
+				origin: Some(map_origin(&room)),
+				message: Some(message),
+				server_message_id: m.ids.server_id.to_string(),
+				platform_message_id: m.ids.platform_id.unwrap_or_default(),
+				reply: m.reply.map(|reply| pb::Reply {
+					server_message_id: reply.server_message_id.unwrap_or_default(),
+					platform_message_id: reply.platform_message_id.unwrap_or_default(),
+					user_id: reply.user_id.unwrap_or_default(),
+					user_login: reply.user_login,
+					user_display: reply.user_display.unwrap_or_default(),
+					message: reply.message,
+				}),
+			})]
+		}
+		IngestPayload::AssetBundle(bundle) => map_asset_bundle_events(topic, &room, bundle)
+			.into_iter()
+			.map(pb::event_envelope::Event::AssetBundle)
+			.collect(),
+		IngestPayload::RoomState(state) => {
+			let settings = pb::RoomChatSettings {
+				emote_only: state.settings.emote_only,
+				subscribers_only: state.settings.subscribers_only,
+				unique_chat: state.settings.unique_chat,
+				slow_mode: state.settings.slow_mode,
+				slow_mode_wait_time_seconds: state.settings.slow_mode_wait_time_seconds,
+				followers_only: state.settings.followers_only,
+				followers_only_duration_minutes: state.settings.followers_only_duration_minutes,
+			};
+
+			vec![pb::event_envelope::Event::RoomState(pb::RoomStateEvent {
+				origin: Some(map_origin(&room)),
+				settings: Some(settings),
+				flags: state.flags.into_iter().collect(),
+				notes: state.notes.unwrap_or_default(),
+			})]
+		}
+		IngestPayload::StreamStatus(status) => vec![pb::event_envelope::Event::StreamStatus(pb::StreamStatusEvent {
+			origin: Some(map_origin(&room)),
+			live: status.live,
+			started_at_unix_ms: status.started_at.map(unix_ms_from),
+			title: status.title,
+			category: status.category,
+			viewer_count: status.viewer_count,
+		})],
+		IngestPayload::Poll(poll) => vec![pb::event_envelope::Event::Poll(map_poll_event(&room, poll))],
+		IngestPayload::Redemption(redemption) => {
+			vec![pb::event_envelope::Event::Redemption(map_redemption_event(&room, redemption))]
+		}
+		IngestPayload::Progress(progress) => {
+			vec![pb::event_envelope::Event::Progress(map_progress_event(&room, progress))]
+		}
+		IngestPayload::Moderation(m) => vec![pb::event_envelope::Event::Moderation(map_moderation_event(&room, *m))],
+		IngestPayload::UserNotice(n) => vec![pb::event_envelope::Event::UserNotice(map_user_notice_event(&room, n))],
+	};
+
+	events
+		.into_iter()
+		.map(|event| pb::EventEnvelope {
+			topic: topic.to_string(),
+			cursor: 0,
+			server_time_unix_ms: unix_ms_now(),
+			event: Some(event),
+		})
+		.collect()
+}
+
+fn map_poll_event(room: &RoomKey, poll: Poll) -> pb::PollEvent {
+	let kind = match poll.kind {
+		PollKind::Poll => pb::poll_event::Kind::Poll,
+		PollKind::Prediction => pb::poll_event::Kind::Prediction,
+	};
+	let status = match poll.status {
+		PollStatus::Active => pb::poll_event::Status::Active,
+		PollStatus::Locked => pb::poll_event::Status::Locked,
+		PollStatus::Ended => pb::poll_event::Status::Ended,
+		PollStatus::Canceled => pb::poll_event::Status::Canceled,
+	};
+
+	pb::PollEvent {
+		origin: Some(map_origin(room)),
+		poll_id: poll.id,
+		kind: kind as i32,
+		status: status as i32,
+		title: poll.title,
+		choices: poll
+			.choices
+			.into_iter()
+			.map(|c| pb::PollChoice {
+				choice_id: c.id,
+				title: c.title,
+				votes: c.votes,
+				channel_points: c.channel_points,
+			})
+			.collect(),
+		winning_choice_id: poll.winning_choice_id.unwrap_or_default(),
+		started_at_unix_ms: poll.started_at.map(unix_ms_from),
+		ends_at_unix_ms: poll.ends_at.map(unix_ms_from),
+	}
+}
+
+pub(crate) fn map_redemption_event(room: &RoomKey, redemption: Redemption) -> pb::RedemptionEvent {
+	let status = match redemption.status {
+		RedemptionStatus::Unfulfilled => pb::redemption_event::Status::Unfulfilled,
+		RedemptionStatus::Fulfilled => pb::redemption_event::Status::Fulfilled,
+		RedemptionStatus::Canceled => pb::redemption_event::Status::Canceled,
+	};
+
+	pb::RedemptionEvent {
+		origin: Some(map_origin(room)),
+		redemption_id: redemption.id,
+		reward_id: redemption.reward_id,
+		reward_title: redemption.reward_title,
+		cost: redemption.cost,
+		user: Some(map_user_ref(redemption.user)),
+		user_input: redemption.user_input.unwrap_or_default(),
+		status: status as i32,
+		redeemed_at_unix_ms: redemption.redeemed_at.map(unix_ms_from),
+	}
+}
+
+pub(crate) fn map_progress_event(room: &RoomKey, progress: Progress) -> pb::ProgressEvent {
+	let kind = match progress.kind {
+		ProgressKind::HypeTrain => pb::progress_event::Kind::HypeTrain,
+		ProgressKind::Goal => pb::progress_event::Kind::Goal,
+		ProgressKind::CharityCampaign => pb::progress_event::Kind::CharityCampaign,
+	};
+
+	pb::ProgressEvent {
+		origin: Some(map_origin(room)),
+		progress_id: progress.id,
+		kind: kind as i32,
+		level: progress.level.unwrap_or_default(),
+		title: progress.title.unwrap_or_default(),
+		current: progress.current,
+		target: progress.target,
+		currency: progress.currency.unwrap_or_default(),
+		expires_at_unix_ms: progress.expires_at.map(unix_ms_from),
+		ended: progress.ended,
+	}
+}
+
+/// Whether an event is held until the client opens the events stream (live chat is not).
+fn buffer_until_events_ready(env: &pb::EventEnvelope) -> bool {
+	!matches!(
+		env.event,
+		Some(pb::event_envelope::Event::ChatMessage(_)) | Some(pb::event_envelope::Event::TopicLagged(_))
+	)
+}
+
+/// Newest cursor written to the client per topic; a resumed session replays after it.
+type DeliveredCursors = std::sync::Mutex<HashMap<String, u64>>;
+
+fn note_delivered(delivered: &DeliveredCursors, topic: &str, cursor: u64) {
+	if cursor == 0 {
+		return;
+	}
+	let mut delivered = delivered.lock().expect("delivered cursors");
+	let entry = delivered.entry(topic.to_string()).or_insert(0);
+	*entry = (*entry).max(cursor);
+}
+
+/// Write events held back until the events stream opened.
+async fn flush_pending(
+	events_send: &mut quinn::SendStream,
+	codec: Codec,
+	pending: &mut Vec<pb::EventEnvelope>,
+	delivered: &DeliveredCursors,
+) -> anyhow::Result<()> {
+	for env in pending.drain(..) {
+		note_delivered(delivered, &env.topic, env.cursor);
+		let frame = encode_frame_with_codec(
+			codec,
+			&pb::Envelope {
+				version: PROTOCOL_VERSION,
+				request_id: String::new(),
+				msg: Some(pb::envelope::Msg::Event(env)),
+			},
+			DEFAULT_MAX_FRAME_SIZE,
+		)?;
+		events_send.write_all(&frame).await?;
+	}
+	Ok(())
+}
+
+/// Replay `result.topic` after `last_cursor` into `pending` and fill in the result's status and cursor.
+async fn replay_subscription(
+	replay_service: &ReplayService,
+	result: &mut pb::SubscriptionResult,
+	last_cursor: u64,
+	pending: &mut Vec<pb::EventEnvelope>,
+	delivered: &DeliveredCursors,
+) -> anyhow::Result<()> {
+	let outcome = replay_service
+		.replay(&result.topic, last_cursor)
+		.await
+		.context("replay events")?;
+
+	result.status = outcome.status as i32;
+	result.current_cursor = outcome.current_cursor;
+	// Replayed items are noted once written; without any, the head is where a resume picks up.
+	let resume_from = if outcome.items.is_empty() {
+		outcome.current_cursor.max(last_cursor)
+	} else {
+		last_cursor
+	};
+	note_delivered(delivered, &result.topic, resume_from);
+	if !outcome.items.is_empty() {
+		pending.extend(outcome.items);
+	}
+
+	let dropped = outcome.current_cursor.saturating_sub(last_cursor);
+	if outcome.status == pb::subscription_result::Status::ReplayNotAvailable && dropped > 0 {
+		let lagged = pb::TopicLaggedEvent {
+			dropped,
+			detail: "replay buffer exhausted".to_string(),
+		};
+		pending.push(pb::EventEnvelope {
+			topic: result.topic.clone(),
+			cursor: 0,
+			server_time_unix_ms: unix_ms_now(),
+			event: Some(pb::event_envelope::Event::TopicLagged(lagged)),
+		});
+	}
+	Ok(())
+}
+
+/// Leave the rooms and release the platform identities of a session whose grace period ended.
+async fn release_parked_session(state: &RwLock<GlobalState>, adapter_manager: &AdapterManager, parked: ParkedSession) {
+	let topics_to_leave = state.write().await.remove_conn(parked.conn_id);
+	if !topics_to_leave.is_empty() {
+		debug!(conn_id = parked.conn_id, topics_to_leave = ?topics_to_leave, "resume grace ended, leaving rooms");
+		adapter_manager.apply_global_joins_leaves(&[], &topics_to_leave).await;
+	}
+	for key in &parked.user_sessions {
+		adapter_manager.release_user_session(key).await;
+	}
+}
+
 /// Per-connection server settings.
 #[derive(Debug, Clone)]
 pub struct ConnectionSettings {
@@ -215,12 +734,14 @@ pub struct ConnectionSettings {
 	pub fan_in_channel_capacity: usize,
 
 	pub auth_token: Option<chatty_platform::SecretString>,
-	pub auth_hmac_secret: Option<chatty_platform::SecretString>,
+	/// Secrets accepted for v1 HMAC tokens; `None` disables token auth.
+	pub auth_hmac_keys: Option<HmacKeyring>,
 
 	pub twitch_client_id: Option<String>,
 	pub twitch_client_secret: Option<chatty_platform::SecretString>,
 	pub kick_client_id: Option<String>,
 	pub kick_client_secret: Option<chatty_platform::SecretString>,
+	pub youtube_base_url: Option<String>,
 
 	pub command_rate_limit_per_conn_burst: u32,
 	pub command_rate_limit_per_conn_per_minute: u32,
@@ -234,11 +755,12 @@ impl Default for ConnectionSettings {
 			max_frame_bytes: DEFAULT_MAX_FRAME_SIZE as u32,
 			fan_in_channel_capacity: 1024,
 			auth_token: None,
-			auth_hmac_secret: None,
+			auth_hmac_keys: None,
 			twitch_client_id: None,
 			twitch_client_secret: None,
 			kick_client_id: None,
 			kick_client_secret: None,
+			youtube_base_url: None,
 			command_rate_limit_per_conn_burst: 0,
 			command_rate_limit_per_conn_per_minute: 0,
 			command_rate_limit_per_topic_burst: 0,
@@ -338,6 +860,52 @@ impl CommandRateLimiter {
 	}
 }
 
+/// Releases a connection's user sessions once the connection ends, whichever way it exits.
+struct UserSessionsGuard {
+	adapter_manager: Arc<AdapterManager>,
+	keys: Vec<UserSessionKey>,
+}
+
+impl Drop for UserSessionsGuard {
+	fn drop(&mut self) {
+		if self.keys.is_empty() {
+			return;
+		}
+		let adapter_manager = Arc::clone(&self.adapter_manager);
+		let keys = std::mem::take(&mut self.keys);
+		tokio::spawn(async move {
+			for key in keys {
+				adapter_manager.release_user_session(&key).await;
+			}
+		});
+	}
+}
+
+/// Drops the connection's subscriptions if it exits before reaching the park/cleanup path at the end of
+/// [`handle_connection`] (e.g. a resumed session whose Welcome write fails).
+struct ConnSubscriptionsGuard {
+	state: Arc<RwLock<GlobalState>>,
+	adapter_manager: Arc<AdapterManager>,
+	conn_id: Option<u64>,
+}
+
+impl Drop for ConnSubscriptionsGuard {
+	fn drop(&mut self) {
+		let Some(conn_id) = self.conn_id.take() else {
+			return;
+		};
+		let state = Arc::clone(&self.state);
+		let adapter_manager = Arc::clone(&self.adapter_manager);
+		tokio::spawn(async move {
+			let topics_to_leave = state.write().await.remove_conn(conn_id);
+			if !topics_to_leave.is_empty() {
+				debug!(conn_id, topics_to_leave = ?topics_to_leave, "connection aborted, leaving rooms");
+				adapter_manager.apply_global_joins_leaves(&[], &topics_to_leave).await;
+			}
+		});
+	}
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn handle_connection(
 	conn_id: u64,
@@ -347,6 +915,10 @@ pub async fn handle_connection(
 	room_hub: RoomHub,
 	replay_service: Arc<ReplayService>,
 	audit_service: Arc<AuditService>,
+	token_store: Arc<TokenStore>,
+	chat_log: Arc<ChatLogService>,
+	shutdown: ShutdownSignal,
+	resume: Arc<ResumeRegistry>,
 	settings: ConnectionSettings,
 ) -> anyhow::Result<()> {
 	struct ConnectionGaugeGuard;
@@ -359,6 +931,8 @@ pub async fn handle_connection(
 	metrics::gauge!("chatty_server_active_connections").increment(1.0);
 	let _conn_guard = ConnectionGaugeGuard;
 
+	let remote_addr = connection.remote_address();
+	let selected_codec = connection_codec(&connection);
 	let (mut control_send, mut control_recv) =
 		connection.accept_bi().await.context("accept control bidirectional stream")?;
 
@@ -367,6 +941,8 @@ pub async fn handle_connection(
 	let reader_task = tokio::spawn(async move {
 		let mut buf = Vec::<u8>::with_capacity(16 * 1024);
 		let mut tmp = [0u8; 8192];
+		// The ALPN protocol fixed the codec before any frame, so Hello is decoded with it too.
+		let codec = selected_codec;
 
 		loop {
 			let n = match control_recv.read(&mut tmp).await {
@@ -380,7 +956,7 @@ pub async fn handle_connection(
 			buf.extend_from_slice(&tmp[..n]);
 
 			loop {
-				match chatty_protocol::decode_frame::<pb::Envelope>(&buf, DEFAULT_MAX_FRAME_SIZE) {
+				match chatty_protocol::decode_frame_with_codec::<pb::Envelope>(codec, &buf, DEFAULT_MAX_FRAME_SIZE) {
 					Ok((msg, used)) => {
 						buf.drain(0..used);
 						metrics::counter!("chatty_server_envelopes_in_total").increment(1);
@@ -401,26 +977,24 @@ pub async fn handle_connection(
 
 	let hello = wait_for_hello(&mut ctrl_rx).await?;
 	let client_auth_token = hello.auth_token.clone();
-	let selected_codec = match negotiate_codec(&hello) {
-		Ok(c) => c,
-		Err(msg) => {
-			let _ = send_envelope(
-				&mut control_send,
-				pb::Envelope {
-					version: PROTOCOL_VERSION,
+	if let Err(msg) = check_hello_codec(&hello, selected_codec) {
+		let _ = send_envelope(
+			&mut control_send,
+			selected_codec,
+			pb::Envelope {
+				version: PROTOCOL_VERSION,
+				request_id: String::new(),
+				msg: Some(pb::envelope::Msg::Error(pb::Error {
+					code: "UNSUPPORTED_CODEC".to_string(),
+					message: msg,
+					topic: String::new(),
 					request_id: String::new(),
-					msg: Some(pb::envelope::Msg::Error(pb::Error {
-						code: "UNSUPPORTED_CODEC".to_string(),
-						message: msg,
-						topic: String::new(),
-						request_id: String::new(),
-					})),
-				},
-			)
-			.await;
-			return Err(anyhow!("unsupported codec"));
-		}
-	};
+				})),
+			},
+		)
+		.await;
+		return Err(anyhow!("unsupported codec"));
+	}
 	let client_instance_id = if hello.client_instance_id.trim().is_empty() {
 		format!("conn-{conn_id}")
 	} else {
@@ -430,13 +1004,13 @@ pub async fn handle_connection(
 	info!(
 		conn_id,
 		client_name = %hello.client_name,
-		client_instance_id = %hello.client_instance_id,
+		client_instance_id = %client_instance_id,
 		"received Hello"
 	);
 	metrics::counter!("chatty_server_hello_total").increment(1);
 
 	let mut auth_claims: Option<AuthClaims> = None;
-	if settings.auth_token.is_some() || settings.auth_hmac_secret.is_some() {
+	if settings.auth_token.is_some() || settings.auth_hmac_keys.is_some() {
 		let provided = hello.auth_token.trim();
 		let mut authorized = false;
 		if let Some(expected) = settings.auth_token.as_ref()
@@ -447,13 +1021,27 @@ pub async fn handle_connection(
 		}
 
 		if !authorized
-			&& let Some(secret) = settings.auth_hmac_secret.as_ref()
+			&& let Some(keyring) = settings.auth_hmac_keys.as_ref()
 			&& !provided.is_empty()
 		{
-			match verify_hmac_token(provided, secret.expose()) {
+			match keyring.verify(provided) {
 				Ok(claims) => {
-					authorized = true;
-					auth_claims = Some(claims);
+					let revoked = match claims.jti.as_deref() {
+						Some(jti) => token_store.is_revoked(jti).await,
+						None => Ok(false),
+					};
+					match revoked {
+						Ok(false) => {
+							authorized = true;
+							auth_claims = Some(claims);
+						}
+						Ok(true) => {
+							warn!(conn_id, sub = %claims.sub, jti = ?claims.jti, "auth token revoked");
+						}
+						Err(e) => {
+							warn!(conn_id, error = %e, "auth token revocation check failed");
+						}
+					}
 				}
 				Err(e) => {
 					warn!(conn_id, error = %e, "auth token rejected");
@@ -465,6 +1053,7 @@ pub async fn handle_connection(
 			warn!(conn_id, "unauthorized: missing/invalid auth token");
 			send_envelope(
 				&mut control_send,
+				selected_codec,
 				pb::Envelope {
 					version: PROTOCOL_VERSION,
 					request_id: String::new(),
@@ -482,18 +1071,27 @@ pub async fn handle_connection(
 		}
 	}
 
-	let mut user_oauth = hello.user_oauth_token.trim().to_string();
-	if !user_oauth.is_empty() {
-		let hello_client_id = hello.twitch_client_id.trim();
-		let hello_user_id = hello.twitch_user_id.trim();
-		let hello_username = hello.twitch_username.trim();
-		let mut refresh_token = hello.twitch_refresh_token.trim().to_string();
+	let resumed_session = match hello.resume_token.trim() {
+		"" => None,
+		token => {
+			let claimed = resume.claim(token, &client_instance_id, auth_claims.as_ref().map(|c| c.sub.as_str()));
+			if claimed.is_none() {
+				info!(conn_id, "resume token is not resumable; starting a new session");
+			}
+			claimed
+		}
+	};
 
+	// A resumed session keeps the platform identities validated when it was first opened.
+	let mut twitch_session: Option<(String, AdapterAuth)> = None;
+	let user_oauth = hello.user_oauth_token.trim().to_string();
+	if resumed_session.is_none() && !user_oauth.is_empty() {
 		let validated = match validate_user_token(&user_oauth).await {
 			Err(e) => {
 				warn!(conn_id, error = %e, "invalid twitch oauth token");
 				send_envelope(
 					&mut control_send,
+					selected_codec,
 					pb::Envelope {
 						version: PROTOCOL_VERSION,
 						request_id: String::new(),
@@ -513,70 +1111,47 @@ pub async fn handle_connection(
 		};
 
 		let hello_client_id = hello.twitch_client_id.trim();
-		let hello_user_id = hello.twitch_user_id.trim();
 		let hello_username = hello.twitch_username.trim();
-		let mut refresh_token = hello.twitch_refresh_token.trim().to_string();
+		let refresh_token = hello.twitch_refresh_token.trim();
 
 		let client_id = if !hello_client_id.is_empty() {
 			hello_client_id.to_string()
 		} else {
 			validated.client_id.clone()
 		};
-		let user_id = if !hello_user_id.is_empty() {
-			hello_user_id.to_string()
-		} else {
-			validated.user_id.clone()
-		};
 		let username = if !hello_username.is_empty() {
 			hello_username.to_string()
 		} else {
 			validated.login.clone()
 		};
 
-		let refresh_token = if refresh_token.trim().is_empty() {
+		let refresh_token = if refresh_token.is_empty() {
 			None
 		} else {
-			Some(SecretString::new(refresh_token))
+			Some(SecretString::new(refresh_token.to_string()))
 		};
 
-		let updated = adapter_manager
-			.update_auth(
-				Platform::Twitch,
-				AdapterAuth::TwitchUser {
-					client_id,
-					access_token: SecretString::new(user_oauth.to_string()),
-					refresh_token,
-					user_id: Some(user_id),
-					username: Some(username),
-					expires_in: Some(std::time::Duration::from_secs(validated.expires_in)),
-				},
-			)
-			.await;
-		if updated {
-			info!(conn_id, "applied user OAuth token to twitch adapter");
-		} else {
-			warn!(conn_id, "no twitch adapter available for user OAuth token");
-		}
+		twitch_session = Some((
+			validated.user_id.clone(),
+			AdapterAuth::TwitchUser {
+				client_id,
+				access_token: SecretString::new(user_oauth),
+				refresh_token,
+				user_id: Some(validated.user_id.clone()),
+				username: Some(username),
+				expires_in: Some(std::time::Duration::from_secs(validated.expires_in)),
+			},
+		));
 	}
 
-	let mut kick_auth: Option<AdapterAuth> = None;
+	let mut kick_session: Option<(String, AdapterAuth)> = None;
 	let mut kick_oauth = hello.kick_user_oauth_token.trim().to_string();
-	if !kick_oauth.is_empty() {
-		let mut kick_user_id = hello.kick_user_id.trim().to_string();
+	if resumed_session.is_none() && !kick_oauth.is_empty() {
 		let mut kick_refresh = hello.kick_refresh_token.trim().to_string();
 
 		let validated = match validate_kick_user_token(&kick_oauth).await {
 			Ok(v) => v,
 			Err(e) => {
-				if kick_refresh.is_empty()
-					&& let Some(AdapterAuth::UserAccessToken {
-						refresh_token: Some(token),
-						..
-					}) = adapter_manager.query_auth(Platform::Kick).await
-				{
-					kick_refresh = token.expose().to_string();
-				}
-
 				if !kick_refresh.is_empty()
 					&& let Some(client_id) = settings.kick_client_id.as_ref()
 					&& let Some(client_secret) = settings.kick_client_secret.as_ref()
@@ -594,6 +1169,7 @@ pub async fn handle_connection(
 									warn!(conn_id, error = %e, "invalid kick oauth token after refresh");
 									send_envelope(
 										&mut control_send,
+										selected_codec,
 										pb::Envelope {
 											version: PROTOCOL_VERSION,
 											request_id: String::new(),
@@ -615,6 +1191,7 @@ pub async fn handle_connection(
 							warn!(conn_id, error = %e, "kick oauth refresh failed");
 							send_envelope(
 								&mut control_send,
+								selected_codec,
 								pb::Envelope {
 									version: PROTOCOL_VERSION,
 									request_id: String::new(),
@@ -635,6 +1212,7 @@ pub async fn handle_connection(
 					warn!(conn_id, error = %e, "invalid kick oauth token");
 					send_envelope(
 						&mut control_send,
+						selected_codec,
 						pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: String::new(),
@@ -653,47 +1231,161 @@ pub async fn handle_connection(
 			}
 		};
 
-		if kick_user_id.trim().is_empty()
-			&& let Some(user) = validated.user.as_ref()
-		{
-			kick_user_id = user.user_id.to_string();
+		if let Some(user) = validated.user.as_ref() {
+			let kick_user_id = user.user_id.to_string();
+			let expires_in = validated.token.exp.and_then(|exp| {
+				let now = std::time::SystemTime::now()
+					.duration_since(std::time::UNIX_EPOCH)
+					.unwrap_or_default()
+					.as_secs();
+				exp.checked_sub(now).map(std::time::Duration::from_secs)
+			});
+
+			kick_session = Some((
+				kick_user_id.clone(),
+				AdapterAuth::UserAccessToken {
+					access_token: SecretString::new(kick_oauth),
+					refresh_token: if kick_refresh.trim().is_empty() {
+						None
+					} else {
+						Some(SecretString::new(kick_refresh))
+					},
+					user_id: Some(kick_user_id),
+					expires_in,
+				},
+			));
+		} else {
+			warn!(
+				conn_id,
+				"kick oauth token has no resolvable user; commands will be unavailable"
+			);
 		}
+	}
 
-		let auth = AdapterAuth::UserAccessToken {
-			access_token: SecretString::new(kick_oauth.to_string()),
-			refresh_token: if kick_refresh.trim().is_empty() {
-				None
-			} else {
-				Some(SecretString::new(kick_refresh))
-			},
-			user_id: if kick_user_id.trim().is_empty() {
-				None
-			} else {
-				Some(kick_user_id)
-			},
-			expires_in: None,
-		};
-		kick_auth = Some(auth.clone());
+	let mut youtube_session: Option<(String, AdapterAuth)> = None;
+	let youtube_oauth = hello.youtube_user_oauth_token.trim().to_string();
+	if resumed_session.is_none() && !youtube_oauth.is_empty() {
+		let base_url = settings.youtube_base_url.as_deref().unwrap_or(YOUTUBE_API_BASE_URL);
+		match validate_youtube_user_token(base_url, &youtube_oauth).await {
+			Ok(channel_id) => {
+				youtube_session = Some((
+					channel_id.clone(),
+					AdapterAuth::UserAccessToken {
+						access_token: SecretString::new(youtube_oauth),
+						refresh_token: None,
+						user_id: Some(channel_id),
+						expires_in: None,
+					},
+				));
+			}
+			Err(e) => {
+				warn!(conn_id, error = %e, "invalid youtube oauth token");
+				send_envelope(
+					&mut control_send,
+					selected_codec,
+					pb::Envelope {
+						version: PROTOCOL_VERSION,
+						request_id: String::new(),
+						msg: Some(pb::envelope::Msg::Error(pb::Error {
+							code: "UNAUTHORIZED".to_string(),
+							message: "invalid youtube oauth token".to_string(),
+							topic: String::new(),
+							request_id: String::new(),
+						})),
+					},
+				)
+				.await
+				.ok();
+				return Ok(());
+			}
+		}
+	}
 
-		let updated = adapter_manager.update_auth(Platform::Kick, auth).await;
+	let mut user_sessions: HashMap<Platform, UserSessionKey> = HashMap::new();
+	if let Some(parked) = resumed_session.as_ref() {
+		for key in &parked.user_sessions {
+			info!(conn_id, platform = %key.platform, user_id = %key.user_id, "resumed user session");
+			user_sessions.insert(key.platform, key.clone());
+		}
+	}
+	for (platform, session) in [
+		(Platform::Twitch, twitch_session),
+		(Platform::Kick, kick_session),
+		(Platform::YouTube, youtube_session),
+	] {
+		if let Some((user_id, auth)) = session {
+			let key = adapter_manager.register_user_session(platform, user_id, auth).await;
+			info!(conn_id, %platform, user_id = %key.user_id, "registered user session");
+			user_sessions.insert(platform, key);
+		}
+	}
+	let mut user_sessions_guard = UserSessionsGuard {
+		adapter_manager: Arc::clone(&adapter_manager),
+		keys: user_sessions.values().cloned().collect(),
+	};
 
-		if updated {
-			info!(conn_id, "applied user OAuth token to kick adapter");
-		} else {
-			warn!(conn_id, "no kick adapter available for user OAuth token");
+	let mut subscriptions_guard = ConnSubscriptionsGuard {
+		state: Arc::clone(&state),
+		adapter_manager: Arc::clone(&adapter_manager),
+		conn_id: Some(conn_id),
+	};
+
+	let delivered: Arc<DeliveredCursors> = Arc::new(std::sync::Mutex::new(HashMap::new()));
+	let pending_replay: Arc<Mutex<Vec<pb::EventEnvelope>>> = Arc::new(Mutex::new(Vec::new()));
+
+	let mut resumed_subscriptions = Vec::new();
+	if let Some(parked) = resumed_session.as_ref() {
+		let (topics, topics_to_leave) = state.write().await.resume_conn(parked.conn_id, conn_id, auth_claims.as_ref());
+		if !topics_to_leave.is_empty() {
+			adapter_manager.apply_global_joins_leaves(&[], &topics_to_leave).await;
+		}
+
+		let mut pending = pending_replay.lock().await;
+		for topic in topics {
+			let last_cursor = parked.cursors.get(&topic).copied().unwrap_or(0);
+			let mut result = pb::SubscriptionResult {
+				topic,
+				status: pb::subscription_result::Status::Ok as i32,
+				current_cursor: 0,
+				detail: String::new(),
+			};
+			if let Err(e) = replay_subscription(&replay_service, &mut result, last_cursor, &mut pending, &delivered).await {
+				warn!(conn_id, topic = %result.topic, error = %e, "replay for resumed subscription failed");
+				result.status = pb::subscription_result::Status::InternalError as i32;
+			}
+			resumed_subscriptions.push(result);
 		}
+		drop(pending);
+
+		info!(
+			conn_id,
+			parked_conn_id = parked.conn_id,
+			topics = resumed_subscriptions.len(),
+			"resumed session"
+		);
+		metrics::counter!("chatty_server_sessions_resumed_total").increment(1);
 	}
 
+	let resume_token = if resume.is_enabled() {
+		ResumeRegistry::new_token()
+	} else {
+		String::new()
+	};
+
 	let welcome = pb::Welcome {
 		server_name: format!("chatty-server/{}", env!("CARGO_PKG_VERSION")),
 		server_instance_id: format!("conn-{conn_id}"),
 		server_time_unix_ms: unix_ms_now(),
 		max_frame_bytes: settings.max_frame_bytes,
-		selected_codec: selected_codec as i32,
+		selected_codec: selected_codec.as_pb() as i32,
+		resume_token: resume_token.clone(),
+		resumed: resumed_session.is_some(),
+		resumed_subscriptions: resumed_subscriptions.clone(),
 	};
 
 	send_envelope(
 		&mut control_send,
+		selected_codec,
 		pb::Envelope {
 			version: PROTOCOL_VERSION,
 			request_id: String::new(),
@@ -703,16 +1395,48 @@ pub async fn handle_connection(
 	.await
 	.context("send Welcome")?;
 
+	let mut platform_user_ids = user_sessions
+		.iter()
+		.map(|(platform, key)| format!("{platform}:{}", key.user_id))
+		.collect::<Vec<_>>();
+	platform_user_ids.sort();
+	let session = SessionStart {
+		session_id: format!("conn-{conn_id}-{}", unix_ms_now()),
+		client_id: format!("conn-{conn_id}"),
+		client_name: hello.client_name.clone(),
+		client_instance_id: client_instance_id.clone(),
+		remote_addr: Some(remote_addr.to_string()),
+		auth_subject: auth_claims.as_ref().map(|c| c.sub.clone()),
+		platform_user_ids,
+	};
+	if let Err(e) = audit_service.record_session_start(&session).await {
+		warn!(conn_id, error = %e, "failed to record session start");
+	}
+
 	let events_send: Arc<Mutex<Option<quinn::SendStream>>> = Arc::new(Mutex::new(None));
-	let pending_replay: Arc<Mutex<Vec<pb::EventEnvelope>>> = Arc::new(Mutex::new(Vec::new()));
+	if !resumed_subscriptions.is_empty() {
+		// The resuming client opens its events stream right after Welcome instead of subscribing. Errors
+		// are not returned here so the restored subscriptions still go through the cleanup below.
+		match connection.accept_bi().await {
+			Ok((mut send, _recv)) => {
+				info!(conn_id, "accepted events bidirectional stream for resumed session");
+				let mut pending = pending_replay.lock().await;
+				match flush_pending(&mut send, selected_codec, &mut pending, &delivered).await {
+					Ok(()) => *events_send.lock().await = Some(send),
+					Err(e) => warn!(conn_id, error = %e, "failed to replay resumed session events"),
+				}
+			}
+			Err(e) => warn!(conn_id, error = %e, "resumed client did not open the events stream"),
+		}
+	}
 
 	let room_hub_for_events = room_hub.clone();
 
 	let state_for_events = Arc::clone(&state);
 	let events_send_for_task = Arc::clone(&events_send);
 	let pending_replay_for_task = Arc::clone(&pending_replay);
-	let replay_service_for_task = Arc::clone(&replay_service);
-	let client_id_for_task = client_instance_id.clone();
+	let delivered_for_task = Arc::clone(&delivered);
+	let mut shutdown_rx = shutdown.subscribe();
 
 	let events_task = tokio::spawn(async move {
 		let mut first_event_sent = false;
@@ -782,589 +1506,132 @@ pub async fn handle_connection(
 		let mut current_topics =
 			reconcile_room_tasks(conn_id, &state_for_events, &room_hub_for_events, &fan_in_tx, &mut room_tasks).await;
 
+		let mut shutdown_notified = false;
+
 		loop {
+			let reconnect_after = (!shutdown_notified).then(|| *shutdown_rx.borrow_and_update()).flatten();
+			if let Some(reconnect_after) = reconnect_after {
+				shutdown_notified = true;
+				let mut guard = events_send_for_task.lock().await;
+				// Without an events stream the client is not listening for notices; it sees the close instead.
+				if let Some(events_send) = guard.as_mut() {
+					let mut pending = pending_replay_for_task.lock().await;
+					flush_pending(events_send, selected_codec, &mut pending, &delivered_for_task)
+						.await
+						.context("flush pending events")?;
+					drop(pending);
+					let frame = encode_frame_with_codec(
+						selected_codec,
+						&shutdown_notice_envelope(reconnect_after),
+						DEFAULT_MAX_FRAME_SIZE,
+					)?;
+					events_send.write_all(&frame).await.context("write shutdown notice")?;
+					metrics::counter!("chatty_server_shutdown_notices_total").increment(1);
+					info!(conn_id, "sent shutdown notice");
+				}
+				continue;
+			}
+
 			if current_topics.is_empty() {
-				tokio::time::sleep(std::time::Duration::from_millis(25)).await;
+				tokio::select! {
+					_ = tokio::time::sleep(std::time::Duration::from_millis(25)) => {}
+					Ok(()) = shutdown_rx.changed(), if !shutdown_notified => {}
+				}
 				current_topics =
 					reconcile_room_tasks(conn_id, &state_for_events, &room_hub_for_events, &fan_in_tx, &mut room_tasks)
 						.await;
 				continue;
 			}
 
-			let (topic, item) = match fan_in_rx.recv().await {
-				Some(v) => v,
-				None => return Ok::<(), anyhow::Error>(()),
+			let (topic, item) = tokio::select! {
+				item = fan_in_rx.recv() => match item {
+					Some(v) => v,
+					None => return Ok::<(), anyhow::Error>(()),
+				},
+				Ok(()) = shutdown_rx.changed(), if !shutdown_notified => continue,
 			};
 
 			if !current_topics.contains(&topic) {
 				continue;
 			}
 
-			let mut guard = events_send_for_task.lock().await;
-			let events_ready = guard.is_some();
-			if let Some(events_send) = guard.as_mut() {
-				let mut pending = pending_replay_for_task.lock().await;
-				if !pending.is_empty() {
-					for env in pending.drain(..) {
-						let frame = match encode_frame(
-							&pb::Envelope {
-								version: PROTOCOL_VERSION,
-								request_id: String::new(),
-								msg: Some(pb::envelope::Msg::Event(env)),
-							},
-							DEFAULT_MAX_FRAME_SIZE,
-						) {
-							Ok(f) => f,
-							Err(e) => {
-								error!(conn_id, error = %e, "failed to encode pending replay frame");
-								return Err::<(), anyhow::Error>(anyhow!(e));
-							}
-						};
+			let envelopes = match item {
+				RoomHubItem::Event(env) => vec![*env],
+				RoomHubItem::Ingest(ingest) => map_ingest_event(&topic, *ingest),
+				RoomHubItem::Lagged { dropped } => {
+					warn!(
+						conn_id,
+						topic = %topic,
+						dropped,
+						"room subscription lagged; events were dropped"
+					);
 
-						if let Err(e) = events_send.write_all(&frame).await {
-							return Err(anyhow!(e).context("events stream write failed (pending replay)"));
-						}
-					}
+					vec![pb::EventEnvelope {
+						topic: topic.clone(),
+						cursor: 0,
+						server_time_unix_ms: unix_ms_now(),
+						event: Some(pb::event_envelope::Event::TopicLagged(pb::TopicLaggedEvent {
+							dropped,
+							detail: "room subscriber queue full".to_string(),
+						})),
+					}]
 				}
-			}
-
-			match item {
-				RoomHubItem::Ingest(ingest) => match ingest.payload {
-					IngestPayload::ChatMessage(m) => {
-						let Some(events_send) = guard.as_mut() else {
-							continue;
-						};
+				RoomHubItem::Status(st) => vec![adapter_status_envelope(&topic, st)],
+			};
 
-						let origin = pb::Origin {
-							platform: match ingest.room.platform {
-								Platform::Twitch => 1,
-								Platform::Kick => 2,
-								Platform::YouTube => 3,
-							},
-							channel: ingest.room.room_id.as_str().to_string(),
-							channel_display: ingest.room.room_id.as_str().to_string(),
-						};
-
-						let platform_time_unix_ms = ingest
-							.platform_time
-							.and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
-							.map(|d| d.as_millis() as i64)
-							.unwrap_or(0);
-
-						let emotes: Vec<pb::AssetRef> = m
-							.emotes
-							.into_iter()
-							.map(|emote| pb::AssetRef {
-								id: emote.id,
-								name: emote.name,
-								images: emote
-									.images
-									.into_iter()
-									.map(|img| pb::AssetImage {
-										scale: map_asset_scale(img.scale),
-										url: img.url,
-										format: img.format,
-										width: img.width,
-										height: img.height,
-									})
-									.collect(),
-							})
-							.collect();
-
-						let message = pb::ChatMessage {
-							author_id: m.author.id,
-							author_login: m.author.login,
-							author_display: m.author.display.unwrap_or_default(),
-							text: m.text,
-							platform_time_unix_ms,
-							badge_ids: m.badges,
-							emotes,
-						};
-
-						let chat_message_event = pb::ChatMessageEvent {
-							origin: Some(origin),
-							message: Some(message),
-							server_message_id: m.ids.server_id.to_string(),
-							platform_message_id: m.ids.platform_id.unwrap_or_default(),
-							reply: m.reply.map(|reply| pb::Reply {
-								server_message_id: reply.server_message_id.unwrap_or_default(),
-								platform_message_id: reply.platform_message_id.unwrap_or_default(),
-								user_id: reply.user_id.unwrap_or_default(),
-								user_login: reply.user_login,
-								user_display: reply.user_display.unwrap_or_default(),
-								message: reply.message,
-							}),
-						};
-
-						let env = pb::EventEnvelope {
-							topic: topic.clone(),
-							cursor: 0,
-							server_time_unix_ms: unix_ms_now(),
-							event: Some(pb::event_envelope::Event::ChatMessage(chat_message_event)),
-						};
-
-						let env = replay_service_for_task
-							.push_event(&client_id_for_task, &topic, env)
-							.await
-							.context("persist replay event")?;
-
-						let cursor = env.cursor;
-						let frame = match encode_frame(
-							&pb::Envelope {
-								version: PROTOCOL_VERSION,
-								request_id: String::new(),
-								msg: Some(pb::envelope::Msg::Event(env)),
-							},
-							DEFAULT_MAX_FRAME_SIZE,
-						) {
-							Ok(f) => f,
-							Err(e) => {
-								error!(conn_id, error = %e, "failed to encode event frame");
-								return Err::<(), anyhow::Error>(anyhow!(e));
-							}
-						};
-
-						if !first_event_sent {
-							first_event_sent = true;
-							info!(
-								conn_id,
-								topic = %topic,
-								cursor,
-								frame_len = frame.len(),
-								"writing first ingest-driven event frame to events stream"
-							);
-						} else {
-							debug!(
-								conn_id,
-								topic = %topic,
-								cursor,
-								frame_len = frame.len(),
-								"writing ingest-driven event frame to events stream"
-							);
-						}
-
-						if let Err(e) = events_send.write_all(&frame).await {
-							return Err(anyhow!(e).context("events stream write failed"));
-						}
-					}
-					IngestPayload::AssetBundle(bundle) => {
-						if !events_ready {
-							let origin = pb::Origin {
-								platform: match ingest.room.platform {
-									Platform::Twitch => 1,
-									Platform::Kick => 2,
-									Platform::YouTube => 3,
-								},
-								channel: ingest.room.room_id.as_str().to_string(),
-								channel_display: ingest.room.room_id.as_str().to_string(),
-							};
-
-							let provider = match bundle.provider {
-								AssetProvider::Twitch => 1,
-								AssetProvider::Kick => 2,
-								AssetProvider::SevenTv => 3,
-								AssetProvider::Ffz => 4,
-								AssetProvider::Bttv => 5,
-							};
-
-							let scope = match bundle.scope {
-								AssetScope::Global => 1,
-								AssetScope::Channel => 2,
-							};
-
-							let etag = bundle.etag.clone().unwrap_or_else(|| compute_asset_bundle_etag(&bundle));
-							let emotes: Vec<pb::AssetRef> = bundle
-								.emotes
-								.into_iter()
-								.map(|emote| pb::AssetRef {
-									id: emote.id,
-									name: emote.name,
-									images: emote
-										.images
-										.into_iter()
-										.map(|img| pb::AssetImage {
-											scale: map_asset_scale(img.scale),
-											url: img.url,
-											format: img.format,
-											width: img.width,
-											height: img.height,
-										})
-										.collect(),
-								})
-								.collect();
-							let badges: Vec<pb::AssetRef> = bundle
-								.badges
-								.into_iter()
-								.map(|badge| pb::AssetRef {
-									id: badge.id,
-									name: badge.name,
-									images: badge
-										.images
-										.into_iter()
-										.map(|img| pb::AssetImage {
-											scale: map_asset_scale(img.scale),
-											url: img.url,
-											format: img.format,
-											width: img.width,
-											height: img.height,
-										})
-										.collect(),
-								})
-								.collect();
-
-							let assets = pb::AssetBundleEvent {
-								origin: Some(origin),
-								provider,
-								scope,
-								cache_key: bundle.cache_key,
-								etag,
-								emotes,
-								badges,
-							};
-
-							let original_emotes = assets.emotes.len();
-							let original_badges = assets.badges.len();
-							let chunks = build_asset_bundle_chunks(&topic, &assets, DEFAULT_MAX_FRAME_SIZE);
-							if chunks.events.is_empty() {
-								warn!(
-									conn_id,
-									topic = %topic,
-									cache_key = %assets.cache_key,
-									provider = assets.provider,
-									scope = assets.scope,
-									original_emotes,
-									original_badges,
-									dropped_emotes = chunks.dropped_emotes,
-									dropped_badges = chunks.dropped_badges,
-									"dropping AssetBundle; no chunk fits within max frame size"
-								);
-								continue;
-							}
-
-							if chunks.dropped_emotes > 0 || chunks.dropped_badges > 0 {
-								warn!(
-									conn_id,
-									topic = %topic,
-									cache_key = %assets.cache_key,
-									provider = assets.provider,
-									scope = assets.scope,
-									original_emotes,
-									original_badges,
-									dropped_emotes = chunks.dropped_emotes,
-									dropped_badges = chunks.dropped_badges,
-									"some AssetBundle entries dropped; too large for max frame size"
-								);
-							}
-
-							for assets in chunks.events {
-								info!(
-									conn_id,
-									topic = %topic,
-									cache_key = %assets.cache_key,
-									provider = assets.provider,
-									scope = assets.scope,
-									emote_count = assets.emotes.len(),
-									badge_count = assets.badges.len(),
-									"buffering AssetBundle event until events stream opens"
-								);
-
-								let env = pb::EventEnvelope {
-									topic: topic.clone(),
-									cursor: 0,
-									server_time_unix_ms: unix_ms_now(),
-									event: Some(pb::event_envelope::Event::AssetBundle(assets)),
-								};
-
-								let env = replay_service_for_task
-									.push_event(&client_id_for_task, &topic, env)
-									.await
-									.context("persist replay event")?;
-
-								let mut pending = pending_replay_for_task.lock().await;
-								pending.push(env);
-							}
-							continue;
-						}
-
-						let Some(events_send) = guard.as_mut() else {
-							continue;
-						};
-
-						let origin = pb::Origin {
-							platform: match ingest.room.platform {
-								Platform::Twitch => 1,
-								Platform::Kick => 2,
-								Platform::YouTube => 3,
-							},
-							channel: ingest.room.room_id.as_str().to_string(),
-							channel_display: ingest.room.room_id.as_str().to_string(),
-						};
-
-						let provider = match bundle.provider {
-							AssetProvider::Twitch => 1,
-							AssetProvider::Kick => 2,
-							AssetProvider::SevenTv => 3,
-							AssetProvider::Ffz => 4,
-							AssetProvider::Bttv => 5,
-						};
-						let scope = match bundle.scope {
-							AssetScope::Global => 1,
-							AssetScope::Channel => 2,
-						};
-
-						let etag = bundle.etag.clone().unwrap_or_else(|| compute_asset_bundle_etag(&bundle));
-						let emotes: Vec<pb::AssetRef> = bundle
-							.emotes
-							.into_iter()
-							.map(|emote| pb::AssetRef {
-								id: emote.id,
-								name: emote.name,
-								images: emote
-									.images
-									.into_iter()
-									.map(|img| pb::AssetImage {
-										scale: map_asset_scale(img.scale),
-										url: img.url,
-										format: img.format,
-										width: img.width,
-										height: img.height,
-									})
-									.collect(),
-							})
-							.collect();
-
-						let badges: Vec<pb::AssetRef> = bundle
-							.badges
-							.into_iter()
-							.map(|badge| pb::AssetRef {
-								id: badge.id,
-								name: badge.name,
-								images: badge
-									.images
-									.into_iter()
-									.map(|img| pb::AssetImage {
-										scale: map_asset_scale(img.scale),
-										url: img.url,
-										format: img.format,
-										width: img.width,
-										height: img.height,
-									})
-									.collect(),
-							})
-							.collect();
-
-						let assets = pb::AssetBundleEvent {
-							origin: Some(origin),
-							provider,
-							scope,
-							cache_key: bundle.cache_key,
-							etag,
-							emotes,
-							badges,
-						};
-
-						let original_emotes = assets.emotes.len();
-						let original_badges = assets.badges.len();
-						let chunks = build_asset_bundle_chunks(&topic, &assets, DEFAULT_MAX_FRAME_SIZE);
-						if chunks.events.is_empty() {
-							warn!(
-								conn_id,
-								topic = %topic,
-								cache_key = %assets.cache_key,
-								provider = assets.provider,
-								scope = assets.scope,
-								original_emotes,
-								original_badges,
-								dropped_emotes = chunks.dropped_emotes,
-								dropped_badges = chunks.dropped_badges,
-								"dropping AssetBundle; no chunk fits within max frame size"
-							);
-							continue;
-						}
-
-						if chunks.dropped_emotes > 0 || chunks.dropped_badges > 0 {
-							warn!(
-								conn_id,
-								topic = %topic,
-								cache_key = %assets.cache_key,
-								provider = assets.provider,
-								scope = assets.scope,
-								original_emotes,
-								original_badges,
-								dropped_emotes = chunks.dropped_emotes,
-								dropped_badges = chunks.dropped_badges,
-								"some AssetBundle entries dropped; too large for max frame size"
-							);
-						}
-
-						for assets in chunks.events {
-							info!(
-								conn_id,
-								topic = %topic,
-								cache_key = %assets.cache_key,
-								provider = assets.provider,
-								scope = assets.scope,
-								emote_count = assets.emotes.len(),
-								badge_count = assets.badges.len(),
-								"emitting AssetBundle event"
-							);
-
-							let env = pb::EventEnvelope {
-								topic: topic.clone(),
-								cursor: 0,
-								server_time_unix_ms: unix_ms_now(),
-								event: Some(pb::event_envelope::Event::AssetBundle(assets)),
-							};
-
-							let env = replay_service_for_task
-								.push_event(&client_id_for_task, &topic, env)
-								.await
-								.context("persist replay event")?;
-
-							let frame = match encode_frame(
-								&pb::Envelope {
-									version: PROTOCOL_VERSION,
-									request_id: String::new(),
-									msg: Some(pb::envelope::Msg::Event(env)),
-								},
-								DEFAULT_MAX_FRAME_SIZE,
-							) {
-								Ok(f) => f,
-								Err(e) => {
-									error!(conn_id, error = %e, "failed to encode asset bundle frame");
-									return Err::<(), anyhow::Error>(anyhow!(e));
-								}
-							};
-
-							if let Err(e) = events_send.write_all(&frame).await {
-								return Err(anyhow!(e).context("events stream write failed"));
-							}
-						}
-					}
-					IngestPayload::RoomState(state) => {
-						let origin = pb::Origin {
-							platform: match ingest.room.platform {
-								Platform::Twitch => 1,
-								Platform::Kick => 2,
-								Platform::YouTube => 3,
-							},
-							channel: ingest.room.room_id.as_str().to_string(),
-							channel_display: ingest.room.room_id.as_str().to_string(),
-						};
-
-						let settings = pb::RoomChatSettings {
-							emote_only: state.settings.emote_only,
-							subscribers_only: state.settings.subscribers_only,
-							unique_chat: state.settings.unique_chat,
-							slow_mode: state.settings.slow_mode,
-							slow_mode_wait_time_seconds: state.settings.slow_mode_wait_time_seconds,
-							followers_only: state.settings.followers_only,
-							followers_only_duration_minutes: state.settings.followers_only_duration_minutes,
-						};
-
-						let room_state = pb::RoomStateEvent {
-							origin: Some(origin),
-							settings: Some(settings),
-							flags: state.flags.into_iter().collect(),
-							notes: state.notes.unwrap_or_default(),
-						};
-
-						let env = pb::EventEnvelope {
-							topic: topic.clone(),
-							cursor: 0,
-							server_time_unix_ms: unix_ms_now(),
-							event: Some(pb::event_envelope::Event::RoomState(room_state)),
-						};
-
-						if !events_ready {
-							let env = replay_service_for_task
-								.push_event(&client_id_for_task, &topic, env)
-								.await
-								.context("persist room state event")?;
-							let mut pending = pending_replay_for_task.lock().await;
-							pending.push(env);
-							continue;
-						}
-
-						let Some(events_send) = guard.as_mut() else {
-							continue;
-						};
-
-						let env = replay_service_for_task
-							.push_event(&client_id_for_task, &topic, env)
-							.await
-							.context("persist room state event")?;
-
-						let frame = match encode_frame(
-							&pb::Envelope {
-								version: PROTOCOL_VERSION,
-								request_id: String::new(),
-								msg: Some(pb::envelope::Msg::Event(env)),
-							},
-							DEFAULT_MAX_FRAME_SIZE,
-						) {
-							Ok(f) => f,
-							Err(e) => {
-								error!(conn_id, error = %e, "failed to encode room state frame");
-								return Err::<(), anyhow::Error>(anyhow!(e));
-							}
-						};
-
-						if let Err(e) = events_send.write_all(&frame).await {
-							return Err(anyhow!(e).context("events stream write failed"));
-						}
-					}
-					_ => {}
-				},
-				RoomHubItem::Lagged { dropped } => {
-					let Some(events_send) = guard.as_mut() else {
-						continue;
-					};
-
-					let lagged = pb::TopicLaggedEvent {
-						dropped,
-						detail: "room subscriber queue full".to_string(),
-					};
-
-					let env = pb::EventEnvelope {
-						topic: topic.clone(),
-						cursor: 0,
-						server_time_unix_ms: unix_ms_now(),
-						event: Some(pb::event_envelope::Event::TopicLagged(lagged)),
-					};
-
-					let env = replay_service_for_task
-						.push_event(&client_id_for_task, &topic, env)
-						.await
-						.context("persist replay event")?;
+			let mut guard = events_send_for_task.lock().await;
+			let Some(events_send) = guard.as_mut() else {
+				let mut pending = pending_replay_for_task.lock().await;
+				pending.extend(envelopes.into_iter().filter(buffer_until_events_ready));
+				continue;
+			};
 
-					let frame = match encode_frame(
+			let mut frames = Vec::new();
+			{
+				let mut pending = pending_replay_for_task.lock().await;
+				for env in pending.drain(..).chain(envelopes) {
+					let cursor = env.cursor;
+					note_delivered(&delivered_for_task, &env.topic, cursor);
+					let frame = encode_frame_with_codec(
+						selected_codec,
 						&pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: String::new(),
 							msg: Some(pb::envelope::Msg::Event(env)),
 						},
 						DEFAULT_MAX_FRAME_SIZE,
-					) {
-						Ok(f) => f,
-						Err(e) => {
-							error!(conn_id, error = %e, "failed to encode lagged event frame");
-							return Err::<(), anyhow::Error>(anyhow!(e));
-						}
-					};
-
-					if let Err(e) = events_send.write_all(&frame).await {
-						return Err(anyhow!(e).context("events stream write failed (lagged event)"));
-					}
+					)
+					.map_err(|e| {
+						error!(conn_id, error = %e, "failed to encode event frame");
+						anyhow!(e)
+					})?;
+					frames.push((cursor, frame));
+				}
+			}
 
-					warn!(
+			for (cursor, frame) in frames {
+				if !first_event_sent {
+					first_event_sent = true;
+					info!(
 						conn_id,
 						topic = %topic,
-						dropped,
-						"room subscription lagged; events were dropped"
+						cursor,
+						frame_len = frame.len(),
+						"writing first event frame to events stream"
+					);
+				} else {
+					debug!(
+						conn_id,
+						topic = %topic,
+						cursor,
+						frame_len = frame.len(),
+						"writing event frame to events stream"
 					);
 				}
-				RoomHubItem::Status(_st) => {}
+
+				if let Err(e) = events_send.write_all(&frame).await {
+					return Err(anyhow!(e).context("events stream write failed"));
+				}
 			}
 
 			current_topics =
@@ -1385,6 +1652,7 @@ pub async fn handle_connection(
 
 					send_envelope(
 						&mut control_send,
+						selected_codec,
 						pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: env.request_id,
@@ -1398,51 +1666,20 @@ pub async fn handle_connection(
 					let last_cursor_by_topic: HashMap<String, u64> =
 						sub.subs.iter().map(|s| (s.topic.clone(), s.last_cursor)).collect();
 					debug!(conn_id, topics = ?sub.subs.iter().map(|s| &s.topic).collect::<Vec<_>>(), "received Subscribe");
-					let (mut results, topics_to_join) = handle_subscribe(conn_id, &state, sub).await;
+					let (mut results, topics_to_join) = handle_subscribe(conn_id, &state, sub, auth_claims.as_ref()).await;
 					debug!(conn_id, topics_to_join = ?topics_to_join, "Subscribe processed, topics_to_join determined");
 
 					let mut pending = pending_replay.lock().await;
 					for result in &mut results {
 						let last_cursor = *last_cursor_by_topic.get(&result.topic).unwrap_or(&0);
-						let outcome = {
-							replay_service
-								.replay(&client_instance_id, &result.topic, last_cursor)
-								.await
-								.context("replay events")?
-						};
-
-						result.status = outcome.status as i32;
-						result.current_cursor = outcome.current_cursor;
-						if !outcome.items.is_empty() {
-							pending.extend(outcome.items);
-						}
-
-						let dropped = outcome.current_cursor.saturating_sub(last_cursor);
-						if outcome.status == pb::subscription_result::Status::ReplayNotAvailable && dropped > 0 {
-							let lagged = pb::TopicLaggedEvent {
-								dropped,
-								detail: "replay buffer exhausted".to_string(),
-							};
-							let env = pb::EventEnvelope {
-								topic: result.topic.clone(),
-								cursor: 0,
-								server_time_unix_ms: unix_ms_now(),
-								event: Some(pb::event_envelope::Event::TopicLagged(lagged)),
-							};
-
-							let env = replay_service
-								.push_event(&client_instance_id, &result.topic, env)
-								.await
-								.context("persist replay event")?;
-							result.current_cursor = env.cursor;
-							pending.push(env);
-						}
+						replay_subscription(&replay_service, result, last_cursor, &mut pending, &delivered).await?;
 					}
 					drop(pending);
 
 					let permission_results = results.clone();
 					send_envelope(
 						&mut control_send,
+						selected_codec,
 						pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: env.request_id,
@@ -1470,10 +1707,16 @@ pub async fn handle_connection(
 							continue;
 						};
 
-						let perms_auth = if room.platform == Platform::Kick {
-							kick_auth.clone()
-						} else {
-							None
+						// Joining during an upstream outage would otherwise look like a quiet room.
+						if let Some(st) = adapter_manager.last_status(room.platform).await
+							&& !st.connected
+						{
+							permission_events.push(adapter_status_envelope(&result.topic, st));
+						}
+
+						let perms_auth = match user_sessions.get(&room.platform) {
+							Some(key) => adapter_manager.user_auth(key).await,
+							None => None,
 						};
 
 						if let Some(perms) = adapter_manager.query_permissions(&room, perms_auth).await {
@@ -1489,13 +1732,9 @@ pub async fn handle_connection(
 									can_ban: perms.can_ban,
 									is_moderator: perms.is_moderator,
 									is_broadcaster: perms.is_broadcaster,
+									can_manage_redemptions: perms.can_manage_redemptions,
 								})),
 							};
-
-							let env = replay_service
-								.push_event(&client_instance_id, &result.topic, env)
-								.await
-								.context("persist permissions event")?;
 							permission_events.push(env);
 						}
 					}
@@ -1518,17 +1757,7 @@ pub async fn handle_connection(
 
 					if let Some(events_send) = guard.as_mut() {
 						let mut pending = pending_replay.lock().await;
-						for env in pending.drain(..) {
-							let frame = encode_frame(
-								&pb::Envelope {
-									version: PROTOCOL_VERSION,
-									request_id: String::new(),
-									msg: Some(pb::envelope::Msg::Event(env)),
-								},
-								DEFAULT_MAX_FRAME_SIZE,
-							)?;
-							events_send.write_all(&frame).await?;
-						}
+						flush_pending(events_send, selected_codec, &mut pending, &delivered).await?;
 					}
 				}
 
@@ -1537,6 +1766,7 @@ pub async fn handle_connection(
 
 					send_envelope(
 						&mut control_send,
+						selected_codec,
 						pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: env.request_id,
@@ -1558,11 +1788,14 @@ pub async fn handle_connection(
 						&cmd,
 						&adapter_manager,
 						&audit_service,
-						kick_auth.clone(),
+						&user_sessions,
+						&replay_service,
+						&chat_log,
 					)
 					.await;
 					send_envelope(
 						&mut control_send,
+						selected_codec,
 						pb::Envelope {
 							version: PROTOCOL_VERSION,
 							request_id: env.request_id,
@@ -1585,7 +1818,41 @@ pub async fn handle_connection(
 	}
 	.await;
 
-	{
+	subscriptions_guard.conn_id = None;
+
+	// Sessions are not parked during shutdown; the process is about to lose them anyway.
+	if resume.is_enabled() && !shutdown.is_triggered() {
+		events_task.abort();
+		let _ = events_task.await;
+
+		let parked = ParkedSession {
+			conn_id,
+			client_instance_id: client_instance_id.clone(),
+			auth_subject: auth_claims.as_ref().map(|c| c.sub.clone()),
+			user_sessions: std::mem::take(&mut user_sessions_guard.keys),
+			cursors: delivered.lock().expect("delivered cursors").clone(),
+		};
+		debug!(
+			conn_id,
+			topics = parked.cursors.len(),
+			"connection closed, parking session for resume"
+		);
+		resume.park(resume_token.clone(), parked);
+		metrics::gauge!("chatty_server_parked_sessions").set(resume.parked_len() as f64);
+
+		let resume = Arc::clone(&resume);
+		let state = Arc::clone(&state);
+		let adapter_manager = Arc::clone(&adapter_manager);
+		tokio::spawn(async move {
+			tokio::time::sleep(resume.grace()).await;
+			if let Some(parked) = resume.expire(&resume_token) {
+				release_parked_session(&state, &adapter_manager, parked).await;
+			}
+			metrics::gauge!("chatty_server_parked_sessions").set(resume.parked_len() as f64);
+		});
+
+		let _ = reader_task.await;
+	} else {
 		let topics_to_leave = {
 			let mut st = state.write().await;
 			let topics = st.topics_for_conn(conn_id);
@@ -1596,10 +1863,14 @@ pub async fn handle_connection(
 			debug!(conn_id, topics_to_leave = ?topics_to_leave, "connection closed, leaving rooms");
 			adapter_manager.apply_global_joins_leaves(&[], &topics_to_leave).await;
 		}
+
+		let _ = reader_task.await;
+		let _ = events_task.await;
 	}
 
-	let _ = reader_task.await;
-	let _ = events_task.await;
+	if let Err(e) = audit_service.record_session_end(&session.session_id).await {
+		warn!(conn_id, error = %e, "failed to record session end");
+	}
 
 	loop_result
 }
@@ -1614,29 +1885,50 @@ async fn wait_for_hello(ctrl_rx: &mut mpsc::UnboundedReceiver<pb::Envelope>) ->
 	Err(anyhow!("connection closed before Hello"))
 }
 
-fn negotiate_codec(hello: &pb::Hello) -> Result<pb::Codec, String> {
-	let mut supported: Vec<pb::Codec> = hello
-		.supported_codecs
-		.iter()
-		.filter_map(|c| pb::Codec::try_from(*c).ok())
-		.collect();
-
-	if supported.is_empty() {
-		supported.push(pb::Codec::Protobuf);
-	}
+/// Codec fixed by the negotiated ALPN protocol; connections without a known protocol use protobuf.
+fn connection_codec(connection: &quinn::Connection) -> Codec {
+	connection
+		.handshake_data()
+		.and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+		.and_then(|data| data.protocol)
+		.and_then(|protocol| Codec::from_alpn(&protocol))
+		.unwrap_or_default()
+}
 
-	let preferred = pb::Codec::try_from(hello.preferred_codec).ok();
-	if let Some(pref) = preferred
-		&& supported.contains(&pref)
+/// A `Hello` listing codecs must include the one its ALPN protocol selected; an empty list accepts it.
+fn check_hello_codec(hello: &pb::Hello, codec: Codec) -> Result<(), String> {
+	if hello.supported_codecs.is_empty()
+		|| hello
+			.supported_codecs
+			.iter()
+			.filter_map(|c| Codec::try_from(*c).ok())
+			.any(|c| c == codec)
 	{
-		return Ok(pref);
-	}
-
-	if supported.contains(&pb::Codec::Protobuf) {
-		return Ok(pb::Codec::Protobuf);
+		return Ok(());
 	}
+	Err(format!(
+		"connection codec {codec:?} (selected via ALPN) is not among the codecs offered in Hello"
+	))
+}
 
-	Err("server supports only protobuf".to_string())
+/// Returns `None` when no field is set, since an empty update is almost certainly a client bug.
+fn map_room_settings_request(s: &pb::RoomChatSettings) -> Option<RoomChatSettings> {
+	let any_set = s.emote_only.is_some()
+		|| s.subscribers_only.is_some()
+		|| s.unique_chat.is_some()
+		|| s.slow_mode.is_some()
+		|| s.slow_mode_wait_time_seconds.is_some()
+		|| s.followers_only.is_some()
+		|| s.followers_only_duration_minutes.is_some();
+	any_set.then_some(RoomChatSettings {
+		emote_only: s.emote_only,
+		subscribers_only: s.subscribers_only,
+		unique_chat: s.unique_chat,
+		slow_mode: s.slow_mode,
+		slow_mode_wait_time_seconds: s.slow_mode_wait_time_seconds,
+		followers_only: s.followers_only,
+		followers_only_duration_minutes: s.followers_only_duration_minutes,
+	})
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1649,7 +1941,9 @@ async fn handle_command(
 	cmd: &pb::Command,
 	adapter_manager: &AdapterManager,
 	audit_service: &AuditService,
-	kick_auth: Option<AdapterAuth>,
+	user_sessions: &HashMap<Platform, UserSessionKey>,
+	replay_service: &ReplayService,
+	chat_log: &ChatLogService,
 ) -> pb::CommandResult {
 	if let Some(expected) = settings.auth_token.as_ref()
 		&& (client_auth_token.trim().is_empty() || client_auth_token != expected.expose())
@@ -1658,17 +1952,16 @@ async fn handle_command(
 		return pb::CommandResult {
 			status: pb::command_result::Status::NotAuthorized as i32,
 			detail: "missing/invalid auth token".to_string(),
+			..Default::default()
 		};
 	}
-	if settings.auth_hmac_secret.is_some() {
-		let Some(claims) = auth_claims else {
-			metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
-			return pb::CommandResult {
-				status: pb::command_result::Status::NotAuthorized as i32,
-				detail: "missing/invalid auth token".to_string(),
-			};
+	if settings.auth_hmac_keys.is_some() && auth_claims.is_none() {
+		metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
+		return pb::CommandResult {
+			status: pb::command_result::Status::NotAuthorized as i32,
+			detail: "missing/invalid auth token".to_string(),
+			..Default::default()
 		};
-		let _ = claims;
 	}
 
 	if !rate_limiter.allow_connection() {
@@ -1677,6 +1970,7 @@ async fn handle_command(
 		return pb::CommandResult {
 			status: pb::command_result::Status::NotAuthorized as i32,
 			detail: "rate limited".to_string(),
+			..Default::default()
 		};
 	}
 
@@ -1685,6 +1979,7 @@ async fn handle_command(
 		return pb::CommandResult {
 			status: pb::command_result::Status::InvalidCommand as i32,
 			detail: "missing command payload".to_string(),
+			..Default::default()
 		};
 	};
 
@@ -1693,8 +1988,35 @@ async fn handle_command(
 		pb::command::Command::DeleteMessage(c) => ("delete_message", c.topic.as_str()),
 		pb::command::Command::TimeoutUser(c) => ("timeout_user", c.topic.as_str()),
 		pb::command::Command::BanUser(c) => ("ban_user", c.topic.as_str()),
+		pb::command::Command::FetchHistory(c) => ("fetch_history", c.topic.as_str()),
+		pb::command::Command::SearchChatLog(c) => ("search_chat_log", c.topic.as_str()),
+		pb::command::Command::UnbanUser(c) => ("unban_user", c.topic.as_str()),
+		pb::command::Command::UntimeoutUser(c) => ("untimeout_user", c.topic.as_str()),
+		pb::command::Command::ClearChat(c) => ("clear_chat", c.topic.as_str()),
+		pb::command::Command::ClearUserMessages(c) => ("clear_user_messages", c.topic.as_str()),
+		pb::command::Command::UpdateRoomSettings(c) => ("update_room_settings", c.topic.as_str()),
+		pb::command::Command::AutomodResolve(c) => ("automod_resolve", c.topic.as_str()),
+		pb::command::Command::ListUnbanRequests(c) => ("list_unban_requests", c.topic.as_str()),
+		pb::command::Command::ResolveUnbanRequest(c) => ("resolve_unban_request", c.topic.as_str()),
+		pb::command::Command::CreatePoll(c) => ("create_poll", c.topic.as_str()),
+		pb::command::Command::EndPoll(c) => ("end_poll", c.topic.as_str()),
+		pb::command::Command::CreatePrediction(c) => ("create_prediction", c.topic.as_str()),
+		pb::command::Command::ResolvePrediction(c) => ("resolve_prediction", c.topic.as_str()),
+		pb::command::Command::FulfillRedemption(c) => ("fulfill_redemption", c.topic.as_str()),
+		pb::command::Command::CancelRedemption(c) => ("cancel_redemption", c.topic.as_str()),
 	};
 
+	if let Some(claims) = auth_claims
+		&& !(claims.allows_command(kind) && claims.allows_topic(topic))
+	{
+		metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
+		return pb::CommandResult {
+			status: pb::command_result::Status::NotAuthorized as i32,
+			detail: format!("token does not allow {kind} on this topic"),
+			..Default::default()
+		};
+	}
+
 	let room: RoomKey = match RoomTopic::parse(topic) {
 		Ok(r) => r,
 		Err(e) => {
@@ -1702,6 +2024,7 @@ async fn handle_command(
 			return pb::CommandResult {
 				status: pb::command_result::Status::InvalidTopic as i32,
 				detail: format!("invalid topic: {e}"),
+				..Default::default()
 			};
 		}
 	};
@@ -1713,6 +2036,7 @@ async fn handle_command(
 		return pb::CommandResult {
 			status: pb::command_result::Status::NotAuthorized as i32,
 			detail: "rate limited".to_string(),
+			..Default::default()
 		};
 	}
 
@@ -1723,6 +2047,7 @@ async fn handle_command(
 				return pb::CommandResult {
 					status: pb::command_result::Status::InvalidCommand as i32,
 					detail: "empty message".to_string(),
+					..Default::default()
 				};
 			}
 			(
@@ -1745,6 +2070,7 @@ async fn handle_command(
 				return pb::CommandResult {
 					status: pb::command_result::Status::InvalidCommand as i32,
 					detail: "missing platform_message_id".to_string(),
+					..Default::default()
 				};
 			}
 			(
@@ -1762,6 +2088,7 @@ async fn handle_command(
 				return pb::CommandResult {
 					status: pb::command_result::Status::InvalidCommand as i32,
 					detail: "missing user_id or duration".to_string(),
+					..Default::default()
 				};
 			}
 			(
@@ -1785,6 +2112,7 @@ async fn handle_command(
 				return pb::CommandResult {
 					status: pb::command_result::Status::InvalidCommand as i32,
 					detail: "missing user_id".to_string(),
+					..Default::default()
 				};
 			}
 			(
@@ -1801,8 +2129,293 @@ async fn handle_command(
 				None,
 			)
 		}
+		pb::command::Command::UnbanUser(c) => {
+			if c.user_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing user_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::UnbanUser {
+					room: room.clone(),
+					user_id: c.user_id.clone(),
+				},
+				Some(c.user_id.as_str()),
+				None,
+			)
+		}
+		pb::command::Command::UntimeoutUser(c) => {
+			if c.user_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing user_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::UntimeoutUser {
+					room: room.clone(),
+					user_id: c.user_id.clone(),
+				},
+				Some(c.user_id.as_str()),
+				None,
+			)
+		}
+		pb::command::Command::ClearChat(_) => (CommandRequest::ClearChat { room: room.clone() }, None, None),
+		pb::command::Command::ClearUserMessages(c) => {
+			if c.user_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing user_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::ClearUserMessages {
+					room: room.clone(),
+					user_id: c.user_id.clone(),
+				},
+				Some(c.user_id.as_str()),
+				None,
+			)
+		}
+		pb::command::Command::UpdateRoomSettings(c) => {
+			let Some(settings) = c.settings.as_ref().and_then(map_room_settings_request) else {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing room settings".to_string(),
+					..Default::default()
+				};
+			};
+			(
+				CommandRequest::UpdateRoomSettings {
+					room: room.clone(),
+					settings,
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::AutomodResolve(c) => {
+			if c.message_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing message_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::AutoModResolve {
+					room: room.clone(),
+					message_id: c.message_id.clone(),
+					allow: c.allow,
+				},
+				None,
+				Some(c.message_id.as_str()),
+			)
+		}
+		pb::command::Command::ResolveUnbanRequest(c) => {
+			if c.request_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing request_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::ResolveUnbanRequest {
+					room: room.clone(),
+					request_id: c.request_id.clone(),
+					approve: c.approve,
+					resolution_text: if c.resolution_text.trim().is_empty() {
+						None
+					} else {
+						Some(c.resolution_text.clone())
+					},
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::CreatePoll(c) => {
+			if c.title.trim().is_empty() || c.choices.iter().filter(|t| !t.trim().is_empty()).count() < 2 {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing title or fewer than two choices".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::CreatePoll {
+					room: room.clone(),
+					title: c.title.trim().to_string(),
+					choices: c
+						.choices
+						.iter()
+						.map(|t| t.trim())
+						.filter(|t| !t.is_empty())
+						.map(str::to_string)
+						.collect(),
+					duration_seconds: c.duration_seconds,
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::EndPoll(c) => {
+			if c.poll_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing poll_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::EndPoll {
+					room: room.clone(),
+					poll_id: c.poll_id.clone(),
+					archive: c.archive,
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::CreatePrediction(c) => {
+			if c.title.trim().is_empty() || c.outcomes.iter().filter(|t| !t.trim().is_empty()).count() < 2 {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing title or fewer than two outcomes".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::CreatePrediction {
+					room: room.clone(),
+					title: c.title.trim().to_string(),
+					outcomes: c
+						.outcomes
+						.iter()
+						.map(|t| t.trim())
+						.filter(|t| !t.is_empty())
+						.map(str::to_string)
+						.collect(),
+					prediction_window_seconds: c.prediction_window_seconds,
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::ResolvePrediction(c) => {
+			if c.prediction_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing prediction_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::ResolvePrediction {
+					room: room.clone(),
+					prediction_id: c.prediction_id.clone(),
+					winning_outcome_id: if c.winning_outcome_id.trim().is_empty() {
+						None
+					} else {
+						Some(c.winning_outcome_id.clone())
+					},
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::FulfillRedemption(c) => {
+			if c.reward_id.trim().is_empty() || c.redemption_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing reward_id or redemption_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::FulfillRedemption {
+					room: room.clone(),
+					reward_id: c.reward_id.clone(),
+					redemption_id: c.redemption_id.clone(),
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::CancelRedemption(c) => {
+			if c.reward_id.trim().is_empty() || c.redemption_id.trim().is_empty() {
+				metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
+				return pb::CommandResult {
+					status: pb::command_result::Status::InvalidCommand as i32,
+					detail: "missing reward_id or redemption_id".to_string(),
+					..Default::default()
+				};
+			}
+			(
+				CommandRequest::CancelRedemption {
+					room: room.clone(),
+					reward_id: c.reward_id.clone(),
+					redemption_id: c.redemption_id.clone(),
+				},
+				None,
+				None,
+			)
+		}
+		pb::command::Command::ListUnbanRequests(c) => {
+			let Some(key) = user_sessions.get(&room.platform) else {
+				return no_user_session_result(room.platform);
+			};
+			let command_auth = adapter_manager.user_auth(key).await;
+			return list_unban_requests(conn_id, adapter_manager, command_auth, &room, c).await;
+		}
+		pb::command::Command::FetchHistory(c) => {
+			return fetch_history(conn_id, replay_service, &room, c).await;
+		}
+		pb::command::Command::SearchChatLog(c) => {
+			return search_chat_log(conn_id, chat_log, audit_service, &room, c).await;
+		}
 	};
 
+	// Platform commands always run as the connection's own linked account, never the adapter's ingest token.
+	let Some(key) = user_sessions.get(&room.platform) else {
+		return no_user_session_result(room.platform);
+	};
+	let command_auth = adapter_manager.user_auth(key).await;
+
+	// Token roles cannot express "broadcaster", so redemption updates are gated on the adapter's own
+	// permission snapshot for the linked account.
+	if matches!(
+		request,
+		CommandRequest::FulfillRedemption { .. } | CommandRequest::CancelRedemption { .. }
+	) && !adapter_manager
+		.query_permissions(&room, command_auth.clone())
+		.await
+		.is_some_and(|p| p.can_manage_redemptions)
+	{
+		metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
+		return pb::CommandResult {
+			status: pb::command_result::Status::NotAuthorized as i32,
+			detail: "managing redemptions requires the broadcaster's account".to_string(),
+			..Default::default()
+		};
+	}
+
 	if let Err(e) = audit_service
 		.record_command(&format!("conn-{conn_id}"), topic, kind, target_user_id, target_message_id)
 		.await
@@ -1822,55 +2435,267 @@ async fn handle_command(
 
 	metrics::counter!("chatty_server_commands_total").increment(1);
 
-	let command_auth = if room.platform == Platform::Kick { kick_auth } else { None };
 	match adapter_manager.execute_command(request, command_auth).await {
 		Ok(()) => {
 			metrics::counter!("chatty_server_commands_ok_total").increment(1);
 			pb::CommandResult {
 				status: pb::command_result::Status::Ok as i32,
 				detail: "command executed".to_string(),
+				..Default::default()
 			}
 		}
-		Err(CommandError::NotSupported(detail)) => {
+		Err(e) => command_error_result(e),
+	}
+}
+
+fn no_user_session_result(platform: Platform) -> pb::CommandResult {
+	metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
+	pb::CommandResult {
+		status: pb::command_result::Status::NotAuthorized as i32,
+		detail: format!("no linked {platform} account for this connection"),
+		..Default::default()
+	}
+}
+
+fn command_error_result(err: CommandError) -> pb::CommandResult {
+	match err {
+		CommandError::NotSupported(detail) => {
 			metrics::counter!("chatty_server_commands_not_supported_total").increment(1);
 			pb::CommandResult {
 				status: pb::command_result::Status::NotSupported as i32,
 				detail: detail.unwrap_or_else(|| "command not supported by adapter".to_string()),
+				..Default::default()
 			}
 		}
-		Err(CommandError::NotAuthorized(detail)) => {
+		CommandError::NotAuthorized(detail) => {
 			metrics::counter!("chatty_server_commands_not_authorized_total").increment(1);
 			pb::CommandResult {
 				status: pb::command_result::Status::NotAuthorized as i32,
 				detail: detail.unwrap_or_else(|| "not authorized".to_string()),
+				..Default::default()
 			}
 		}
-		Err(CommandError::InvalidTopic(detail)) => {
+		CommandError::InvalidTopic(detail) => {
 			metrics::counter!("chatty_server_commands_invalid_topic_total").increment(1);
 			pb::CommandResult {
 				status: pb::command_result::Status::InvalidTopic as i32,
 				detail: detail.unwrap_or_else(|| "invalid topic".to_string()),
+				..Default::default()
 			}
 		}
-		Err(CommandError::InvalidCommand(detail)) => {
+		CommandError::InvalidCommand(detail) => {
 			metrics::counter!("chatty_server_commands_invalid_command_total").increment(1);
 			pb::CommandResult {
 				status: pb::command_result::Status::InvalidCommand as i32,
 				detail: detail.unwrap_or_else(|| "invalid command".to_string()),
+				..Default::default()
+			}
+		}
+		CommandError::Internal(_) => {
+			metrics::counter!("chatty_server_commands_internal_error_total").increment(1);
+			pb::CommandResult {
+				status: pb::command_result::Status::InternalError as i32,
+				detail: "internal error".to_string(),
+				..Default::default()
+			}
+		}
+	}
+}
+
+fn map_unban_request(req: UnbanRequest) -> pb::UnbanRequest {
+	pb::UnbanRequest {
+		request_id: req.id,
+		user: Some(map_user_ref(req.user)),
+		text: req.text.unwrap_or_default(),
+		status: req.status,
+		created_at_unix_ms: req.created_at.map(unix_ms_from),
+		resolved_by: req.resolved_by.map(map_user_ref),
+		resolution_text: req.resolution_text.unwrap_or_default(),
+	}
+}
+
+/// List a room's unban requests from the platform. Read-only, so it is not audited.
+async fn list_unban_requests(
+	conn_id: u64,
+	adapter_manager: &AdapterManager,
+	auth: Option<AdapterAuth>,
+	room: &RoomKey,
+	cmd: &pb::ListUnbanRequestsCommand,
+) -> pb::CommandResult {
+	metrics::counter!("chatty_server_commands_total").increment(1);
+	let status = match cmd.status.trim() {
+		"" => "pending".to_string(),
+		s => s.to_ascii_lowercase(),
+	};
+
+	match adapter_manager.list_unban_requests(room, status, auth).await {
+		Ok(requests) => {
+			debug!(conn_id, room = %room, requests = requests.len(), "served unban requests");
+			metrics::counter!("chatty_server_commands_ok_total").increment(1);
+			pb::CommandResult {
+				status: pb::command_result::Status::Ok as i32,
+				detail: format!("{} unban requests", requests.len()),
+				unban_requests: Some(pb::ListUnbanRequestsResult {
+					requests: requests.into_iter().map(map_unban_request).collect(),
+				}),
+				..Default::default()
+			}
+		}
+		Err(e) => {
+			warn!(conn_id, room = %room, error = %e, "failed to list unban requests");
+			command_error_result(e)
+		}
+	}
+}
+
+const HISTORY_DEFAULT_LIMIT: usize = 50;
+const HISTORY_MAX_LIMIT: usize = 200;
+
+/// Answer a scrollback request from the shared replay log. Read-only, so it is not audited.
+async fn fetch_history(
+	conn_id: u64,
+	replay_service: &ReplayService,
+	room: &RoomKey,
+	cmd: &pb::FetchHistoryCommand,
+) -> pb::CommandResult {
+	let topic = RoomTopic::format(room);
+	let limit = match cmd.limit {
+		0 => HISTORY_DEFAULT_LIMIT,
+		n => (n as usize).min(HISTORY_MAX_LIMIT),
+	};
+
+	metrics::counter!("chatty_server_commands_total").increment(1);
+	match replay_service.history(&topic, cmd.before_cursor, limit).await {
+		Ok(page) => {
+			let events: Vec<pb::EventEnvelope> = page
+				.items
+				.into_iter()
+				.filter(|env| {
+					matches!(
+						env.event,
+						Some(
+							pb::event_envelope::Event::ChatMessage(_)
+								| pb::event_envelope::Event::Moderation(_)
+								| pb::event_envelope::Event::UserNotice(_)
+						)
+					)
+				})
+				.collect();
+			debug!(
+				conn_id,
+				topic = %topic,
+				before_cursor = cmd.before_cursor,
+				events = events.len(),
+				next_before_cursor = page.next_before_cursor,
+				"served history page"
+			);
+			metrics::counter!("chatty_server_commands_ok_total").increment(1);
+			pb::CommandResult {
+				status: pb::command_result::Status::Ok as i32,
+				detail: format!("{} events", events.len()),
+				history: Some(pb::FetchHistoryResult {
+					events,
+					next_before_cursor: page.next_before_cursor,
+				}),
+				..Default::default()
+			}
+		}
+		Err(e) => {
+			metrics::counter!("chatty_server_commands_internal_error_total").increment(1);
+			warn!(conn_id, topic = %topic, error = %e, "failed to read history");
+			pb::CommandResult {
+				status: pb::command_result::Status::InternalError as i32,
+				detail: "internal error".to_string(),
+				..Default::default()
+			}
+		}
+	}
+}
+
+const SEARCH_DEFAULT_LIMIT: usize = 50;
+const SEARCH_MAX_LIMIT: usize = 200;
+
+/// Search the persistent chat log. Searches are audited since they look up users' past messages.
+async fn search_chat_log(
+	conn_id: u64,
+	chat_log: &ChatLogService,
+	audit_service: &AuditService,
+	room: &RoomKey,
+	cmd: &pb::SearchChatLogCommand,
+) -> pb::CommandResult {
+	metrics::counter!("chatty_server_commands_total").increment(1);
+	if !chat_log.is_enabled() {
+		metrics::counter!("chatty_server_commands_not_supported_total").increment(1);
+		return pb::CommandResult {
+			status: pb::command_result::Status::NotSupported as i32,
+			detail: "chat log is disabled on this server".to_string(),
+			..Default::default()
+		};
+	}
+
+	let topic = RoomTopic::format(room);
+	let non_empty = |s: &str| {
+		let s = s.trim();
+		(!s.is_empty()).then(|| s.to_string())
+	};
+	let limit = match cmd.limit {
+		0 => SEARCH_DEFAULT_LIMIT,
+		n => (n as usize).min(SEARCH_MAX_LIMIT),
+	};
+	let query = ChatLogQuery {
+		topic: topic.clone(),
+		user: non_empty(&cmd.user),
+		text: non_empty(&cmd.text),
+		since_unix_ms: (cmd.since_unix_ms > 0).then_some(cmd.since_unix_ms),
+		until_unix_ms: (cmd.until_unix_ms > 0).then_some(cmd.until_unix_ms),
+		before_id: (cmd.before_id > 0).then_some(cmd.before_id),
+		limit,
+	};
+
+	if let Err(e) = audit_service
+		.record_command(
+			&format!("conn-{conn_id}"),
+			&topic,
+			"search_chat_log",
+			query.user.as_deref(),
+			None,
+		)
+		.await
+	{
+		metrics::counter!("chatty_server_command_audit_failures_total").increment(1);
+		warn!(conn_id, error = %e, "failed to persist command audit");
+	}
+
+	match chat_log.search(&query).await {
+		Ok(entries) => {
+			let next_before_id = if entries.len() == limit {
+				entries.last().map(|e| e.id).unwrap_or(0)
+			} else {
+				0
+			};
+			debug!(conn_id, topic = %topic, entries = entries.len(), next_before_id, "served chat log search");
+			metrics::counter!("chatty_server_commands_ok_total").increment(1);
+			pb::CommandResult {
+				status: pb::command_result::Status::Ok as i32,
+				detail: format!("{} entries", entries.len()),
+				search: Some(pb::SearchChatLogResult { entries, next_before_id }),
+				..Default::default()
 			}
 		}
-		Err(CommandError::Internal(_)) => {
+		Err(e) => {
 			metrics::counter!("chatty_server_commands_internal_error_total").increment(1);
+			warn!(conn_id, topic = %topic, error = %e, "failed to search chat log");
 			pb::CommandResult {
 				status: pb::command_result::Status::InternalError as i32,
 				detail: "internal error".to_string(),
+				..Default::default()
 			}
 		}
 	}
 }
 
-async fn send_envelope(send: &mut quinn::SendStream, env: pb::Envelope) -> anyhow::Result<()> {
-	let frame = encode_frame(&env, DEFAULT_MAX_FRAME_SIZE).map_err(|e| anyhow!(e))?;
+async fn send_envelope(send: &mut quinn::SendStream, codec: Codec, env: pb::Envelope) -> anyhow::Result<()> {
+	let frame = encode_frame_with_codec(codec, &env, DEFAULT_MAX_FRAME_SIZE).map_err(|e| anyhow!(e))?;
 	metrics::counter!("chatty_server_envelopes_out_total").increment(1);
 	metrics::counter!("chatty_server_control_bytes_out_total").increment(frame.len() as u64);
 
@@ -1882,11 +2707,12 @@ async fn handle_subscribe(
 	conn_id: u64,
 	state: &Arc<RwLock<GlobalState>>,
 	sub: pb::Subscribe,
+	claims: Option<&AuthClaims>,
 ) -> (Vec<pb::SubscriptionResult>, Vec<String>) {
 	metrics::counter!("chatty_server_subscribe_requests_total").increment(1);
 	metrics::counter!("chatty_server_subscribe_topics_total").increment(sub.subs.len() as u64);
 	let mut st = state.write().await;
-	st.handle_subscribe(conn_id, sub)
+	st.handle_subscribe(conn_id, sub, claims)
 }
 
 async fn handle_unsubscribe(
@@ -0,0 +1,172 @@
+#![forbid(unsafe_code)]
+
+use std::time::{Duration, SystemTime};
+
+use chatty_domain::{Platform, RoomId, RoomKey};
+use chatty_platform::{
+	AdapterStatus, ModerationAction, ModerationEvent, Progress, ProgressKind, Redemption, RedemptionStatus, UserNotice,
+	UserRef,
+};
+use chatty_protocol::pb;
+
+use crate::server::connection::{
+	map_adapter_status_event, map_moderation_event, map_progress_event, map_redemption_event, map_user_notice_event,
+};
+
+fn room(id: &str) -> RoomKey {
+	RoomKey::new(Platform::Twitch, RoomId::new(id.to_string()).expect("valid RoomId"))
+}
+
+fn user(id: &str) -> UserRef {
+	UserRef {
+		id: id.to_string(),
+		login: format!("{id}_login"),
+		display: None,
+	}
+}
+
+#[test]
+fn moderation_timeout_maps_expiry_and_users() {
+	let expires_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+	let ev = ModerationEvent {
+		kind: "timeout".to_string(),
+		actor: Some(user("mod")),
+		target: Some(user("target")),
+		target_message_platform_id: None,
+		notes: None,
+		action: Some(ModerationAction::Timeout {
+			duration_seconds: Some(600),
+			expires_at: Some(expires_at),
+			reason: Some("spam".to_string()),
+		}),
+	};
+
+	let out = map_moderation_event(&room("chan"), ev);
+	assert_eq!(out.kind, "timeout");
+	assert_eq!(out.origin.as_ref().map(|o| o.channel.as_str()), Some("chan"));
+	assert_eq!(out.actor.as_ref().map(|u| u.id.as_str()), Some("mod"));
+	assert_eq!(out.target.as_ref().map(|u| u.login.as_str()), Some("target_login"));
+	assert!(out.target_message_platform_id.is_empty());
+
+	let Some(pb::moderation_action::Action::Timeout(t)) = out.action.and_then(|a| a.action) else {
+		panic!("expected timeout action");
+	};
+	assert_eq!(t.duration_seconds, Some(600));
+	assert_eq!(t.expires_at_unix_ms, Some(1_700_000_000_123));
+	assert_eq!(t.reason, "spam");
+}
+
+#[test]
+fn moderation_role_and_clear_actions_map_to_distinct_variants() {
+	let mk = |action| ModerationEvent {
+		kind: "x".to_string(),
+		actor: None,
+		target: None,
+		target_message_platform_id: None,
+		notes: None,
+		action: Some(action),
+	};
+
+	let vip = map_moderation_event(&room("chan"), mk(ModerationAction::VipAdd { user: user("v") }));
+	assert!(matches!(
+		vip.action.and_then(|a| a.action),
+		Some(pb::moderation_action::Action::VipAdd(pb::moderation_action::RoleChange { user: Some(u) })) if u.id == "v"
+	));
+
+	let clear = map_moderation_event(&room("chan"), mk(ModerationAction::ClearChat {}));
+	assert!(matches!(
+		clear.action.and_then(|a| a.action),
+		Some(pb::moderation_action::Action::ClearChat(_))
+	));
+}
+
+#[test]
+fn user_notice_maps_optional_fields() {
+	let notice = UserNotice {
+		kind: "raid".to_string(),
+		text: Some("Raid: someone -> viewers=5".to_string()),
+		user: Some(user("raider")),
+	};
+
+	let out = map_user_notice_event(&room("chan"), notice);
+	assert_eq!(out.kind, "raid");
+	assert_eq!(out.text, "Raid: someone -> viewers=5");
+	assert_eq!(out.user.map(|u| u.id), Some("raider".to_string()));
+
+	let empty = map_user_notice_event(
+		&room("chan"),
+		UserNotice {
+			kind: "subscribe".to_string(),
+			text: None,
+			user: None,
+		},
+	);
+	assert!(empty.text.is_empty());
+	assert!(empty.user.is_none());
+}
+
+#[test]
+fn adapter_status_maps_platform_and_error() {
+	let out = map_adapter_status_event(AdapterStatus {
+		platform: Platform::Kick,
+		connected: false,
+		detail: "kick ws read error".to_string(),
+		last_error: Some("connection reset".to_string()),
+		time: SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000),
+	});
+	assert_eq!(out.platform, pb::Platform::Kick as i32);
+	assert!(!out.connected);
+	assert_eq!(out.detail, "kick ws read error");
+	assert_eq!(out.last_error, "connection reset");
+	assert_eq!(out.time_unix_ms, 1_700_000_000_000);
+}
+
+#[test]
+fn redemption_maps_reward_user_and_status() {
+	let out = map_redemption_event(
+		&room("chan"),
+		Redemption {
+			id: "red-1".to_string(),
+			reward_id: "reward-1".to_string(),
+			reward_title: "Hydrate".to_string(),
+			cost: 250,
+			user: user("viewer"),
+			user_input: None,
+			status: RedemptionStatus::Fulfilled,
+			redeemed_at: Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_000)),
+		},
+	);
+	assert_eq!(out.redemption_id, "red-1");
+	assert_eq!(out.reward_id, "reward-1");
+	assert_eq!(out.reward_title, "Hydrate");
+	assert_eq!(out.cost, 250);
+	assert_eq!(out.user.as_ref().map(|u| u.id.as_str()), Some("viewer"));
+	assert!(out.user_input.is_empty());
+	assert_eq!(out.status, pb::redemption_event::Status::Fulfilled as i32);
+	assert_eq!(out.redeemed_at_unix_ms, Some(1_700_000_000_000));
+}
+
+#[test]
+fn progress_maps_kind_level_and_amounts() {
+	let out = map_progress_event(
+		&room("chan"),
+		Progress {
+			id: "train-1".to_string(),
+			kind: ProgressKind::HypeTrain,
+			level: Some(2),
+			title: None,
+			current: 300,
+			target: 1800,
+			currency: None,
+			expires_at: Some(SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_300_000)),
+			ended: false,
+		},
+	);
+	assert_eq!(out.progress_id, "train-1");
+	assert_eq!(out.kind, pb::progress_event::Kind::HypeTrain as i32);
+	assert_eq!(out.level, 2);
+	assert_eq!((out.current, out.target), (300, 1800));
+	assert!(out.title.is_empty() && out.currency.is_empty());
+	assert_eq!(out.expires_at_unix_ms, Some(1_700_000_300_000));
+	assert!(!out.ended);
+}
@@ -30,6 +30,11 @@ impl HealthState {
 		self.ready.store(true, Ordering::Relaxed);
 	}
 
+	/// Fail readiness checks so load balancers stop routing new clients here.
+	pub fn mark_not_ready(&self) {
+		self.ready.store(false, Ordering::Relaxed);
+	}
+
 	pub fn is_ready(&self) -> bool {
 		self.ready.load(Ordering::Relaxed)
 	}
@@ -3,18 +3,43 @@
 pub mod adapter_manager;
 pub mod audit;
 pub mod auth;
+pub mod chat_log;
 pub mod connection;
 pub mod health;
 pub mod replay;
+pub mod resume;
 pub mod room_hub;
 pub mod router;
+pub mod shutdown;
 pub mod state;
+pub mod tokens;
 
 #[cfg(test)]
 mod adapter_manager_tests;
 
+#[cfg(test)]
+mod audit_tests;
+
+#[cfg(test)]
+mod auth_tests;
+
+#[cfg(test)]
+mod chat_log_tests;
+
+#[cfg(test)]
+mod event_mapping_tests;
+
 #[cfg(test)]
 mod quic_demo_adapter_tests;
 
+#[cfg(test)]
+mod replay_tests;
+
+#[cfg(test)]
+mod resume_tests;
+
 #[cfg(test)]
 mod room_hub_tests;
+
+#[cfg(test)]
+mod tokens_tests;
@@ -5,8 +5,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context as _, anyhow};
-use chatty_client_core::{ClientConfigV1, SessionControl};
-use chatty_protocol::pb;
+use chatty_client_core::{ClientConfigV1, ClientCoreError, SessionControl};
+use chatty_protocol::{Codec, pb};
 use tokio::sync::{RwLock, mpsc, oneshot};
 use tracing::{debug, warn};
 
@@ -14,11 +14,15 @@ use crate::adapters::DemoAdapter;
 use crate::quic::config::QuicServerConfig;
 use crate::server::adapter_manager::{AdapterManagerConfig, start_global_adapter_manager};
 use crate::server::audit::AuditService;
-use crate::server::connection::{ConnectionSettings, handle_connection};
+use crate::server::chat_log::ChatLogService;
+use crate::server::connection::{ConnectionSettings, PROTOCOL_VERSION, handle_connection};
 use crate::server::replay::{ReplayService, ReplayStoreConfig};
+use crate::server::resume::ResumeRegistry;
 use crate::server::room_hub::{RoomHub, RoomHubConfig};
 use crate::server::router::{RouterConfig, spawn_ingest_router};
+use crate::server::shutdown::ShutdownSignal;
 use crate::server::state::GlobalState;
+use crate::server::tokens::TokenStore;
 
 fn init_rustls_crypto_provider() {
 	let _ = rustls::crypto::CryptoProvider::install_default(rustls::crypto::aws_lc_rs::default_provider());
@@ -29,12 +33,25 @@ async fn run_demo_server_with_cfg(
 	ready_tx: oneshot::Sender<SocketAddr>,
 	replay_cfg: ReplayStoreConfig,
 	max_connections: usize,
+	shutdown: ShutdownSignal,
+	resume: Arc<ResumeRegistry>,
+) -> anyhow::Result<()> {
+	let state = Arc::new(RwLock::new(GlobalState::default()));
+	run_demo_server_with_state(endpoint, ready_tx, replay_cfg, max_connections, shutdown, resume, state).await
+}
+
+async fn run_demo_server_with_state(
+	endpoint: quinn::Endpoint,
+	ready_tx: oneshot::Sender<SocketAddr>,
+	replay_cfg: ReplayStoreConfig,
+	max_connections: usize,
+	shutdown: ShutdownSignal,
+	resume: Arc<ResumeRegistry>,
+	state: Arc<RwLock<GlobalState>>,
 ) -> anyhow::Result<()> {
 	let local_addr = endpoint.local_addr().context("server local_addr")?;
 	let _ = ready_tx.send(local_addr);
 
-	let state = Arc::new(RwLock::new(GlobalState::default()));
-
 	let demo = DemoAdapter::new().with_emit_interval(Duration::from_millis(10));
 	let platform_adapters: Vec<Box<dyn chatty_platform::PlatformAdapter>> = vec![Box::new(demo)];
 
@@ -44,15 +61,23 @@ async fn run_demo_server_with_cfg(
 		platform_adapters,
 	));
 	let room_hub = RoomHub::new(RoomHubConfig::default());
-	let _router = spawn_ingest_router(Arc::clone(&adapter_manager), room_hub.clone(), RouterConfig::default());
+	let replay_service = Arc::new(ReplayService::new_in_memory(replay_cfg.clone()));
+	let chat_log = Arc::new(ChatLogService::disabled());
+	let _router = spawn_ingest_router(
+		Arc::clone(&adapter_manager),
+		room_hub.clone(),
+		Arc::clone(&replay_service),
+		Arc::clone(&chat_log),
+		RouterConfig::default(),
+	);
 
 	let settings = ConnectionSettings {
 		auth_token: None,
 		..ConnectionSettings::default()
 	};
 
-	let replay_service = Arc::new(ReplayService::new_in_memory(replay_cfg.clone()));
 	let audit_service = Arc::new(AuditService::disabled());
+	let token_store = Arc::new(TokenStore::disabled());
 
 	let mut handles = Vec::with_capacity(max_connections);
 
@@ -71,6 +96,10 @@ async fn run_demo_server_with_cfg(
 		let replay_service = Arc::clone(&replay_service);
 		let settings = settings.clone();
 		let audit_service = Arc::clone(&audit_service);
+		let token_store = Arc::clone(&token_store);
+		let chat_log = Arc::clone(&chat_log);
+		let shutdown = shutdown.clone();
+		let resume = Arc::clone(&resume);
 
 		handles.push((
 			conn_id,
@@ -84,6 +113,10 @@ async fn run_demo_server_with_cfg(
 					room_hub,
 					replay_service,
 					audit_service,
+					token_store,
+					chat_log,
+					shutdown,
+					resume,
 					settings,
 				)
 				.await
@@ -115,7 +148,45 @@ async fn run_demo_server_with_cfg(
 }
 
 async fn run_demo_server(endpoint: quinn::Endpoint, ready_tx: oneshot::Sender<SocketAddr>) -> anyhow::Result<()> {
-	run_demo_server_with_cfg(endpoint, ready_tx, ReplayStoreConfig::default(), 1).await
+	run_demo_server_with_cfg(
+		endpoint,
+		ready_tx,
+		ReplayStoreConfig::default(),
+		1,
+		ShutdownSignal::new(),
+		disabled_resume(),
+	)
+	.await
+}
+
+fn disabled_resume() -> Arc<ResumeRegistry> {
+	Arc::new(ResumeRegistry::new(Duration::ZERO))
+}
+
+/// Raw QUIC connection trusting the dev certificate, for tests that need to misbehave below `SessionControl`.
+async fn connect_raw(server_addr: SocketAddr, cert_der: Vec<u8>) -> anyhow::Result<quinn::Connection> {
+	connect_raw_with_codec(server_addr, cert_der, Codec::Protobuf).await
+}
+
+/// Raw QUIC connection offering only the ALPN protocol of `codec`.
+async fn connect_raw_with_codec(
+	server_addr: SocketAddr,
+	cert_der: Vec<u8>,
+	codec: Codec,
+) -> anyhow::Result<quinn::Connection> {
+	let mut roots = rustls::RootCertStore::empty();
+	roots
+		.add(rustls::pki_types::CertificateDer::from(cert_der))
+		.context("trust dev certificate")?;
+	let mut tls = rustls::ClientConfig::builder()
+		.with_root_certificates(roots)
+		.with_no_client_auth();
+	tls.alpn_protocols = vec![codec.alpn().to_vec()];
+	let quic_tls = quinn::crypto::rustls::QuicClientConfig::try_from(tls).context("quic client tls")?;
+
+	let mut endpoint = quinn::Endpoint::client("127.0.0.1:0".parse()?).context("client endpoint")?;
+	endpoint.set_default_client_config(quinn::ClientConfig::new(Arc::new(quic_tls)));
+	endpoint.connect(server_addr, "localhost")?.await.context("raw quic connect")
 }
 
 fn client_cfg(server_addr: SocketAddr, instance_id: &str) -> ClientConfigV1 {
@@ -126,6 +197,7 @@ fn client_cfg(server_addr: SocketAddr, instance_id: &str) -> ClientConfigV1 {
 		client_name: "chatty-test-client".to_string(),
 		client_instance_id: instance_id.to_string(),
 		user_oauth_token: None,
+		tls_insecure_skip_verify: true,
 		..ClientConfigV1::default()
 	}
 }
@@ -189,6 +261,73 @@ async fn demo_adapter_end_to_end_event_flow() -> anyhow::Result<()> {
 	Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cbor_codec_end_to_end_event_flow() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let quic_cfg = QuicServerConfig::dev(bind_addr);
+	let (endpoint, _cert_der) = quic_cfg.bind_dev_endpoint()?;
+
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move { run_demo_server(endpoint, ready_tx).await });
+
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let cfg = ClientConfigV1 {
+		codec: Codec::Cbor,
+		..client_cfg(server_addr, "cbor-codec-test")
+	};
+
+	let (mut control, welcome) = SessionControl::connect(cfg).await.context("client connect")?;
+	assert_eq!(welcome.selected_codec, pb::Codec::Cbor as i32);
+
+	let pong = control.ping(42).await.context("ping")?;
+	assert_eq!(pong.client_time_unix_ms, 42);
+
+	let topic = "room:twitch/demo".to_string();
+	let _ = control.subscribe(vec![topic.clone()]).await.context("subscribe")?;
+
+	let mut events = control.open_events_stream().await.context("open events stream")?;
+
+	let (ev_tx, mut ev_rx) = mpsc::channel::<chatty_protocol::pb::EventEnvelope>(8);
+	let events_task = tokio::spawn(async move {
+		events
+			.run_events_loop(|ev| {
+				let _ = ev_tx.try_send(ev);
+			})
+			.await
+	});
+
+	let mut matched = false;
+	let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+	while !matched {
+		let timeout = deadline.saturating_duration_since(tokio::time::Instant::now());
+		let ev = tokio::time::timeout(timeout, ev_rx.recv())
+			.await
+			.context("timeout waiting for event")?
+			.context("events channel closed")?;
+
+		assert_eq!(ev.topic, topic);
+		if let Some(chatty_protocol::pb::event_envelope::Event::ChatMessage(cm)) = ev.event {
+			let msg = cm.message.expect("chat message is present");
+			assert!(msg.text.contains("demo ingest message"));
+			matched = true;
+		}
+	}
+
+	events_task.abort();
+	let _ = events_task.await;
+
+	let server_res = server_task.await.context("server join")?;
+	server_res.context("server run")?;
+
+	Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn reconnect_resumes_with_replay() -> anyhow::Result<()> {
 	init_rustls_crypto_provider();
@@ -198,8 +337,17 @@ async fn reconnect_resumes_with_replay() -> anyhow::Result<()> {
 	let (endpoint, _cert_der) = quic_cfg.bind_dev_endpoint()?;
 
 	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
-	let server_task =
-		tokio::spawn(async move { run_demo_server_with_cfg(endpoint, ready_tx, ReplayStoreConfig::default(), 2).await });
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_cfg(
+			endpoint,
+			ready_tx,
+			ReplayStoreConfig::default(),
+			2,
+			ShutdownSignal::new(),
+			disabled_resume(),
+		)
+		.await
+	});
 
 	let mut server_addr = ready_rx.await.context("server ready")?;
 	if server_addr.ip().is_unspecified() {
@@ -275,6 +423,127 @@ async fn reconnect_resumes_with_replay() -> anyhow::Result<()> {
 	Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_token_restores_subscriptions_and_replays_missed_events() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let quic_cfg = QuicServerConfig::dev(bind_addr);
+	let (endpoint, _cert_der) = quic_cfg.bind_dev_endpoint()?;
+
+	let resume = Arc::new(ResumeRegistry::new(Duration::from_secs(30)));
+	let server_resume = Arc::clone(&resume);
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_cfg(
+			endpoint,
+			ready_tx,
+			ReplayStoreConfig::default(),
+			2,
+			ShutdownSignal::new(),
+			server_resume,
+		)
+		.await
+	});
+
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let cfg = client_cfg(server_addr, "session-resume-test");
+	let topic = "room:twitch/demo".to_string();
+
+	let (mut control, welcome) = SessionControl::connect(cfg.clone()).await.context("client connect")?;
+	assert!(!welcome.resumed);
+	let resume_token = control.resume_token().context("resume token in Welcome")?.to_string();
+	let _ = control.subscribe(vec![topic.clone()]).await.context("subscribe")?;
+	let mut events = control.open_events_stream().await.context("open events stream")?;
+
+	let (ev_tx, mut ev_rx) = mpsc::channel::<pb::EventEnvelope>(32);
+	let events_task = tokio::spawn(async move {
+		events
+			.run_events_loop(|ev| {
+				let _ = ev_tx.try_send(ev);
+			})
+			.await
+	});
+
+	let mut last_cursor = 0;
+	while last_cursor < 3 {
+		let ev = tokio::time::timeout(Duration::from_secs(5), ev_rx.recv())
+			.await
+			.context("timeout waiting for event")?
+			.context("events channel closed")?;
+		last_cursor = last_cursor.max(ev.cursor);
+	}
+
+	events_task.abort();
+	let _ = events_task.await;
+	control.close(0, "test disconnect");
+	drop(control);
+
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while resume.parked_len() == 0 {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.context("session was not parked")?;
+	// Let the demo adapter emit events the client misses while disconnected.
+	tokio::time::sleep(Duration::from_millis(100)).await;
+
+	let cfg = ClientConfigV1 {
+		resume_token: Some(resume_token.clone()),
+		..cfg
+	};
+	let (mut control, welcome) = SessionControl::connect(cfg).await.context("client resume")?;
+	assert!(welcome.resumed);
+	assert_eq!(control.resumed_topics(), std::slice::from_ref(&topic));
+	assert!(
+		control.resume_token().is_some_and(|t| t != resume_token),
+		"resume tokens are single use"
+	);
+	assert_eq!(resume.parked_len(), 0);
+
+	// No Subscribe: the events stream carries the replay straight away.
+	let mut events = control.open_events_stream().await.context("open events stream (resume)")?;
+	let (ev_tx, mut ev_rx) = mpsc::channel::<pb::EventEnvelope>(64);
+	let events_task = tokio::spawn(async move {
+		events
+			.run_events_loop(|ev| {
+				let _ = ev_tx.try_send(ev);
+			})
+			.await
+	});
+
+	let mut cursors = Vec::new();
+	while cursors.len() < 5 {
+		let ev = tokio::time::timeout(Duration::from_secs(5), ev_rx.recv())
+			.await
+			.context("timeout waiting for resumed event")?
+			.context("events channel closed")?;
+		if ev.cursor > 0 {
+			cursors.push(ev.cursor);
+		}
+	}
+	assert!(cursors[0] > last_cursor, "replay starts after what was delivered");
+	assert!(
+		cursors.windows(2).all(|w| w[1] == w[0] + 1),
+		"missed events are replayed without gaps: {cursors:?}"
+	);
+
+	events_task.abort();
+	let _ = events_task.await;
+	control.close(0, "test done");
+	drop(control);
+
+	let server_res = server_task.await.context("server join")?;
+	server_res.context("server run")?;
+
+	Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn reconnect_reports_lagged_when_replay_exhausted() -> anyhow::Result<()> {
 	init_rustls_crypto_provider();
@@ -288,7 +557,9 @@ async fn reconnect_reports_lagged_when_replay_exhausted() -> anyhow::Result<()>
 		retention_secs: None,
 	};
 	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
-	let server_task = tokio::spawn(async move { run_demo_server_with_cfg(endpoint, ready_tx, replay_cfg, 2).await });
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_cfg(endpoint, ready_tx, replay_cfg, 2, ShutdownSignal::new(), disabled_resume()).await
+	});
 
 	let mut server_addr = ready_rx.await.context("server ready")?;
 	if server_addr.ip().is_unspecified() {
@@ -364,3 +635,315 @@ async fn reconnect_reports_lagged_when_replay_exhausted() -> anyhow::Result<()>
 
 	Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn shutdown_notice_ends_client_events_loop() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let quic_cfg = QuicServerConfig::dev(bind_addr);
+	let (endpoint, _cert_der) = quic_cfg.bind_dev_endpoint()?;
+
+	let shutdown = ShutdownSignal::new();
+	let server_shutdown = shutdown.clone();
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_cfg(
+			endpoint,
+			ready_tx,
+			ReplayStoreConfig::default(),
+			1,
+			server_shutdown,
+			disabled_resume(),
+		)
+		.await
+	});
+
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let (mut control, _welcome) = SessionControl::connect(client_cfg(server_addr, "shutdown-test"))
+		.await
+		.context("client connect")?;
+	let _ = control
+		.subscribe(vec!["room:twitch/demo".to_string()])
+		.await
+		.context("subscribe")?;
+	let mut events = control.open_events_stream().await.context("open events stream")?;
+
+	let (ev_tx, mut ev_rx) = mpsc::channel::<pb::EventEnvelope>(32);
+	let events_task = tokio::spawn(async move {
+		events
+			.run_events_loop(|ev| {
+				let _ = ev_tx.try_send(ev);
+			})
+			.await
+	});
+
+	tokio::time::timeout(Duration::from_secs(5), ev_rx.recv())
+		.await
+		.context("timeout waiting for event")?
+		.context("events channel closed")?;
+
+	shutdown.trigger(Duration::from_millis(250));
+	let res = tokio::time::timeout(Duration::from_secs(5), events_task)
+		.await
+		.context("timeout waiting for shutdown notice")?
+		.context("events task join")?;
+	match res {
+		Err(ClientCoreError::ServerShuttingDown { reconnect_after }) => {
+			assert_eq!(reconnect_after, Duration::from_millis(250));
+		}
+		other => panic!("expected shutdown notice, got {other:?}"),
+	}
+
+	control.close(0, "test done");
+	drop(control);
+
+	let server_res = server_task.await.context("server join")?;
+	server_res.context("server run")?;
+
+	Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn commands_without_user_session_are_not_authorized() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let quic_cfg = QuicServerConfig::dev(bind_addr);
+	let (endpoint, _cert_der) = quic_cfg.bind_dev_endpoint()?;
+
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move { run_demo_server(endpoint, ready_tx).await });
+
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let (mut control, _welcome) = SessionControl::connect(client_cfg(server_addr, "sessionless-test"))
+		.await
+		.context("client connect")?;
+
+	let topic = "room:twitch/demo".to_string();
+	let commands = [
+		pb::command::Command::SendChat(pb::SendChatCommand {
+			topic: topic.clone(),
+			text: "hello".to_string(),
+			..Default::default()
+		}),
+		pb::command::Command::CreatePoll(pb::CreatePollCommand {
+			topic: topic.clone(),
+			title: "poll".to_string(),
+			choices: vec!["a".to_string(), "b".to_string()],
+			duration_seconds: 60,
+		}),
+		pb::command::Command::FulfillRedemption(pb::FulfillRedemptionCommand {
+			topic: topic.clone(),
+			reward_id: "reward".to_string(),
+			redemption_id: "redemption".to_string(),
+		}),
+	];
+	for command in commands {
+		let result = control
+			.send_command(pb::Command { command: Some(command) })
+			.await
+			.context("send command")?;
+		assert_eq!(result.status, pb::command_result::Status::NotAuthorized as i32);
+		assert_eq!(result.detail, "no linked twitch account for this connection");
+	}
+
+	control.close(0, "test done");
+	drop(control);
+
+	let server_res = server_task.await.context("server join")?;
+	server_res.context("server run")?;
+
+	Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn failed_welcome_on_resume_releases_claimed_subscriptions() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let quic_cfg = QuicServerConfig::dev(bind_addr);
+	let (endpoint, cert_der) = quic_cfg.bind_dev_endpoint()?;
+
+	let resume = Arc::new(ResumeRegistry::new(Duration::from_secs(30)));
+	let state = Arc::new(RwLock::new(GlobalState::default()));
+	let server_resume = Arc::clone(&resume);
+	let server_state = Arc::clone(&state);
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_state(
+			endpoint,
+			ready_tx,
+			ReplayStoreConfig::default(),
+			2,
+			ShutdownSignal::new(),
+			server_resume,
+			server_state,
+		)
+		.await
+	});
+
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let cfg = client_cfg(server_addr, "resume-welcome-failure-test");
+	let topic = "room:twitch/demo".to_string();
+	let (mut control, _welcome) = SessionControl::connect(cfg.clone()).await.context("client connect")?;
+	let resume_token = control.resume_token().context("resume token in Welcome")?.to_string();
+	let _ = control.subscribe(vec![topic.clone()]).await.context("subscribe")?;
+	let mut events = control.open_events_stream().await.context("open events stream")?;
+	let (ev_tx, mut ev_rx) = mpsc::channel::<pb::EventEnvelope>(8);
+	let events_task = tokio::spawn(async move {
+		events
+			.run_events_loop(|ev| {
+				let _ = ev_tx.try_send(ev);
+			})
+			.await
+	});
+	tokio::time::timeout(Duration::from_secs(5), ev_rx.recv())
+		.await
+		.context("timeout waiting for event")?
+		.context("events channel closed")?;
+	events_task.abort();
+	let _ = events_task.await;
+	control.close(0, "test disconnect");
+	drop(control);
+
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while resume.parked_len() == 0 {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.context("session was not parked")?;
+	assert_eq!(state.read().await.topic_refcounts_snapshot().get(&topic), Some(&1));
+
+	// Claim the parked session, then refuse the Welcome so the server's write fails after the claim.
+	let connection = connect_raw(server_addr, cert_der).await?;
+	let (mut send, mut recv) = connection.open_bi().await.context("open control stream")?;
+	let hello = pb::Envelope {
+		version: PROTOCOL_VERSION,
+		request_id: String::new(),
+		msg: Some(pb::envelope::Msg::Hello(pb::Hello {
+			client_name: cfg.client_name.clone(),
+			client_instance_id: cfg.client_instance_id.clone(),
+			resume_token,
+			..Default::default()
+		})),
+	};
+	let frame = chatty_protocol::encode_frame(&hello, chatty_protocol::DEFAULT_MAX_FRAME_SIZE)?;
+	send.write_all(&frame).await.context("write Hello")?;
+	recv.stop(0u32.into()).context("stop control recv")?;
+
+	let server_res = tokio::time::timeout(Duration::from_secs(10), server_task)
+		.await
+		.context("timeout waiting for server")?
+		.context("server join")?;
+	let err = server_res.expect_err("resumed connection fails to send Welcome");
+	assert!(format!("{err:#}").contains("send Welcome"), "unexpected error: {err:#}");
+	assert_eq!(resume.parked_len(), 0, "the parked session was claimed");
+	connection.close(0u32.into(), b"test done");
+
+	tokio::time::timeout(Duration::from_secs(5), async {
+		while !state.read().await.topic_refcounts_snapshot().is_empty() {
+			tokio::time::sleep(Duration::from_millis(10)).await;
+		}
+	})
+	.await
+	.context("claimed subscriptions were leaked")?;
+
+	Ok(())
+}
+
+async fn read_raw_envelope(recv: &mut quinn::RecvStream, codec: Codec) -> anyhow::Result<pb::Envelope> {
+	let mut buf = Vec::new();
+	let mut tmp = [0u8; 4096];
+	loop {
+		match chatty_protocol::decode_frame_with_codec::<pb::Envelope>(codec, &buf, chatty_protocol::DEFAULT_MAX_FRAME_SIZE)
+		{
+			Ok((env, _)) => return Ok(env),
+			Err(chatty_protocol::FramingError::InsufficientData { .. }) => {}
+			Err(e) => return Err(anyhow!(e).context("decode envelope")),
+		}
+		let n = recv.read(&mut tmp).await?.context("stream closed before a full frame")?;
+		buf.extend_from_slice(&tmp[..n]);
+	}
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn cbor_alpn_carries_hello_welcome_and_handshake_errors_as_cbor() -> anyhow::Result<()> {
+	init_rustls_crypto_provider();
+
+	let bind_addr: SocketAddr = "127.0.0.1:0".parse().context("parse bind addr")?;
+	let (endpoint, cert_der) = QuicServerConfig::dev(bind_addr).bind_dev_endpoint()?;
+	let (ready_tx, ready_rx) = oneshot::channel::<SocketAddr>();
+	let server_task = tokio::spawn(async move {
+		run_demo_server_with_cfg(
+			endpoint,
+			ready_tx,
+			ReplayStoreConfig::default(),
+			2,
+			ShutdownSignal::new(),
+			disabled_resume(),
+		)
+		.await
+	});
+	let mut server_addr = ready_rx.await.context("server ready")?;
+	if server_addr.ip().is_unspecified() {
+		server_addr.set_ip(std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1)));
+	}
+
+	let hello = |supported: pb::Codec| pb::Envelope {
+		version: PROTOCOL_VERSION,
+		request_id: String::new(),
+		msg: Some(pb::envelope::Msg::Hello(pb::Hello {
+			client_name: "cbor-raw-client".to_string(),
+			supported_codecs: vec![supported as i32],
+			..Default::default()
+		})),
+	};
+
+	let connection = connect_raw_with_codec(server_addr, cert_der.clone(), Codec::Cbor).await?;
+	let (mut send, mut recv) = connection.open_bi().await.context("open control stream")?;
+	let frame = chatty_protocol::encode_frame_with_codec(
+		Codec::Cbor,
+		&hello(pb::Codec::Cbor),
+		chatty_protocol::DEFAULT_MAX_FRAME_SIZE,
+	)?;
+	send.write_all(&frame).await.context("write Hello")?;
+	let welcome = read_raw_envelope(&mut recv, Codec::Cbor).await?;
+	let Some(pb::envelope::Msg::Welcome(welcome)) = welcome.msg else {
+		panic!("expected Welcome, got {:?}", welcome.msg);
+	};
+	assert_eq!(welcome.selected_codec, pb::Codec::Cbor as i32);
+	connection.close(0u32.into(), b"test done");
+
+	// The ALPN protocol selected CBOR, so a Hello offering only protobuf is refused in CBOR.
+	let connection = connect_raw_with_codec(server_addr, cert_der, Codec::Cbor).await?;
+	let (mut send, mut recv) = connection.open_bi().await.context("open control stream")?;
+	let frame = chatty_protocol::encode_frame_with_codec(
+		Codec::Cbor,
+		&hello(pb::Codec::Protobuf),
+		chatty_protocol::DEFAULT_MAX_FRAME_SIZE,
+	)?;
+	send.write_all(&frame).await.context("write Hello")?;
+	let error = read_raw_envelope(&mut recv, Codec::Cbor).await?;
+	let Some(pb::envelope::Msg::Error(error)) = error.msg else {
+		panic!("expected Error, got {:?}", error.msg);
+	};
+	assert_eq!(error.code, "UNSUPPORTED_CODEC");
+	connection.close(0u32.into(), b"test done");
+
+	server_task.abort();
+	Ok(())
+}
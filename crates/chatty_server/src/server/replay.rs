@@ -2,11 +2,13 @@
 
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Context, anyhow};
 use chatty_protocol::pb;
 use prost::Message;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, mpsc, oneshot};
+use tracing::warn;
 
 #[derive(Debug, Clone)]
 pub struct ReplayStoreConfig {
@@ -30,30 +32,32 @@ pub struct ReplayOutcome {
 	pub items: Vec<pb::EventEnvelope>,
 }
 
+/// One page of older events from a topic log, ordered oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryPage {
+	pub items: Vec<pb::EventEnvelope>,
+	/// Cursor to request the next (older) page with; 0 once the retained log is exhausted.
+	pub next_before_cursor: u64,
+}
+
+/// Shared replay log, one buffer per topic (cursors are assigned once per topic, not per client).
 #[derive(Debug, Default)]
 pub struct ReplayStore {
-	clients: HashMap<String, ClientReplay>,
+	topics: HashMap<String, TopicReplay>,
 }
 
 #[derive(Debug, Default)]
-struct ClientReplay {
-	current_cursor_by_topic: HashMap<String, u64>,
-	buffer_by_topic: HashMap<String, VecDeque<pb::EventEnvelope>>,
+struct TopicReplay {
+	current_cursor: u64,
+	buffer: VecDeque<pb::EventEnvelope>,
 }
 
 impl ReplayStore {
-	pub fn push_event(
-		&mut self,
-		client_id: &str,
-		topic: &str,
-		mut env: pb::EventEnvelope,
-		cfg: &ReplayStoreConfig,
-	) -> pb::EventEnvelope {
-		let client = self.clients.entry(client_id.to_string()).or_default();
-		let cursor = client.current_cursor_by_topic.entry(topic.to_string()).or_insert(0);
-		*cursor = cursor.saturating_add(1);
-		env.cursor = *cursor;
-		let buf = client.buffer_by_topic.entry(topic.to_string()).or_default();
+	pub fn push_event(&mut self, topic: &str, mut env: pb::EventEnvelope, cfg: &ReplayStoreConfig) -> pb::EventEnvelope {
+		let entry = self.topics.entry(topic.to_string()).or_default();
+		entry.current_cursor = entry.current_cursor.saturating_add(1);
+		env.cursor = entry.current_cursor;
+		let buf = &mut entry.buffer;
 		buf.push_back(env.clone());
 
 		if let Some(retention) = cfg.retention_secs {
@@ -84,61 +88,98 @@ impl ReplayStore {
 		env
 	}
 
-	pub fn replay(&self, client_id: &str, topic: &str, last_cursor: u64) -> ReplayOutcome {
-		let client = match self.clients.get(client_id) {
-			Some(c) => c,
-			None => {
-				return ReplayOutcome {
-					status: pb::subscription_result::Status::Ok,
-					current_cursor: 0,
-					items: Vec::new(),
-				};
-			}
-		};
-
-		let current_cursor = client.current_cursor_by_topic.get(topic).copied().unwrap_or(0);
-		let Some(buf) = client.buffer_by_topic.get(topic) else {
-			let status = if last_cursor == 0 {
-				pb::subscription_result::Status::Ok
-			} else {
-				pb::subscription_result::Status::ReplayNotAvailable
-			};
+	pub fn replay(&self, topic: &str, last_cursor: u64) -> ReplayOutcome {
+		let Some(entry) = self.topics.get(topic) else {
 			return ReplayOutcome {
-				status,
-				current_cursor,
+				status: replay_status(last_cursor, 0, None),
+				current_cursor: 0,
 				items: Vec::new(),
 			};
 		};
 
-		let oldest_cursor = buf.front().map(|e| e.cursor).unwrap_or(0);
-		if last_cursor > 0 && last_cursor < oldest_cursor {
-			return ReplayOutcome {
-				status: pb::subscription_result::Status::ReplayNotAvailable,
-				current_cursor,
-				items: Vec::new(),
-			};
-		}
+		let oldest_cursor = entry.buffer.front().map(|e| e.cursor);
+		let status = replay_status(last_cursor, entry.current_cursor, oldest_cursor);
+		let items = if status == pb::subscription_result::Status::Ok && last_cursor > 0 {
+			entry.buffer.iter().filter(|e| e.cursor > last_cursor).cloned().collect()
+		} else {
+			Vec::new()
+		};
 
-		let items = buf.iter().filter(|e| e.cursor > last_cursor).cloned().collect::<Vec<_>>();
 		ReplayOutcome {
-			status: pb::subscription_result::Status::Ok,
-			current_cursor,
+			status,
+			current_cursor: entry.current_cursor,
 			items,
 		}
 	}
+
+	pub fn history(&self, topic: &str, before_cursor: u64, limit: usize) -> HistoryPage {
+		let Some(entry) = self.topics.get(topic) else {
+			return HistoryPage::default();
+		};
+
+		let mut items: Vec<pb::EventEnvelope> = entry
+			.buffer
+			.iter()
+			.rev()
+			.filter(|e| before_cursor == 0 || e.cursor < before_cursor)
+			.take(limit)
+			.cloned()
+			.collect();
+		items.reverse();
+		history_page(items, limit)
+	}
 }
 
+/// Build a history page from events ordered oldest first.
+///
+/// A short page means the retained log has nothing older, so no further page is offered.
+fn history_page(items: Vec<pb::EventEnvelope>, limit: usize) -> HistoryPage {
+	let next_before_cursor = if items.len() < limit {
+		0
+	} else {
+		items.first().map(|e| e.cursor).filter(|c| *c > 1).unwrap_or(0)
+	};
+	HistoryPage {
+		items,
+		next_before_cursor,
+	}
+}
+
+/// Replay status for a resume request against a topic log.
+///
+/// `last_cursor == 0` means "start live" and never replays. A cursor ahead of the log (e.g. after the
+/// log was reset) or older than the oldest retained event cannot be resumed.
+fn replay_status(last_cursor: u64, current_cursor: u64, oldest_cursor: Option<u64>) -> pb::subscription_result::Status {
+	if last_cursor == 0 || last_cursor == current_cursor {
+		return pb::subscription_result::Status::Ok;
+	}
+
+	if last_cursor > current_cursor {
+		return pb::subscription_result::Status::ReplayNotAvailable;
+	}
+
+	match oldest_cursor {
+		Some(oldest) if last_cursor.saturating_add(1) >= oldest => pb::subscription_result::Status::Ok,
+		_ => pb::subscription_result::Status::ReplayNotAvailable,
+	}
+}
+
+/// Storage for the shared per-topic replay log.
 #[async_trait::async_trait]
 pub trait ReplayBackend: Send + Sync {
+	/// Append an event to the topic log, assigning the next topic cursor.
 	async fn push_event(
 		&self,
-		client_id: &str,
 		topic: &str,
 		env: pb::EventEnvelope,
 		cfg: &ReplayStoreConfig,
 	) -> anyhow::Result<pb::EventEnvelope>;
 
-	async fn replay(&self, client_id: &str, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome>;
+	/// Events after `last_cursor` for a resuming subscriber.
+	async fn replay(&self, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome>;
+
+	/// Up to `limit` events older than `before_cursor` (0 = newest), ordered oldest first.
+	async fn history(&self, topic: &str, before_cursor: u64, limit: usize) -> anyhow::Result<HistoryPage>;
 }
 
 pub struct InMemoryReplayBackend {
@@ -157,25 +198,40 @@ impl Default for InMemoryReplayBackend {
 impl ReplayBackend for InMemoryReplayBackend {
 	async fn push_event(
 		&self,
-		client_id: &str,
 		topic: &str,
 		env: pb::EventEnvelope,
 		cfg: &ReplayStoreConfig,
 	) -> anyhow::Result<pb::EventEnvelope> {
 		let mut guard = self.inner.lock().await;
-		Ok(guard.push_event(client_id, topic, env, cfg))
+		Ok(guard.push_event(topic, env, cfg))
+	}
+
+	async fn replay(&self, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
+		let guard = self.inner.lock().await;
+		Ok(guard.replay(topic, last_cursor))
 	}
 
-	async fn replay(&self, client_id: &str, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
+	async fn history(&self, topic: &str, before_cursor: u64, limit: usize) -> anyhow::Result<HistoryPage> {
 		let guard = self.inner.lock().await;
-		Ok(guard.replay(client_id, topic, last_cursor))
+		Ok(guard.history(topic, before_cursor, limit))
 	}
 }
 
+/// Most queued messages the replay writer takes per round (one transaction).
+const REPLAY_WRITE_BATCH: usize = 256;
+/// Capacity of the queue between the ingest router and the replay writer.
+const REPLAY_WRITE_QUEUE: usize = 4096;
+/// How often the replay writer applies capacity and retention trims to topics it wrote to.
+const REPLAY_TRIM_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Database-backed replay log. Cursors are assigned in memory and events are written by a background task in
+/// batches, so appending never waits on the database; reads flush the queue first so they see every appended event.
 #[derive(Clone)]
 pub struct PersistentReplayBackend {
 	backend: PersistentBackend,
 	per_topic_capacity: usize,
+	cursors: Arc<std::sync::Mutex<HashMap<String, u64>>>,
+	writer: mpsc::Sender<ReplayWrite>,
 }
 
 #[derive(Clone)]
@@ -185,161 +241,246 @@ enum PersistentBackend {
 	Mysql(sqlx::MySqlPool),
 }
 
+enum ReplayWrite {
+	Event {
+		topic: String,
+		env: Box<pb::EventEnvelope>,
+		cfg: ReplayStoreConfig,
+	},
+	Flush(oneshot::Sender<()>),
+}
+
 impl PersistentReplayBackend {
 	pub async fn connect(database_url: &str, per_topic_capacity: usize) -> anyhow::Result<Self> {
-		if database_url.starts_with("sqlite:") {
+		let backend = if database_url.starts_with("sqlite:") {
 			let pool = sqlx::SqlitePool::connect(database_url).await.context("connect sqlite")?;
 			sqlx::migrate!("migrations/sqlite")
 				.run(&pool)
 				.await
 				.context("run sqlite migrations")?;
-
-			Ok(Self {
-				backend: PersistentBackend::Sqlite(pool),
-				per_topic_capacity,
-			})
+			PersistentBackend::Sqlite(pool)
 		} else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
 			let pool = sqlx::PgPool::connect(database_url).await.context("connect postgres")?;
 			sqlx::migrate!("migrations/postgres")
 				.run(&pool)
 				.await
 				.context("run postgres migrations")?;
-
-			Ok(Self {
-				backend: PersistentBackend::Postgres(pool),
-				per_topic_capacity,
-			})
+			PersistentBackend::Postgres(pool)
 		} else if database_url.starts_with("mysql:") || database_url.starts_with("mariadb:") {
 			let pool = sqlx::MySqlPool::connect(database_url).await.context("connect mysql")?;
 			sqlx::migrate!("migrations/mysql")
 				.run(&pool)
 				.await
 				.context("run mysql migrations")?;
-
-			Ok(Self {
-				backend: PersistentBackend::Mysql(pool),
-				per_topic_capacity,
-			})
+			PersistentBackend::Mysql(pool)
 		} else {
-			Err(anyhow!("unsupported database_url (use sqlite:, postgres:, mysql:)"))
+			return Err(anyhow!("unsupported database_url (use sqlite:, postgres:, mysql:)"));
+		};
+
+		let cursors = backend.load_cursors().await?;
+		let (writer, rx) = mpsc::channel(REPLAY_WRITE_QUEUE);
+		tokio::spawn(run_replay_writer(backend.clone(), rx));
+
+		Ok(Self {
+			backend,
+			per_topic_capacity,
+			cursors: Arc::new(std::sync::Mutex::new(cursors)),
+			writer,
+		})
+	}
+
+	/// Newest cursor handed out for `topic`, whether or not it has been persisted yet.
+	fn current_cursor(&self, topic: &str) -> u64 {
+		self.cursors.lock().expect("replay cursors").get(topic).copied().unwrap_or(0)
+	}
+
+	/// Wait until every event appended so far is written and trimmed.
+	async fn flush(&self) -> anyhow::Result<()> {
+		let (tx, rx) = oneshot::channel();
+		self.writer
+			.send(ReplayWrite::Flush(tx))
+			.await
+			.map_err(|_| anyhow!("replay writer stopped"))?;
+		rx.await.map_err(|_| anyhow!("replay writer stopped"))
+	}
+}
+
+/// Batches queued replay events into one transaction per round and trims the topics it touched, either
+/// periodically or when a reader flushes.
+async fn run_replay_writer(backend: PersistentBackend, mut rx: mpsc::Receiver<ReplayWrite>) {
+	let mut dirty: HashMap<String, (u64, ReplayStoreConfig)> = HashMap::new();
+	let mut trim_tick = tokio::time::interval(REPLAY_TRIM_INTERVAL);
+	let mut batch = Vec::with_capacity(REPLAY_WRITE_BATCH);
+
+	loop {
+		tokio::select! {
+			received = rx.recv_many(&mut batch, REPLAY_WRITE_BATCH) => {
+				if received == 0 {
+					break;
+				}
+				let mut events = Vec::with_capacity(batch.len());
+				let mut flushes = Vec::new();
+				for msg in batch.drain(..) {
+					match msg {
+						ReplayWrite::Event { topic, env, cfg } => events.push((topic, *env, cfg)),
+						ReplayWrite::Flush(done) => flushes.push(done),
+					}
+				}
+
+				if !events.is_empty() {
+					match backend.insert_batch(&events).await {
+						Ok(()) => {
+							for (topic, env, cfg) in events {
+								dirty.insert(topic, (env.cursor, cfg));
+							}
+						}
+						Err(e) => {
+							metrics::counter!("chatty_server_replay_write_failures_total").increment(events.len() as u64);
+							warn!(events = events.len(), error = %e, "failed to persist replay events");
+						}
+					}
+				}
+				if !flushes.is_empty() {
+					backend.trim_topics(&mut dirty).await;
+					for done in flushes {
+						let _ = done.send(());
+					}
+				}
+			}
+			_ = trim_tick.tick() => backend.trim_topics(&mut dirty).await,
 		}
 	}
+}
 
-	async fn next_cursor(&self, client_id: &str, topic: &str) -> anyhow::Result<u64> {
-		match &self.backend {
-			PersistentBackend::Sqlite(pool) => {
-				let mut tx = pool.begin().await.context("begin sqlite tx")?;
-				let row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = ? AND topic = ?")
-						.bind(client_id)
-						.bind(topic)
-						.fetch_optional(&mut *tx)
-						.await
-						.context("select cursor (sqlite)")?;
+impl PersistentBackend {
+	async fn load_cursors(&self) -> anyhow::Result<HashMap<String, u64>> {
+		const SQL: &str = "SELECT topic, cursor FROM replay_topic_cursors";
+		let rows: Vec<(String, i64)> = match self {
+			PersistentBackend::Sqlite(pool) => sqlx::query_as(SQL).fetch_all(pool).await.context("load cursors (sqlite)")?,
+			PersistentBackend::Postgres(pool) => {
+				sqlx::query_as(SQL).fetch_all(pool).await.context("load cursors (postgres)")?
+			}
+			PersistentBackend::Mysql(pool) => sqlx::query_as(SQL).fetch_all(pool).await.context("load cursors (mysql)")?,
+		};
+		Ok(rows.into_iter().map(|(topic, cursor)| (topic, cursor as u64)).collect())
+	}
 
-				let next = row.map(|(c,)| c as u64 + 1).unwrap_or(1);
-				sqlx::query(
-					"INSERT INTO replay_cursors (client_id, topic, cursor) VALUES (?, ?, ?) \
-					ON CONFLICT(client_id, topic) DO UPDATE SET cursor = excluded.cursor",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(next as i64)
-				.execute(&mut *tx)
-				.await
-				.context("upsert cursor (sqlite)")?;
+	/// Insert a batch of events and advance each topic's stored cursor in one transaction.
+	async fn insert_batch(&self, events: &[(String, pb::EventEnvelope, ReplayStoreConfig)]) -> anyhow::Result<()> {
+		let mut latest: HashMap<&str, u64> = HashMap::new();
+		for (topic, env, _) in events {
+			let cursor = latest.entry(topic.as_str()).or_default();
+			*cursor = (*cursor).max(env.cursor);
+		}
 
+		match self {
+			PersistentBackend::Sqlite(pool) => {
+				let mut tx = pool.begin().await.context("begin sqlite tx")?;
+				for (topic, env, _) in events {
+					sqlx::query(
+						"INSERT INTO replay_topic_events (topic, cursor, payload, created_at) VALUES (?, ?, ?, strftime('%s','now'))",
+					)
+					.bind(topic)
+					.bind(env.cursor as i64)
+					.bind(env.encode_to_vec())
+					.execute(&mut *tx)
+					.await
+					.context("insert replay event (sqlite)")?;
+				}
+				for (topic, cursor) in latest {
+					sqlx::query(
+						"INSERT INTO replay_topic_cursors (topic, cursor) VALUES (?, ?) \
+						ON CONFLICT(topic) DO UPDATE SET cursor = MAX(cursor, excluded.cursor)",
+					)
+					.bind(topic)
+					.bind(cursor as i64)
+					.execute(&mut *tx)
+					.await
+					.context("upsert cursor (sqlite)")?;
+				}
 				tx.commit().await.context("commit sqlite tx")?;
-				Ok(next)
 			}
 			PersistentBackend::Postgres(pool) => {
 				let mut tx = pool.begin().await.context("begin postgres tx")?;
-
-				let row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = $1 AND topic = $2 FOR UPDATE")
-						.bind(client_id)
-						.bind(topic)
-						.fetch_optional(&mut *tx)
-						.await
-						.context("select cursor (postgres)")?;
-
-				let next = row.map(|(c,)| c as u64 + 1).unwrap_or(1);
-				sqlx::query(
-					"INSERT INTO replay_cursors (client_id, topic, cursor) VALUES ($1, $2, $3) \
-					ON CONFLICT (client_id, topic) DO UPDATE SET cursor = EXCLUDED.cursor",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(next as i64)
-				.execute(&mut *tx)
-				.await
-				.context("upsert cursor (postgres)")?;
-
+				for (topic, env, _) in events {
+					sqlx::query(
+						"INSERT INTO replay_topic_events (topic, cursor, payload, created_at) VALUES ($1, $2, $3, NOW())",
+					)
+					.bind(topic)
+					.bind(env.cursor as i64)
+					.bind(env.encode_to_vec())
+					.execute(&mut *tx)
+					.await
+					.context("insert replay event (postgres)")?;
+				}
+				for (topic, cursor) in latest {
+					sqlx::query(
+						"INSERT INTO replay_topic_cursors (topic, cursor) VALUES ($1, $2) \
+						ON CONFLICT (topic) DO UPDATE SET cursor = GREATEST(replay_topic_cursors.cursor, EXCLUDED.cursor)",
+					)
+					.bind(topic)
+					.bind(cursor as i64)
+					.execute(&mut *tx)
+					.await
+					.context("upsert cursor (postgres)")?;
+				}
 				tx.commit().await.context("commit postgres tx")?;
-				Ok(next)
 			}
 			PersistentBackend::Mysql(pool) => {
 				let mut tx = pool.begin().await.context("begin mysql tx")?;
-				let row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = ? AND topic = ? FOR UPDATE")
-						.bind(client_id)
-						.bind(topic)
-						.fetch_optional(&mut *tx)
-						.await
-						.context("select cursor (mysql)")?;
+				for (topic, env, _) in events {
+					sqlx::query(
+						"INSERT INTO replay_topic_events (topic, cursor, payload, created_at) VALUES (?, ?, ?, NOW())",
+					)
+					.bind(topic)
+					.bind(env.cursor as i64)
+					.bind(env.encode_to_vec())
+					.execute(&mut *tx)
+					.await
+					.context("insert replay event (mysql)")?;
+				}
+				for (topic, cursor) in latest {
+					sqlx::query(
+						"INSERT INTO replay_topic_cursors (topic, cursor) VALUES (?, ?) \
+						ON DUPLICATE KEY UPDATE cursor = GREATEST(cursor, VALUES(cursor))",
+					)
+					.bind(topic)
+					.bind(cursor as i64)
+					.execute(&mut *tx)
+					.await
+					.context("upsert cursor (mysql)")?;
+				}
+				tx.commit().await.context("commit mysql tx")?;
+			}
+		}
 
-				let next = row.map(|(c,)| c as u64 + 1).unwrap_or(1);
-				sqlx::query(
-					"INSERT INTO replay_cursors (client_id, topic, cursor) VALUES (?, ?, ?) \
-					ON DUPLICATE KEY UPDATE cursor = VALUES(cursor)",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(next as i64)
-				.execute(&mut *tx)
-				.await
-				.context("upsert cursor (mysql)")?;
+		Ok(())
+	}
 
-				tx.commit().await.context("commit mysql tx")?;
-				Ok(next)
+	async fn trim_topics(&self, dirty: &mut HashMap<String, (u64, ReplayStoreConfig)>) {
+		for (topic, (cursor, cfg)) in dirty.drain() {
+			if let Err(e) = self.trim_topic(&topic, cursor, &cfg).await {
+				warn!(topic = %topic, error = %e, "failed to trim replay log");
 			}
 		}
 	}
-}
 
-#[async_trait::async_trait]
-impl ReplayBackend for PersistentReplayBackend {
-	async fn push_event(
-		&self,
-		client_id: &str,
-		topic: &str,
-		mut env: pb::EventEnvelope,
-		cfg: &ReplayStoreConfig,
-	) -> anyhow::Result<pb::EventEnvelope> {
-		let cursor = self.next_cursor(client_id, topic).await?;
-		env.cursor = cursor;
-		let payload = prost::Message::encode_to_vec(&env);
+	/// Apply the capacity and retention limits to one topic, `cursor` being its newest written event.
+	async fn trim_topic(&self, topic: &str, cursor: u64, cfg: &ReplayStoreConfig) -> anyhow::Result<()> {
 		let cap = cfg.per_topic_capacity;
-		let cap = if cap == 0 { self.per_topic_capacity } else { cap };
-		let retention_secs = cfg.retention_secs;
-
-		match &self.backend {
+		let retention_threshold = cfg.retention_secs.map(|retention| {
+			let now = std::time::SystemTime::now()
+				.duration_since(std::time::UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_secs() as i64;
+			now.saturating_sub(retention as i64)
+		});
+
+		match self {
 			PersistentBackend::Sqlite(pool) => {
-				sqlx::query(
-					"INSERT INTO replay_events (client_id, topic, cursor, payload, created_at) VALUES (?, ?, ?, ?, strftime('%s','now'))",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(cursor as i64)
-				.bind(payload)
-				.execute(pool)
-				.await
-				.context("insert replay event (sqlite)")?;
-
 				if cap > 0 {
 					let threshold = cursor.saturating_sub(cap as u64) as i64;
-					sqlx::query("DELETE FROM replay_events WHERE client_id = ? AND topic = ? AND cursor <= ?")
-						.bind(client_id)
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = ? AND cursor <= ?")
 						.bind(topic)
 						.bind(threshold)
 						.execute(pool)
@@ -347,14 +488,8 @@ impl ReplayBackend for PersistentReplayBackend {
 						.context("trim replay events (sqlite)")?;
 				}
 
-				if let Some(retention) = retention_secs {
-					let now = std::time::SystemTime::now()
-						.duration_since(std::time::UNIX_EPOCH)
-						.unwrap_or_default()
-						.as_secs() as i64;
-					let threshold = now.saturating_sub(retention as i64);
-					sqlx::query("DELETE FROM replay_events WHERE client_id = ? AND topic = ? AND created_at < ?")
-						.bind(client_id)
+				if let Some(threshold) = retention_threshold {
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = ? AND created_at < ?")
 						.bind(topic)
 						.bind(threshold)
 						.execute(pool)
@@ -363,21 +498,9 @@ impl ReplayBackend for PersistentReplayBackend {
 				}
 			}
 			PersistentBackend::Postgres(pool) => {
-				sqlx::query(
-					"INSERT INTO replay_events (client_id, topic, cursor, payload, created_at) VALUES ($1, $2, $3, $4, NOW())",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(cursor as i64)
-				.bind(payload)
-				.execute(pool)
-				.await
-				.context("insert replay event (postgres)")?;
-
 				if cap > 0 {
 					let threshold = cursor.saturating_sub(cap as u64) as i64;
-					sqlx::query("DELETE FROM replay_events WHERE client_id = $1 AND topic = $2 AND cursor <= $3")
-						.bind(client_id)
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = $1 AND cursor <= $2")
 						.bind(topic)
 						.bind(threshold)
 						.execute(pool)
@@ -385,39 +508,19 @@ impl ReplayBackend for PersistentReplayBackend {
 						.context("trim replay events (postgres)")?;
 				}
 
-				if let Some(retention) = retention_secs {
-					let now = std::time::SystemTime::now()
-						.duration_since(std::time::UNIX_EPOCH)
-						.unwrap_or_default()
-						.as_secs() as i64;
-					let threshold = now.saturating_sub(retention as i64);
-					sqlx::query(
-						"DELETE FROM replay_events WHERE client_id = $1 AND topic = $2 AND created_at < to_timestamp($3)",
-					)
-					.bind(client_id)
-					.bind(topic)
-					.bind(threshold)
-					.execute(pool)
-					.await
-					.context("retention trim replay events (postgres)")?;
+				if let Some(threshold) = retention_threshold {
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = $1 AND created_at < to_timestamp($2)")
+						.bind(topic)
+						.bind(threshold)
+						.execute(pool)
+						.await
+						.context("retention trim replay events (postgres)")?;
 				}
 			}
 			PersistentBackend::Mysql(pool) => {
-				sqlx::query(
-					"INSERT INTO replay_events (client_id, topic, cursor, payload, created_at) VALUES (?, ?, ?, ?, NOW())",
-				)
-				.bind(client_id)
-				.bind(topic)
-				.bind(cursor as i64)
-				.bind(payload)
-				.execute(pool)
-				.await
-				.context("insert replay event (mysql)")?;
-
 				if cap > 0 {
 					let threshold = cursor.saturating_sub(cap as u64) as i64;
-					sqlx::query("DELETE FROM replay_events WHERE client_id = ? AND topic = ? AND cursor <= ?")
-						.bind(client_id)
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = ? AND cursor <= ?")
 						.bind(topic)
 						.bind(threshold)
 						.execute(pool)
@@ -425,146 +528,212 @@ impl ReplayBackend for PersistentReplayBackend {
 						.context("trim replay events (mysql)")?;
 				}
 
-				if let Some(retention) = retention_secs {
-					let now = std::time::SystemTime::now()
-						.duration_since(std::time::UNIX_EPOCH)
-						.unwrap_or_default()
-						.as_secs() as i64;
-					let threshold = now.saturating_sub(retention as i64);
-					sqlx::query(
-						"DELETE FROM replay_events WHERE client_id = ? AND topic = ? AND created_at < FROM_UNIXTIME(?)",
-					)
-					.bind(client_id)
-					.bind(topic)
-					.bind(threshold)
-					.execute(pool)
-					.await
-					.context("retention trim replay events (mysql)")?;
+				if let Some(threshold) = retention_threshold {
+					sqlx::query("DELETE FROM replay_topic_events WHERE topic = ? AND created_at < FROM_UNIXTIME(?)")
+						.bind(topic)
+						.bind(threshold)
+						.execute(pool)
+						.await
+						.context("retention trim replay events (mysql)")?;
 				}
 			}
 		}
 
+		Ok(())
+	}
+}
+
+/// Replay `items` (cursors in `(last_cursor, current_cursor]`), or report the replay as unavailable when
+/// some of those events never made it to the database.
+fn contiguous_replay(last_cursor: u64, current_cursor: u64, items: Vec<pb::EventEnvelope>) -> anyhow::Result<ReplayOutcome> {
+	if items.len() as u64 != current_cursor - last_cursor {
+		metrics::counter!("chatty_server_replay_gaps_total").increment(1);
+		return Ok(ReplayOutcome {
+			status: pb::subscription_result::Status::ReplayNotAvailable,
+			current_cursor,
+			items: Vec::new(),
+		});
+	}
+
+	Ok(ReplayOutcome {
+		status: pb::subscription_result::Status::Ok,
+		current_cursor,
+		items,
+	})
+}
+
+fn decode_replay_rows(rows: Vec<(Vec<u8>,)>) -> anyhow::Result<Vec<pb::EventEnvelope>> {
+	let mut items = Vec::with_capacity(rows.len());
+	for (payload,) in rows {
+		let env = pb::EventEnvelope::decode(payload.as_slice()).context("decode replay event")?;
+		items.push(env);
+	}
+	Ok(items)
+}
+
+#[async_trait::async_trait]
+impl ReplayBackend for PersistentReplayBackend {
+	async fn push_event(
+		&self,
+		topic: &str,
+		mut env: pb::EventEnvelope,
+		cfg: &ReplayStoreConfig,
+	) -> anyhow::Result<pb::EventEnvelope> {
+		env.cursor = {
+			let mut cursors = self.cursors.lock().expect("replay cursors");
+			let cursor = cursors.entry(topic.to_string()).or_default();
+			*cursor += 1;
+			*cursor
+		};
+		let mut cfg = cfg.clone();
+		if cfg.per_topic_capacity == 0 {
+			cfg.per_topic_capacity = self.per_topic_capacity;
+		}
+
+		self.writer
+			.send(ReplayWrite::Event {
+				topic: topic.to_string(),
+				env: Box::new(env.clone()),
+				cfg,
+			})
+			.await
+			.map_err(|_| anyhow!("replay writer stopped"))?;
 		Ok(env)
 	}
 
-	async fn replay(&self, client_id: &str, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
+	async fn replay(&self, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
+		// Clients hold cursors handed out in memory, so compare against those rather than the stored cursor,
+		// which lags behind whenever a batch fails to persist.
+		let current_cursor = self.current_cursor(topic);
+		self.flush().await?;
 		match &self.backend {
 			PersistentBackend::Sqlite(pool) => {
-				let cursor_row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = ? AND topic = ?")
-						.bind(client_id)
+				let (oldest,): (Option<i64>,) =
+					sqlx::query_as("SELECT MIN(cursor) FROM replay_topic_events WHERE topic = ?")
 						.bind(topic)
-						.fetch_optional(pool)
+						.fetch_one(pool)
 						.await
-						.context("select cursor (sqlite)")?;
+						.context("select oldest cursor (sqlite)")?;
+
+				let status = replay_status(last_cursor, current_cursor, oldest.map(|c| c as u64));
+				if status != pb::subscription_result::Status::Ok || last_cursor == 0 {
+					return Ok(ReplayOutcome {
+						status,
+						current_cursor,
+						items: Vec::new(),
+					});
+				}
 
-				let current_cursor = cursor_row.map(|(c,)| c as u64).unwrap_or(0);
 				let rows = sqlx::query_as::<_, (Vec<u8>,)>(
-					"SELECT payload FROM replay_events WHERE client_id = ? AND topic = ? AND cursor > ? ORDER BY cursor ASC",
+					"SELECT payload FROM replay_topic_events WHERE topic = ? AND cursor > ? AND cursor <= ? ORDER BY cursor ASC",
 				)
-				.bind(client_id)
 				.bind(topic)
 				.bind(last_cursor as i64)
+				.bind(current_cursor as i64)
 				.fetch_all(pool)
 				.await
 				.context("select replay events (sqlite)")?;
 
-				let mut items = Vec::with_capacity(rows.len());
-				for (payload,) in rows {
-					let env = pb::EventEnvelope::decode(payload.as_slice()).context("decode replay event")?;
-					items.push(env);
-				}
-
-				let status = if last_cursor > 0 && items.is_empty() && current_cursor > 0 {
-					pb::subscription_result::Status::ReplayNotAvailable
-				} else {
-					pb::subscription_result::Status::Ok
-				};
-
-				Ok(ReplayOutcome {
-					status,
-					current_cursor,
-					items,
-				})
+				contiguous_replay(last_cursor, current_cursor, decode_replay_rows(rows)?)
 			}
 			PersistentBackend::Postgres(pool) => {
-				let cursor_row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = $1 AND topic = $2")
-						.bind(client_id)
+				let (oldest,): (Option<i64>,) =
+					sqlx::query_as("SELECT MIN(cursor) FROM replay_topic_events WHERE topic = $1")
 						.bind(topic)
-						.fetch_optional(pool)
+						.fetch_one(pool)
 						.await
-						.context("select cursor (postgres)")?;
+						.context("select oldest cursor (postgres)")?;
+
+				let status = replay_status(last_cursor, current_cursor, oldest.map(|c| c as u64));
+				if status != pb::subscription_result::Status::Ok || last_cursor == 0 {
+					return Ok(ReplayOutcome {
+						status,
+						current_cursor,
+						items: Vec::new(),
+					});
+				}
 
-				let current_cursor = cursor_row.map(|(c,)| c as u64).unwrap_or(0);
 				let rows = sqlx::query_as::<_, (Vec<u8>,)>(
-					"SELECT payload FROM replay_events WHERE client_id = $1 AND topic = $2 AND cursor > $3 ORDER BY cursor ASC",
+					"SELECT payload FROM replay_topic_events WHERE topic = $1 AND cursor > $2 AND cursor <= $3 ORDER BY cursor ASC",
 				)
-				.bind(client_id)
 				.bind(topic)
 				.bind(last_cursor as i64)
+				.bind(current_cursor as i64)
 				.fetch_all(pool)
 				.await
 				.context("select replay events (postgres)")?;
 
-				let mut items = Vec::with_capacity(rows.len());
-				for (payload,) in rows {
-					let env = pb::EventEnvelope::decode(payload.as_slice()).context("decode replay event")?;
-					items.push(env);
-				}
-
-				let status = if last_cursor > 0 && items.is_empty() && current_cursor > 0 {
-					pb::subscription_result::Status::ReplayNotAvailable
-				} else {
-					pb::subscription_result::Status::Ok
-				};
-
-				Ok(ReplayOutcome {
-					status,
-					current_cursor,
-					items,
-				})
+				contiguous_replay(last_cursor, current_cursor, decode_replay_rows(rows)?)
 			}
 			PersistentBackend::Mysql(pool) => {
-				let cursor_row: Option<(i64,)> =
-					sqlx::query_as("SELECT cursor FROM replay_cursors WHERE client_id = ? AND topic = ?")
-						.bind(client_id)
+				let (oldest,): (Option<i64>,) =
+					sqlx::query_as("SELECT MIN(cursor) FROM replay_topic_events WHERE topic = ?")
 						.bind(topic)
-						.fetch_optional(pool)
+						.fetch_one(pool)
 						.await
-						.context("select cursor (mysql)")?;
+						.context("select oldest cursor (mysql)")?;
+
+				let status = replay_status(last_cursor, current_cursor, oldest.map(|c| c as u64));
+				if status != pb::subscription_result::Status::Ok || last_cursor == 0 {
+					return Ok(ReplayOutcome {
+						status,
+						current_cursor,
+						items: Vec::new(),
+					});
+				}
 
-				let current_cursor = cursor_row.map(|(c,)| c as u64).unwrap_or(0);
 				let rows = sqlx::query_as::<_, (Vec<u8>,)>(
-					"SELECT payload FROM replay_events WHERE client_id = ? AND topic = ? AND cursor > ? ORDER BY cursor ASC",
+					"SELECT payload FROM replay_topic_events WHERE topic = ? AND cursor > ? AND cursor <= ? ORDER BY cursor ASC",
 				)
-				.bind(client_id)
 				.bind(topic)
 				.bind(last_cursor as i64)
+				.bind(current_cursor as i64)
 				.fetch_all(pool)
 				.await
 				.context("select replay events (mysql)")?;
 
-				let mut items = Vec::with_capacity(rows.len());
-				for (payload,) in rows {
-					let env = pb::EventEnvelope::decode(payload.as_slice()).context("decode replay event")?;
-					items.push(env);
-				}
-
-				let status = if last_cursor > 0 && items.is_empty() && current_cursor > 0 {
-					pb::subscription_result::Status::ReplayNotAvailable
-				} else {
-					pb::subscription_result::Status::Ok
-				};
-
-				Ok(ReplayOutcome {
-					status,
-					current_cursor,
-					items,
-				})
+				contiguous_replay(last_cursor, current_cursor, decode_replay_rows(rows)?)
 			}
 		}
 	}
+
+	async fn history(&self, topic: &str, before_cursor: u64, limit: usize) -> anyhow::Result<HistoryPage> {
+		self.flush().await?;
+		let before = if before_cursor == 0 { i64::MAX } else { before_cursor as i64 };
+		let rows = match &self.backend {
+			PersistentBackend::Sqlite(pool) => sqlx::query_as::<_, (Vec<u8>,)>(
+				"SELECT payload FROM replay_topic_events WHERE topic = ? AND cursor < ? ORDER BY cursor DESC LIMIT ?",
+			)
+			.bind(topic)
+			.bind(before)
+			.bind(limit as i64)
+			.fetch_all(pool)
+			.await
+			.context("select history events (sqlite)")?,
+			PersistentBackend::Postgres(pool) => sqlx::query_as::<_, (Vec<u8>,)>(
+				"SELECT payload FROM replay_topic_events WHERE topic = $1 AND cursor < $2 ORDER BY cursor DESC LIMIT $3",
+			)
+			.bind(topic)
+			.bind(before)
+			.bind(limit as i64)
+			.fetch_all(pool)
+			.await
+			.context("select history events (postgres)")?,
+			PersistentBackend::Mysql(pool) => sqlx::query_as::<_, (Vec<u8>,)>(
+				"SELECT payload FROM replay_topic_events WHERE topic = ? AND cursor < ? ORDER BY cursor DESC LIMIT ?",
+			)
+			.bind(topic)
+			.bind(before)
+			.bind(limit as i64)
+			.fetch_all(pool)
+			.await
+			.context("select history events (mysql)")?,
+		};
+
+		let mut items = decode_replay_rows(rows)?;
+		items.reverse();
+		Ok(history_page(items, limit))
+	}
 }
 
 #[derive(Clone)]
@@ -606,16 +775,13 @@ impl ReplayService {
 		}
 	}
 
-	pub async fn push_event(
-		&self,
-		client_id: &str,
-		topic: &str,
-		env: pb::EventEnvelope,
-	) -> anyhow::Result<pb::EventEnvelope> {
-		self.backend.push_event(client_id, topic, env, &self.cfg).await
+	/// Append an event to the shared topic log and return it with its assigned cursor.
+	pub async fn push_event(&self, topic: &str, env: pb::EventEnvelope) -> anyhow::Result<pb::EventEnvelope> {
+		self.backend.push_event(topic, env, &self.cfg).await
 	}
 
-	pub async fn replay(&self, client_id: &str, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
+	/// Replay the shared topic log after `last_cursor`.
+	pub async fn replay(&self, topic: &str, last_cursor: u64) -> anyhow::Result<ReplayOutcome> {
 		if !self.enabled {
 			let status = if last_cursor == 0 {
 				pb::subscription_result::Status::Ok
@@ -628,6 +794,14 @@ impl ReplayService {
 				items: Vec::new(),
 			});
 		}
-		self.backend.replay(client_id, topic, last_cursor).await
+		self.backend.replay(topic, last_cursor).await
+	}
+
+	/// Page backwards through the shared topic log for scrollback.
+	pub async fn history(&self, topic: &str, before_cursor: u64, limit: usize) -> anyhow::Result<HistoryPage> {
+		if !self.enabled || limit == 0 {
+			return Ok(HistoryPage::default());
+		}
+		self.backend.history(topic, before_cursor, limit).await
 	}
 }
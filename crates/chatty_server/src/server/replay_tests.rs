@@ -0,0 +1,198 @@
+#![forbid(unsafe_code)]
+
+use chatty_protocol::pb;
+
+use crate::server::replay::{PersistentReplayBackend, ReplayService, ReplayStoreConfig};
+
+fn chat_env(topic: &str, text: &str) -> pb::EventEnvelope {
+	pb::EventEnvelope {
+		topic: topic.to_string(),
+		cursor: 0,
+		server_time_unix_ms: 0,
+		event: Some(pb::event_envelope::Event::ChatMessage(pb::ChatMessageEvent {
+			message: Some(pb::ChatMessage {
+				text: text.to_string(),
+				..Default::default()
+			}),
+			..Default::default()
+		})),
+	}
+}
+
+fn cfg(per_topic_capacity: usize) -> ReplayStoreConfig {
+	ReplayStoreConfig {
+		per_topic_capacity,
+		retention_secs: None,
+	}
+}
+
+async fn assert_shared_log_semantics(replay: &ReplayService) -> anyhow::Result<()> {
+	let topic_a = "room:twitch/a";
+	let topic_b = "room:twitch/b";
+
+	for i in 1..=5u64 {
+		let env = replay.push_event(topic_a, chat_env(topic_a, &format!("a-{i}"))).await?;
+		assert_eq!(env.cursor, i);
+	}
+	let env = replay.push_event(topic_b, chat_env(topic_b, "b-1")).await?;
+	assert_eq!(env.cursor, 1, "cursors are assigned per topic");
+
+	let fresh = replay.replay(topic_a, 0).await?;
+	assert_eq!(fresh.status, pb::subscription_result::Status::Ok);
+	assert_eq!(fresh.current_cursor, 5);
+	assert!(fresh.items.is_empty(), "a fresh subscriber starts live");
+
+	let resumed = replay.replay(topic_a, 3).await?;
+	assert_eq!(resumed.status, pb::subscription_result::Status::Ok);
+	assert_eq!(resumed.items.iter().map(|e| e.cursor).collect::<Vec<_>>(), vec![4, 5]);
+
+	let up_to_date = replay.replay(topic_a, 5).await?;
+	assert_eq!(up_to_date.status, pb::subscription_result::Status::Ok);
+	assert!(up_to_date.items.is_empty());
+
+	let exhausted = replay.replay(topic_a, 1).await?;
+	assert_eq!(exhausted.status, pb::subscription_result::Status::ReplayNotAvailable);
+	assert!(exhausted.items.is_empty());
+
+	let ahead = replay.replay(topic_b, 7).await?;
+	assert_eq!(ahead.status, pb::subscription_result::Status::ReplayNotAvailable);
+
+	Ok(())
+}
+
+async fn assert_history_paging(replay: &ReplayService) -> anyhow::Result<()> {
+	let topic = "room:twitch/history";
+	for i in 1..=5u64 {
+		replay.push_event(topic, chat_env(topic, &format!("h-{i}"))).await?;
+	}
+
+	let newest = replay.history(topic, 0, 2).await?;
+	assert_eq!(newest.items.iter().map(|e| e.cursor).collect::<Vec<_>>(), vec![4, 5]);
+	assert_eq!(newest.next_before_cursor, 4);
+
+	let older = replay.history(topic, newest.next_before_cursor, 2).await?;
+	assert_eq!(older.items.iter().map(|e| e.cursor).collect::<Vec<_>>(), vec![3]);
+	assert_eq!(older.next_before_cursor, 0, "cursors 1-2 were trimmed by the capacity");
+
+	let unknown = replay.history("room:twitch/unknown", 0, 2).await?;
+	assert!(unknown.items.is_empty());
+	assert_eq!(unknown.next_before_cursor, 0);
+
+	Ok(())
+}
+
+#[tokio::test]
+async fn in_memory_log_is_shared_per_topic() -> anyhow::Result<()> {
+	let replay = ReplayService::new_in_memory(cfg(3));
+	assert_shared_log_semantics(&replay).await
+}
+
+#[tokio::test]
+async fn sqlite_log_is_shared_per_topic() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-replay-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let backend = PersistentReplayBackend::connect(&url, 3).await?;
+	let replay = ReplayService::new_persistent(backend, cfg(3));
+	let res = assert_shared_log_semantics(&replay).await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn in_memory_history_pages_backwards() -> anyhow::Result<()> {
+	let replay = ReplayService::new_in_memory(cfg(3));
+	assert_history_paging(&replay).await
+}
+
+#[tokio::test]
+async fn sqlite_history_pages_backwards() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-history-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let backend = PersistentReplayBackend::connect(&url, 3).await?;
+	let replay = ReplayService::new_persistent(backend, cfg(3));
+	let res = assert_history_paging(&replay).await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn sqlite_cursors_continue_after_reconnect() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-cursor-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+	let topic = "room:twitch/cursors";
+
+	let res = async {
+		let replay = ReplayService::new_persistent(PersistentReplayBackend::connect(&url, 3).await?, cfg(3));
+		for _ in 0..2 {
+			replay.push_event(topic, chat_env(topic, "before")).await?;
+		}
+		// Reads flush the batched writer, so the events are on disk once this returns.
+		assert_eq!(replay.replay(topic, 0).await?.current_cursor, 2);
+		drop(replay);
+
+		let replay = ReplayService::new_persistent(PersistentReplayBackend::connect(&url, 3).await?, cfg(3));
+		let env = replay.push_event(topic, chat_env(topic, "after")).await?;
+		assert_eq!(env.cursor, 3, "cursors are seeded from the database");
+		let resumed = replay.replay(topic, 1).await?;
+		assert_eq!(resumed.items.iter().map(|e| e.cursor).collect::<Vec<_>>(), vec![2, 3]);
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn sqlite_replay_reports_gap_when_a_batch_fails_to_persist() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-gap-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+	let topic = "room:twitch/gap";
+
+	let res = async {
+		let replay = ReplayService::new_persistent(PersistentReplayBackend::connect(&url, 10).await?, cfg(10));
+		let raw = sqlx::SqlitePool::connect(&url).await?;
+
+		replay.push_event(topic, chat_env(topic, "stored")).await?;
+		assert_eq!(replay.replay(topic, 0).await?.current_cursor, 1);
+
+		sqlx::query(
+			"CREATE TRIGGER fail_replay_insert BEFORE INSERT ON replay_topic_events \
+			BEGIN SELECT RAISE(ABORT, 'disk full'); END",
+		)
+		.execute(&raw)
+		.await?;
+		let lost = replay.push_event(topic, chat_env(topic, "lost")).await?;
+		assert_eq!(lost.cursor, 2);
+		replay.replay(topic, 0).await?;
+		sqlx::query("DROP TRIGGER fail_replay_insert").execute(&raw).await?;
+
+		let after = replay.push_event(topic, chat_env(topic, "after")).await?;
+		assert_eq!(after.cursor, 3);
+
+		let gap = replay.replay(topic, 1).await?;
+		assert_eq!(gap.status, pb::subscription_result::Status::ReplayNotAvailable);
+		assert_eq!(gap.current_cursor, 3);
+		assert!(gap.items.is_empty());
+
+		let past_gap = replay.replay(topic, 2).await?;
+		assert_eq!(past_gap.status, pb::subscription_result::Status::Ok);
+		assert_eq!(past_gap.items.iter().map(|e| e.cursor).collect::<Vec<_>>(), vec![3]);
+
+		let caught_up = replay.replay(topic, 3).await?;
+		assert_eq!(caught_up.status, pb::subscription_result::Status::Ok);
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
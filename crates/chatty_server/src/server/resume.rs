@@ -0,0 +1,76 @@
+#![forbid(unsafe_code)]
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::server::adapter_manager::UserSessionKey;
+
+/// What a disconnected connection leaves behind for its client to pick up again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParkedSession {
+	/// Connection id the subscriptions stay registered under in `GlobalState` while parked.
+	pub conn_id: u64,
+	pub client_instance_id: String,
+	/// `sub` of the auth token the session was opened with; a resume must present the same subject.
+	pub auth_subject: Option<String>,
+	/// Validated platform identities, kept registered while parked.
+	pub user_sessions: Vec<UserSessionKey>,
+	/// Newest cursor written to the client per topic; replay resumes after it.
+	pub cursors: HashMap<String, u64>,
+}
+
+/// Sessions of dropped connections, claimable with their resume token until the grace period ends.
+pub struct ResumeRegistry {
+	grace: Duration,
+	parked: Mutex<HashMap<String, ParkedSession>>,
+}
+
+impl ResumeRegistry {
+	/// A zero grace period disables resumption.
+	pub fn new(grace: Duration) -> Self {
+		Self {
+			grace,
+			parked: Mutex::new(HashMap::new()),
+		}
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		!self.grace.is_zero()
+	}
+
+	pub fn grace(&self) -> Duration {
+		self.grace
+	}
+
+	/// Fresh unguessable token for a Welcome.
+	pub fn new_token() -> String {
+		uuid::Uuid::new_v4().simple().to_string()
+	}
+
+	/// Park a session under `token`. The caller expires it with [`ResumeRegistry::expire`] once the grace
+	/// period is over.
+	pub fn park(&self, token: String, session: ParkedSession) {
+		self.parked.lock().expect("parked sessions").insert(token, session);
+	}
+
+	/// Claim a parked session. Tokens presented by another client instance or auth subject are refused
+	/// and stay parked for their owner.
+	pub fn claim(&self, token: &str, client_instance_id: &str, auth_subject: Option<&str>) -> Option<ParkedSession> {
+		let mut parked = self.parked.lock().expect("parked sessions");
+		let session = parked.get(token)?;
+		if session.client_instance_id != client_instance_id || session.auth_subject.as_deref() != auth_subject {
+			return None;
+		}
+		parked.remove(token)
+	}
+
+	/// Drop a parked session whose grace period ended; `None` when it was claimed in time.
+	pub fn expire(&self, token: &str) -> Option<ParkedSession> {
+		self.parked.lock().expect("parked sessions").remove(token)
+	}
+
+	pub fn parked_len(&self) -> usize {
+		self.parked.lock().expect("parked sessions").len()
+	}
+}
@@ -0,0 +1,79 @@
+#![forbid(unsafe_code)]
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chatty_domain::Platform;
+use chatty_protocol::pb;
+
+use crate::server::adapter_manager::UserSessionKey;
+use crate::server::auth::AuthClaims;
+use crate::server::resume::{ParkedSession, ResumeRegistry};
+use crate::server::state::GlobalState;
+
+fn parked(conn_id: u64) -> ParkedSession {
+	ParkedSession {
+		conn_id,
+		client_instance_id: "instance-a".to_string(),
+		auth_subject: Some("overlay".to_string()),
+		user_sessions: vec![UserSessionKey {
+			platform: Platform::Twitch,
+			user_id: "42".to_string(),
+		}],
+		cursors: HashMap::from([("room:twitch/demo".to_string(), 7)]),
+	}
+}
+
+#[test]
+fn parked_session_is_claimed_once_by_its_owner() {
+	let registry = ResumeRegistry::new(Duration::from_secs(60));
+	assert!(registry.is_enabled());
+	assert!(!ResumeRegistry::new(Duration::ZERO).is_enabled());
+
+	let token = ResumeRegistry::new_token();
+	assert_ne!(token, ResumeRegistry::new_token());
+	registry.park(token.clone(), parked(1));
+
+	assert!(registry.claim(&token, "instance-b", Some("overlay")).is_none());
+	assert!(registry.claim(&token, "instance-a", Some("other")).is_none());
+	assert!(registry.claim(&token, "instance-a", None).is_none());
+	assert_eq!(registry.parked_len(), 1, "foreign claims leave the session parked");
+
+	assert_eq!(registry.claim(&token, "instance-a", Some("overlay")), Some(parked(1)));
+	assert!(registry.claim(&token, "instance-a", Some("overlay")).is_none());
+	assert!(registry.expire(&token).is_none(), "a claimed session does not expire");
+}
+
+#[test]
+fn resume_conn_moves_subscriptions_and_drops_topics_out_of_scope() {
+	let mut state = GlobalState::default();
+	let sub = |topics: &[&str]| pb::Subscribe {
+		subs: topics
+			.iter()
+			.map(|t| pb::Subscription {
+				topic: t.to_string(),
+				last_cursor: 0,
+			})
+			.collect(),
+	};
+	state.handle_subscribe(1, sub(&["room:twitch/a", "room:kick/b"]), None);
+	state.handle_subscribe(2, sub(&["room:kick/b"]), None);
+
+	let claims = AuthClaims {
+		sub: "overlay".to_string(),
+		exp: u64::MAX,
+		topics: Some(vec!["room:twitch/*".to_string()]),
+		..Default::default()
+	};
+	let (resumed, topics_to_leave) = state.resume_conn(1, 3, Some(&claims));
+	assert_eq!(resumed, vec!["room:twitch/a"]);
+	assert!(topics_to_leave.is_empty(), "room:kick/b is still used by connection 2");
+	assert!(state.topics_for_conn(1).is_empty());
+	assert_eq!(state.topics_for_conn(3).len(), 1);
+
+	let refcounts = state.topic_refcounts_snapshot();
+	assert_eq!(refcounts.get("room:twitch/a"), Some(&1));
+	assert_eq!(refcounts.get("room:kick/b"), Some(&1));
+
+	assert_eq!(state.resume_conn(1, 4, None), (Vec::new(), Vec::new()));
+}
@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use chatty_domain::RoomKey;
 use chatty_platform::{AdapterStatus, IngestEvent};
+use chatty_protocol::pb;
 use tokio::sync::{Mutex, mpsc};
 use tracing::debug;
 
@@ -38,7 +39,9 @@ impl Default for RoomHubConfig {
 pub enum RoomHubItem {
 	Ingest(Box<IngestEvent>),
 
-	#[allow(dead_code)]
+	/// Event already appended to the shared replay log (carries its topic cursor).
+	Event(Box<pb::EventEnvelope>),
+
 	Status(AdapterStatus),
 
 	/// Indicates the subscriber is lagging and items were dropped.
@@ -112,6 +115,22 @@ impl RoomHub {
 		self.publish_to_room(room, RoomHubItem::Status(status)).await;
 	}
 
+	/// Publish an adapter status event to subscribers of every room on the status' platform.
+	pub async fn publish_platform_status(&self, status: AdapterStatus) {
+		let rooms: Vec<RoomKey> = {
+			let inner = self.inner.lock().await;
+			inner
+				.rooms
+				.keys()
+				.filter(|room| room.platform == status.platform)
+				.cloned()
+				.collect()
+		};
+		for room in rooms {
+			self.publish_status(room, status.clone()).await;
+		}
+	}
+
 	/// Internal publish helper used by the server routing layer.
 	pub(crate) async fn publish_to_room(&self, room: RoomKey, item: RoomHubItem) {
 		let mut inner = self.inner.lock().await;
@@ -1,9 +1,9 @@
 #![forbid(unsafe_code)]
 
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use chatty_domain::{Platform, RoomId, RoomKey};
-use chatty_platform::{ChatMessage, IngestEvent, IngestPayload, UserRef};
+use chatty_platform::{AdapterStatus, ChatMessage, IngestEvent, IngestPayload, UserRef};
 use tokio::time::timeout;
 
 use crate::server::room_hub::{RoomHub, RoomHubConfig, RoomHubItem};
@@ -123,3 +123,41 @@ async fn bounded_queue_drops_and_emits_lagged_marker() {
 		other => panic!("expected Lagged marker, got: {other:?}"),
 	}
 }
+
+#[tokio::test]
+async fn platform_status_reaches_every_room_of_that_platform() {
+	let hub = RoomHub::new(RoomHubConfig {
+		subscriber_queue_capacity: 16,
+		debug_logs: false,
+	});
+
+	let mut rx_a = hub.subscribe_room(room(Platform::Twitch, "a")).await;
+	let mut rx_b = hub.subscribe_room(room(Platform::Twitch, "b")).await;
+	let mut rx_kick = hub.subscribe_room(room(Platform::Kick, "a")).await;
+
+	hub.publish_platform_status(AdapterStatus {
+		platform: Platform::Twitch,
+		connected: false,
+		detail: "eventsub ws ended".to_string(),
+		last_error: None,
+		time: SystemTime::now(),
+	})
+	.await;
+
+	for rx in [&mut rx_a, &mut rx_b] {
+		let item = timeout(Duration::from_millis(250), rx.recv())
+			.await
+			.expect("expected to receive within timeout")
+			.expect("channel open");
+		match item {
+			RoomHubItem::Status(st) => {
+				assert!(!st.connected);
+				assert_eq!(st.detail, "eventsub ws ended");
+			}
+			other => panic!("expected Status item, got: {other:?}"),
+		}
+	}
+
+	let got_unexpected = timeout(Duration::from_millis(50), rx_kick.recv()).await;
+	assert!(got_unexpected.is_err(), "kick room unexpectedly received a twitch status");
+}
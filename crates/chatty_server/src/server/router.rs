@@ -2,11 +2,15 @@
 
 use std::sync::Arc;
 
-use chatty_platform::IngestEvent;
-use tokio::sync::broadcast;
+use chatty_domain::RoomTopic;
+use chatty_platform::{AdapterStatus, IngestEvent};
+use tokio::sync::{broadcast, mpsc};
 use tracing::{debug, info, warn};
 
-use crate::server::adapter_manager::IngestBroadcastRx;
+use crate::server::adapter_manager::{IngestBroadcastRx, StatusBroadcastRx};
+use crate::server::chat_log::{ChatLogRecord, ChatLogService, spawn_chat_log_writer};
+use crate::server::connection::map_ingest_event;
+use crate::server::replay::ReplayService;
 use crate::server::room_hub::{RoomHub, RoomHubItem};
 
 /// Settings for the ingest router.
@@ -26,21 +30,37 @@ impl Default for RouterConfig {
 	}
 }
 
-/// Router that consumes the global ingest broadcast and republishes into the per-room hub.
-#[derive(Debug)]
+/// Router that consumes the global ingest broadcast, sequences events into the shared per-topic
+/// replay log, republishes them into the per-room hub and queues chat content for the chat log.
+/// Persistence happens on background writers, so routing never waits on the database.
+/// Adapter status updates are fanned out to every room of the reporting platform.
 pub struct IngestRouter {
 	cfg: RouterConfig,
 	room_hub: RoomHub,
+	replay_service: Arc<ReplayService>,
+	chat_log_tx: Option<mpsc::Sender<ChatLogRecord>>,
 	ingest_rx: IngestBroadcastRx,
+	status_rx: StatusBroadcastRx,
 }
 
 impl IngestRouter {
-	/// Create a router from an existing ingest receiver and a `RoomHub`.
-	pub fn new(ingest_rx: IngestBroadcastRx, room_hub: RoomHub, cfg: RouterConfig) -> Self {
+	/// Create a router from existing ingest and status receivers and a `RoomHub`.
+	pub fn new(
+		ingest_rx: IngestBroadcastRx,
+		status_rx: StatusBroadcastRx,
+		room_hub: RoomHub,
+		replay_service: Arc<ReplayService>,
+		chat_log: Arc<ChatLogService>,
+		cfg: RouterConfig,
+	) -> Self {
+		let chat_log_tx = chat_log.is_enabled().then(|| spawn_chat_log_writer(chat_log));
 		Self {
 			cfg,
 			room_hub,
+			replay_service,
+			chat_log_tx,
 			ingest_rx,
+			status_rx,
 		}
 	}
 
@@ -48,45 +68,99 @@ impl IngestRouter {
 	pub fn from_adapter_manager(
 		adapter_manager: &crate::server::adapter_manager::AdapterManager,
 		room_hub: RoomHub,
+		replay_service: Arc<ReplayService>,
+		chat_log: Arc<ChatLogService>,
 		cfg: RouterConfig,
 	) -> Self {
-		Self::new(adapter_manager.subscribe_ingest(), room_hub, cfg)
+		Self::new(
+			adapter_manager.subscribe_ingest(),
+			adapter_manager.subscribe_status(),
+			room_hub,
+			replay_service,
+			chat_log,
+			cfg,
+		)
 	}
 
 	/// Run the routing loop until the upstream broadcast is closed.
 	pub async fn run(mut self) {
 		info!("ingest router started");
 
+		let mut status_open = true;
 		loop {
-			let ingest = match self.ingest_rx.recv().await {
-				Ok(ev) => ev,
-				Err(broadcast::error::RecvError::Lagged(n)) => {
-					if self.cfg.log_upstream_lag {
-						warn!(
-							lagged = n,
-							"ingest router lagged on global broadcast; some ingest events may be dropped before routing"
-						);
+			tokio::select! {
+				res = self.ingest_rx.recv() => match res {
+					Ok(ingest) => self.route_ingest(ingest).await,
+					Err(broadcast::error::RecvError::Lagged(n)) => {
+						if self.cfg.log_upstream_lag {
+							warn!(
+								lagged = n,
+								"ingest router lagged on global broadcast; some ingest events may be dropped before routing"
+							);
+						}
 					}
-					continue;
-				}
-				Err(broadcast::error::RecvError::Closed) => {
-					info!("ingest router exiting (upstream ingest broadcast closed)");
-					break;
+					Err(broadcast::error::RecvError::Closed) => {
+						info!("ingest router exiting (upstream ingest broadcast closed)");
+						break;
+					}
+				},
+				res = self.status_rx.recv(), if status_open => match res {
+					Ok(status) => self.route_status(status).await,
+					Err(broadcast::error::RecvError::Lagged(n)) => {
+						debug!(lagged = n, "ingest router lagged on adapter status broadcast");
+					}
+					Err(broadcast::error::RecvError::Closed) => status_open = false,
+				},
+			}
+		}
+	}
+
+	async fn route_status(&self, status: AdapterStatus) {
+		if self.cfg.debug_log_events {
+			debug!(
+				platform = %status.platform,
+				connected = status.connected,
+				"routing adapter status to room hub"
+			);
+		}
+		self.room_hub.publish_platform_status(status).await;
+	}
+
+	async fn route_ingest(&self, ingest: IngestEvent) {
+		if self.cfg.debug_log_events {
+			debug!(
+				room = %ingest.room,
+				platform = %ingest.platform,
+				"routing ingest event to room hub"
+			);
+		}
+
+		let room = ingest.room.clone();
+		let topic = RoomTopic::format(&room);
+		let log_record = self
+			.chat_log_tx
+			.as_ref()
+			.and_then(|_| ChatLogRecord::from_ingest(&topic, &ingest));
+		for env in map_ingest_event(&topic, ingest) {
+			// Cursors are assigned once per topic here, in memory; every subscriber shares them.
+			let env = match self.replay_service.push_event(&topic, env.clone()).await {
+				Ok(env) => env,
+				Err(e) => {
+					warn!(topic = %topic, error = %e, "failed to append event to replay log; delivering without cursor");
+					env
 				}
 			};
 
-			if self.cfg.debug_log_events {
-				debug!(
-					room = %ingest.room,
-					platform = %ingest.platform,
-					"routing ingest event to room hub"
-				);
-			}
-
 			self.room_hub
-				.publish_to_room(ingest.room.clone(), RoomHubItem::Ingest(Box::new(ingest)))
+				.publish_to_room(room.clone(), RoomHubItem::Event(Box::new(env)))
 				.await;
 		}
+
+		if let (Some(record), Some(tx)) = (log_record, &self.chat_log_tx)
+			&& tx.send(record).await.is_err()
+		{
+			warn!(topic = %topic, "chat log writer stopped; dropping event");
+		}
 	}
 
 	/// Access the hub.
@@ -100,9 +174,11 @@ impl IngestRouter {
 pub fn spawn_ingest_router(
 	adapter_manager: Arc<crate::server::adapter_manager::AdapterManager>,
 	room_hub: RoomHub,
+	replay_service: Arc<ReplayService>,
+	chat_log: Arc<ChatLogService>,
 	cfg: RouterConfig,
 ) -> RoomHub {
-	let router = IngestRouter::from_adapter_manager(&adapter_manager, room_hub.clone(), cfg);
+	let router = IngestRouter::from_adapter_manager(&adapter_manager, room_hub.clone(), replay_service, chat_log, cfg);
 
 	tokio::spawn(async move {
 		router.run().await;
@@ -0,0 +1,46 @@
+#![forbid(unsafe_code)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// Server-wide shutdown broadcast. Every connection watches it so it can tell its client to
+/// reconnect before the server stops.
+#[derive(Clone)]
+pub struct ShutdownSignal {
+	/// Reconnect delay to suggest to clients, set once shutdown begins.
+	tx: Arc<watch::Sender<Option<Duration>>>,
+}
+
+impl Default for ShutdownSignal {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl ShutdownSignal {
+	pub fn new() -> Self {
+		let (tx, _rx) = watch::channel(None);
+		Self { tx: Arc::new(tx) }
+	}
+
+	/// Begin shutdown; later calls keep the first reconnect delay.
+	pub fn trigger(&self, reconnect_after: Duration) {
+		self.tx.send_if_modified(|current| {
+			if current.is_some() {
+				return false;
+			}
+			*current = Some(reconnect_after);
+			true
+		});
+	}
+
+	pub fn is_triggered(&self) -> bool {
+		self.tx.borrow().is_some()
+	}
+
+	pub fn subscribe(&self) -> watch::Receiver<Option<Duration>> {
+		self.tx.subscribe()
+	}
+}
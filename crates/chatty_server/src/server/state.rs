@@ -4,6 +4,8 @@ use std::collections::{HashMap, HashSet};
 
 use chatty_protocol::pb;
 
+use crate::server::auth::AuthClaims;
+
 /// Shared server state.
 #[derive(Debug, Default)]
 pub struct GlobalState {
@@ -49,8 +51,47 @@ impl GlobalState {
 		topics_to_leave
 	}
 
+	/// Moves the subscriptions of a parked connection to `to` without touching refcounts.
+	///
+	/// Topics the resuming connection's `claims` no longer allow are dropped; returns the kept topics and
+	/// the topics to leave.
+	pub fn resume_conn(&mut self, from: u64, to: u64, claims: Option<&AuthClaims>) -> (Vec<String>, Vec<String>) {
+		let Some(prev) = self.subs_by_conn.remove(&from) else {
+			return (Vec::new(), Vec::new());
+		};
+
+		let (kept, dropped): (HashSet<String>, HashSet<String>) = prev
+			.into_iter()
+			.partition(|topic| claims.is_none_or(|c| c.allows_topic(topic)));
+
+		let mut topics_to_leave = Vec::new();
+		for topic in dropped {
+			if let Some(rc) = self.topic_refcounts.get_mut(&topic) {
+				if *rc <= 1 {
+					self.topic_refcounts.remove(&topic);
+					topics_to_leave.push(topic);
+				} else {
+					*rc -= 1;
+				}
+			}
+		}
+
+		let mut resumed: Vec<String> = kept.iter().cloned().collect();
+		resumed.sort();
+		self.subs_by_conn.entry(to).or_default().extend(kept);
+
+		(resumed, topics_to_leave)
+	}
+
 	/// Applies a `Subscribe` request and returns results and join topics.
-	pub fn handle_subscribe(&mut self, conn_id: u64, sub: pb::Subscribe) -> (Vec<pb::SubscriptionResult>, Vec<String>) {
+	///
+	/// Topics outside the scope of the connection's token `claims` are rejected as not authorized.
+	pub fn handle_subscribe(
+		&mut self,
+		conn_id: u64,
+		sub: pb::Subscribe,
+		claims: Option<&AuthClaims>,
+	) -> (Vec<pb::SubscriptionResult>, Vec<String>) {
 		let mut results = Vec::with_capacity(sub.subs.len());
 		let mut topics_to_join = Vec::new();
 
@@ -59,7 +100,17 @@ impl GlobalState {
 		for s in sub.subs {
 			let topic = s.topic;
 
-			let (status, detail) = if validate_topic(&topic) {
+			let (status, detail) = if !validate_topic(&topic) {
+				(
+					pb::subscription_result::Status::InvalidTopic,
+					"expected topic starting with \"room:\"".to_string(),
+				)
+			} else if claims.is_some_and(|c| !c.allows_topic(&topic)) {
+				(
+					pb::subscription_result::Status::NotAuthorized,
+					"token does not allow this topic".to_string(),
+				)
+			} else {
 				let was_new = topic_set.insert(topic.clone());
 				if was_new {
 					let rc = self.topic_refcounts.entry(topic.clone()).or_insert(0);
@@ -71,11 +122,6 @@ impl GlobalState {
 				}
 
 				(pb::subscription_result::Status::Ok, String::new())
-			} else {
-				(
-					pb::subscription_result::Status::InvalidTopic,
-					"expected topic starting with \"room:\"".to_string(),
-				)
 			};
 
 			results.push(pb::SubscriptionResult {
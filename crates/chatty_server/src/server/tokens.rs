@@ -0,0 +1,319 @@
+#![forbid(unsafe_code)]
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, anyhow};
+use sqlx::QueryBuilder;
+
+use crate::server::auth::AuthClaims;
+
+/// How long a "not revoked" answer is trusted before the database is asked again. Revocations made
+/// by another process (`chatty_server token revoke`) take effect on running servers within this window.
+const REVOCATION_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Cached answers kept before stale entries are pruned.
+const REVOCATION_CACHE_MAX: usize = 4096;
+
+/// Issued and revoked token ids. Revocation is only possible for tokens that carry a `jti`.
+pub struct TokenStore {
+	backend: Option<TokenBackend>,
+	/// `jti -> (revoked, checked_at)`; revoked answers never go stale.
+	revocations: Mutex<HashMap<String, (bool, Instant)>>,
+}
+
+enum TokenBackend {
+	Sqlite(sqlx::SqlitePool),
+	Postgres(sqlx::PgPool),
+	Mysql(sqlx::MySqlPool),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IssuedToken {
+	pub jti: String,
+	pub subject: String,
+	pub key_id: Option<String>,
+	pub role: Option<String>,
+	pub topics: Vec<String>,
+	pub commands: Vec<String>,
+	pub issued_at_unix: i64,
+	pub expires_at_unix: i64,
+	pub revoked_at_unix: Option<i64>,
+}
+
+type IssuedTokenDbRow = (
+	String,
+	String,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	Option<String>,
+	i64,
+	i64,
+	Option<i64>,
+);
+
+impl TokenStore {
+	/// Connect and apply migrations.
+	pub async fn connect(database_url: &str) -> anyhow::Result<Self> {
+		let backend = if database_url.starts_with("sqlite:") {
+			let pool = sqlx::SqlitePool::connect(database_url).await.context("connect sqlite")?;
+			sqlx::migrate!("migrations/sqlite")
+				.run(&pool)
+				.await
+				.context("run sqlite migrations")?;
+			TokenBackend::Sqlite(pool)
+		} else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
+			let pool = sqlx::PgPool::connect(database_url).await.context("connect postgres")?;
+			sqlx::migrate!("migrations/postgres")
+				.run(&pool)
+				.await
+				.context("run postgres migrations")?;
+			TokenBackend::Postgres(pool)
+		} else if database_url.starts_with("mysql:") || database_url.starts_with("mariadb:") {
+			let pool = sqlx::MySqlPool::connect(database_url).await.context("connect mysql")?;
+			sqlx::migrate!("migrations/mysql")
+				.run(&pool)
+				.await
+				.context("run mysql migrations")?;
+			TokenBackend::Mysql(pool)
+		} else {
+			return Err(anyhow!("unsupported database_url for tokens"));
+		};
+		Ok(Self {
+			backend: Some(backend),
+			revocations: Mutex::new(HashMap::new()),
+		})
+	}
+
+	/// Without persistence nothing is recorded and no token is ever considered revoked.
+	pub fn disabled() -> Self {
+		Self {
+			backend: None,
+			revocations: Mutex::new(HashMap::new()),
+		}
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		self.backend.is_some()
+	}
+
+	pub async fn record_issued(&self, claims: &AuthClaims, issued_at_unix: i64) -> anyhow::Result<()> {
+		let Some(backend) = &self.backend else {
+			return Ok(());
+		};
+		let jti = claims.jti.as_deref().ok_or_else(|| anyhow!("token has no jti"))?;
+		let role = claims.role.map(|r| r.as_str());
+		let topics = claims.topics.as_ref().map(|t| t.join(","));
+		let commands = claims.commands.as_ref().map(|c| c.join(","));
+		let expires_at = i64::try_from(claims.exp).unwrap_or(i64::MAX);
+
+		match backend {
+			TokenBackend::Sqlite(pool) => {
+				sqlx::query(
+					"INSERT INTO issued_tokens (jti, subject, key_id, role, topics, commands, issued_at, expires_at) \
+					VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+				)
+				.bind(jti)
+				.bind(&claims.sub)
+				.bind(claims.kid.as_deref())
+				.bind(role)
+				.bind(topics.as_deref())
+				.bind(commands.as_deref())
+				.bind(issued_at_unix)
+				.bind(expires_at)
+				.execute(pool)
+				.await
+				.context("insert issued_tokens (sqlite)")?;
+			}
+			TokenBackend::Postgres(pool) => {
+				sqlx::query(
+					"INSERT INTO issued_tokens (jti, subject, key_id, role, topics, commands, issued_at, expires_at) \
+					VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+				)
+				.bind(jti)
+				.bind(&claims.sub)
+				.bind(claims.kid.as_deref())
+				.bind(role)
+				.bind(topics.as_deref())
+				.bind(commands.as_deref())
+				.bind(issued_at_unix)
+				.bind(expires_at)
+				.execute(pool)
+				.await
+				.context("insert issued_tokens (postgres)")?;
+			}
+			TokenBackend::Mysql(pool) => {
+				sqlx::query(
+					"INSERT INTO issued_tokens (jti, subject, key_id, role, topics, commands, issued_at, expires_at) \
+					VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+				)
+				.bind(jti)
+				.bind(&claims.sub)
+				.bind(claims.kid.as_deref())
+				.bind(role)
+				.bind(topics.as_deref())
+				.bind(commands.as_deref())
+				.bind(issued_at_unix)
+				.bind(expires_at)
+				.execute(pool)
+				.await
+				.context("insert issued_tokens (mysql)")?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Revoke `jti`; returns false when it was already revoked. Ids that were never recorded as
+	/// issued can be revoked too, e.g. tokens minted before the issue log existed.
+	pub async fn revoke(&self, jti: &str, revoked_at_unix: i64) -> anyhow::Result<bool> {
+		let Some(backend) = &self.backend else {
+			return Err(anyhow!("token revocation requires persistence"));
+		};
+
+		let affected = match backend {
+			TokenBackend::Sqlite(pool) => {
+				sqlx::query("INSERT OR IGNORE INTO revoked_tokens (jti, revoked_at) VALUES (?, ?)")
+					.bind(jti)
+					.bind(revoked_at_unix)
+					.execute(pool)
+					.await
+					.context("insert revoked_tokens (sqlite)")?
+					.rows_affected()
+			}
+			TokenBackend::Postgres(pool) => {
+				sqlx::query("INSERT INTO revoked_tokens (jti, revoked_at) VALUES ($1, $2) ON CONFLICT (jti) DO NOTHING")
+					.bind(jti)
+					.bind(revoked_at_unix)
+					.execute(pool)
+					.await
+					.context("insert revoked_tokens (postgres)")?
+					.rows_affected()
+			}
+			TokenBackend::Mysql(pool) => sqlx::query("INSERT IGNORE INTO revoked_tokens (jti, revoked_at) VALUES (?, ?)")
+				.bind(jti)
+				.bind(revoked_at_unix)
+				.execute(pool)
+				.await
+				.context("insert revoked_tokens (mysql)")?
+				.rows_affected(),
+		};
+
+		self.remember(jti, true);
+		Ok(affected > 0)
+	}
+
+	/// Whether `jti` has been revoked, answered from the in-memory cache when possible.
+	pub async fn is_revoked(&self, jti: &str) -> anyhow::Result<bool> {
+		let Some(backend) = &self.backend else {
+			return Ok(false);
+		};
+
+		if let Some(&(revoked, checked_at)) = self.revocations.lock().expect("revocation cache").get(jti)
+			&& (revoked || checked_at.elapsed() < REVOCATION_CACHE_TTL)
+		{
+			return Ok(revoked);
+		}
+
+		let row: Option<(i64,)> = match backend {
+			TokenBackend::Sqlite(pool) => sqlx::query_as("SELECT revoked_at FROM revoked_tokens WHERE jti = ?")
+				.bind(jti)
+				.fetch_optional(pool)
+				.await
+				.context("select revoked_tokens (sqlite)")?,
+			TokenBackend::Postgres(pool) => sqlx::query_as("SELECT revoked_at FROM revoked_tokens WHERE jti = $1")
+				.bind(jti)
+				.fetch_optional(pool)
+				.await
+				.context("select revoked_tokens (postgres)")?,
+			TokenBackend::Mysql(pool) => sqlx::query_as("SELECT revoked_at FROM revoked_tokens WHERE jti = ?")
+				.bind(jti)
+				.fetch_optional(pool)
+				.await
+				.context("select revoked_tokens (mysql)")?,
+		};
+
+		let revoked = row.is_some();
+		self.remember(jti, revoked);
+		Ok(revoked)
+	}
+
+	fn remember(&self, jti: &str, revoked: bool) {
+		let mut cache = self.revocations.lock().expect("revocation cache");
+		if cache.len() >= REVOCATION_CACHE_MAX {
+			cache.retain(|_, (revoked, checked_at)| *revoked || checked_at.elapsed() < REVOCATION_CACHE_TTL);
+		}
+		cache.insert(jti.to_string(), (revoked, Instant::now()));
+	}
+
+	/// Issued tokens, newest first, with their revocation time if any.
+	pub async fn list(&self, limit: usize) -> anyhow::Result<Vec<IssuedToken>> {
+		let Some(backend) = &self.backend else {
+			return Err(anyhow!("token store disabled"));
+		};
+
+		let rows: Vec<IssuedTokenDbRow> = match backend {
+			TokenBackend::Sqlite(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_token_select(&mut qb, limit);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list issued_tokens (sqlite)")?
+			}
+			TokenBackend::Postgres(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_token_select(&mut qb, limit);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list issued_tokens (postgres)")?
+			}
+			TokenBackend::Mysql(pool) => {
+				let mut qb = QueryBuilder::new("");
+				push_token_select(&mut qb, limit);
+				qb.build_query_as()
+					.fetch_all(pool)
+					.await
+					.context("list issued_tokens (mysql)")?
+			}
+		};
+
+		Ok(rows.into_iter().map(token_from_row).collect())
+	}
+}
+
+fn push_token_select<'a, DB>(qb: &mut QueryBuilder<'a, DB>, limit: usize)
+where
+	DB: sqlx::Database,
+	i64: sqlx::Encode<'a, DB> + sqlx::Type<DB>,
+{
+	qb.push(
+		"SELECT i.jti, i.subject, i.key_id, i.role, i.topics, i.commands, i.issued_at, i.expires_at, r.revoked_at \
+		FROM issued_tokens i LEFT JOIN revoked_tokens r ON r.jti = i.jti \
+		ORDER BY i.issued_at DESC, i.jti DESC LIMIT ",
+	)
+	.push_bind(limit.max(1) as i64);
+}
+
+fn split_list(list: Option<String>) -> Vec<String> {
+	list.map(|l| l.split(',').filter(|s| !s.is_empty()).map(str::to_string).collect())
+		.unwrap_or_default()
+}
+
+fn token_from_row(row: IssuedTokenDbRow) -> IssuedToken {
+	let (jti, subject, key_id, role, topics, commands, issued_at_unix, expires_at_unix, revoked_at_unix) = row;
+	IssuedToken {
+		jti,
+		subject,
+		key_id,
+		role,
+		topics: split_list(topics),
+		commands: split_list(commands),
+		issued_at_unix,
+		expires_at_unix,
+		revoked_at_unix,
+	}
+}
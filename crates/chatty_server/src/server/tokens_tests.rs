@@ -0,0 +1,65 @@
+#![forbid(unsafe_code)]
+
+use crate::server::auth::{AuthClaims, TokenRole};
+use crate::server::tokens::TokenStore;
+
+fn claims(jti: &str, exp: u64) -> AuthClaims {
+	AuthClaims {
+		sub: "overlay".to_string(),
+		exp,
+		jti: Some(jti.to_string()),
+		kid: Some("k1".to_string()),
+		topics: Some(vec!["room:twitch/*".to_string(), "room:kick/chan".to_string()]),
+		role: Some(TokenRole::Moderator),
+		..Default::default()
+	}
+}
+
+#[tokio::test]
+async fn sqlite_tokens_record_list_and_revoke() -> anyhow::Result<()> {
+	let path = std::env::temp_dir().join(format!("chatty-tokens-test-{}.db", std::process::id()));
+	let _ = std::fs::remove_file(&path);
+	let url = format!("sqlite://{}?mode=rwc", path.display());
+
+	let res = async {
+		let store = TokenStore::connect(&url).await?;
+		store.record_issued(&claims("a", 2_000), 1_000).await?;
+		store.record_issued(&claims("b", 3_000), 2_000).await?;
+		assert!(!store.is_revoked("a").await?);
+
+		assert!(store.revoke("a", 2_500).await?);
+		assert!(!store.revoke("a", 2_600).await?, "revoking twice is a no-op");
+		assert!(store.is_revoked("a").await?, "the local revocation is visible immediately");
+
+		let listed = store.list(10).await?;
+		assert_eq!(listed.iter().map(|t| t.jti.as_str()).collect::<Vec<_>>(), vec!["b", "a"]);
+		assert_eq!(listed[0].key_id.as_deref(), Some("k1"));
+		assert_eq!(listed[0].role.as_deref(), Some("moderator"));
+		assert_eq!(listed[0].topics, vec!["room:twitch/*", "room:kick/chan"]);
+		assert!(listed[0].commands.is_empty());
+		assert_eq!(listed[0].revoked_at_unix, None);
+		assert_eq!(listed[1].revoked_at_unix, Some(2_500));
+		assert_eq!(store.list(1).await?.len(), 1);
+
+		// Another process (e.g. `token revoke`) shares the database but not the cache.
+		let other = TokenStore::connect(&url).await?;
+		assert!(other.is_revoked("a").await?);
+		assert!(other.revoke("never-issued", 3_000).await?);
+
+		anyhow::Ok(())
+	}
+	.await;
+
+	let _ = std::fs::remove_file(&path);
+	res
+}
+
+#[tokio::test]
+async fn disabled_store_never_revokes() -> anyhow::Result<()> {
+	let store = TokenStore::disabled();
+	store.record_issued(&claims("a", 2_000), 1_000).await?;
+	assert!(!store.is_revoked("a").await?);
+	assert!(store.revoke("a", 1_500).await.is_err());
+	assert!(store.list(10).await.is_err());
+	Ok(())
+}
@@ -10,3 +10,9 @@ pub fn unix_ms_now() -> i64 {
 		.unwrap_or(Duration::from_secs(0))
 		.as_millis() as i64
 }
+
+/// Convert a `SystemTime` to Unix milliseconds (0 if before the epoch).
+#[inline]
+pub fn unix_ms_from(t: SystemTime) -> i64 {
+	t.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as i64).unwrap_or(0)
+}
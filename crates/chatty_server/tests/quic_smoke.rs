@@ -145,6 +145,7 @@ async fn run_minimal_server(
 				server_time_unix_ms: unix_ms_now(),
 				max_frame_bytes: DEFAULT_MAX_FRAME_SIZE as u32,
 				selected_codec: pb::Codec::Protobuf as i32,
+				..Default::default()
 			})),
 		},
 	)
@@ -297,6 +298,7 @@ async fn quic_smoke_client_receives_synthetic_event() -> anyhow::Result<()> {
 		server_addr: Some(server_addr),
 		client_name: "chatty-test-client".to_string(),
 		client_instance_id: "test-instance".to_string(),
+		tls_insecure_skip_verify: true,
 		..ClientConfigV1::default()
 	};
 